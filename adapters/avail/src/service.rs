@@ -7,9 +7,11 @@ use async_trait::async_trait;
 use avail_subxt::api::runtime_types::sp_core::bounded::bounded_vec::BoundedVec;
 use avail_subxt::primitives::AvailExtrinsicParams;
 use avail_subxt::{api, AvailConfig};
+use bytes::Bytes;
+use codec::Encode;
 use pin_project::pin_project;
 use reqwest::StatusCode;
-use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec};
 use sov_rollup_interface::services::da::DaService;
 use sp_core::crypto::Pair as PairTrait;
 use sp_keyring::sr25519::sr25519::Pair;
@@ -20,6 +22,7 @@ use tracing::info;
 use crate::avail::{Confidence, ExtrinsicsData};
 use crate::spec::block::AvailBlock;
 use crate::spec::header::AvailHeader;
+use crate::spec::proofs::{CompletenessProof, InclusionProof};
 use crate::spec::transaction::AvailBlobTransaction;
 use crate::spec::DaLayerSpec;
 use crate::verifier::Verifier;
@@ -189,7 +192,7 @@ impl DaService for DaProvider {
 
     type FilteredBlock = AvailBlock;
     type HeaderStream = AvailBlockHeaderStream;
-    type TransactionId = ();
+    type TransactionId = u64;
     type Error = anyhow::Error;
 
     // Make an RPC call to the node to get the block at the given height, if one exists.
@@ -214,12 +217,22 @@ impl DaService for DaProvider {
             None => return Err(anyhow!("Hash for height: {} not found.", height)),
         };
 
-        let header = match { node_client.rpc().header(Some(hash)).await? } {
-            Some(i) => i,
-            None => return Err(anyhow!("Header for hash: {} not found.", hash)),
+        let block = match { node_client.rpc().block(Some(hash)).await? } {
+            Some(i) => i.block,
+            None => return Err(anyhow!("Block for hash: {} not found.", hash)),
         };
 
-        let header = AvailHeader::new(header, hash);
+        // The extrinsics root commits to every extrinsic of the block, so all of them are needed to prove
+        // against it. The node serves them without their length prefix, which is part of their encoding.
+        // They are kept as they are, without being decoded, so that extrinsics of unknown types don't
+        // prevent the block from being read.
+        let extrinsics = block
+            .extrinsics
+            .into_iter()
+            .map(|extrinsic| Bytes::from(extrinsic.0.encode()))
+            .collect();
+
+        let header = AvailHeader::new(block.header, hash);
         let transactions: Result<Vec<AvailBlobTransaction>, anyhow::Error> = appdata
             .extrinsics
             .iter()
@@ -230,6 +243,7 @@ impl DaService for DaProvider {
         Ok(AvailBlock {
             header,
             transactions,
+            extrinsics,
         })
     }
 
@@ -265,30 +279,33 @@ impl DaService for DaProvider {
     }
 
     // Extract the blob transactions relevant to a particular rollup from a block.
-    // NOTE: The avail light client is expected to be run in app specific mode, and hence the
-    // transactions in the block are already filtered and retrieved by light client.
+    // NOTE: The avail light client is expected to be run in app specific mode, but the transactions are
+    // still filtered by app id, so that blobs of other rollups never reach the state transition function.
     fn extract_relevant_blobs(
         &self,
         block: &Self::FilteredBlock,
     ) -> Vec<<Self::Spec as DaSpec>::BlobTransaction> {
-        block.transactions.clone()
+        block.transactions_for_app(self.app_id)
     }
 
     // Extract the inclusion and completeness proof for filtered block provided.
     // The output of this method will be passed to the verifier.
-    // NOTE: The light client here has already completed DA sampling and verification of inclusion and soundness.
     async fn get_extraction_proof(
         &self,
-        _block: &Self::FilteredBlock,
-        _blobs: &[<Self::Spec as DaSpec>::BlobTransaction],
+        block: &Self::FilteredBlock,
+        blobs: &[<Self::Spec as DaSpec>::BlobTransaction],
     ) -> (
         <Self::Spec as DaSpec>::InclusionMultiProof,
         <Self::Spec as DaSpec>::CompletenessProof,
     ) {
-        ((), ())
+        (
+            InclusionProof::for_block(block, blobs),
+            CompletenessProof::from_block(block),
+        )
     }
 
-    async fn send_transaction(&self, blob: &[u8]) -> Result<(), Self::Error> {
+    // Returns the height of the block the transaction has been included in.
+    async fn send_transaction(&self, blob: &[u8]) -> Result<Self::TransactionId, Self::Error> {
         let data_transfer = api::tx()
             .data_availability()
             .submit_data(BoundedVec(blob.to_vec()));
@@ -303,7 +320,33 @@ impl DaService for DaProvider {
 
         info!("Transaction submitted: {:#?}", h.extrinsic_hash());
 
-        Ok(())
+        let block_hash = h.wait_for_in_block().await?.block_hash();
+        let header = self
+            .node_client
+            .rpc()
+            .header(Some(block_hash))
+            .await?
+            .ok_or(anyhow!("Header for hash: {} not found.", block_hash))?;
+
+        Ok(header.number as u64)
+    }
+
+    async fn wait_for_finalization(&self, tx_id: &Self::TransactionId) -> Result<u64, Self::Error> {
+        let height = *tx_id;
+        let start_time = std::time::Instant::now();
+
+        while self.get_last_finalized_block_header().await?.height() < height {
+            if start_time.elapsed() >= self.polling_timeout {
+                return Err(anyhow!(
+                    "Block at height: {} not finalized after timeout: {}s",
+                    height,
+                    self.polling_timeout.as_secs()
+                ));
+            }
+            tokio::time::sleep(self.polling_interval).await;
+        }
+
+        Ok(height)
     }
 
     async fn send_aggregated_zk_proof(&self, _proof: &[u8]) -> Result<u64, Self::Error> {
@@ -1,3 +1,4 @@
+use bytes::Bytes;
 use serde::{Deserialize, Serialize};
 use sov_rollup_interface::da::BlockHeaderTrait;
 use sov_rollup_interface::services::da::SlotData;
@@ -10,6 +11,19 @@ use crate::verifier::ChainValidityCondition;
 pub struct AvailBlock {
     pub header: AvailHeader,
     pub transactions: Vec<AvailBlobTransaction>,
+    /// Every SCALE-encoded extrinsic of the block, including the data submissions of other app ids.
+    pub extrinsics: Vec<Bytes>,
+}
+
+impl AvailBlock {
+    /// The blob transactions of the block which were submitted under the given app id.
+    pub fn transactions_for_app(&self, app_id: u32) -> Vec<AvailBlobTransaction> {
+        self.transactions
+            .iter()
+            .filter(|tx| tx.app_id() == app_id)
+            .cloned()
+            .collect()
+    }
 }
 
 impl SlotData for AvailBlock {
@@ -38,3 +52,48 @@ impl SlotData for AvailBlock {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use primitive_types::H256;
+    use sov_rollup_interface::da::BlobReaderTrait;
+
+    use super::*;
+    use crate::spec::address::AvailAddress;
+    use crate::spec::header::Header;
+
+    #[test]
+    fn test_transactions_for_app() {
+        let tx = |app_id: u32, hash: u8| {
+            AvailBlobTransaction::new_for_testing(
+                &[hash],
+                [hash; 32],
+                AvailAddress::from([app_id as u8; 32]),
+                app_id,
+            )
+        };
+        let header = Header {
+            parent_hash: H256::repeat_byte(1),
+            number: 10,
+            state_root: H256::repeat_byte(2),
+            extrinsics_root: H256::repeat_byte(3),
+            data_root: H256::repeat_byte(4),
+        };
+        let block = AvailBlock {
+            header: AvailHeader::from_parts(header, H256::repeat_byte(5)),
+            transactions: vec![tx(1, 1), tx(2, 2), tx(1, 3), tx(2, 4)],
+            extrinsics: vec![],
+        };
+
+        let hashes = |app_id| {
+            block
+                .transactions_for_app(app_id)
+                .iter()
+                .map(|tx| tx.hash()[0])
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(hashes(1), vec![1, 3]);
+        assert_eq!(hashes(2), vec![2, 4]);
+        assert!(hashes(3).is_empty());
+    }
+}
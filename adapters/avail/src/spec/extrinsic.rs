@@ -0,0 +1,186 @@
+//! A decoder for the `submit_data` extrinsics which carry the data of every app. It doesn't depend
+//! on the runtime types of the Avail node, so that the verifier can use it inside of the zkVM.
+
+/// The version byte of a signed extrinsic in the v4 format.
+const SIGNED_EXTRINSIC_V4: u8 = 0b1000_0100;
+/// The index of the `DataAvailability` pallet and of its `submit_data` call.
+const SUBMIT_DATA_CALL: [u8; 2] = [29, 1];
+
+/// The fields of a `submit_data` extrinsic which the rollup depends on.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Submission<'a> {
+    pub sender: [u8; 32],
+    pub app_id: u32,
+    pub data: &'a [u8],
+}
+
+/// Decodes a SCALE-encoded extrinsic, including its length prefix. Returns `None` if the extrinsic
+/// isn't a signed `submit_data` call, which is the case for the inherents of every block.
+pub(crate) fn decode_submission(mut encoded: &[u8]) -> Option<Submission<'_>> {
+    let input = &mut encoded;
+    if usize::try_from(read_compact(input)?).ok()? != input.len() {
+        return None;
+    }
+    if take(input, 1)? != [SIGNED_EXTRINSIC_V4] {
+        return None;
+    }
+
+    // Like `AvailBlobTransaction::new`, only `MultiAddress::Id` senders are supported.
+    if take(input, 1)? != [0] {
+        return None;
+    }
+    let sender = take(input, 32)?.try_into().ok()?;
+    // Ed25519 and Sr25519 signatures are 64 bytes long, ECDSA ones 65.
+    let signature_len = match take(input, 1)?[0] {
+        0 | 1 => 64,
+        2 => 65,
+        _ => return None,
+    };
+    take(input, signature_len)?;
+    // An immortal era is a single zero byte, a mortal one takes two bytes.
+    if take(input, 1)? != [0] {
+        take(input, 1)?;
+    }
+    let _nonce = read_compact(input)?;
+    let _tip = read_compact(input)?;
+    let app_id = u32::try_from(read_compact(input)?).ok()?;
+
+    if take(input, 2)? != SUBMIT_DATA_CALL {
+        return None;
+    }
+    let data_len = usize::try_from(read_compact(input)?).ok()?;
+    let data = take(input, data_len)?;
+    if !input.is_empty() {
+        return None;
+    }
+
+    Some(Submission {
+        sender,
+        app_id,
+        data,
+    })
+}
+
+/// Appends the SCALE compact encoding of `value` to `output`.
+pub(crate) fn encode_compact(value: u64, output: &mut Vec<u8>) {
+    match value {
+        0..=0x3f => output.push((value as u8) << 2),
+        0x40..=0x3fff => output.extend_from_slice(&((value as u16) << 2 | 0b01).to_le_bytes()),
+        0x4000..=0x3fff_ffff => {
+            output.extend_from_slice(&((value as u32) << 2 | 0b10).to_le_bytes())
+        }
+        _ => {
+            let bytes = value.to_le_bytes();
+            let len = bytes.len() - (value.leading_zeros() / 8) as usize;
+            output.push(((len - 4) as u8) << 2 | 0b11);
+            output.extend_from_slice(&bytes[..len]);
+        }
+    }
+}
+
+fn read_compact(input: &mut &[u8]) -> Option<u128> {
+    let first = *input.first()?;
+    let value = match first & 0b11 {
+        0b00 => {
+            take(input, 1)?;
+            u128::from(first >> 2)
+        }
+        0b01 => u128::from(u16::from_le_bytes(take(input, 2)?.try_into().ok()?) >> 2),
+        0b10 => u128::from(u32::from_le_bytes(take(input, 4)?.try_into().ok()?) >> 2),
+        _ => {
+            take(input, 1)?;
+            let len = usize::from(first >> 2) + 4;
+            if len > 16 {
+                return None;
+            }
+            let mut bytes = [0u8; 16];
+            bytes[..len].copy_from_slice(take(input, len)?);
+            u128::from_le_bytes(bytes)
+        }
+    };
+    Some(value)
+}
+
+fn take<'a>(input: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if input.len() < len {
+        return None;
+    }
+    let (head, tail) = input.split_at(len);
+    *input = tail;
+    Some(head)
+}
+
+/// Encodes a `submit_data` extrinsic with an immortal era and a dummy Sr25519 signature.
+#[cfg(test)]
+pub(crate) fn encode_submission(sender: [u8; 32], app_id: u32, data: &[u8]) -> Vec<u8> {
+    let mut extrinsic = vec![SIGNED_EXTRINSIC_V4, 0];
+    extrinsic.extend_from_slice(&sender);
+    extrinsic.push(1);
+    extrinsic.extend_from_slice(&[1u8; 64]);
+    // The era, nonce and tip.
+    extrinsic.extend_from_slice(&[0, 0, 0]);
+    encode_compact(app_id.into(), &mut extrinsic);
+    extrinsic.extend_from_slice(&SUBMIT_DATA_CALL);
+    encode_compact(data.len() as u64, &mut extrinsic);
+    extrinsic.extend_from_slice(data);
+
+    let mut encoded = Vec::with_capacity(extrinsic.len() + 4);
+    encode_compact(extrinsic.len() as u64, &mut encoded);
+    encoded.extend_from_slice(&extrinsic);
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compact_round_trip() {
+        for value in [
+            0,
+            1,
+            63,
+            64,
+            16383,
+            16384,
+            1 << 30,
+            u32::MAX as u64,
+            u64::MAX,
+        ] {
+            let mut encoded = Vec::new();
+            encode_compact(value, &mut encoded);
+            let input = &mut &encoded[..];
+            assert_eq!(read_compact(input), Some(u128::from(value)));
+            assert!(input.is_empty());
+        }
+    }
+
+    #[test]
+    fn test_decode_submission() {
+        let encoded = encode_submission([7u8; 32], 300, b"blob data");
+        assert_eq!(
+            decode_submission(&encoded),
+            Some(Submission {
+                sender: [7u8; 32],
+                app_id: 300,
+                data: b"blob data",
+            })
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_other_extrinsics() {
+        let encoded = encode_submission([7u8; 32], 1, b"blob data");
+        assert_eq!(decode_submission(&encoded[..encoded.len() - 1]), None);
+
+        let mut unsigned = encoded.clone();
+        unsigned[2] = 0b0000_0100;
+        assert_eq!(decode_submission(&unsigned), None);
+
+        // Change the call index to another call of the pallet.
+        let mut other_call = encoded.clone();
+        let call_index = encoded.len() - b"blob data".len() - 2;
+        other_call[call_index] = 0;
+        assert_eq!(decode_submission(&other_call), None);
+    }
+}
@@ -26,18 +26,25 @@ pub struct AvailHeader {
     pub header: Header,
 }
 
-#[cfg(feature = "native")]
 impl AvailHeader {
+    #[cfg(feature = "native")]
     pub fn new(header: SubxtHeader, hash: H256) -> Self {
-        Self {
-            hash: AvailHash::new(hash),
-            header: Header {
+        Self::from_parts(
+            Header {
                 parent_hash: header.parent_hash,
                 number: header.number,
                 state_root: header.state_root,
                 data_root: header.data_root(),
                 extrinsics_root: header.extrinsics_root,
             },
+            hash,
+        )
+    }
+
+    pub fn from_parts(header: Header, hash: H256) -> Self {
+        Self {
+            hash: AvailHash::new(hash),
+            header,
         }
     }
 }
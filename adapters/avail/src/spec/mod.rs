@@ -4,13 +4,22 @@ use crate::verifier::ChainValidityCondition;
 
 pub mod address;
 pub mod block;
+pub(crate) mod extrinsic;
 mod hash;
 pub mod header;
+pub mod proofs;
 pub mod transaction;
 
 #[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
 pub struct DaLayerSpec;
 
+/// The parameters of the rollup which are baked into its verifier.
+#[derive(serde::Serialize, serde::Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct ChainParams {
+    /// The id of the application the rollup submits its blobs under.
+    pub app_id: u32,
+}
+
 impl DaSpec for DaLayerSpec {
     type SlotHash = hash::AvailHash;
 
@@ -22,9 +31,9 @@ impl DaSpec for DaLayerSpec {
 
     type ValidityCondition = ChainValidityCondition;
 
-    type InclusionMultiProof = ();
+    type InclusionMultiProof = proofs::InclusionProof;
 
-    type CompletenessProof = ();
+    type CompletenessProof = proofs::CompletenessProof;
 
-    type ChainParams = ();
+    type ChainParams = ChainParams;
 }
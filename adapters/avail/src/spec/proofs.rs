@@ -0,0 +1,216 @@
+use bytes::Bytes;
+use serde::{Deserialize, Serialize};
+
+use super::block::AvailBlock;
+use super::extrinsic::encode_compact;
+use super::transaction::AvailBlobTransaction;
+
+/// The position of each relevant blob among the extrinsics of its block.
+#[derive(Debug, Default, PartialEq, Clone, Serialize, Deserialize)]
+pub struct InclusionProof {
+    pub extrinsic_indices: Vec<u32>,
+}
+
+impl InclusionProof {
+    pub fn for_block(block: &AvailBlock, blobs: &[AvailBlobTransaction]) -> Self {
+        let mut extrinsic_indices = Vec::with_capacity(blobs.len());
+        let mut next_extrinsic = 0;
+        for blob in blobs {
+            let extrinsic_index = block.extrinsics[next_extrinsic..]
+                .iter()
+                .position(|extrinsic| extrinsic == blob.extrinsic())
+                .map(|i| i + next_extrinsic)
+                .expect("Relevant blobs must be submitted in the block they are extracted from");
+            extrinsic_indices.push(extrinsic_index as u32);
+            next_extrinsic = extrinsic_index + 1;
+        }
+        Self { extrinsic_indices }
+    }
+}
+
+/// Every extrinsic of a block, in order, from which the verifier rebuilds the `extrinsics_root`
+/// of the block header and finds the data submitted under each app id.
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
+pub struct CompletenessProof {
+    pub extrinsics: Vec<Bytes>,
+}
+
+impl CompletenessProof {
+    pub fn from_block(block: &AvailBlock) -> Self {
+        Self {
+            extrinsics: block.extrinsics.clone(),
+        }
+    }
+}
+
+/// The leaf committed to by Avail's data root for a data submission: the keccak-256 hash of the data.
+pub fn data_leaf(data: &[u8]) -> [u8; 32] {
+    sp_core_hashing::keccak_256(data)
+}
+
+/// The root of the keccak-256 binary Merkle tree which Avail commits to as the `data_root` of a header.
+/// Nodes are paired from the left and an unpaired last node is promoted to the next level unchanged.
+pub fn data_root(leaves: &[[u8; 32]]) -> [u8; 32] {
+    if leaves.is_empty() {
+        return [0u8; 32];
+    }
+
+    let mut level = leaves.to_vec();
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|nodes| match nodes {
+                [left, right] => {
+                    let mut combined = [0u8; 64];
+                    combined[..32].copy_from_slice(left);
+                    combined[32..].copy_from_slice(right);
+                    sp_core_hashing::keccak_256(&combined)
+                }
+                [node] => *node,
+                _ => unreachable!("chunks of two have one or two nodes"),
+            })
+            .collect();
+    }
+    level[0]
+}
+
+const EMPTY_TRIE: u8 = 0;
+const LEAF_NODE: u8 = 0b01 << 6;
+const BRANCH_NODE: u8 = 0b10 << 6;
+const BRANCH_NODE_WITH_VALUE: u8 = 0b11 << 6;
+
+/// The root which a Substrate header commits to as its `extrinsics_root`: the root of the base-16
+/// Patricia-Merkle trie with blake2-256 hashes, which stores the `i`th SCALE-encoded extrinsic
+/// under the compact encoding of `i`. Values are always stored inline, as in Substrate's
+/// `StateVersion::V0`.
+pub fn extrinsics_root<E: AsRef<[u8]>>(extrinsics: &[E]) -> [u8; 32] {
+    let mut entries: Vec<(Vec<u8>, &[u8])> = extrinsics
+        .iter()
+        .enumerate()
+        .map(|(i, extrinsic)| {
+            let mut key = Vec::new();
+            encode_compact(i as u64, &mut key);
+            let nibbles = key
+                .iter()
+                .flat_map(|byte| [byte >> 4, byte & 0x0f])
+                .collect();
+            (nibbles, extrinsic.as_ref())
+        })
+        .collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if entries.is_empty() {
+        return sp_core_hashing::blake2_256(&[EMPTY_TRIE]);
+    }
+    sp_core_hashing::blake2_256(&encode_trie_node(&entries, 0))
+}
+
+/// Encodes the trie node holding the sorted `entries`, whose keys share their first `depth` nibbles.
+fn encode_trie_node(entries: &[(Vec<u8>, &[u8])], depth: usize) -> Vec<u8> {
+    let (first_key, first_value) = &entries[0];
+    if entries.len() == 1 {
+        let mut node = trie_node_header(LEAF_NODE, &first_key[depth..]);
+        encode_compact(first_value.len() as u64, &mut node);
+        node.extend_from_slice(first_value);
+        return node;
+    }
+
+    // The keys are sorted, so the nibbles shared by the first and the last key are shared by all of them.
+    let last_key = &entries[entries.len() - 1].0;
+    let split = depth
+        + first_key[depth..]
+            .iter()
+            .zip(&last_key[depth..])
+            .take_while(|(a, b)| a == b)
+            .count();
+    let (value, mut children) = if first_key.len() == split {
+        (Some(*first_value), &entries[1..])
+    } else {
+        (None, entries)
+    };
+
+    let kind = match value {
+        Some(_) => BRANCH_NODE_WITH_VALUE,
+        None => BRANCH_NODE,
+    };
+    let mut node = trie_node_header(kind, &first_key[depth..split]);
+    let bitmap_start = node.len();
+    node.extend_from_slice(&[0, 0]);
+    if let Some(value) = value {
+        encode_compact(value.len() as u64, &mut node);
+        node.extend_from_slice(value);
+    }
+
+    let mut bitmap = 0u16;
+    while let Some((key, _)) = children.first() {
+        let nibble = key[split];
+        let count = children
+            .iter()
+            .take_while(|(key, _)| key[split] == nibble)
+            .count();
+        bitmap |= 1 << nibble;
+
+        // Children shorter than a hash are inlined into their parent.
+        let child = encode_trie_node(&children[..count], split + 1);
+        if child.len() < 32 {
+            encode_compact(child.len() as u64, &mut node);
+            node.extend_from_slice(&child);
+        } else {
+            encode_compact(32, &mut node);
+            node.extend_from_slice(&sp_core_hashing::blake2_256(&child));
+        }
+        children = &children[count..];
+    }
+    node[bitmap_start..bitmap_start + 2].copy_from_slice(&bitmap.to_le_bytes());
+    node
+}
+
+/// The header of a trie node followed by its partial key, with an odd nibble out in its own byte.
+fn trie_node_header(kind: u8, partial_key: &[u8]) -> Vec<u8> {
+    let mut header = Vec::new();
+    // The length of the partial key is stored in the six low bits of the first byte, and
+    // continued in the following bytes if it doesn't fit.
+    if partial_key.len() < 63 {
+        header.push(kind | partial_key.len() as u8);
+    } else {
+        header.push(kind | 63);
+        let mut rest = partial_key.len() - 63;
+        while rest >= 255 {
+            header.push(255);
+            rest -= 255;
+        }
+        header.push(rest as u8);
+    }
+
+    if partial_key.len() % 2 == 1 {
+        header.push(partial_key[0]);
+    }
+    for pair in partial_key[partial_key.len() % 2..].chunks(2) {
+        header.push(pair[0] << 4 | pair[1]);
+    }
+    header
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use sp_trie::TrieConfiguration;
+
+    use super::*;
+
+    #[test]
+    fn test_extrinsics_root_matches_substrate() {
+        // Enough extrinsics for both one and two byte keys, and for hashed and inlined children.
+        for count in [0, 1, 2, 3, 16, 17, 64, 65, 200] {
+            let extrinsics: Vec<Vec<u8>> =
+                (0..count).map(|i| vec![i as u8; (i * 7) % 50]).collect();
+            let expected = sp_trie::LayoutV0::<sp_core::Blake2Hasher>::ordered_trie_root(
+                extrinsics.iter().map(|e| &e[..]),
+            );
+            assert_eq!(
+                extrinsics_root(&extrinsics),
+                expected.to_fixed_bytes(),
+                "{count} extrinsics"
+            );
+        }
+    }
+}
@@ -12,6 +12,8 @@ use serde::{Deserialize, Serialize};
 use sov_rollup_interface::da::{BlobReaderTrait, CountedBufReader};
 
 use super::address::AvailAddress;
+#[cfg(test)]
+use super::extrinsic::decode_submission;
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
 
@@ -19,6 +21,9 @@ pub struct AvailBlobTransaction {
     blob: CountedBufReader<Bytes>,
     hash: [u8; 32],
     address: AvailAddress,
+    app_id: u32,
+    /// The SCALE-encoded extrinsic which submitted the blob.
+    extrinsic: Bytes,
 }
 
 impl BlobReaderTrait for AvailBlobTransaction {
@@ -32,6 +37,13 @@ impl BlobReaderTrait for AvailBlobTransaction {
         self.hash
     }
 
+    // The blob data is the last field of the encoded `submit_data` call, so checking that the extrinsic ends
+    // with it binds the data to the hash of the extrinsic.
+    fn verify_hash(&self) -> bool {
+        Self::extrinsic_hash(&self.extrinsic) == self.hash
+            && self.extrinsic.ends_with(&self.full_data())
+    }
+
     fn verified_data(&self) -> &[u8] {
         self.blob.accumulator()
     }
@@ -50,9 +62,11 @@ impl BlobReaderTrait for AvailBlobTransaction {
 impl AvailBlobTransaction {
     #[cfg(feature = "native")]
     pub fn new(unchecked_extrinsic: &AppUncheckedExtrinsic) -> anyhow::Result<Self> {
-        let address = match &unchecked_extrinsic.signature {
+        let (address, app_id) = match &unchecked_extrinsic.signature {
             //TODO: Handle other types of MultiAddress.
-            Some((subxt::utils::MultiAddress::Id(id), _, _)) => AvailAddress::from(id.clone().0),
+            Some((subxt::utils::MultiAddress::Id(id), _, extra)) => {
+                (AvailAddress::from(id.clone().0), extra.app_id.0)
+            }
             _ => {
                 return Err(anyhow!(
                     "Unsigned extrinsic being used to create AvailBlobTransaction."
@@ -70,13 +84,67 @@ impl AvailBlobTransaction {
             }
         };
 
+        let extrinsic = unchecked_extrinsic.encode();
         Ok(AvailBlobTransaction {
-            hash: sp_core_hashing::blake2_256(&unchecked_extrinsic.encode()),
+            hash: Self::extrinsic_hash(&extrinsic),
             address,
             blob,
+            app_id,
+            extrinsic: Bytes::from(extrinsic),
         })
     }
 
+    /// The id of the application the extrinsic was submitted under.
+    pub fn app_id(&self) -> u32 {
+        self.app_id
+    }
+
+    /// The SCALE-encoded extrinsic which submitted the blob.
+    pub(crate) fn extrinsic(&self) -> &Bytes {
+        &self.extrinsic
+    }
+
+    /// The hash of a transaction is the blake2 hash of its SCALE-encoded extrinsic.
+    pub(crate) fn extrinsic_hash(encoded_extrinsic: &[u8]) -> [u8; 32] {
+        sp_core_hashing::blake2_256(encoded_extrinsic)
+    }
+
+    /// The whole data of the blob, regardless of how much of it has been read so far.
+    pub(crate) fn full_data(&self) -> Vec<u8> {
+        let mut blob = self.blob.clone();
+        blob.advance(blob.total_len());
+        blob.accumulator().to_vec()
+    }
+
+    #[cfg(test)]
+    pub(crate) fn new_for_testing(
+        data: &[u8],
+        hash: [u8; 32],
+        address: AvailAddress,
+        app_id: u32,
+    ) -> Self {
+        AvailBlobTransaction {
+            blob: CountedBufReader::<Bytes>::new(Bytes::copy_from_slice(data)),
+            hash,
+            address,
+            app_id,
+            extrinsic: Bytes::copy_from_slice(data),
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn from_encoded_submission(extrinsic: &[u8]) -> Self {
+        let submission =
+            decode_submission(extrinsic).expect("The extrinsic must be a submit_data call");
+        AvailBlobTransaction {
+            blob: CountedBufReader::<Bytes>::new(Bytes::copy_from_slice(submission.data)),
+            hash: Self::extrinsic_hash(extrinsic),
+            address: AvailAddress::from(submission.sender),
+            app_id: submission.app_id,
+            extrinsic: Bytes::copy_from_slice(extrinsic),
+        }
+    }
+
     pub fn combine_hash(&self, hash: [u8; 32]) -> [u8; 32] {
         let mut combined_hashes: Vec<u8> = Vec::with_capacity(64);
         combined_hashes.extend_from_slice(hash.as_ref());
@@ -85,3 +153,104 @@ impl AvailBlobTransaction {
         sp_core_hashing::blake2_256(&combined_hashes)
     }
 }
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use codec::Decode;
+
+    use super::*;
+
+    const GOLDEN_DATA: &[u8] = b"sovereign-sdk avail golden blob";
+
+    /// A signed `DataAvailability::submit_data` extrinsic, SCALE-encoded: its length, the version
+    /// byte of a signed v4 extrinsic, `MultiAddress::Id([7; 32])`, an sr25519 signature of `[1; 64]`,
+    /// an immortal era, nonce 5, tip 0, app id 1, then the call (pallet 29, call 1) with `GOLDEN_DATA`.
+    const GOLDEN_EXTRINSIC: &[u8] = &[
+        0x25, 0x02, 0x84, 0x00, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07,
+        0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x07,
+        0x07, 0x07, 0x07, 0x07, 0x07, 0x07, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01,
+        0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x01, 0x00, 0x14, 0x00, 0x04,
+        0x1d, 0x01, 0x7c, 0x73, 0x6f, 0x76, 0x65, 0x72, 0x65, 0x69, 0x67, 0x6e, 0x2d, 0x73, 0x64,
+        0x6b, 0x20, 0x61, 0x76, 0x61, 0x69, 0x6c, 0x20, 0x67, 0x6f, 0x6c, 0x64, 0x65, 0x6e, 0x20,
+        0x62, 0x6c, 0x6f, 0x62,
+    ];
+
+    /// `blake2_256(GOLDEN_EXTRINSIC)`
+    const EXPECTED_HASH: [u8; 32] = [
+        0x6c, 0xf7, 0xf0, 0xc7, 0x0f, 0x23, 0x6a, 0x5d, 0x44, 0x6e, 0xee, 0x4d, 0x4b, 0x32, 0x30,
+        0x53, 0xfc, 0x4c, 0xd6, 0x4a, 0xba, 0x9a, 0x6e, 0x33, 0x28, 0x60, 0x1e, 0xa4, 0x5f, 0xf8,
+        0xd9, 0x19,
+    ];
+
+    /// `blake2_256([0; 32] ++ EXPECTED_HASH)`
+    const EXPECTED_COMBINED_HASH: [u8; 32] = [
+        0xbb, 0xb8, 0x9d, 0x55, 0x03, 0xce, 0x42, 0xcc, 0x2a, 0x18, 0x38, 0x60, 0x1a, 0x4a, 0xb3,
+        0xb0, 0x6b, 0x6c, 0x8e, 0x01, 0xec, 0x0b, 0xb3, 0x20, 0x49, 0x43, 0x5f, 0x7a, 0xa7, 0xb8,
+        0x9c, 0x8d,
+    ];
+
+    /// `blake2_256(EXPECTED_COMBINED_HASH ++ EXPECTED_HASH)`
+    const EXPECTED_TWICE_COMBINED_HASH: [u8; 32] = [
+        0xdb, 0x16, 0xe4, 0x2b, 0xed, 0x32, 0x5b, 0xee, 0x91, 0xf8, 0x8f, 0x94, 0x35, 0xa1, 0x0c,
+        0x25, 0x96, 0xb5, 0x0d, 0xa2, 0x6f, 0x63, 0xa8, 0x67, 0xce, 0x61, 0x65, 0xf5, 0xff, 0x14,
+        0xd9, 0xa7,
+    ];
+
+    fn golden_transaction() -> AvailBlobTransaction {
+        let extrinsic = AppUncheckedExtrinsic::decode(&mut &GOLDEN_EXTRINSIC[..])
+            .expect("the golden extrinsic must decode");
+        AvailBlobTransaction::new(&extrinsic).expect("the golden extrinsic is a submit_data call")
+    }
+
+    #[test]
+    fn test_golden_transaction_fields() {
+        let tx = golden_transaction();
+
+        assert_eq!(tx.sender(), AvailAddress::from([7u8; 32]));
+        assert_eq!(tx.app_id(), 1);
+        assert_eq!(tx.full_data(), GOLDEN_DATA);
+        assert_eq!(tx.extrinsic.as_ref(), GOLDEN_EXTRINSIC);
+    }
+
+    #[test]
+    fn test_golden_extrinsic_decodes_without_runtime_types() {
+        let submission = decode_submission(GOLDEN_EXTRINSIC).expect("a submit_data extrinsic");
+
+        assert_eq!(submission.sender, [7u8; 32]);
+        assert_eq!(submission.app_id, 1);
+        assert_eq!(submission.data, GOLDEN_DATA);
+    }
+
+    #[test]
+    fn test_hash_is_stable() {
+        assert_eq!(golden_transaction().hash(), EXPECTED_HASH);
+    }
+
+    #[test]
+    fn test_combine_hash_is_stable() {
+        let tx = golden_transaction();
+
+        let combined = tx.combine_hash([0u8; 32]);
+        assert_eq!(combined, EXPECTED_COMBINED_HASH);
+        assert_eq!(tx.combine_hash(combined), EXPECTED_TWICE_COMBINED_HASH);
+    }
+
+    #[test]
+    fn test_verify_hash() {
+        assert!(golden_transaction().verify_hash());
+    }
+
+    #[test]
+    fn test_verify_hash_rejects_tampering() {
+        let mut wrong_hash = golden_transaction();
+        wrong_hash.hash = [0u8; 32];
+        assert!(!wrong_hash.verify_hash());
+
+        let mut wrong_data = golden_transaction();
+        wrong_data.blob = CountedBufReader::<Bytes>::new(Bytes::from_static(b"swapped"));
+        assert!(!wrong_data.verify_hash());
+    }
+}
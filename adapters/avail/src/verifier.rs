@@ -1,10 +1,14 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
-use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec, DaVerifier};
+use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait, DaSpec, DaVerifier};
 use sov_rollup_interface::zk::ValidityCondition;
 use thiserror::Error;
 
-use crate::spec::DaLayerSpec;
+use crate::spec::address::AvailAddress;
+use crate::spec::extrinsic::decode_submission;
+use crate::spec::proofs::{data_leaf, data_root, extrinsics_root};
+use crate::spec::transaction::AvailBlobTransaction;
+use crate::spec::{ChainParams, DaLayerSpec};
 
 #[derive(Error, Debug)]
 pub enum ValidityConditionError {
@@ -12,6 +16,22 @@ pub enum ValidityConditionError {
     BlocksNotConsecutive,
 }
 
+#[derive(Error, Debug)]
+pub enum VerifierError {
+    #[error("the data submitted in the block doesn't match the data root of the block header")]
+    DataRootMismatch,
+    #[error("the inclusion proof has {proofs} extrinsic indices for {txs} transactions")]
+    InclusionProofLengthMismatch { proofs: usize, txs: usize },
+    #[error("transactions must be listed in the order they were submitted in the block")]
+    TransactionsOutOfOrder,
+    #[error("transaction 0x{} is not part of the block", hex::encode(.hash))]
+    TransactionNotIncluded { hash: [u8; 32] },
+    #[error("the extrinsics of the completeness proof don't match the extrinsics root of the block header")]
+    ExtrinsicsRootMismatch,
+    #[error("the block has {expected} transactions of the rollup, but {found} were provided")]
+    MissingTransactions { expected: usize, found: usize },
+}
+
 #[derive(
     Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Copy, BorshDeserialize, BorshSerialize,
 )]
@@ -45,22 +65,87 @@ impl ValidityCondition for ChainValidityCondition {
     }
 }
 
-pub struct Verifier;
+pub struct Verifier {
+    app_id: u32,
+}
 
 impl DaVerifier for Verifier {
     type Spec = DaLayerSpec;
 
-    type Error = ValidityConditionError;
+    type Error = VerifierError;
 
     // Verify that the given list of blob transactions is complete and correct.
-    // NOTE: Function return unit since application client already verifies application data.
-    fn verify_relevant_tx_list<SimpleHasher>(
+    // The completeness proof carries every extrinsic of the block, so that the verifier can rebuild the
+    // extrinsics root committed to by the header and decode the app id of every data submission itself.
+    // The inclusion proof points each transaction at its own extrinsic.
+    fn verify_relevant_tx_list(
         &self,
         block_header: &<Self::Spec as DaSpec>::BlockHeader,
         txs: &[<Self::Spec as DaSpec>::BlobTransaction],
-        _inclusion_proof: <Self::Spec as DaSpec>::InclusionMultiProof,
-        _completeness_proof: <Self::Spec as DaSpec>::CompletenessProof,
+        inclusion_proof: <Self::Spec as DaSpec>::InclusionMultiProof,
+        completeness_proof: <Self::Spec as DaSpec>::CompletenessProof,
     ) -> Result<<Self::Spec as DaSpec>::ValidityCondition, Self::Error> {
+        let extrinsics = &completeness_proof.extrinsics;
+        if &extrinsics_root(extrinsics) != block_header.header.extrinsics_root.as_fixed_bytes() {
+            return Err(VerifierError::ExtrinsicsRootMismatch);
+        }
+
+        let submissions: Vec<_> = extrinsics
+            .iter()
+            .map(|extrinsic| decode_submission(extrinsic.as_ref()))
+            .collect();
+        let data_leaves: Vec<_> = submissions
+            .iter()
+            .flatten()
+            .map(|submission| data_leaf(submission.data))
+            .collect();
+        if &data_root(&data_leaves) != block_header.header.data_root.as_fixed_bytes() {
+            return Err(VerifierError::DataRootMismatch);
+        }
+
+        if inclusion_proof.extrinsic_indices.len() != txs.len() {
+            return Err(VerifierError::InclusionProofLengthMismatch {
+                proofs: inclusion_proof.extrinsic_indices.len(),
+                txs: txs.len(),
+            });
+        }
+
+        let mut previous_index = None;
+        for (tx, &index) in txs.iter().zip(inclusion_proof.extrinsic_indices.iter()) {
+            if previous_index.map_or(false, |previous| index <= previous) {
+                return Err(VerifierError::TransactionsOutOfOrder);
+            }
+            previous_index = Some(index);
+
+            let index = index as usize;
+            let included = match (extrinsics.get(index), submissions.get(index)) {
+                (Some(extrinsic), Some(Some(submission))) => {
+                    AvailBlobTransaction::extrinsic_hash(extrinsic) == tx.hash()
+                        && submission.app_id == self.app_id
+                        && AvailAddress::from(submission.sender) == tx.sender()
+                        && submission.data == tx.full_data()
+                }
+                _ => false,
+            };
+            if !included {
+                return Err(VerifierError::TransactionNotIncluded { hash: tx.hash() });
+            }
+        }
+
+        // Every transaction points at a distinct submission of the rollup, so the list is complete
+        // if there are as many transactions as there are submissions.
+        let expected = submissions
+            .iter()
+            .flatten()
+            .filter(|submission| submission.app_id == self.app_id)
+            .count();
+        if expected != txs.len() {
+            return Err(VerifierError::MissingTransactions {
+                expected,
+                found: txs.len(),
+            });
+        }
+
         let mut txs_commitment: [u8; 32] = [0u8; 32];
 
         for tx in txs {
@@ -76,7 +161,213 @@ impl DaVerifier for Verifier {
         Ok(validity_condition)
     }
 
-    fn new(_params: <Self::Spec as DaSpec>::ChainParams) -> Self {
-        Verifier {}
+    fn new(params: <Self::Spec as DaSpec>::ChainParams) -> Self {
+        let ChainParams { app_id } = params;
+        Verifier { app_id }
+    }
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use bytes::Bytes;
+    use primitive_types::H256;
+    use sov_rollup_interface::services::da::SlotData;
+
+    use super::*;
+    use crate::spec::block::AvailBlock;
+    use crate::spec::extrinsic::encode_submission;
+    use crate::spec::header::{AvailHeader, Header};
+    use crate::spec::proofs::{CompletenessProof, InclusionProof};
+
+    const ROLLUP_APP_ID: u32 = 1;
+    const OTHER_APP_ID: u32 = 2;
+
+    fn verifier() -> Verifier {
+        Verifier::new(ChainParams {
+            app_id: ROLLUP_APP_ID,
+        })
+    }
+
+    fn submission(app_id: u32, data: &[u8]) -> Vec<u8> {
+        encode_submission([app_id as u8; 32], app_id, data)
+    }
+
+    /// A block with the given extrinsics, preceded by an unsigned inherent. Its transactions are
+    /// the submissions of the rollup.
+    fn block_with(submissions: Vec<Vec<u8>>) -> AvailBlock {
+        let inherent = vec![0x0c, 0x04, 0x03, 0x00];
+        let extrinsics: Vec<Bytes> = std::iter::once(inherent)
+            .chain(submissions)
+            .map(Bytes::from)
+            .collect();
+        let data_leaves: Vec<_> = extrinsics
+            .iter()
+            .filter_map(|extrinsic| decode_submission(extrinsic.as_ref()))
+            .map(|submission| data_leaf(submission.data))
+            .collect();
+        let transactions = extrinsics
+            .iter()
+            .filter(|extrinsic| {
+                decode_submission(extrinsic.as_ref())
+                    .map_or(false, |submission| submission.app_id == ROLLUP_APP_ID)
+            })
+            .map(|extrinsic| AvailBlobTransaction::from_encoded_submission(extrinsic.as_ref()))
+            .collect();
+
+        let header = Header {
+            parent_hash: H256::repeat_byte(1),
+            number: 10,
+            state_root: H256::repeat_byte(2),
+            extrinsics_root: H256(extrinsics_root(&extrinsics)),
+            data_root: H256(data_root(&data_leaves)),
+        };
+        AvailBlock {
+            header: AvailHeader::from_parts(header, H256::repeat_byte(4)),
+            transactions,
+            extrinsics,
+        }
+    }
+
+    fn interleaved_block() -> AvailBlock {
+        block_with(vec![
+            submission(OTHER_APP_ID, b"other app 1"),
+            submission(ROLLUP_APP_ID, b"rollup 1"),
+            submission(OTHER_APP_ID, b"other app 2"),
+            submission(ROLLUP_APP_ID, b"rollup 2"),
+        ])
+    }
+
+    #[test]
+    fn test_verify_extracted_proofs() {
+        let block = interleaved_block();
+        let inclusion_proof = InclusionProof::for_block(&block, &block.transactions);
+        let completeness_proof = CompletenessProof::from_block(&block);
+        assert_eq!(inclusion_proof.extrinsic_indices, vec![2, 4]);
+
+        let validity_condition = verifier()
+            .verify_relevant_tx_list(
+                block.header(),
+                &block.transactions,
+                inclusion_proof,
+                completeness_proof,
+            )
+            .unwrap();
+        assert_eq!(validity_condition, block.validity_condition());
+    }
+
+    #[test]
+    fn test_verify_rejects_swapped_blob() {
+        let block = interleaved_block();
+        let inclusion_proof = InclusionProof::for_block(&block, &block.transactions);
+        let completeness_proof = CompletenessProof::from_block(&block);
+
+        let swapped = vec![
+            block.transactions[0].clone(),
+            AvailBlobTransaction::from_encoded_submission(&submission(
+                ROLLUP_APP_ID,
+                b"not in the block",
+            )),
+        ];
+        let result = verifier().verify_relevant_tx_list(
+            block.header(),
+            &swapped,
+            inclusion_proof,
+            completeness_proof,
+        );
+        assert!(matches!(
+            result,
+            Err(VerifierError::TransactionNotIncluded { hash }) if hash == swapped[1].hash()
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_mislabelled_submission() {
+        let block = interleaved_block();
+        // A submission of another app, claimed to belong to the rollup.
+        let mislabelled =
+            AvailBlobTransaction::from_encoded_submission(block.extrinsics[1].as_ref());
+        let txs = vec![
+            mislabelled,
+            block.transactions[0].clone(),
+            block.transactions[1].clone(),
+        ];
+        let inclusion_proof = InclusionProof::for_block(&block, &txs);
+        let completeness_proof = CompletenessProof::from_block(&block);
+        assert_eq!(inclusion_proof.extrinsic_indices, vec![1, 2, 4]);
+
+        let result = verifier().verify_relevant_tx_list(
+            block.header(),
+            &txs,
+            inclusion_proof,
+            completeness_proof,
+        );
+        assert!(matches!(
+            result,
+            Err(VerifierError::TransactionNotIncluded { hash }) if hash == txs[0].hash()
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_withheld_blob() {
+        let block = interleaved_block();
+        let txs = vec![block.transactions[0].clone()];
+        let inclusion_proof = InclusionProof::for_block(&block, &txs);
+        let completeness_proof = CompletenessProof::from_block(&block);
+
+        let result = verifier().verify_relevant_tx_list(
+            block.header(),
+            &txs,
+            inclusion_proof,
+            completeness_proof,
+        );
+        assert!(matches!(
+            result,
+            Err(VerifierError::MissingTransactions {
+                expected: 2,
+                found: 1
+            })
+        ));
+    }
+
+    #[test]
+    fn test_verify_no_relevant_txs() {
+        let block = block_with(vec![
+            submission(OTHER_APP_ID, b"other app 1"),
+            submission(OTHER_APP_ID, b"other app 2"),
+        ]);
+        let validity_condition = verifier()
+            .verify_no_relevant_txs(block.header(), CompletenessProof::from_block(&block))
+            .unwrap();
+        assert_eq!(validity_condition, block.validity_condition());
+
+        // A blob of the rollup can't be passed off as a submission of another app.
+        let block = interleaved_block();
+        let result = verifier()
+            .verify_no_relevant_txs(block.header(), CompletenessProof::from_block(&block));
+        assert!(matches!(
+            result,
+            Err(VerifierError::MissingTransactions {
+                expected: 2,
+                found: 0
+            })
+        ));
+    }
+
+    #[test]
+    fn test_verify_rejects_extrinsics_not_matching_header() {
+        let block = interleaved_block();
+        let inclusion_proof = InclusionProof::for_block(&block, &block.transactions);
+        let mut completeness_proof = CompletenessProof::from_block(&block);
+        completeness_proof
+            .extrinsics
+            .push(Bytes::from(submission(OTHER_APP_ID, b"injected")));
+
+        let result = verifier().verify_relevant_tx_list(
+            block.header(),
+            &block.transactions,
+            inclusion_proof,
+            completeness_proof,
+        );
+        assert!(matches!(result, Err(VerifierError::ExtrinsicsRootMismatch)));
     }
 }
@@ -12,7 +12,7 @@ use celestia_types::ExtendedHeader;
 use jsonrpsee::core::client::Subscription;
 use jsonrpsee::http_client::{HeaderMap, HttpClient};
 use pin_project::pin_project;
-use sov_rollup_interface::da::CountedBufReader;
+use sov_rollup_interface::da::{BlockHeaderTrait, CountedBufReader};
 use sov_rollup_interface::services::da::DaService;
 use tracing::{debug, info, instrument, trace};
 
@@ -26,6 +26,8 @@ use crate::{BlobWithSender, CelestiaHeader};
 // Approximate value, just to make it work.
 const GAS_PER_BYTE: usize = 20;
 const GAS_PRICE: usize = 1;
+const FINALIZATION_POLLING_INTERVAL: std::time::Duration = std::time::Duration::from_secs(1);
+const FINALIZATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(60);
 
 #[derive(Debug, Clone)]
 pub struct CelestiaService {
@@ -145,7 +147,8 @@ impl DaService for CelestiaService {
 
     type FilteredBlock = FilteredCelestiaBlock;
     type HeaderStream = CelestiaBlockHeaderSubscription;
-    type TransactionId = ();
+    /// The height of the block the blob was included in.
+    type TransactionId = u64;
     type Error = BoxError;
 
     #[instrument(skip(self), err)]
@@ -242,7 +245,7 @@ impl DaService for CelestiaService {
     }
 
     #[instrument(skip_all, err)]
-    async fn send_transaction(&self, blob: &[u8]) -> Result<(), Self::Error> {
+    async fn send_transaction(&self, blob: &[u8]) -> Result<Self::TransactionId, Self::Error> {
         debug!("Sending {} bytes of raw data to Celestia.", blob.len());
 
         let gas_limit = get_gas_limit_for_bytes(blob.len()) as u64;
@@ -265,7 +268,25 @@ impl DaService for CelestiaService {
             "Blob has been submitted to Celestia. block-height={}",
             height,
         );
-        Ok(())
+        Ok(height)
+    }
+
+    async fn wait_for_finalization(&self, tx_id: &Self::TransactionId) -> Result<u64, Self::Error> {
+        // Tendermint has instant finality, so the blob is finalized
+        // as soon as the head reaches the block it has been included in.
+        let height = *tx_id;
+        let start_time = std::time::Instant::now();
+        while self.get_head_block_header().await?.height() < height {
+            if start_time.elapsed() >= FINALIZATION_TIMEOUT {
+                return Err(format!(
+                    "Block at height={} has not been finalized in {:?}",
+                    height, FINALIZATION_TIMEOUT
+                )
+                .into());
+            }
+            tokio::time::sleep(FINALIZATION_POLLING_INTERVAL).await;
+        }
+        Ok(height)
     }
 
     async fn send_aggregated_zk_proof(&self, aggregated_proof: &[u8]) -> Result<u64, Self::Error> {
@@ -602,6 +623,43 @@ mod tests {
         assert!(error.to_string().contains("Transaction missing"));
     }
 
+    #[tokio::test]
+    async fn verification_of_empty_slot() {
+        let block = without_rollup_data::filtered_block();
+        let (_, _, da_service, rollup_params) = setup_service(None).await;
+
+        let (_, completeness_proof) = da_service.get_extraction_proof(&block, &[]).await;
+
+        let verifier = CelestiaVerifier::new(rollup_params);
+        let validity_cond = verifier
+            .verify_no_relevant_txs(&block.header, completeness_proof)
+            .unwrap();
+
+        assert_eq!(validity_cond.block_hash, *block.header.hash().inner());
+    }
+
+    #[tokio::test]
+    async fn verification_of_empty_slot_fails_if_blob_is_hidden() {
+        let block = with_rollup_data::filtered_block();
+        let (_, _, da_service, rollup_params) = setup_service(None).await;
+        let verifier = CelestiaVerifier::new(rollup_params);
+
+        // An honest completeness proof reveals the rollup blobs.
+        let txs = da_service.extract_relevant_blobs(&block);
+        let (_, completeness_proof) = da_service.get_extraction_proof(&block, &txs).await;
+        assert!(verifier
+            .verify_no_relevant_txs(&block.header, completeness_proof)
+            .is_err());
+
+        // The completeness proof of an empty slot doesn't cover the rows holding the rollup namespace.
+        let (_, empty_completeness_proof) = da_service
+            .get_extraction_proof(&without_rollup_data::filtered_block(), &[])
+            .await;
+        assert!(verifier
+            .verify_no_relevant_txs(&block.header, empty_completeness_proof)
+            .is_err());
+    }
+
     #[tokio::test]
     async fn verification_fails_if_not_all_etxs_are_proven() {
         let block = with_rollup_data::filtered_block();
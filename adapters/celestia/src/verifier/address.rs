@@ -18,6 +18,7 @@ impl AsRef<[u8]> for CelestiaAddress {
 /// Decodes slice of bytes into CelestiaAddress
 /// Treats it as string if it starts with HRP and the rest is valid ASCII
 /// Otherwise just decodes the tendermint Id and creates address from that.
+/// Celestia account ids are 20 bytes long, so any other raw length is rejected.
 impl<'a> TryFrom<&'a [u8]> for CelestiaAddress {
     type Error = anyhow::Error;
 
@@ -29,7 +30,14 @@ impl<'a> TryFrom<&'a [u8]> for CelestiaAddress {
             let s = unsafe { std::str::from_utf8_unchecked(value) };
             s.parse().context("failed parsing celestia address")
         } else {
-            let array = value.try_into().context("invalid slice length")?;
+            let array: [u8; tendermint::account::LENGTH] =
+                value.try_into().with_context(|| {
+                    format!(
+                        "invalid slice length: expected a bech32 address or a {}-byte account id, got {} bytes",
+                        tendermint::account::LENGTH,
+                        value.len()
+                    )
+                })?;
             let id = tendermint::account::Id::new(array);
             Ok(Self(AccAddress::new(id)))
         }
@@ -89,6 +97,27 @@ mod tests {
         assert_eq!(address_from_str, address_from_slice);
     }
 
+    #[test]
+    fn test_from_str_as_ref_roundtrip() {
+        let raw_address_str = "celestia1hvp2nfz3r6nqt8mlrzqf9ctwle942tkr0wql75";
+        let address = CelestiaAddress::from_str(raw_address_str).unwrap();
+
+        let id = address.as_ref();
+        assert_eq!(id.len(), tendermint::account::LENGTH);
+        let address_from_id = CelestiaAddress::try_from(id).unwrap();
+        assert_eq!(address, address_from_id);
+        assert_eq!(raw_address_str, address_from_id.to_string());
+    }
+
+    #[test]
+    fn test_try_from_rejects_32_byte_id() {
+        let err = CelestiaAddress::try_from(&[7u8; 32][..]).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "invalid slice length: expected a bech32 address or a 20-byte account id, got 32 bytes"
+        );
+    }
+
     // 20 u8 -> 32 u5
     fn check_from_bytes_as_ascii(input: [u8; 20]) {
         let encoded =
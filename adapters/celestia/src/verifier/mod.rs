@@ -41,6 +41,12 @@ impl BlobReaderTrait for BlobWithSender {
         self.hash
     }
 
+    // The hash is the share commitment of the blob, which can't be recomputed from the blob data
+    // without splitting it into shares again, so it is accepted as is.
+    fn verify_hash(&self) -> bool {
+        true
+    }
+
     fn verified_data(&self) -> &[u8] {
         self.blob.accumulator()
     }
@@ -336,3 +342,58 @@ impl CelestiaVerifier {
         Ok(NamespaceGroup::from_shares(verified_shares))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Builds the validity conditions of `len` consecutive blocks, starting from `seed`.
+    fn chain(seed: u8, len: usize) -> Vec<ChainValidityCondition> {
+        let hash = |i: usize| {
+            let mut hash = [seed; 32];
+            hash[..8].copy_from_slice(&(i as u64).to_le_bytes());
+            hash
+        };
+        (0..len)
+            .map(|i| ChainValidityCondition {
+                prev_hash: hash(i),
+                block_hash: hash(i + 1),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn combine_all_empty() {
+        let combined = ChainValidityCondition::combine_all::<sha2::Sha256>([]).unwrap();
+        assert_eq!(None, combined);
+    }
+
+    #[test]
+    fn combine_all_single() {
+        let conditions = chain(1, 1);
+        let combined = ChainValidityCondition::combine_all::<sha2::Sha256>(conditions.clone());
+        assert_eq!(Some(conditions[0]), combined.unwrap());
+    }
+
+    proptest! {
+        #[test]
+        fn combine_all_contiguous_chain(seed in any::<u8>(), len in 1usize..32) {
+            let conditions = chain(seed, len);
+            let combined = ChainValidityCondition::combine_all::<sha2::Sha256>(conditions.clone())
+                .unwrap()
+                .unwrap();
+            prop_assert_eq!(conditions.last().unwrap().block_hash, combined.block_hash);
+        }
+
+        #[test]
+        fn combine_all_rejects_gap(seed in any::<u8>(), len in 3usize..32, gap in any::<prop::sample::Index>()) {
+            let mut conditions = chain(seed, len);
+            // Dropping any block but the first and the last one leaves a gap in the chain.
+            conditions.remove(1 + gap.index(len - 2));
+            let combined = ChainValidityCondition::combine_all::<sha2::Sha256>(conditions);
+            prop_assert!(matches!(combined, Err(ValidityConditionError::BlocksNotConsecutive)));
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! Optional compression of blob payloads submitted to the mock DA layer.
+use std::io::{Read, Write};
+
+use anyhow::{bail, Context};
+use flate2::read::DeflateDecoder;
+use flate2::write::DeflateEncoder;
+
+/// Marks a blob whose payload is stored as is, because compressing it didn't make it smaller.
+const UNCOMPRESSED_MARKER: u8 = 0;
+/// Marks a blob whose payload is compressed with deflate.
+const DEFLATE_MARKER: u8 = 1;
+/// The largest payload a compressed blob may expand to, so that a small blob can't make the
+/// node allocate an arbitrary amount of memory.
+const MAX_DECOMPRESSED_LEN: usize = 16 * 1024 * 1024;
+
+/// The compression applied by [`crate::MockDaService`] to the blobs it submits, and reversed
+/// when it extracts them.
+///
+/// All the nodes of a rollup must use the same setting: a compressed blob starts with a marker
+/// byte, which a node without compression would pass to the rollup as part of the payload.
+///
+/// This is only meant for testing. The blobs are decompressed by the native
+/// [`crate::MockDaService`], and neither the [`crate::MockDaVerifier`] nor a zk guest checks
+/// that the extracted payload matches the posted bytes. A real DA adapter would have to
+/// decompress on the read path of its verifier instead.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BlobCompression {
+    /// Blobs are submitted as they are, without a marker byte.
+    #[default]
+    None,
+    /// Blobs are compressed with deflate, unless that would make them bigger.
+    Deflate,
+}
+
+impl BlobCompression {
+    /// Turns a blob payload into the bytes which are posted on the DA layer.
+    pub fn compress(&self, blob: &[u8]) -> Vec<u8> {
+        match self {
+            BlobCompression::None => blob.to_vec(),
+            BlobCompression::Deflate => {
+                let mut encoder = DeflateEncoder::new(vec![DEFLATE_MARKER], Default::default());
+                encoder
+                    .write_all(blob)
+                    .expect("Writing to a vector never fails");
+                let compressed = encoder.finish().expect("Writing to a vector never fails");
+                if compressed.len() <= blob.len() {
+                    compressed
+                } else {
+                    let mut stored = Vec::with_capacity(blob.len() + 1);
+                    stored.push(UNCOMPRESSED_MARKER);
+                    stored.extend_from_slice(blob);
+                    stored
+                }
+            }
+        }
+    }
+
+    /// Recovers the blob payload from the bytes which were posted on the DA layer.
+    pub fn decompress(&self, posted: &[u8]) -> anyhow::Result<Vec<u8>> {
+        if *self == BlobCompression::None {
+            return Ok(posted.to_vec());
+        }
+        match posted.split_first() {
+            Some((&UNCOMPRESSED_MARKER, payload)) => Ok(payload.to_vec()),
+            Some((&DEFLATE_MARKER, compressed)) => inflate(compressed, MAX_DECOMPRESSED_LEN),
+            Some((marker, _)) => bail!("Unknown blob compression marker {}", marker),
+            None => bail!("Compressed blob is empty"),
+        }
+    }
+}
+
+/// Decompresses a deflate payload, failing if it expands to more than `max_len` bytes.
+fn inflate(compressed: &[u8], max_len: usize) -> anyhow::Result<Vec<u8>> {
+    let mut blob = Vec::new();
+    // Reading one byte past the limit tells a payload of exactly `max_len` bytes from a bigger one.
+    DeflateDecoder::new(compressed)
+        .take(max_len as u64 + 1)
+        .read_to_end(&mut blob)
+        .context("Invalid deflate payload")?;
+    if blob.len() > max_len {
+        bail!("Compressed blob expands to more than {} bytes", max_len);
+    }
+    Ok(blob)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let compressible = vec![7; 1000];
+        let incompressible: Vec<u8> = (0..=255).collect();
+
+        for compression in [BlobCompression::None, BlobCompression::Deflate] {
+            for blob in [&compressible, &incompressible, &Vec::new()] {
+                let posted = compression.compress(blob);
+                assert_eq!(*blob, compression.decompress(&posted).unwrap());
+            }
+        }
+
+        let posted = BlobCompression::Deflate.compress(&incompressible);
+        assert_eq!(incompressible.len() + 1, posted.len());
+        assert!(BlobCompression::Deflate.decompress(&[]).is_err());
+        assert!(BlobCompression::Deflate.decompress(&[2, 1, 2]).is_err());
+    }
+
+    #[test]
+    fn test_reject_oversized_payload() {
+        let posted = BlobCompression::Deflate.compress(&[7; 1000]);
+        assert_eq!(DEFLATE_MARKER, posted[0]);
+
+        assert_eq!(vec![7; 1000], inflate(&posted[1..], 1000).unwrap());
+        assert_eq!(
+            "Compressed blob expands to more than 999 bytes",
+            inflate(&posted[1..], 999).unwrap_err().to_string()
+        );
+    }
+}
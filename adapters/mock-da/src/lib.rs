@@ -1,6 +1,8 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 
+#[cfg(feature = "native")]
+mod compression;
 #[cfg(feature = "native")]
 mod service;
 mod types;
@@ -8,6 +10,8 @@ mod validity_condition;
 /// Contains DaSpec and DaVerifier
 pub mod verifier;
 
+#[cfg(feature = "native")]
+pub use compression::BlobCompression;
 #[cfg(feature = "native")]
 pub use service::*;
 pub use types::*;
@@ -7,12 +7,13 @@ use std::time::Duration;
 use async_trait::async_trait;
 use pin_project::pin_project;
 use sha2::Digest;
-use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec, Time};
+use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait, DaSpec, Time};
 use sov_rollup_interface::maybestd::sync::Arc;
 use sov_rollup_interface::services::da::{DaService, SlotData};
 use tokio::sync::{broadcast, RwLock, RwLockWriteGuard};
 use tokio::time;
 
+use crate::compression::BlobCompression;
 use crate::types::{MockAddress, MockBlob, MockBlock, MockDaVerifier};
 use crate::verifier::MockDaSpec;
 use crate::{MockBlockHeader, MockHash};
@@ -70,6 +71,7 @@ pub struct MockDaService {
     finalized_header_sender: broadcast::Sender<MockBlockHeader>,
     wait_attempts: usize,
     planned_fork: Arc<Mutex<Option<PlannedFork>>>,
+    compression: BlobCompression,
 }
 
 impl MockDaService {
@@ -95,9 +97,17 @@ impl MockDaService {
             finalized_header_sender: tx,
             wait_attempts: 100_0000,
             planned_fork: Arc::new(Mutex::new(None)),
+            compression: BlobCompression::None,
         }
     }
 
+    /// Compresses the blobs submitted by [`DaService::send_transaction`] with the given scheme,
+    /// and decompresses them in [`DaService::extract_relevant_blobs`].
+    pub fn with_compression(mut self, compression: BlobCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
     /// Get sequencer address
     pub fn get_sequencer_address(&self) -> MockAddress {
         self.sequencer_da_address
@@ -284,7 +294,8 @@ impl DaService for MockDaService {
     type Verifier = MockDaVerifier;
     type FilteredBlock = MockBlock;
     type HeaderStream = MockDaBlockHeaderStream;
-    type TransactionId = ();
+    /// The height of the block the blob was included in.
+    type TransactionId = u64;
     type Error = anyhow::Error;
 
     /// Gets block at given height
@@ -346,7 +357,38 @@ impl DaService for MockDaService {
         &self,
         block: &Self::FilteredBlock,
     ) -> Vec<<Self::Spec as DaSpec>::BlobTransaction> {
-        block.blobs.clone()
+        if self.compression == BlobCompression::None {
+            return block.blobs.clone();
+        }
+        block
+            .blobs
+            .iter()
+            .map(|blob| {
+                let mut posted = blob.clone();
+                match self.compression.decompress(posted.full_data()) {
+                    // The decompressed blob is identified by the hash of its data, so that it
+                    // still passes `BlobReaderTrait::verify_hash`.
+                    Ok(data) => {
+                        let hash = hash_to_array(&data);
+                        MockBlob::new_with_zkp_proof(
+                            data,
+                            blob.zk_proofs_data.clone(),
+                            blob.address,
+                            hash,
+                        )
+                    }
+                    // The rollup rejects the blob like any other malformed blob.
+                    Err(e) => {
+                        tracing::warn!(
+                            "Failed to decompress blob {}: {}",
+                            hex::encode(blob.hash),
+                            e
+                        );
+                        blob.clone()
+                    }
+                }
+            })
+            .collect()
     }
 
     async fn get_extraction_proof(
@@ -360,10 +402,25 @@ impl DaService for MockDaService {
         ([0u8; 32], ())
     }
 
-    async fn send_transaction(&self, blob: &[u8]) -> Result<(), Self::Error> {
+    async fn send_transaction(&self, blob: &[u8]) -> Result<Self::TransactionId, Self::Error> {
+        let blob = self.compression.compress(blob);
         let mut blocks = self.blocks.write().await;
-        let _ = self.add_blob(blob, Default::default(), &mut blocks).await?;
-        Ok(())
+        self.add_blob(&blob, Default::default(), &mut blocks).await
+    }
+
+    async fn wait_for_finalization(&self, tx_id: &Self::TransactionId) -> Result<u64, Self::Error> {
+        let height = *tx_id;
+        // Waits self.wait_attempts * 10ms for the block to be finalized
+        for _ in 0..self.wait_attempts {
+            if self.get_last_finalized_block_header().await?.height() >= height {
+                return Ok(height);
+            }
+            time::sleep(Duration::from_millis(10)).await;
+        }
+        anyhow::bail!(
+            "Block at height={height} has not been finalized in {:?}",
+            Duration::from_millis((self.wait_attempts * 10) as u64),
+        );
     }
 
     async fn send_aggregated_zk_proof(&self, proof: &[u8]) -> Result<u64, Self::Error> {
@@ -434,6 +491,24 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_wait_for_finalization() {
+        let mut da = MockDaService::with_finality(MockAddress::new([1; 32]), 1);
+        da.wait_attempts = 10;
+
+        let first_height = da.send_transaction(&[1, 2, 3]).await.unwrap();
+        assert_eq!(1, first_height);
+        // Not finalized until another block is submitted
+        assert!(da.wait_for_finalization(&first_height).await.is_err());
+
+        let second_height = da.send_transaction(&[4, 5, 6]).await.unwrap();
+        assert_eq!(2, second_height);
+        assert_eq!(
+            first_height,
+            da.wait_for_finalization(&first_height).await.unwrap()
+        );
+    }
+
     async fn get_finalized_headers_collector(
         da: &mut MockDaService,
         expected_num_headers: usize,
@@ -630,6 +705,24 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_compressed_blobs_are_extracted_uncompressed() {
+        let da = MockDaService::new(MockAddress::new([1; 32]))
+            .with_compression(BlobCompression::Deflate);
+        let blob = b"sovereign".repeat(100);
+
+        let height = da.send_transaction(&blob).await.unwrap();
+        let block = da.get_block_at(height).await.unwrap();
+
+        let mut posted = block.blobs[0].clone();
+        assert!(posted.full_data().len() < blob.len());
+
+        let mut extracted = da.extract_relevant_blobs(&block);
+        assert_eq!(1, extracted.len());
+        assert_eq!(blob, extracted[0].full_data());
+        assert!(extracted[0].verify_hash());
+    }
+
     mod reo4g_control {
         use super::*;
         use crate::{MockAddress, MockDaService};
@@ -797,4 +890,134 @@ mod tests {
     fn assert_consecutive_blocks(block1: &MockBlock, block2: &MockBlock) {
         assert_eq!(block2.header().prev_hash(), block1.header().hash())
     }
+
+    mod retrying {
+        use std::sync::atomic::{AtomicU32, Ordering};
+
+        use sov_rollup_interface::services::da::{RetryConfig, RetryError, RetryingDaService};
+
+        use super::*;
+
+        /// Fails the first `failures` calls to `get_block_at`, then forwards to a [`MockDaService`].
+        struct FlakyDaService {
+            inner: MockDaService,
+            failures: u32,
+            attempts: AtomicU32,
+        }
+
+        #[async_trait]
+        impl DaService for FlakyDaService {
+            type Spec = MockDaSpec;
+            type Verifier = MockDaVerifier;
+            type FilteredBlock = MockBlock;
+            type HeaderStream = MockDaBlockHeaderStream;
+            type TransactionId = u64;
+            type Error = anyhow::Error;
+
+            async fn get_block_at(&self, height: u64) -> Result<MockBlock, anyhow::Error> {
+                if self.attempts.fetch_add(1, Ordering::SeqCst) < self.failures {
+                    anyhow::bail!("connection reset by peer");
+                }
+                self.inner.get_block_at(height).await
+            }
+
+            async fn get_last_finalized_block_header(
+                &self,
+            ) -> Result<MockBlockHeader, anyhow::Error> {
+                self.inner.get_last_finalized_block_header().await
+            }
+
+            async fn subscribe_finalized_header(
+                &self,
+            ) -> Result<MockDaBlockHeaderStream, anyhow::Error> {
+                self.inner.subscribe_finalized_header().await
+            }
+
+            async fn get_head_block_header(&self) -> Result<MockBlockHeader, anyhow::Error> {
+                self.inner.get_head_block_header().await
+            }
+
+            fn extract_relevant_blobs(&self, block: &MockBlock) -> Vec<MockBlob> {
+                self.inner.extract_relevant_blobs(block)
+            }
+
+            async fn get_extraction_proof(
+                &self,
+                block: &MockBlock,
+                blobs: &[MockBlob],
+            ) -> ([u8; 32], ()) {
+                self.inner.get_extraction_proof(block, blobs).await
+            }
+
+            async fn send_transaction(&self, blob: &[u8]) -> Result<u64, anyhow::Error> {
+                self.inner.send_transaction(blob).await
+            }
+
+            async fn wait_for_finalization(&self, tx_id: &u64) -> Result<u64, anyhow::Error> {
+                self.inner.wait_for_finalization(tx_id).await
+            }
+
+            async fn send_aggregated_zk_proof(&self, data: &[u8]) -> Result<u64, anyhow::Error> {
+                self.inner.send_aggregated_zk_proof(data).await
+            }
+
+            async fn get_aggregated_proofs_at(
+                &self,
+                height: u64,
+            ) -> Result<Vec<Vec<u8>>, anyhow::Error> {
+                self.inner.get_aggregated_proofs_at(height).await
+            }
+        }
+
+        async fn flaky_da(failures: u32, max_retries: u32) -> RetryingDaService<FlakyDaService> {
+            let mut inner = MockDaService::new(MockAddress::new([1; 32]));
+            inner.wait_attempts = 2;
+            inner.send_transaction(&[1, 2, 3, 4]).await.unwrap();
+            let flaky = FlakyDaService {
+                inner,
+                failures,
+                attempts: AtomicU32::new(0),
+            };
+            let config = RetryConfig {
+                max_retries,
+                initial_backoff: Duration::from_millis(1),
+                max_backoff: Duration::from_millis(4),
+            };
+            RetryingDaService::new(flaky, config)
+        }
+
+        #[tokio::test]
+        async fn fails_twice_then_succeeds() {
+            let da = flaky_da(2, 3).await;
+
+            let block = da.get_block_at(1).await.unwrap();
+
+            assert_eq!(1, block.header().height());
+            assert_eq!(3, da.inner().attempts.load(Ordering::SeqCst));
+        }
+
+        #[tokio::test]
+        async fn gives_up_after_max_retries() {
+            let da = flaky_da(2, 1).await;
+
+            let result = da.get_block_at(1).await;
+
+            assert!(matches!(
+                result,
+                Err(RetryError::RetriesExhausted { attempts: 2, .. })
+            ));
+        }
+
+        #[tokio::test]
+        async fn does_not_retry_unavailable_height() {
+            let da = flaky_da(0, 3)
+                .await
+                .with_retryable_errors(|e| !e.to_string().contains("No block at height"));
+
+            let result = da.get_block_at(5).await;
+
+            assert!(matches!(result, Err(RetryError::NotRetried(_))));
+            assert_eq!(1, da.inner().attempts.load(Ordering::SeqCst));
+        }
+    }
 }
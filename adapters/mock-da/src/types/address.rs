@@ -1,27 +1,51 @@
 use std::str::FromStr;
 
+use serde::ser::SerializeTuple;
 use sov_rollup_interface::{BasicAddress, RollupAddress};
 
 /// Sequencer DA address used in tests.
 pub const MOCK_SEQUENCER_DA_ADDRESS: [u8; 32] = [0u8; 32];
 
-/// A mock address type used for testing. Internally, this type is standard 32 byte array.
-#[derive(
-    Debug, PartialEq, Clone, Eq, Copy, Hash, Default, borsh::BorshDeserialize, borsh::BorshSerialize,
-)]
-pub struct MockAddress {
+/// A mock address type used for testing, backed by an array of `N` bytes.
+///
+/// Lets tests model DA layers whose addresses are not 32 bytes long.
+#[derive(Debug, PartialEq, Clone, Eq, Copy, Hash)]
+pub struct MockAddressN<const N: usize> {
     /// Underlying mock address.
-    addr: [u8; 32],
+    addr: [u8; N],
 }
 
-impl MockAddress {
+/// A mock address type used for testing. Internally, this type is standard 32 byte array.
+pub type MockAddress = MockAddressN<32>;
+
+impl<const N: usize> MockAddressN<N> {
     /// Creates a new mock address containing the given bytes.
-    pub const fn new(addr: [u8; 32]) -> Self {
+    pub const fn new(addr: [u8; N]) -> Self {
         Self { addr }
     }
 }
 
-impl serde::Serialize for MockAddress {
+impl<const N: usize> Default for MockAddressN<N> {
+    fn default() -> Self {
+        Self { addr: [0; N] }
+    }
+}
+
+impl<const N: usize> borsh::BorshDeserialize for MockAddressN<N> {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let mut addr = [0; N];
+        reader.read_exact(&mut addr)?;
+        Ok(Self { addr })
+    }
+}
+
+impl<const N: usize> borsh::BorshSerialize for MockAddressN<N> {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        writer.write_all(&self.addr)
+    }
+}
+
+impl<const N: usize> serde::Serialize for MockAddressN<N> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -29,77 +53,106 @@ impl serde::Serialize for MockAddress {
         if serializer.is_human_readable() {
             serde::Serialize::serialize(&hex::encode(self.addr), serializer)
         } else {
-            serde::Serialize::serialize(&self.addr, serializer)
+            // Encoded as a tuple, like a fixed-size array, so the length isn't written out.
+            let mut tuple = serializer.serialize_tuple(N)?;
+            for byte in &self.addr {
+                tuple.serialize_element(byte)?;
+            }
+            tuple.end()
+        }
+    }
+}
+
+struct AddressBytesVisitor<const N: usize>;
+
+impl<'de, const N: usize> serde::de::Visitor<'de> for AddressBytesVisitor<N> {
+    type Value = [u8; N];
+
+    fn expecting(&self, formatter: &mut core::fmt::Formatter) -> core::fmt::Result {
+        write!(formatter, "an array of {} bytes", N)
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        let mut addr = [0; N];
+        for (i, byte) in addr.iter_mut().enumerate() {
+            *byte = seq
+                .next_element()?
+                .ok_or_else(|| serde::de::Error::invalid_length(i, &self))?;
         }
+        Ok(addr)
     }
 }
 
-impl<'de> serde::Deserialize<'de> for MockAddress {
+impl<'de, const N: usize> serde::Deserialize<'de> for MockAddressN<N> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         if deserializer.is_human_readable() {
             let hex_addr: String = serde::Deserialize::deserialize(deserializer)?;
-            Ok(MockAddress::from_str(&hex_addr).map_err(serde::de::Error::custom)?)
+            Ok(MockAddressN::from_str(&hex_addr).map_err(serde::de::Error::custom)?)
         } else {
-            let addr = <[u8; 32] as serde::Deserialize>::deserialize(deserializer)?;
-            Ok(MockAddress { addr })
+            let addr = deserializer.deserialize_tuple(N, AddressBytesVisitor::<N>)?;
+            Ok(MockAddressN { addr })
         }
     }
 }
 
-impl FromStr for MockAddress {
+impl<const N: usize> FromStr for MockAddressN<N> {
     type Err = anyhow::Error;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let addr = hex::decode(s).map_err(anyhow::Error::msg)?;
-        if addr.len() != 32 {
+        if addr.len() != N {
             return Err(anyhow::anyhow!("Invalid address length"));
         }
 
-        let mut array = [0; 32];
+        let mut array = [0; N];
         array.copy_from_slice(&addr);
-        Ok(MockAddress { addr: array })
+        Ok(MockAddressN { addr: array })
     }
 }
 
-impl<'a> TryFrom<&'a [u8]> for MockAddress {
+impl<'a, const N: usize> TryFrom<&'a [u8]> for MockAddressN<N> {
     type Error = anyhow::Error;
 
     fn try_from(addr: &'a [u8]) -> Result<Self, Self::Error> {
-        if addr.len() != 32 {
-            anyhow::bail!("Address must be 32 bytes long");
+        if addr.len() != N {
+            anyhow::bail!("Address must be {} bytes long", N);
         }
-        let mut addr_bytes = [0u8; 32];
+        let mut addr_bytes = [0u8; N];
         addr_bytes.copy_from_slice(addr);
         Ok(Self { addr: addr_bytes })
     }
 }
 
-impl AsRef<[u8]> for MockAddress {
+impl<const N: usize> AsRef<[u8]> for MockAddressN<N> {
     fn as_ref(&self) -> &[u8] {
         &self.addr
     }
 }
 
-impl From<[u8; 32]> for MockAddress {
-    fn from(addr: [u8; 32]) -> Self {
-        MockAddress { addr }
+impl<const N: usize> From<[u8; N]> for MockAddressN<N> {
+    fn from(addr: [u8; N]) -> Self {
+        MockAddressN { addr }
     }
 }
 
-impl std::fmt::Display for MockAddress {
+impl<const N: usize> std::fmt::Display for MockAddressN<N> {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         write!(f, "{}", hex::encode(self.addr))
     }
 }
 
-impl BasicAddress for MockAddress {}
+impl<const N: usize> BasicAddress for MockAddressN<N> {}
 impl RollupAddress for MockAddress {}
 
 #[cfg(test)]
 mod tests {
+    use borsh::{BorshDeserialize, BorshSerialize};
     use sov_rollup_interface::maybestd::string::ToString;
 
     use super::*;
@@ -111,4 +164,23 @@ mod tests {
         let recovered_addr = s.parse::<MockAddress>().unwrap();
         assert_eq!(addr, recovered_addr);
     }
+
+    #[test]
+    fn test_short_mock_address_roundtrip() {
+        let addr = MockAddressN::<20>::new([7u8; 20]);
+
+        let recovered_addr = addr.to_string().parse::<MockAddressN<20>>().unwrap();
+        assert_eq!(addr, recovered_addr);
+
+        let bytes = bincode::serialize(&addr).unwrap();
+        assert_eq!(20, bytes.len());
+        assert_eq!(addr, bincode::deserialize(&bytes).unwrap());
+
+        let bytes = addr.try_to_vec().unwrap();
+        assert_eq!(&[7u8; 20][..], &bytes[..]);
+        assert_eq!(addr, MockAddressN::try_from_slice(&bytes).unwrap());
+
+        assert!(MockAddressN::<20>::try_from(&[7u8; 32][..]).is_err());
+        assert!(MockAddress::try_from(addr.as_ref()).is_err());
+    }
 }
@@ -3,9 +3,10 @@ mod address;
 use std::fmt::{Debug, Formatter};
 use std::hash::Hasher;
 
-pub use address::{MockAddress, MOCK_SEQUENCER_DA_ADDRESS};
+pub use address::{MockAddress, MockAddressN, MOCK_SEQUENCER_DA_ADDRESS};
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
+use sha2::Digest;
 use sov_rollup_interface::da::{BlockHashTrait, BlockHeaderTrait, CountedBufReader, Time};
 use sov_rollup_interface::services::da::SlotData;
 use sov_rollup_interface::Bytes;
@@ -150,9 +151,9 @@ pub struct MockDaVerifier {}
     serde::Serialize,
     serde::Deserialize,
 )]
-/// A mock BlobTransaction from a DA layer used for testing.
-pub struct MockBlob {
-    pub(crate) address: MockAddress,
+/// A mock BlobTransaction from a DA layer used for testing, sent from an `N` byte [`MockAddressN`].
+pub struct MockBlob<const N: usize = 32> {
+    pub(crate) address: MockAddressN<N>,
     pub(crate) hash: [u8; 32],
     /// Actual data from the blob. Public for testing purposes.
     pub data: CountedBufReader<Bytes>,
@@ -160,9 +161,9 @@ pub struct MockBlob {
     pub(crate) zk_proofs_data: Vec<u8>,
 }
 
-impl MockBlob {
+impl<const N: usize> MockBlob<N> {
     /// Creates a new mock blob with the given data, claiming to have been published by the provided address.
-    pub fn new(data: Vec<u8>, address: MockAddress, hash: [u8; 32]) -> Self {
+    pub fn new(data: Vec<u8>, address: MockAddressN<N>, hash: [u8; 32]) -> Self {
         Self {
             address,
             data: CountedBufReader::new(Bytes::from(data)),
@@ -171,11 +172,18 @@ impl MockBlob {
         }
     }
 
+    /// Creates a new mock blob with the given data, claiming to have been published by the provided address.
+    /// Like the blobs of the mock DA service, it is identified by the sha256 hash of its data.
+    pub fn with_data_hash(data: Vec<u8>, address: MockAddressN<N>) -> Self {
+        let hash = sha2::Sha256::digest(&data).into();
+        Self::new(data, address, hash)
+    }
+
     /// Creates a new mock blob with the given data and an aggretated zkp proof, claiming to have been published by the provided address.
     pub fn new_with_zkp_proof(
         data: Vec<u8>,
         zk_proofs_data: Vec<u8>,
-        address: MockAddress,
+        address: MockAddressN<N>,
         hash: [u8; 32],
     ) -> Self {
         Self {
@@ -6,34 +6,45 @@ use serde::{Deserialize, Serialize};
 use sha2::Digest;
 use sov_rollup_interface::zk::{ValidityCondition, ValidityConditionChecker};
 
-/// A trivial test validity condition structure that only contains a boolean
+/// A trivial test validity condition structure that only contains booleans
 #[derive(
     Debug, BorshDeserialize, BorshSerialize, Serialize, Deserialize, PartialEq, Clone, Copy, Eq,
 )]
 pub struct MockValidityCond {
     /// The associated validity condition field. If it is true, the validity condition is verified
     pub is_valid: bool,
+    /// If it is false, combining this condition with any other one fails
+    pub should_combine: bool,
 }
 
 // Validity conditions should usually be valid
 impl Default for MockValidityCond {
     fn default() -> Self {
-        Self { is_valid: true }
+        Self {
+            is_valid: true,
+            should_combine: true,
+        }
     }
 }
 
 impl ValidityCondition for MockValidityCond {
     type Error = Error;
     fn combine<H: Digest>(&self, rhs: Self) -> Result<Self, Self::Error> {
+        anyhow::ensure!(
+            self.should_combine && rhs.should_combine,
+            "Mock validity conditions can't be combined"
+        );
         Ok(MockValidityCond {
             is_valid: self.is_valid & rhs.is_valid,
+            should_combine: true,
         })
     }
 }
 
 #[derive(BorshDeserialize, BorshSerialize, Debug)]
-/// A mock validity condition checker that always evaluate to cond
+/// A mock validity condition checker that evaluates to cond, unless it is set to reject every condition
 pub struct MockValidityCondChecker<Cond: ValidityCondition> {
+    should_accept: bool,
     phantom: PhantomData<Cond>,
 }
 
@@ -41,7 +52,11 @@ impl ValidityConditionChecker<MockValidityCond> for MockValidityCondChecker<Mock
     type Error = Error;
 
     fn check(&mut self, condition: &MockValidityCond) -> Result<(), Self::Error> {
-        if condition.is_valid {
+        if !self.should_accept {
+            Err(anyhow::format_err!(
+                "Mock validity condition checker rejects every condition"
+            ))
+        } else if condition.is_valid {
             Ok(())
         } else {
             Err(anyhow::format_err!("Invalid mock validity condition"))
@@ -52,7 +67,13 @@ impl ValidityConditionChecker<MockValidityCond> for MockValidityCondChecker<Mock
 impl<Cond: ValidityCondition> MockValidityCondChecker<Cond> {
     /// Creates new test validity condition
     pub fn new() -> Self {
+        Self::with_should_accept(true)
+    }
+
+    /// Creates a checker which rejects every condition if `should_accept` is false
+    pub fn with_should_accept(should_accept: bool) -> Self {
         Self {
+            should_accept,
             phantom: Default::default(),
         }
     }
@@ -63,3 +84,102 @@ impl<Cond: ValidityCondition> Default for MockValidityCondChecker<Cond> {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use sov_rollup_interface::zk::{
+        verify_transition_chain, StateTransition, TransitionChainError,
+    };
+
+    use super::*;
+    use crate::MockDaSpec;
+
+    #[test]
+    fn combine_fails_unless_both_conditions_combine() {
+        let valid = MockValidityCond::default();
+        let not_combining = MockValidityCond {
+            should_combine: false,
+            ..Default::default()
+        };
+
+        assert!(valid.combine::<sha2::Sha256>(valid).is_ok());
+        assert!(valid.combine::<sha2::Sha256>(not_combining).is_err());
+        assert!(not_combining.combine::<sha2::Sha256>(valid).is_err());
+    }
+
+    fn transition(
+        initial_state_root: [u8; 32],
+        final_state_root: [u8; 32],
+        validity_condition: MockValidityCond,
+    ) -> StateTransition<MockDaSpec, [u8; 32]> {
+        StateTransition {
+            initial_state_root,
+            final_state_root,
+            slot_hash: [0; 32].into(),
+            validity_condition,
+        }
+    }
+
+    #[test]
+    fn contiguous_transitions_form_a_chain() {
+        let transitions = [
+            transition([0; 32], [1; 32], MockValidityCond::default()),
+            transition([1; 32], [2; 32], MockValidityCond::default()),
+            transition([2; 32], [3; 32], MockValidityCond::default()),
+        ];
+
+        let combined = verify_transition_chain::<_, _, sha2::Sha256>(&transitions).unwrap();
+
+        assert_eq!(MockValidityCond::default(), combined);
+    }
+
+    #[test]
+    fn broken_transition_chain_is_rejected() {
+        let mut transitions = vec![
+            transition([0; 32], [1; 32], MockValidityCond::default()),
+            transition([9; 32], [2; 32], MockValidityCond::default()),
+            transition([2; 32], [3; 32], MockValidityCond::default()),
+        ];
+
+        let result = verify_transition_chain::<_, _, sha2::Sha256>(&transitions);
+        assert!(matches!(
+            result,
+            Err(TransitionChainError::RootMismatch { index: 1 })
+        ));
+
+        transitions[1].initial_state_root = [1; 32];
+        transitions[2].validity_condition.should_combine = false;
+        let result = verify_transition_chain::<_, _, sha2::Sha256>(&transitions);
+        assert!(matches!(
+            result,
+            Err(TransitionChainError::IncompatibleConditions { index: 2, .. })
+        ));
+
+        let result = verify_transition_chain::<MockDaSpec, [u8; 32], sha2::Sha256>(&[]);
+        assert!(matches!(result, Err(TransitionChainError::Empty)));
+    }
+
+    #[test]
+    fn accepting_checker_checks_condition() {
+        let mut checker = MockValidityCondChecker::<MockValidityCond>::new();
+        let invalid = MockValidityCond {
+            is_valid: false,
+            ..Default::default()
+        };
+
+        assert!(checker.check(&MockValidityCond::default()).is_ok());
+        assert!(checker.check(&invalid).is_err());
+    }
+
+    #[test]
+    fn rejecting_checker_rejects_valid_condition() {
+        let mut checker = MockValidityCondChecker::<MockValidityCond>::with_should_accept(false);
+
+        let result = checker.check(&MockValidityCond::default());
+
+        assert_eq!(
+            "Mock validity condition checker rejects every condition",
+            result.unwrap_err().to_string()
+        );
+    }
+}
@@ -1,9 +1,10 @@
+use sha2::Digest;
 use sov_rollup_interface::da::{BlobReaderTrait, DaSpec, DaVerifier};
 
-use crate::{MockAddress, MockBlob, MockBlockHeader, MockDaVerifier, MockHash, MockValidityCond};
+use crate::{MockAddressN, MockBlob, MockBlockHeader, MockDaVerifier, MockHash, MockValidityCond};
 
-impl BlobReaderTrait for MockBlob {
-    type Address = MockAddress;
+impl<const N: usize> BlobReaderTrait for MockBlob<N> {
+    type Address = MockAddressN<N>;
 
     fn sender(&self) -> Self::Address {
         self.address
@@ -13,6 +14,14 @@ impl BlobReaderTrait for MockBlob {
         self.hash
     }
 
+    // The mock DA service hashes blobs with sha256 of their data.
+    fn verify_hash(&self) -> bool {
+        let mut data = self.data.clone();
+        data.advance(data.total_len());
+        let hash: [u8; 32] = sha2::Sha256::digest(data.accumulator()).into();
+        hash == self.hash
+    }
+
     fn verified_data(&self) -> &[u8] {
         self.data.accumulator()
     }
@@ -28,15 +37,15 @@ impl BlobReaderTrait for MockBlob {
     }
 }
 
-/// A [`sov_rollup_interface::da::DaSpec`] suitable for testing.
+/// A [`sov_rollup_interface::da::DaSpec`] suitable for testing, with `N` byte sender addresses.
 #[derive(serde::Serialize, serde::Deserialize, Debug, PartialEq, Eq, Clone)]
-pub struct MockDaSpec;
+pub struct MockDaSpec<const N: usize = 32>;
 
-impl DaSpec for MockDaSpec {
+impl<const N: usize> DaSpec for MockDaSpec<N> {
     type SlotHash = MockHash;
     type BlockHeader = MockBlockHeader;
-    type BlobTransaction = MockBlob;
-    type Address = MockAddress;
+    type BlobTransaction = MockBlob<N>;
+    type Address = MockAddressN<N>;
     type ValidityCondition = MockValidityCond;
     type InclusionMultiProof = [u8; 32];
     type CompletenessProof = ();
@@ -62,3 +71,41 @@ impl DaVerifier for MockDaVerifier {
         Ok(Default::default())
     }
 }
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use sha2::Digest;
+    use sov_rollup_interface::da::BlobReaderTrait;
+
+    use crate::{MockAddress, MockBlob};
+
+    #[test]
+    fn test_empty_blob_is_fully_read() {
+        let mut blob = MockBlob::new(vec![], MockAddress::new([1; 32]), [2; 32]);
+
+        assert!(blob.data.is_fully_read());
+        assert_eq!(blob.data.counter(), 0);
+        assert_eq!(blob.total_len(), 0);
+        assert!(blob.verified_data().is_empty());
+
+        assert!(blob.advance(10).is_empty());
+        assert!(blob.full_data().is_empty());
+        assert_eq!(blob.data.counter(), 0);
+        assert_eq!(blob.hash(), [2; 32]);
+    }
+
+    #[test]
+    fn test_verify_hash() {
+        let data = b"mock blob".to_vec();
+        let hash: [u8; 32] = sha2::Sha256::digest(&data).into();
+        let mut blob = MockBlob::new(data.clone(), MockAddress::new([1; 32]), hash);
+        assert!(blob.verify_hash());
+
+        // Reading the blob doesn't change what its hash is checked against.
+        blob.advance(4);
+        assert!(blob.verify_hash());
+
+        let tampered = MockBlob::new(data, MockAddress::new([1; 32]), [2; 32]);
+        assert!(!tampered.verify_hash());
+    }
+}
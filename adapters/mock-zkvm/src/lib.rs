@@ -9,7 +9,11 @@ use anyhow::ensure;
 use borsh::{BorshDeserialize, BorshSerialize};
 use serde::{Deserialize, Serialize};
 use sov_rollup_interface::da::BlockHeaderTrait;
-use sov_rollup_interface::zk::{Matches, StateTransitionData, ValidityCondition};
+use sov_rollup_interface::zk::{Matches, OutputEncoding, StateTransitionData, ValidityCondition};
+
+mod prover;
+
+pub use prover::{MockProver, MockProverGuest};
 
 /// A mock commitment to a particular zkVM program.
 #[derive(Debug, Clone, PartialEq, Eq, BorshDeserialize, BorshSerialize, Serialize, Deserialize)]
@@ -62,6 +66,18 @@ impl<'a> MockProof<'a> {
     }
 }
 
+/// The [`OutputEncoding`] of values committed by the mock zkVM guests,
+/// which is also the default encoding of their `verify_and_extract_output`.
+pub struct BincodeEncoding;
+
+impl<T: serde::de::DeserializeOwned> OutputEncoding<T> for BincodeEncoding {
+    type Error = bincode::Error;
+
+    fn decode(output: &[u8]) -> Result<T, Self::Error> {
+        bincode::deserialize(output)
+    }
+}
+
 #[derive(Clone)]
 struct Notifier {
     notified: Arc<Mutex<bool>>,
@@ -254,3 +270,61 @@ fn test_mock_proof_round_trip() {
     let decoded = MockProof::decode(&encoded).unwrap();
     assert_eq!(proof, decoded);
 }
+
+#[cfg(test)]
+mod tests {
+    use sov_mock_da::{MockDaSpec, MockHash, MockValidityCond};
+    use sov_rollup_interface::zk::{BorshEncoding, StateTransition, Zkvm};
+
+    use super::*;
+
+    type Transition = StateTransition<MockDaSpec, [u8; 32]>;
+
+    fn transition() -> Transition {
+        StateTransition {
+            initial_state_root: [1; 32],
+            final_state_root: [2; 32],
+            slot_hash: MockHash([3; 32]),
+            validity_condition: MockValidityCond::default(),
+        }
+    }
+
+    fn prove(log: &[u8]) -> Vec<u8> {
+        MockProof {
+            program_id: MockCodeCommitment([1; 32]),
+            is_valid: true,
+            log,
+        }
+        .encode_to_vec()
+    }
+
+    fn extract<E: OutputEncoding<Transition>>(proof: &[u8]) -> anyhow::Result<Transition>
+    where
+        anyhow::Error: From<E::Error>,
+    {
+        MockZkvm::<MockValidityCond>::verify_and_extract_output_with::<E, _, _>(
+            proof,
+            &MockCodeCommitment([1; 32]),
+        )
+    }
+
+    #[test]
+    fn test_extract_output_with_borsh() {
+        let proof = prove(&borsh::to_vec(&transition()).unwrap());
+
+        assert_eq!(transition(), extract::<BorshEncoding>(&proof).unwrap());
+    }
+
+    #[test]
+    fn test_extract_output_with_bincode() {
+        let proof = prove(&bincode::serialize(&transition()).unwrap());
+
+        assert_eq!(transition(), extract::<BincodeEncoding>(&proof).unwrap());
+        let output: Transition = MockZkvm::<MockValidityCond>::verify_and_extract_output(
+            &proof,
+            &MockCodeCommitment([1; 32]),
+        )
+        .unwrap();
+        assert_eq!(transition(), output);
+    }
+}
@@ -0,0 +1,224 @@
+//! A mock zkVM modelling the full prove → verify cycle.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::zk::{Matches, Proof, StateTransition, Zkvm, ZkvmGuest, ZkvmHost};
+
+use crate::{MockCodeCommitment, MockProof};
+
+/// A mock prover for a program identified by a [`MockCodeCommitment`].
+///
+/// Hints added with [`ZkvmHost::add_hint`] are buffered and handed to the [`MockProverGuest`]
+/// returned by [`ZkvmHost::simulate_with_hints`]. Everything the guest commits is captured by
+/// [`MockProver::prove`], which produces a proof that only verifies against the code
+/// commitment of this prover.
+#[derive(Clone)]
+pub struct MockProver {
+    code_commitment: MockCodeCommitment,
+    hints: VecDeque<Vec<u8>>,
+    committed: Arc<Mutex<Vec<u8>>>,
+}
+
+impl MockProver {
+    /// Creates a new prover for the program with the given code commitment.
+    pub fn new(code_commitment: MockCodeCommitment) -> Self {
+        Self {
+            code_commitment,
+            hints: Default::default(),
+            committed: Default::default(),
+        }
+    }
+
+    /// Returns the code commitment the proofs of this prover are valid for.
+    pub fn code_commitment(&self) -> &MockCodeCommitment {
+        &self.code_commitment
+    }
+
+    /// Deserializes the values committed by the guest so far, in order.
+    ///
+    /// This lets tests assert the outputs of a guest program, such as the [`StateTransition`]
+    /// committed by the STF verifier, without running a real zkVM.
+    pub fn committed<T: DeserializeOwned>(&self) -> Vec<T> {
+        let committed = self.committed.lock().unwrap();
+        let mut remaining = &committed[..];
+        let mut values = Vec::new();
+        while !remaining.is_empty() {
+            values.push(
+                bincode::deserialize_from(&mut remaining)
+                    .expect("The guest committed a value of a different type"),
+            );
+        }
+        values
+    }
+
+    /// Generates a serialized [`MockProof`] of the output committed by the guest so far.
+    pub fn prove(&self) -> Vec<u8> {
+        let committed = self.committed.lock().unwrap();
+        MockProof {
+            program_id: self.code_commitment.clone(),
+            is_valid: true,
+            log: &committed,
+        }
+        .encode_to_vec()
+    }
+}
+
+impl Zkvm for MockProver {
+    type CodeCommitment = MockCodeCommitment;
+
+    type Error = anyhow::Error;
+
+    fn verify<'a>(
+        serialized_proof: &'a [u8],
+        code_commitment: &Self::CodeCommitment,
+    ) -> Result<&'a [u8], Self::Error> {
+        MockProverGuest::verify(serialized_proof, code_commitment)
+    }
+
+    fn verify_and_extract_output<Da: DaSpec, Root: Serialize + DeserializeOwned>(
+        serialized_proof: &[u8],
+        code_commitment: &Self::CodeCommitment,
+    ) -> Result<StateTransition<Da, Root>, Self::Error> {
+        MockProverGuest::verify_and_extract_output(serialized_proof, code_commitment)
+    }
+}
+
+impl ZkvmHost for MockProver {
+    type Guest = MockProverGuest;
+
+    fn add_hint<T: Serialize>(&mut self, item: T) {
+        let hint = bincode::serialize(&item).unwrap();
+        self.hints.push_back(hint);
+    }
+
+    fn simulate_with_hints(&mut self) -> Self::Guest {
+        MockProverGuest {
+            hints: Mutex::new(std::mem::take(&mut self.hints)),
+            committed: self.committed.clone(),
+        }
+    }
+
+    fn run(&mut self, with_proof: bool) -> Result<Proof, anyhow::Error> {
+        if with_proof {
+            Ok(Proof::Full(self.prove()))
+        } else {
+            Ok(Proof::PublicInput(self.committed.lock().unwrap().clone()))
+        }
+    }
+
+    fn extract_output<Da: DaSpec, Root: Serialize + DeserializeOwned>(
+        proof: &Proof,
+    ) -> Result<StateTransition<Da, Root>, Self::Error> {
+        match proof {
+            Proof::PublicInput(output) => Ok(bincode::deserialize(output)?),
+            Proof::Full(serialized_proof) => {
+                let proof = MockProof::decode(serialized_proof)?;
+                Ok(bincode::deserialize(proof.log)?)
+            }
+        }
+    }
+}
+
+/// The guest of a [`MockProver`], which reads the buffered hints and records its commitments.
+pub struct MockProverGuest {
+    hints: Mutex<VecDeque<Vec<u8>>>,
+    committed: Arc<Mutex<Vec<u8>>>,
+}
+
+impl Zkvm for MockProverGuest {
+    type CodeCommitment = MockCodeCommitment;
+
+    type Error = anyhow::Error;
+
+    fn verify<'a>(
+        serialized_proof: &'a [u8],
+        code_commitment: &Self::CodeCommitment,
+    ) -> Result<&'a [u8], Self::Error> {
+        let proof = MockProof::decode(serialized_proof)?;
+        anyhow::ensure!(
+            proof.program_id.matches(code_commitment),
+            "Proof failed to verify against requested code commitment"
+        );
+        anyhow::ensure!(proof.is_valid, "Proof is not valid");
+        Ok(proof.log)
+    }
+
+    fn verify_and_extract_output<Da: DaSpec, Root: Serialize + DeserializeOwned>(
+        serialized_proof: &[u8],
+        code_commitment: &Self::CodeCommitment,
+    ) -> Result<StateTransition<Da, Root>, Self::Error> {
+        let output = Self::verify(serialized_proof, code_commitment)?;
+        Ok(bincode::deserialize(output)?)
+    }
+}
+
+impl ZkvmGuest for MockProverGuest {
+    fn read_from_host<T: DeserializeOwned>(&self) -> T {
+        let hint = self
+            .hints
+            .lock()
+            .unwrap()
+            .pop_front()
+            .expect("The guest read more hints than the host provided");
+        bincode::deserialize(&hint).unwrap()
+    }
+
+    fn commit<T: Serialize>(&self, item: &T) {
+        let mut committed = self.committed.lock().unwrap();
+        bincode::serialize_into(&mut *committed, item).unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A trivial guest program, squaring its input.
+    fn run_guest(guest: &impl ZkvmGuest) {
+        let input: u64 = guest.read_from_host();
+        guest.commit(&(input * input));
+    }
+
+    fn prove_square(code_commitment: MockCodeCommitment, input: u64) -> Vec<u8> {
+        let mut prover = MockProver::new(code_commitment);
+        prover.add_hint(input);
+        run_guest(&prover.simulate_with_hints());
+        prover.prove()
+    }
+
+    #[test]
+    fn test_prove_and_verify() {
+        let proof = prove_square(MockCodeCommitment([1; 32]), 7);
+
+        let output = MockProver::verify(&proof, &MockCodeCommitment([1; 32])).unwrap();
+        assert_eq!(bincode::deserialize::<u64>(output).unwrap(), 49);
+    }
+
+    #[test]
+    fn test_records_each_commitment() {
+        let mut prover = MockProver::new(MockCodeCommitment([1; 32]));
+        prover.add_hint(3u64);
+
+        let guest = prover.simulate_with_hints();
+        let input: u64 = guest.read_from_host();
+        guest.commit(&input);
+        guest.commit(&(input * input));
+
+        assert_eq!(prover.committed::<u64>(), vec![3, 9]);
+    }
+
+    #[test]
+    fn test_verify_fails_on_code_commitment_mismatch() {
+        let proof = prove_square(MockCodeCommitment([1; 32]), 7);
+
+        let err = MockProver::verify(&proof, &MockCodeCommitment([2; 32])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Proof failed to verify against requested code commitment"
+        );
+    }
+}
@@ -11,6 +11,8 @@ use serde::Serialize;
 use sov_rollup_interface::zk::{Zkvm, ZkvmGuest};
 
 use crate::Risc0MethodId;
+#[cfg(target_os = "zkvm")]
+use crate::Risc0Proof;
 
 #[cfg(target_os = "zkvm")]
 impl ZkvmGuest for Risc0Guest {
@@ -28,6 +30,10 @@ impl ZkvmGuest for Risc0Guest {
 struct Hints {
     values: Vec<u32>,
     position: usize,
+    /// The type name and length in words of each hint, in the order the host wrote them.
+    /// Empty if the hints were provided without tags, in which case reads aren't checked.
+    tags: Vec<(&'static str, usize)>,
+    reads: usize,
 }
 
 #[cfg(not(target_os = "zkvm"))]
@@ -35,9 +41,50 @@ impl Hints {
     pub fn with_hints(hints: Vec<u32>) -> Self {
         Hints {
             values: hints,
-            position: 0,
+            ..Default::default()
+        }
+    }
+
+    /// Checks that the value read from `start` matches the next hint written by the host.
+    ///
+    /// The hints are a plain sequence of words, so reading them in a different order than they
+    /// were written tends to produce garbage rather than an error. A mismatch with the tags
+    /// makes the read panic with a description of both sides instead.
+    fn check_read<T>(&mut self, start: usize, read: risc0_zkvm::serde::Result<T>) -> T {
+        let index = self.reads;
+        self.reads += 1;
+        let read_type = hint_type_name::<T>();
+        match self.tags.get(index) {
+            Some(&(written_type, len)) => assert!(
+                written_type == read_type && read.is_ok() && self.position - start == len,
+                "The guest read hint #{} as `{}`, but the host wrote a `{}` of {} words there. \
+                 The guest must read the hints in the order the host wrote them",
+                index,
+                read_type,
+                written_type,
+                len
+            ),
+            None if !self.tags.is_empty() => panic!(
+                "The guest read hint #{} as `{}`, but the host only wrote {} hints",
+                index,
+                read_type,
+                self.tags.len()
+            ),
+            None => {}
         }
+        read.unwrap_or_else(|e| panic!("Failed to read hint #{} as `{}`: {}", index, read_type, e))
+    }
+}
+
+/// Returns the name of the type of a hint, without the references, which serialize like the
+/// values they point to.
+#[cfg(not(target_os = "zkvm"))]
+pub(crate) fn hint_type_name<T: ?Sized>() -> &'static str {
+    let mut name = std::any::type_name::<T>();
+    while let Some(referenced) = name.strip_prefix('&') {
+        name = referenced.strip_prefix("mut ").unwrap_or(referenced);
     }
+    name
 }
 
 #[cfg(not(target_os = "zkvm"))]
@@ -94,14 +141,30 @@ impl Risc0Guest {
             commits: Default::default(),
         }
     }
+
+    /// Constructs a new Risc0 Guest with the provided hints, where `tags` holds the type name
+    /// and length in words of each hint. Every read is checked against the tag of the hint at
+    /// the same position, see [`crate::host::Risc0Host`].
+    #[cfg(all(feature = "native", not(target_os = "zkvm")))]
+    pub(crate) fn with_tagged_hints(hints: Vec<u32>, tags: Vec<(&'static str, usize)>) -> Self {
+        Self {
+            hints: std::sync::Mutex::new(Hints {
+                tags,
+                ..Hints::with_hints(hints)
+            }),
+            commits: Default::default(),
+        }
+    }
 }
 
 #[cfg(not(target_os = "zkvm"))]
 impl ZkvmGuest for Risc0Guest {
     fn read_from_host<T: serde::de::DeserializeOwned>(&self) -> T {
         let mut hints = self.hints.lock().unwrap();
-        let mut hints = hints.deref_mut();
-        T::deserialize(&mut Deserializer::new(&mut hints)).unwrap()
+        let hints = hints.deref_mut();
+        let start = hints.position;
+        let read = T::deserialize(&mut Deserializer::new(&mut *hints));
+        hints.check_read(start, read)
     }
 
     fn commit<T: serde::Serialize>(&self, item: &T) {
@@ -116,21 +179,89 @@ impl Zkvm for Risc0Guest {
 
     type Error = anyhow::Error;
 
+    /// Verifies a child proof from inside the guest, returning the journal it committed to.
+    ///
+    /// The serialized proof is a [`Risc0Proof`]. Following risc0's composition model, the guest
+    /// only checks the claim that the child program with the given [`Risc0MethodId`] produced
+    /// the journal: the receipt itself must be added as an assumption by the host, and is
+    /// resolved when the outer proof is generated.
+    ///
+    /// ```ignore
+    /// use sov_risc0_adapter::guest::Risc0Guest;
+    /// use sov_risc0_adapter::Risc0MethodId;
+    /// use sov_rollup_interface::zk::{Zkvm, ZkvmGuest};
+    ///
+    /// pub fn main() {
+    ///     let guest = Risc0Guest::new();
+    ///     let child_method_id: Risc0MethodId = guest.read_from_host();
+    ///     let child_proof: Vec<u8> = guest.read_from_host();
+    ///
+    ///     let child_output = Risc0Guest::verify(&child_proof, &child_method_id)
+    ///         .expect("The child proof must be valid");
+    ///     guest.commit(&child_output);
+    /// }
+    /// ```
+    ///
+    /// Proofs can only be verified inside of Risc0's environment. Elsewhere, this method returns an error.
     fn verify<'a>(
-        _serialized_proof: &'a [u8],
-        _code_commitment: &Self::CodeCommitment,
+        serialized_proof: &'a [u8],
+        code_commitment: &Self::CodeCommitment,
     ) -> Result<&'a [u8], Self::Error> {
-        // Implement this method once risc0 supports recursion: issue #633
-        todo!("Implement once risc0 supports recursion: https://github.com/Sovereign-Labs/sovereign-sdk/issues/633")
+        #[cfg(target_os = "zkvm")]
+        {
+            let Risc0Proof::<'a> { journal, .. } = bincode::deserialize(serialized_proof)?;
+            env::verify(code_commitment.0, journal)
+                .map_err(|e| anyhow::anyhow!("Child proof verification failed: {e:?}"))?;
+            Ok(journal)
+        }
+        #[cfg(not(target_os = "zkvm"))]
+        {
+            let _ = (serialized_proof, code_commitment);
+            Err(anyhow::anyhow!(
+                "Proofs can only be verified from within the Risc0 guest"
+            ))
+        }
     }
 
     fn verify_and_extract_output<
         Da: sov_rollup_interface::da::DaSpec,
         Root: Serialize + DeserializeOwned,
     >(
-        _serialized_proof: &[u8],
-        _code_commitment: &Self::CodeCommitment,
+        serialized_proof: &[u8],
+        code_commitment: &Self::CodeCommitment,
     ) -> Result<sov_rollup_interface::zk::StateTransition<Da, Root>, Self::Error> {
-        todo!()
+        let output = Self::verify(serialized_proof, code_commitment)?;
+        Ok(risc0_zkvm::serde::from_slice(output)?)
+    }
+
+    /// Proofs can only be verified from within the Risc0 guest.
+    fn supports_verification() -> bool {
+        cfg!(target_os = "zkvm")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_is_zkvm_guest<Vm: ZkvmGuest<CodeCommitment = Risc0MethodId>>() {}
+
+    #[test]
+    fn test_verify_matches_zkvm_signature() {
+        assert_is_zkvm_guest::<Risc0Guest>();
+
+        let verify: for<'a> fn(&'a [u8], &Risc0MethodId) -> Result<&'a [u8], anyhow::Error> =
+            <Risc0Guest as Zkvm>::verify;
+        let _ = verify;
+    }
+
+    #[test]
+    fn test_verify_fails_outside_of_the_guest() {
+        assert!(!Risc0Guest::supports_verification());
+        let err = Risc0Guest::verify(&[], &Risc0MethodId([0; 8])).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Proofs can only be verified from within the Risc0 guest"
+        );
     }
 }
@@ -1,18 +1,27 @@
 //! This module implements the [`ZkvmHost`] trait for the RISC0 VM.
 
-use risc0_zkvm::{ExecutorEnvBuilder, ExecutorImpl, InnerReceipt, Journal, Receipt, Session};
+use risc0_zkvm::{ExecutorEnvBuilder, ExecutorImpl, Journal, Receipt, Session};
 use serde::de::DeserializeOwned;
 use serde::Serialize;
-use sov_rollup_interface::zk::{Proof, Zkvm, ZkvmHost};
+use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::zk::{Proof, StateTransition, Zkvm, ZkvmHost};
 
-use crate::guest::Risc0Guest;
+use crate::guest::{hint_type_name, Risc0Guest};
 use crate::Risc0MethodId;
 
+pub use crate::Risc0Proof;
+
 /// A [`Risc0Host`] stores a binary to execute in the Risc0 VM, and accumulates hints to be
 /// provided to its execution.
+///
+/// The host also records the type and the serialized length of every hint.
+/// The guest returned by [`ZkvmHost::simulate_with_hints`] checks its reads against them, so
+/// reading the hints in a different order than they were added panics with a description of
+/// the mismatch. The hints passed to the Risc0 VM are not tagged.
 #[derive(Clone)]
 pub struct Risc0Host<'a> {
     env: Vec<u32>,
+    hint_tags: Vec<(&'static str, usize)>,
     elf: &'a [u8],
 }
 
@@ -42,6 +51,7 @@ impl<'a> Risc0Host<'a> {
     pub fn new(elf: &'a [u8]) -> Self {
         Self {
             env: Default::default(),
+            hint_tags: Default::default(),
             elf,
         }
     }
@@ -61,6 +71,16 @@ impl<'a> Risc0Host<'a> {
         let session = self.run_without_proving()?;
         session.prove()
     }
+
+    /// Decodes the [`StateTransition`] committed to the journal of the given receipt.
+    ///
+    /// This doesn't verify the receipt: use [`Zkvm::verify_and_extract_output`] for
+    /// proofs coming from untrusted sources.
+    pub fn extract_output_from_receipt<Da: DaSpec, Root: Serialize + DeserializeOwned>(
+        receipt: &Receipt,
+    ) -> anyhow::Result<StateTransition<Da, Root>> {
+        Ok(receipt.journal.decode()?)
+    }
 }
 
 impl<'a> ZkvmHost for Risc0Host<'a> {
@@ -76,13 +96,19 @@ impl<'a> ZkvmHost for Risc0Host<'a> {
         self.env
             .reserve(std::mem::size_of::<T>() / std::mem::size_of::<u32>());
 
+        let start = self.env.len();
         let mut serializer = risc0_zkvm::serde::Serializer::new(&mut self.env);
         item.serialize(&mut serializer)
             .expect("Risc0 hint serialization is infallible");
+        self.hint_tags
+            .push((hint_type_name::<T>(), self.env.len() - start));
     }
 
     fn simulate_with_hints(&mut self) -> Self::Guest {
-        Risc0Guest::with_hints(std::mem::take(&mut self.env))
+        Risc0Guest::with_tagged_hints(
+            std::mem::take(&mut self.env),
+            std::mem::take(&mut self.hint_tags),
+        )
     }
 
     fn run(&mut self, with_proof: bool) -> Result<Proof, anyhow::Error> {
@@ -97,9 +123,9 @@ impl<'a> ZkvmHost for Risc0Host<'a> {
         }
     }
 
-    fn extract_output<Da: sov_rollup_interface::da::DaSpec, Root: Serialize + DeserializeOwned>(
+    fn extract_output<Da: DaSpec, Root: Serialize + DeserializeOwned>(
         proof: &Proof,
-    ) -> Result<sov_rollup_interface::zk::StateTransition<Da, Root>, Self::Error> {
+    ) -> Result<StateTransition<Da, Root>, Self::Error> {
         match proof {
             Proof::PublicInput(journal) => {
                 let journal: Journal = bincode::deserialize(journal)?;
@@ -107,7 +133,7 @@ impl<'a> ZkvmHost for Risc0Host<'a> {
             }
             Proof::Full(data) => {
                 let receipt: Receipt = bincode::deserialize(data)?;
-                Ok(receipt.journal.decode()?)
+                Self::extract_output_from_receipt(&receipt)
             }
         }
     }
@@ -175,13 +201,3 @@ fn verify_from_slice<'a>(
     receipt.verify(code_commitment.0, journal)?;
     Ok(journal)
 }
-
-/// A convenience type which contains the same data a Risc0 [`Receipt`] but borrows the journal
-/// data. This allows us to avoid one unnecessary copy during proof verification.
-#[derive(serde::Serialize, serde::Deserialize)]
-pub struct Risc0Proof<'a> {
-    /// The cryptographic data certifying the execution of the program.
-    pub receipt: InnerReceipt,
-    /// The public outputs produced by the program execution.
-    pub journal: &'a [u8],
-}
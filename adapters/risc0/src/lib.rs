@@ -4,8 +4,10 @@
 //! This crate contains an adapter allowing the Risc0 to be used as a proof system for
 //! Sovereign SDK rollups.
 use risc0_zkvm::sha::Digest;
+use risc0_zkvm::InnerReceipt;
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use sov_rollup_interface::zk::Matches;
+use sov_rollup_interface::zk::{Matches, OutputEncoding};
 
 pub mod guest;
 #[cfg(feature = "native")]
@@ -36,3 +38,25 @@ impl Matches<[u32; 8]> for Risc0MethodId {
         &self.0 == other
     }
 }
+
+/// The [`OutputEncoding`] of values committed with Risc0's `env::commit`,
+/// which is also the default encoding of `verify_and_extract_output` for the Risc0 zkVMs.
+pub struct Risc0Encoding;
+
+impl<T: DeserializeOwned> OutputEncoding<T> for Risc0Encoding {
+    type Error = risc0_zkvm::serde::Error;
+
+    fn decode(output: &[u8]) -> Result<T, Self::Error> {
+        risc0_zkvm::serde::from_slice(output)
+    }
+}
+
+/// A convenience type which contains the same data a Risc0 [`Receipt`](risc0_zkvm::Receipt) but
+/// borrows the journal data. This allows us to avoid one unnecessary copy during proof verification.
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Risc0Proof<'a> {
+    /// The cryptographic data certifying the execution of the program.
+    pub receipt: InnerReceipt,
+    /// The public outputs produced by the program execution.
+    pub journal: &'a [u8],
+}
@@ -5,6 +5,7 @@ use anyhow::Context;
 use once_cell::sync::Lazy;
 use parking_lot::Mutex;
 use risc0_zkvm::Bytes;
+pub use sov_zk_cycle_utils::CYCLE_AGGREGATOR;
 
 /// A global hashmap mapping metric names to their values.
 pub static GLOBAL_HASHMAP: Lazy<Mutex<HashMap<String, (u64, u64)>>> =
@@ -41,9 +42,14 @@ fn deserialize_custom(serialized: Bytes) -> Result<(String, u64), anyhow::Error>
 ///
 /// When the "bench" feature is enabled, this callback is registered as a syscall
 /// in the Risc0 VM and invoked whenever a function annotated with the [`sov-zk-cycle-utils::cycle_tracker`]
-/// macro is invoked.
+/// macro is invoked, or a [`sov_zk_cycle_utils::CycleScope`] ends. The samples are also recorded
+/// in [`CYCLE_AGGREGATOR`].
 pub fn metrics_callback(input: Bytes) -> Result<Bytes, anyhow::Error> {
     let met_tuple = deserialize_custom(input)?;
+    CYCLE_AGGREGATOR
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .record(&met_tuple.0, met_tuple.1);
     add_value(met_tuple.0, met_tuple.1);
     Ok(Bytes::new())
 }
@@ -1,6 +1,8 @@
+use risc0_zkvm::{InnerReceipt, Receipt};
 use serde::{Deserialize, Serialize};
+use sov_mock_da::{MockDaSpec, MockHash, MockValidityCond};
 use sov_risc0_adapter::host::Risc0Host;
-use sov_rollup_interface::zk::{ZkvmGuest, ZkvmHost};
+use sov_rollup_interface::zk::{StateTransition, ZkvmGuest, ZkvmHost};
 
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
 struct TestStruct {
@@ -32,3 +34,40 @@ fn test_hints_roundtrip() {
     received = guest.read_from_host();
     assert_eq!(hint_b, received);
 }
+
+#[test]
+#[should_panic(
+    expected = "The guest read hint #0 as `u64`, but the host wrote a `native::TestStruct` of"
+)]
+fn test_hints_read_out_of_order() {
+    let mut host = Risc0Host::new(&[]);
+
+    let hint = TestStruct {
+        ints: vec![1, 2, 3],
+        string: "hello".to_string(),
+    };
+    host.add_hint(&hint);
+    host.add_hint(7u64);
+
+    let guest = host.simulate_with_hints();
+    let _: u64 = guest.read_from_host();
+}
+
+#[test]
+fn test_extract_output_from_receipt() {
+    let transition = StateTransition::<MockDaSpec, [u8; 32]> {
+        initial_state_root: [1; 32],
+        final_state_root: [2; 32],
+        slot_hash: MockHash([3; 32]),
+        validity_condition: MockValidityCond::default(),
+    };
+
+    // Build the journal exactly as `env::commit` does inside the guest.
+    let words = risc0_zkvm::serde::to_vec(&transition).unwrap();
+    let journal: &[u8] = bytemuck::cast_slice(&words);
+    let receipt = Receipt::new(InnerReceipt::Fake, journal.to_vec());
+
+    let extracted =
+        Risc0Host::extract_output_from_receipt::<MockDaSpec, [u8; 32]>(&receipt).unwrap();
+    assert_eq!(transition, extracted);
+}
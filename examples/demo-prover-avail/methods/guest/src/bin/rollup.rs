@@ -8,13 +8,12 @@ use demo_stf::app::create_zk_app_template;
 use demo_stf::ArrayWitness;
 use risc0_adapter::guest::Risc0Guest;
 use risc0_zkvm::guest::env;
-use sov_rollup_interface::crypto::NoOpHasher;
 use sov_rollup_interface::da::{DaSpec, DaVerifier};
 use sov_rollup_interface::stf::StateTransitionFunction;
 use sov_rollup_interface::zk::{StateTransition, ZkvmGuest};
 use sov_rollup_interface::da::BlockHeaderTrait;
 use const_rollup_config::{SEQUENCER_AVAIL_DA_ADDRESS};
-use presence::spec::{DaLayerSpec};
+use presence::spec::{ChainParams, DaLayerSpec};
 use presence::spec::header::AvailHeader;
 use presence::spec::address::AvailAddress;
 use presence::spec::block::AvailBlock;
@@ -22,6 +21,10 @@ use presence::verifier::Verifier;
 use presence::spec::transaction::AvailBlobTransaction;
 
 risc0_zkvm::guest::entry!(main);
+
+/// The id of the application the rollup submits its blobs under. Must match the `app_id` of the host config.
+const ROLLUP_APP_ID: u32 = 1;
+
 // steps:
 //  0. Read tx list and proofs
 //  1. Call verify_relevant_tx_list()
@@ -49,7 +52,8 @@ pub fn main() {
 
     let block: AvailBlock = AvailBlock {
         header: header.clone(),
-        transactions: blobs.clone()
+        transactions: blobs.clone(),
+        extrinsics: completeness_proof.extrinsics.clone(),
     };
 
     // Step 2: Apply blobs
@@ -64,9 +68,9 @@ pub fn main() {
     env::write(&"Slot has been applied\n");
 
     // Step 3: Verify tx list
-    let verifier = presence::verifier::Verifier {};
+    let verifier = Verifier::new(ChainParams { app_id: ROLLUP_APP_ID });
     let validity_condition = verifier
-    .verify_relevant_tx_list::<NoOpHasher>(&header, &blobs, inclusion_proof, completeness_proof)
+    .verify_relevant_tx_list(&header, &blobs, inclusion_proof, completeness_proof)
     .expect("Transaction list must be correct");
     env::write(&"Relevant txs verified\n");
 
@@ -10,6 +10,8 @@ use demo_stf::runtime::Runtime;
 use sov_db::ledger_db::{LedgerDB, SlotCommit};
 use sov_mock_da::{MockBlock, MockBlockHeader};
 use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::WorkingSet;
+use sov_modules_stf_blueprint::chain_id::get_chain_id;
 use sov_modules_stf_blueprint::kernels::basic::{BasicKernel, BasicKernelGenesisConfig};
 use sov_modules_stf_blueprint::{GenesisParams, StfBlueprint};
 use sov_prover_storage_manager::new_orphan_storage;
@@ -43,10 +45,9 @@ fn rollup_bench(_bench: &mut Criterion) {
     let ledger_db =
         LedgerDB::with_path(&rollup_config.storage.path).expect("Ledger DB failed to open");
 
-    let da_service = Arc::new(RngDaService::new());
-
     let storage_config = sov_state::config::Config {
         path: rollup_config.storage.path,
+        read_cache_size: None,
     };
     let storage = new_orphan_storage::<DefaultStorageSpec>(&storage_config.path)
         .expect("Failed to initialize orphan ProverStorage");
@@ -73,6 +74,9 @@ fn rollup_bench(_bench: &mut Criterion) {
     };
 
     let (mut current_root, storage) = stf.init_chain(storage, demo_genesis_config);
+    let chain_id = get_chain_id::<DefaultContext>(&mut WorkingSet::new(storage.clone()))
+        .expect("The chain id is set at genesis");
+    let da_service = Arc::new(RngDaService::new(chain_id));
 
     // data generation
     let mut blobs = vec![];
@@ -15,6 +15,8 @@ use prometheus::{Histogram, HistogramOpts, Registry};
 use sov_db::ledger_db::{LedgerDB, SlotCommit};
 use sov_mock_da::{MockBlock, MockBlockHeader, MockDaSpec};
 use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::WorkingSet;
+use sov_modules_stf_blueprint::chain_id::get_chain_id;
 use sov_modules_stf_blueprint::kernels::basic::{BasicKernel, BasicKernelGenesisConfig};
 use sov_modules_stf_blueprint::{GenesisParams, StfBlueprint, TxEffect};
 use sov_prover_storage_manager::ProverStorageManager;
@@ -101,10 +103,9 @@ async fn main() -> Result<(), anyhow::Error> {
     let ledger_db =
         LedgerDB::with_path(&rollup_config.storage.path).expect("Ledger DB failed to open");
 
-    let da_service = Arc::new(RngDaService::new());
-
     let storage_config = sov_state::config::Config {
         path: rollup_config.storage.path.clone(),
+        read_cache_size: None,
     };
     let mut storage_manager =
         ProverStorageManager::<MockDaSpec, DefaultStorageSpec>::new(storage_config)
@@ -139,6 +140,9 @@ async fn main() -> Result<(), anyhow::Error> {
     };
 
     let (mut current_root, storage) = stf.init_chain(storage, demo_genesis_config);
+    let chain_id = get_chain_id::<DefaultContext>(&mut WorkingSet::new(storage.clone()))
+        .expect("The chain id is set at genesis");
+    let da_service = Arc::new(RngDaService::new(chain_id));
 
     storage_manager
         .save_change_set(&genesis_block_header, storage)
@@ -47,7 +47,7 @@ pub fn generate_genesis_config(config_dir: &str) -> anyhow::Result<()> {
     Ok(serde_json::ser::to_writer(data_buf, &data)?)
 }
 
-pub async fn get_bench_blocks() -> anyhow::Result<Vec<MockBlock>> {
+pub async fn get_bench_blocks(chain_id: u64) -> anyhow::Result<Vec<MockBlock>> {
     let txns_per_block = match env::var("TXNS_PER_BLOCK") {
         Ok(txns_per_block) => txns_per_block.parse::<u64>()?,
         Err(_) => {
@@ -68,7 +68,7 @@ pub async fn get_bench_blocks() -> anyhow::Result<Vec<MockBlock>> {
     let mut blocks = vec![];
 
     let create_token_message_gen = BankMessageGenerator::default_generate_create_token();
-    let blob = create_token_message_gen.create_blobs::<<MockDemoRollup as sov_modules_rollup_blueprint::RollupBlueprint>::NativeRuntime>();
+    let blob = create_token_message_gen.create_blobs::<<MockDemoRollup as sov_modules_rollup_blueprint::RollupBlueprint>::NativeRuntime>(chain_id);
     da_service.send_transaction(&blob).await.unwrap();
     let block1 = da_service.get_block_at(1).await.unwrap();
     blocks.push(block1);
@@ -76,7 +76,7 @@ pub async fn get_bench_blocks() -> anyhow::Result<Vec<MockBlock>> {
     let create_transfer_message_gen =
         BankMessageGenerator::default_generate_random_transfers(txns_per_block);
     for i in 0..block_cnt {
-        let blob = create_transfer_message_gen.create_blobs::<<MockDemoRollup as sov_modules_rollup_blueprint::RollupBlueprint>::NativeRuntime>();
+        let blob = create_transfer_message_gen.create_blobs::<<MockDemoRollup as sov_modules_rollup_blueprint::RollupBlueprint>::NativeRuntime>(chain_id);
         da_service.send_transaction(&blob).await.unwrap();
         let blocki = da_service.get_block_at(2 + i).await.unwrap();
         blocks.push(blocki);
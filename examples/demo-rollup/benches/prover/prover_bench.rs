@@ -20,13 +20,14 @@ use prettytable::Table;
 use regex::Regex;
 use risc0::MOCK_DA_ELF;
 use sov_modules_api::default_context::DefaultContext;
-use sov_modules_api::SlotData;
+use sov_modules_api::{SlotData, WorkingSet};
+use sov_modules_stf_blueprint::chain_id::get_chain_id;
 use sov_modules_stf_blueprint::kernels::basic::{BasicKernel, BasicKernelGenesisConfig};
 use sov_modules_stf_blueprint::{GenesisParams, StfBlueprint};
 use sov_prover_storage_manager::ProverStorageManager;
 use sov_risc0_adapter::host::Risc0Host;
 #[cfg(feature = "bench")]
-use sov_risc0_adapter::metrics::GLOBAL_HASHMAP;
+use sov_risc0_adapter::metrics::{CYCLE_AGGREGATOR, GLOBAL_HASHMAP};
 use sov_rollup_interface::da::BlockHeaderTrait;
 use sov_rollup_interface::services::da::DaService;
 use sov_rollup_interface::stf::StateTransitionFunction;
@@ -176,6 +177,7 @@ async fn main() -> Result<(), anyhow::Error> {
     let da_service = MockDaService::new(MockAddress::default());
     let storage_config = sov_state::config::Config {
         path: rollup_config.storage.path,
+        read_cache_size: None,
     };
 
     let mut storage_manager =
@@ -208,6 +210,8 @@ async fn main() -> Result<(), anyhow::Error> {
             .unwrap(),
         genesis_config,
     );
+    let chain_id = get_chain_id::<DefaultContext>(&mut WorkingSet::new(storage.clone()))
+        .expect("The chain id is set at genesis");
     storage_manager
         .save_change_set(genesis_block.header(), storage)
         .unwrap();
@@ -215,7 +219,7 @@ async fn main() -> Result<(), anyhow::Error> {
     storage_manager.finalize(&genesis_block.header).unwrap();
 
     // TODO: Fix this with genesis logic.
-    let blocks = get_bench_blocks().await?;
+    let blocks = get_bench_blocks(chain_id).await?;
 
     for filtered_block in &blocks {
         num_blocks += 1;
@@ -223,9 +227,8 @@ async fn main() -> Result<(), anyhow::Error> {
 
         let height = filtered_block.header().height();
         println!(
-            "Requesting data for height {} and prev_state_root 0x{}",
-            height,
-            hex::encode(prev_state_root.0)
+            "Requesting data for height {} and prev_state_root {}",
+            height, prev_state_root
         );
         let (mut blob_txs, inclusion_proof, completeness_proof) = da_service
             .extract_relevant_blobs_with_proof(filtered_block)
@@ -297,6 +300,8 @@ async fn main() -> Result<(), anyhow::Error> {
         );
         println!("\nCycle Metrics\n");
         print_cycle_averages(metric_map);
+        println!("\nCycles per region\n");
+        println!("{}", CYCLE_AGGREGATOR.lock().unwrap().summary());
         println!("\nTotal cycles consumed for test: {}\n", total_cycles);
     }
 
@@ -1,13 +1,14 @@
 use std::thread;
 use std::time::Duration;
 
-use borsh::ser::BorshSerialize;
 use demo_stf::runtime::RuntimeCall;
+use jsonrpsee::core::client::ClientT;
+use jsonrpsee::rpc_params;
 use sov_mock_da::MockDaSpec;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
 use sov_modules_api::transaction::Transaction;
-use sov_modules_api::PrivateKey;
+use sov_modules_api::{PrivateKey, VersionedCall};
 use sov_nft_module::utils::{
     get_collection_address, get_create_collection_message, get_mint_nft_message,
     get_transfer_nft_message,
@@ -36,16 +37,16 @@ const PK3: [u8; 32] = [
 
 pub fn build_transaction(
     signer: &DefaultPrivateKey,
+    chain_id: u64,
     message: CallMessage<DefaultContext>,
     nonce: u64,
 ) -> Transaction<DefaultContext> {
     let runtime_encoded_message = RuntimeCall::<DefaultContext, MockDaSpec>::nft(message);
-    let chain_id = 0;
     let gas_tip = 0;
     let gas_limit = 0;
     Transaction::<DefaultContext>::new_signed_tx(
         signer,
-        runtime_encoded_message.try_to_vec().unwrap(),
+        runtime_encoded_message.encode_versioned(),
         chain_id,
         gas_tip,
         gas_limit,
@@ -55,6 +56,7 @@ pub fn build_transaction(
 
 pub fn build_create_collection_transactions(
     creator_pk: &DefaultPrivateKey,
+    chain_id: u64,
     start_nonce: &mut u64,
     base_uri: &str,
     collections: &[&str],
@@ -64,6 +66,7 @@ pub fn build_create_collection_transactions(
         .map(|&collection_name| {
             let tx = build_transaction(
                 creator_pk,
+                chain_id,
                 get_create_collection_message(
                     &creator_pk.default_address(),
                     collection_name,
@@ -80,6 +83,7 @@ pub fn build_create_collection_transactions(
 /// Convenience and readability wrapper for build_mint_nft_transaction
 pub fn build_mint_transactions(
     creator_pk: &DefaultPrivateKey,
+    chain_id: u64,
     start_nonce: &mut u64,
     collection: &str,
     start_nft_id: &mut u64,
@@ -91,6 +95,7 @@ pub fn build_mint_transactions(
         .map(|_| {
             let tx = build_transaction(
                 creator_pk,
+                chain_id,
                 get_mint_nft_message(
                     &creator_pk.default_address(),
                     collection,
@@ -109,6 +114,7 @@ pub fn build_mint_transactions(
 
 pub fn build_transfer_transactions(
     signer: &DefaultPrivateKey,
+    chain_id: u64,
     start_nonce: &mut u64,
     collection_address: &CollectionAddress<DefaultContext>,
     nft_ids: Vec<u64>,
@@ -119,6 +125,7 @@ pub fn build_transfer_transactions(
             let new_owner = DefaultPrivateKey::generate().default_address();
             let tx = build_transaction(
                 signer,
+                chain_id,
                 get_transfer_nft_message(collection_address, nft_id, &new_owner),
                 *start_nonce,
             );
@@ -135,11 +142,21 @@ async fn main() {
     let owner_2_pk = DefaultPrivateKey::try_from(&PK3[..]).unwrap();
 
     let client = SimpleClient::new("localhost", 12345).await.unwrap();
+    let chain_id: u64 = client
+        .http()
+        .request("rollup_chainId", rpc_params![])
+        .await
+        .unwrap();
 
     let mut nonce = 0;
     let collections = [COLLECTION_1, COLLECTION_2, COLLECTION_3];
-    let transactions =
-        build_create_collection_transactions(&creator_pk, &mut nonce, DUMMY_URL, &collections);
+    let transactions = build_create_collection_transactions(
+        &creator_pk,
+        chain_id,
+        &mut nonce,
+        DUMMY_URL,
+        &collections,
+    );
     client.send_transactions(transactions, None).await.unwrap();
 
     // sleep is necessary because of how the sequencer currently works
@@ -150,6 +167,7 @@ async fn main() {
     let mut nft_id = 1;
     let mut transactions = build_mint_transactions(
         &creator_pk,
+        chain_id,
         &mut nonce,
         COLLECTION_1,
         &mut nft_id,
@@ -160,6 +178,7 @@ async fn main() {
 
     transactions.extend(build_mint_transactions(
         &creator_pk,
+        chain_id,
         &mut nonce,
         COLLECTION_1,
         &mut nft_id,
@@ -170,6 +189,7 @@ async fn main() {
     let mut nft_id = 1;
     transactions.extend(build_mint_transactions(
         &creator_pk,
+        chain_id,
         &mut nonce,
         COLLECTION_2,
         &mut nft_id,
@@ -194,6 +214,7 @@ async fn main() {
     let nft_ids_to_transfer: Vec<u64> = (1..=6).collect();
     transactions = build_transfer_transactions(
         &owner_1_pk,
+        chain_id,
         &mut owner_1_nonce,
         &collection_1_address,
         nft_ids_to_transfer,
@@ -1,3 +1,5 @@
+use std::sync::Arc;
+
 use async_trait::async_trait;
 use demo_stf::genesis_config::StorageConfig;
 use demo_stf::runtime::Runtime;
@@ -5,14 +7,14 @@ use sov_celestia_adapter::verifier::{CelestiaSpec, CelestiaVerifier, RollupParam
 use sov_celestia_adapter::{CelestiaConfig, CelestiaService};
 use sov_modules_api::default_context::{DefaultContext, ZkDefaultContext};
 use sov_modules_api::{Address, Spec};
-use sov_modules_rollup_blueprint::{RollupBlueprint, WalletBlueprint};
+use sov_modules_rollup_blueprint::{RollupBlueprint, RollupSequencer, WalletBlueprint};
 use sov_modules_stf_blueprint::kernels::basic::BasicKernel;
 use sov_modules_stf_blueprint::StfBlueprint;
 use sov_prover_storage_manager::ProverStorageManager;
 use sov_risc0_adapter::host::Risc0Host;
 use sov_rollup_interface::zk::ZkvmHost;
 use sov_state::{DefaultStorageSpec, Storage, ZkStorage};
-use sov_stf_runner::{ParallelProverService, RollupConfig, RollupProverConfig};
+use sov_stf_runner::{ParallelProverService, RollupConfig, RollupProverConfig, SequencerConfig};
 
 use crate::{ROLLUP_BATCH_NAMESPACE, ROLLUP_PROOF_NAMESPACE};
 
@@ -56,16 +58,29 @@ impl RollupBlueprint for CelestiaDemoRollup {
         storage: &<Self::NativeContext as sov_modules_api::Spec>::Storage,
         ledger_db: &sov_db::ledger_db::LedgerDB,
         da_service: &Self::DaService,
-    ) -> Result<jsonrpsee::RpcModule<()>, anyhow::Error> {
+        sequencer_config: &SequencerConfig,
+    ) -> Result<
+        (
+            jsonrpsee::RpcModule<()>,
+            Arc<RollupSequencer<Self::NativeRuntime, Self::NativeContext, Self::DaService>>,
+        ),
+        anyhow::Error,
+    > {
         // TODO set the sequencer address
-        let sequencer = Address::new([0; 32]);
+        let sequencer_address = Address::new([0; 32]);
 
         #[allow(unused_mut)]
-        let mut rpc_methods = sov_modules_rollup_blueprint::register_rpc::<
+        let (mut rpc_methods, sequencer) = sov_modules_rollup_blueprint::register_rpc::<
             Self::NativeRuntime,
             Self::NativeContext,
             Self::DaService,
-        >(storage, ledger_db, da_service, sequencer)?;
+        >(
+            storage,
+            ledger_db,
+            da_service,
+            sequencer_address,
+            sequencer_config,
+        )?;
 
         #[cfg(feature = "experimental")]
         crate::eth::register_ethereum::<Self::DaService>(
@@ -74,7 +89,7 @@ impl RollupBlueprint for CelestiaDemoRollup {
             &mut rpc_methods,
         )?;
 
-        Ok(rpc_methods)
+        Ok((rpc_methods, sequencer))
     }
 
     async fn create_da_service(
@@ -121,6 +136,7 @@ impl RollupBlueprint for CelestiaDemoRollup {
     ) -> Result<Self::StorageManager, anyhow::Error> {
         let storage_config = StorageConfig {
             path: rollup_config.storage.path.clone(),
+            read_cache_size: rollup_config.storage.read_cache_size,
         };
         ProverStorageManager::new(storage_config)
     }
@@ -105,8 +105,12 @@ impl<C: Context, Da: DaSpec> SlotHooks<Da> for Runtime<C, Da> {
         #[allow(unused_variables)] working_set: &mut sov_modules_api::WorkingSet<C>,
     ) {
         #[cfg(feature = "experimental")]
-        self.evm
-            .begin_slot_hook(slot_header.hash().into(), pre_state_root, working_set);
+        self.evm.begin_slot_hook(
+            slot_header.hash().into(),
+            slot_header.time().unix_timestamp(),
+            pre_state_root,
+            working_set,
+        );
     }
 
     fn end_slot_hook(
@@ -1,14 +1,17 @@
 use std::rc::Rc;
 
+use borsh::BorshSerialize;
 use sov_data_generators::bank_data::{
-    BadNonceBankCallMessages, BadSerializationBankCallMessages, BadSignatureBankCallMessages,
-    BankMessageGenerator,
+    get_default_private_key, BadNonceBankCallMessages, BadSerializationBankCallMessages,
+    BadSignatureBankCallMessages, BankMessageGenerator,
 };
 use sov_data_generators::value_setter_data::{ValueSetterMessage, ValueSetterMessages};
 use sov_data_generators::MessageGenerator;
 use sov_mock_da::MockDaSpec;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
+use sov_modules_api::transaction::Transaction;
+use sov_modules_api::{EncodeCall, PrivateKey};
 use sov_modules_stf_blueprint::RawTx;
 
 use crate::runtime::Runtime;
@@ -16,40 +19,74 @@ use crate::runtime::Runtime;
 type C = DefaultContext;
 type Da = MockDaSpec;
 
-pub fn simulate_da(value_setter_admin: DefaultPrivateKey) -> Vec<RawTx> {
+pub fn simulate_da(value_setter_admin: DefaultPrivateKey, chain_id: u64) -> Vec<RawTx> {
     let mut messages = Vec::default();
 
     let bank_generator = BankMessageGenerator::<C>::default();
-    let bank_txs = bank_generator.create_raw_txs::<Runtime<C, Da>>();
+    let bank_txs = bank_generator.create_raw_txs::<Runtime<C, Da>>(chain_id);
 
     let value_setter = ValueSetterMessages::new(vec![ValueSetterMessage {
         admin: Rc::new(value_setter_admin),
         messages: vec![99, 33],
     }]);
-    messages.extend(value_setter.create_raw_txs::<Runtime<C, Da>>());
+    messages.extend(value_setter.create_raw_txs::<Runtime<C, Da>>(chain_id));
     messages.extend(bank_txs);
     messages
 }
 
-pub fn simulate_da_with_revert_msg() -> Vec<RawTx> {
+pub fn simulate_da_with_revert_msg(chain_id: u64) -> Vec<RawTx> {
     let mut messages = Vec::default();
     let bank_generator = BankMessageGenerator::<C>::create_invalid_transfer();
-    let bank_txns = bank_generator.create_raw_txs::<Runtime<C, Da>>();
+    let bank_txns = bank_generator.create_raw_txs::<Runtime<C, Da>>(chain_id);
     messages.extend(bank_txns);
     messages
 }
 
-pub fn simulate_da_with_bad_sig() -> Vec<RawTx> {
+pub fn simulate_da_with_bad_sig(chain_id: u64) -> Vec<RawTx> {
     let b: BadSignatureBankCallMessages = Default::default();
-    b.create_raw_txs::<Runtime<C, Da>>()
+    b.create_raw_txs::<Runtime<C, Da>>(chain_id)
 }
 
-pub fn simulate_da_with_bad_nonce() -> Vec<RawTx> {
+pub fn simulate_da_with_bad_nonce(chain_id: u64) -> Vec<RawTx> {
     let b: BadNonceBankCallMessages = Default::default();
-    b.create_raw_txs::<Runtime<C, Da>>()
+    b.create_raw_txs::<Runtime<C, Da>>(chain_id)
 }
 
-pub fn simulate_da_with_bad_serialization() -> Vec<RawTx> {
+pub fn simulate_da_with_bad_serialization(chain_id: u64) -> Vec<RawTx> {
     let b: BadSerializationBankCallMessages = Default::default();
-    b.create_raw_txs::<Runtime<C, Da>>()
+    b.create_raw_txs::<Runtime<C, Da>>(chain_id)
+}
+
+/// Creates one token per entry of `valid_until_slots`, named `ExpiringToken{i}` with salt `i`,
+/// in transactions from the default private key which expire after the given slot.
+pub fn simulate_da_with_expiring_txs(valid_until_slots: &[u64], chain_id: u64) -> Vec<RawTx> {
+    let minter_key = get_default_private_key();
+    let minter_address = minter_key.default_address();
+
+    valid_until_slots
+        .iter()
+        .enumerate()
+        .map(|(i, valid_until_slot)| {
+            let message = sov_bank::CallMessage::<C>::CreateToken {
+                salt: i as u64,
+                token_name: format!("ExpiringToken{}", i),
+                initial_balance: 1000,
+                minter_address,
+                authorized_minters: vec![],
+            };
+            let message = <Runtime<C, Da> as EncodeCall<sov_bank::Bank<C>>>::encode_call(message);
+            let tx = Transaction::<C>::new_signed_tx_with_expiry(
+                &minter_key,
+                message,
+                chain_id,
+                0,
+                0,
+                i as u64,
+                Some(*valid_until_slot),
+            );
+            RawTx {
+                data: tx.try_to_vec().unwrap(),
+            }
+        })
+        .collect()
 }
@@ -2,7 +2,8 @@ use std::path::Path;
 
 use sov_mock_da::MockDaSpec;
 use sov_modules_api::default_context::DefaultContext;
-use sov_modules_api::DaSpec;
+use sov_modules_api::{DaSpec, Spec, WorkingSet};
+use sov_modules_stf_blueprint::chain_id::get_chain_id;
 use sov_modules_stf_blueprint::kernels::basic::{BasicKernel, BasicKernelGenesisConfig};
 use sov_modules_stf_blueprint::{GenesisParams, StfBlueprint};
 use sov_prover_storage_manager::ProverStorageManager;
@@ -32,6 +33,7 @@ pub(crate) fn create_storage_manager_for_tests(
 ) -> ProverStorageManager<MockDaSpec, DefaultStorageSpec> {
     let config = sov_state::config::Config {
         path: path.as_ref().to_path_buf(),
+        read_cache_size: None,
     };
     ProverStorageManager::new(config).unwrap()
 }
@@ -51,3 +53,8 @@ pub(crate) fn get_genesis_config_for_tests<Da: DaSpec>(
         kernel: kernel_params,
     }
 }
+
+/// Reads the chain id stored at genesis, which the transactions of a test have to be signed for.
+pub(crate) fn get_chain_id_for_tests(storage: &<C as Spec>::Storage) -> u64 {
+    get_chain_id::<C>(&mut WorkingSet::new(storage.clone())).expect("The chain is initialized")
+}
@@ -1,20 +1,32 @@
 use sov_cli::wallet_state::PrivateKeyAndAddress;
 use sov_data_generators::bank_data::get_default_token_address;
 use sov_data_generators::{has_tx_events, new_test_blob_from_batch};
-use sov_mock_da::{MockBlock, MockDaSpec, MOCK_SEQUENCER_DA_ADDRESS};
-use sov_modules_api::default_context::DefaultContext;
+use sov_db::ledger_db::{LedgerDB, SlotCommit};
+use sov_mock_da::{
+    MockAddress, MockBlob, MockBlock, MockDaSpec, MockDaVerifier, MOCK_SEQUENCER_DA_ADDRESS,
+};
+use sov_mock_zkvm::{MockCodeCommitment, MockProver, MockProverGuest};
+use sov_modules_api::default_context::{DefaultContext, ZkDefaultContext};
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
-use sov_modules_api::{Context, PrivateKey, WorkingSet};
-use sov_modules_stf_blueprint::{Batch, SequencerOutcome, StfBlueprint};
+use sov_modules_api::{Address, Context, DispatchCall, EncodeCall, PrivateKey, WorkingSet};
+use sov_modules_stf_blueprint::chain_id::get_chain_id;
+use sov_modules_stf_blueprint::kernels::basic::BasicKernel;
+use sov_modules_stf_blueprint::{Batch, SequencerOutcome, StfBlueprint, TxEffect};
+use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait};
+use sov_rollup_interface::rpc::{LedgerRpcProvider, QueryMode};
 use sov_rollup_interface::services::da::SlotData;
 use sov_rollup_interface::stf::StateTransitionFunction;
 use sov_rollup_interface::storage::HierarchicalStorageManager;
+use sov_rollup_interface::zk::{StateTransition, StateTransitionData, ZkvmHost};
+use sov_state::ZkStorage;
 
 use crate::runtime::Runtime;
 use crate::tests::da_simulation::simulate_da;
 use crate::tests::{
-    create_storage_manager_for_tests, get_genesis_config_for_tests, StfBlueprintTest, C,
+    create_storage_manager_for_tests, get_chain_id_for_tests, get_genesis_config_for_tests,
+    StfBlueprintTest, C,
 };
+use crate::StfVerifier;
 
 #[test]
 fn test_demo_values_in_db() {
@@ -32,13 +44,14 @@ fn test_demo_values_in_db() {
             .create_storage_on(genesis_block.header())
             .unwrap();
         let (genesis_root, storage) = stf.init_chain(storage, config);
+        let chain_id = get_chain_id_for_tests(&storage);
         storage_manager
             .save_change_set(genesis_block.header(), storage)
             .unwrap();
 
         let priv_key = read_private_key::<DefaultContext>().private_key;
-        let txs = simulate_da(priv_key);
-        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS, [0; 32]);
+        let txs = simulate_da(priv_key, chain_id);
+        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
 
         let mut blobs = [blob];
 
@@ -106,14 +119,15 @@ fn test_demo_values_in_cache() {
         .create_storage_on(genesis_block.header())
         .unwrap();
     let (genesis_root, storage) = stf.init_chain(storage, config);
+    let chain_id = get_chain_id_for_tests(&storage);
     storage_manager
         .save_change_set(genesis_block.header(), storage)
         .unwrap();
 
     let private_key = read_private_key::<DefaultContext>().private_key;
-    let txs = simulate_da(private_key);
+    let txs = simulate_da(private_key, chain_id);
 
-    let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS, [0; 32]);
+    let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
     let mut blobs = [blob];
     let block_1 = genesis_block.next_mock();
     let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
@@ -152,6 +166,70 @@ fn test_demo_values_in_cache() {
     assert_eq!(resp, sov_value_setter::Response { value: Some(33) });
 }
 
+#[test]
+fn test_dry_run_leaves_state_unchanged() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+    let mut storage_manager = create_storage_manager_for_tests(path);
+
+    let stf: StfBlueprintTest = StfBlueprint::new();
+    let config = get_genesis_config_for_tests();
+
+    let genesis_block = MockBlock::default();
+    let storage = storage_manager
+        .create_storage_on(genesis_block.header())
+        .unwrap();
+    let (genesis_root, storage) = stf.init_chain(storage, config);
+    let chain_id = get_chain_id_for_tests(&storage);
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    let private_key = read_private_key::<DefaultContext>().private_key;
+    let txs = simulate_da(private_key, chain_id);
+    let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
+    let block_1 = genesis_block.next_mock();
+
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let dry_run_result = stf.dry_run_slot(
+        &genesis_root,
+        storage,
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut [blob.clone()],
+    );
+    assert_eq!(1, dry_run_result.batch_receipts.len());
+    assert_eq!(4, dry_run_result.batch_receipts[0].tx_receipts.len());
+    assert_eq!(
+        SequencerOutcome::Rewarded(0),
+        dry_run_result.batch_receipts[0].inner
+    );
+
+    // Neither the token created by the bank transactions nor the value were written.
+    let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let mut working_set = WorkingSet::new(storage);
+    let resp = runtime
+        .bank
+        .supply_of(None, get_default_token_address(), &mut working_set)
+        .unwrap();
+    assert_eq!(resp, sov_bank::TotalSupplyResponse { amount: None });
+    let resp = runtime.value_setter.query_value(&mut working_set).unwrap();
+    assert_eq!(resp, sov_value_setter::Response { value: None });
+
+    // Applying the slot for real produces the root predicted by the dry run.
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let result = stf.apply_slot(
+        &genesis_root,
+        storage,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut [blob],
+    );
+    assert_eq!(dry_run_result.state_root, result.state_root);
+}
+
 #[test]
 #[ignore = "end_slot is removed from STF trait"]
 fn test_demo_values_not_in_db() {
@@ -172,12 +250,13 @@ fn test_demo_values_not_in_db() {
             .create_storage_on(genesis_block.header())
             .unwrap();
         let (genesis_root, storage) = stf.init_chain(storage, config);
+        let chain_id = get_chain_id_for_tests(&storage);
         storage_manager
             .save_change_set(genesis_block.header(), storage)
             .unwrap();
 
-        let txs = simulate_da(value_setter_admin_private_key);
-        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS, [0; 32]);
+        let txs = simulate_da(value_setter_admin_private_key, chain_id);
+        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
         let mut blobs = [blob];
 
         let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
@@ -239,6 +318,7 @@ fn test_sequencer_unknown_sequencer() {
             .unwrap(),
         config,
     );
+    let chain_id = get_chain_id_for_tests(&storage);
     storage_manager
         .save_change_set(genesis_block.header(), storage)
         .unwrap();
@@ -246,8 +326,8 @@ fn test_sequencer_unknown_sequencer() {
     let some_sequencer: [u8; 32] = [121; 32];
 
     let private_key = read_private_key::<DefaultContext>().private_key;
-    let txs = simulate_da(private_key);
-    let blob = new_test_blob_from_batch(Batch { txs }, &some_sequencer, [0; 32]);
+    let txs = simulate_da(private_key, chain_id);
+    let blob = new_test_blob_from_batch(Batch { txs }, &some_sequencer);
     let mut blobs = [blob];
 
     let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
@@ -274,6 +354,229 @@ fn test_sequencer_unknown_sequencer() {
     assert!(!has_tx_events(&apply_blob_outcome));
 }
 
+#[test]
+fn test_batches_by_category() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+    let mut storage_manager = create_storage_manager_for_tests(path);
+    let config = get_genesis_config_for_tests();
+
+    let genesis_block = MockBlock::default();
+    let block_1 = genesis_block.next_mock();
+
+    let stf: StfBlueprintTest = StfBlueprint::new().with_blob_categories();
+    let (genesis_root, storage) = stf.init_chain(
+        storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap(),
+        config,
+    );
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    // Each blob starts with its category byte, followed by the batch.
+    let sequencer = MockAddress::new(MOCK_SEQUENCER_DA_ADDRESS);
+    let mut blobs = [1u8, 2, 1].map(|category| {
+        let mut data = vec![category];
+        data.extend(borsh::to_vec(&Batch { txs: vec![] }).unwrap());
+        MockBlob::with_data_hash(data, sequencer)
+    });
+    let blob_hashes: Vec<[u8; 32]> = blobs.iter().map(|blob| blob.hash()).collect();
+
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let apply_block_result = stf.apply_slot(
+        &genesis_root,
+        storage,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut blobs,
+    );
+
+    let categories: Vec<_> = apply_block_result
+        .batch_receipts
+        .iter()
+        .map(|receipt| receipt.category)
+        .collect();
+    assert_eq!(categories, vec![Some(1), Some(2), Some(1)]);
+
+    let ledger_db = LedgerDB::with_path(path).unwrap();
+    let mut slot = SlotCommit::new(block_1);
+    for receipt in apply_block_result.batch_receipts {
+        slot.add_batch(receipt);
+    }
+    ledger_db.commit_slot(slot).unwrap();
+
+    for (category, expected_hashes) in [
+        (1, vec![blob_hashes[0], blob_hashes[2]]),
+        (2, vec![blob_hashes[1]]),
+    ] {
+        let hashes: Vec<[u8; 32]> = ledger_db
+            .get_batches_by_category::<SequencerOutcome<MockAddress>, TxEffect>(
+                category,
+                1,
+                3,
+                QueryMode::Compact,
+            )
+            .unwrap()
+            .into_iter()
+            .map(|batch| batch.unwrap().hash)
+            .collect();
+        assert_eq!(hashes, expected_hashes);
+    }
+}
+
+#[test]
+fn test_zk_state_transition_matches_native() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+    let config = get_genesis_config_for_tests();
+
+    let genesis_block = MockBlock::default();
+    let block_1 = genesis_block.next_mock();
+
+    let stf: StfBlueprintTest = StfBlueprint::new();
+    let storage = storage_manager
+        .create_storage_on(genesis_block.header())
+        .unwrap();
+    let (genesis_root, storage) = stf.init_chain(storage, config);
+    let chain_id = get_chain_id_for_tests(&storage);
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    let priv_key = read_private_key::<DefaultContext>().private_key;
+    let txs = simulate_da(priv_key, chain_id);
+    let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
+    // The guest reads the blobs from scratch, so it must get them before native execution consumes them.
+    let zk_blobs = vec![blob.clone()];
+    let mut blobs = [blob];
+
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let result = stf.apply_slot(
+        &genesis_root,
+        storage,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut blobs,
+    );
+
+    let mut host = MockProver::new(MockCodeCommitment([0; 32]));
+    host.add_hint(StateTransitionData::<_, _, MockDaSpec> {
+        initial_state_root: genesis_root,
+        final_state_root: result.state_root,
+        da_block_header: block_1.header.clone(),
+        inclusion_proof: [0; 32],
+        completeness_proof: (),
+        blobs: zk_blobs,
+        state_transition_witness: result.witness,
+    });
+
+    let zk_stf: StfBlueprint<
+        ZkDefaultContext,
+        MockDaSpec,
+        MockProverGuest,
+        Runtime<ZkDefaultContext, MockDaSpec>,
+        BasicKernel<ZkDefaultContext, MockDaSpec>,
+    > = StfBlueprint::new();
+    StfVerifier::new(zk_stf, MockDaVerifier {})
+        .run_block(host.simulate_with_hints(), ZkStorage::new())
+        .unwrap();
+
+    let committed = host.committed::<StateTransition<MockDaSpec, _>>();
+    assert_eq!(committed.len(), 1);
+    assert_eq!(committed[0].initial_state_root, genesis_root);
+    assert_eq!(committed[0].final_state_root, result.state_root);
+    assert_eq!(committed[0].slot_hash, block_1.header.hash());
+}
+
+#[test]
+fn test_chain_id_is_derived_from_genesis() {
+    let chain_id_at_genesis = |config| {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+        let genesis_block = MockBlock::default();
+        let stf: StfBlueprintTest = StfBlueprint::new();
+        let storage = storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap();
+        let (_, storage) = stf.init_chain(storage, config);
+        get_chain_id::<C>(&mut WorkingSet::new(storage)).unwrap()
+    };
+
+    let chain_id = chain_id_at_genesis(get_genesis_config_for_tests());
+    assert_eq!(
+        chain_id,
+        chain_id_at_genesis(get_genesis_config_for_tests()),
+        "Identical genesis should produce the same chain id"
+    );
+
+    let mut config = get_genesis_config_for_tests();
+    config.runtime.value_setter.admin = Address::from([7; 32]);
+    assert_ne!(
+        chain_id,
+        chain_id_at_genesis(config),
+        "A different genesis should produce a different chain id"
+    );
+}
+
+#[test]
+fn test_genesis_reloaded_from_bytes_has_same_root() {
+    let genesis_root = |config| {
+        let tempdir = tempfile::tempdir().unwrap();
+        let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+        let genesis_block = MockBlock::default();
+        let stf: StfBlueprintTest = StfBlueprint::new();
+        let storage = storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap();
+        let (root, _) = stf.init_chain(storage, config);
+        root
+    };
+
+    let bytes = serde_json::to_vec(&get_genesis_config_for_tests()).unwrap();
+    let reloaded = StfBlueprintTest::genesis_from_bytes(&bytes).unwrap();
+    assert_eq!(
+        genesis_root(get_genesis_config_for_tests()),
+        genesis_root(reloaded)
+    );
+
+    assert!(StfBlueprintTest::genesis_from_bytes(&bytes[1..]).is_err());
+}
+
+#[test]
+fn test_dispatch_reports_module_name() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let mut storage_manager = create_storage_manager_for_tests(tempdir.path());
+    let genesis_block = MockBlock::default();
+    let stf: StfBlueprintTest = StfBlueprint::new();
+    let storage = storage_manager
+        .create_storage_on(genesis_block.header())
+        .unwrap();
+    let (_, storage) = stf.init_chain(storage, get_genesis_config_for_tests());
+    let mut working_set = WorkingSet::new(storage);
+
+    let sender = Address::from([1; 32]);
+    let message = sov_bank::CallMessage::<C>::CreateToken {
+        salt: 0,
+        token_name: "Token".to_owned(),
+        initial_balance: 100,
+        minter_address: sender,
+        authorized_minters: vec![],
+    };
+    let encoded = <Runtime<C, MockDaSpec> as EncodeCall<sov_bank::Bank<C>>>::encode_call(message);
+    let decoded = Runtime::<C, MockDaSpec>::decode_call(&encoded).unwrap();
+
+    let runtime = Runtime::<C, MockDaSpec>::default();
+    let context = C::new(sender, Address::from([2; 32]), 1);
+    let (module_name, result) =
+        runtime.dispatch_call_with_module_name(decoded, &mut working_set, &context);
+    assert_eq!("bank", module_name);
+    assert!(result.is_ok());
+}
+
 fn read_private_key<C: Context>() -> PrivateKeyAndAddress<C> {
     let token_deployer_data =
         std::fs::read_to_string("../../test-data/keys/token_deployer_private_key.json")
@@ -1,20 +1,26 @@
 use sov_accounts::Response;
+use sov_bank::get_token_address;
 use sov_data_generators::bank_data::{get_default_private_key, get_default_token_address};
 use sov_data_generators::{has_tx_events, new_test_blob_from_batch};
-use sov_mock_da::{MockAddress, MockBlock, MockDaSpec, MOCK_SEQUENCER_DA_ADDRESS};
+use sov_mock_da::{MockAddress, MockBlob, MockBlock, MockDaSpec, MOCK_SEQUENCER_DA_ADDRESS};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::{PrivateKey, WorkingSet};
-use sov_modules_stf_blueprint::{Batch, SequencerOutcome, SlashingReason, StfBlueprint, TxEffect};
-use sov_rollup_interface::da::BlobReaderTrait;
+use sov_modules_stf_blueprint::{
+    Batch, DecodeFailurePolicy, SequencerOutcome, SlashingReason, StfBlueprint, TxEffect,
+};
+use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait};
 use sov_rollup_interface::services::da::SlotData;
 use sov_rollup_interface::stf::StateTransitionFunction;
 use sov_rollup_interface::storage::HierarchicalStorageManager;
 
-use super::{create_storage_manager_for_tests, get_genesis_config_for_tests, RuntimeTest};
+use super::{
+    create_storage_manager_for_tests, get_chain_id_for_tests, get_genesis_config_for_tests,
+    RuntimeTest,
+};
 use crate::runtime::Runtime;
 use crate::tests::da_simulation::{
     simulate_da_with_bad_nonce, simulate_da_with_bad_serialization, simulate_da_with_bad_sig,
-    simulate_da_with_revert_msg,
+    simulate_da_with_expiring_txs, simulate_da_with_revert_msg,
 };
 use crate::tests::StfBlueprintTest;
 
@@ -45,12 +51,13 @@ fn test_tx_revert() {
                 .unwrap(),
             config,
         );
+        let chain_id = get_chain_id_for_tests(&storage);
         storage_manager
             .save_change_set(genesis_block.header(), storage)
             .unwrap();
 
-        let txs = simulate_da_with_revert_msg();
-        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS, [0; 32]);
+        let txs = simulate_da_with_revert_msg(chain_id);
+        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
         let mut blobs = [blob];
 
         let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
@@ -146,13 +153,14 @@ fn test_tx_bad_signature() {
                 .unwrap(),
             config,
         );
+        let chain_id = get_chain_id_for_tests(&storage);
         storage_manager
             .save_change_set(genesis_block.header(), storage)
             .unwrap();
 
-        let txs = simulate_da_with_bad_sig();
+        let txs = simulate_da_with_bad_sig(chain_id);
 
-        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS, [0; 32]);
+        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
         let blob_sender = blob.sender();
         let mut blobs = [blob];
 
@@ -215,12 +223,13 @@ fn test_tx_bad_nonce() {
                 .unwrap(),
             config,
         );
+        let chain_id = get_chain_id_for_tests(&storage);
         storage_manager
             .save_change_set(genesis_block.header(), storage)
             .unwrap();
-        let txs = simulate_da_with_bad_nonce();
+        let txs = simulate_da_with_bad_nonce(chain_id);
 
-        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS, [0; 32]);
+        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
         let mut blobs = [blob];
 
         let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
@@ -262,7 +271,7 @@ fn test_tx_bad_serialization() {
     let block_1 = genesis_block.next_mock();
     let mut storage_manager = create_storage_manager_for_tests(path);
 
-    let (genesis_root, sequencer_balance_before) = {
+    let (genesis_root, chain_id, sequencer_balance_before) = {
         let stf: StfBlueprintTest = StfBlueprint::new();
 
         let (genesis_root, storage) = stf.init_chain(
@@ -271,6 +280,7 @@ fn test_tx_bad_serialization() {
                 .unwrap(),
             config,
         );
+        let chain_id = get_chain_id_for_tests(&storage);
 
         let balance = {
             let runtime: RuntimeTest = Runtime::default();
@@ -293,14 +303,14 @@ fn test_tx_bad_serialization() {
         storage_manager
             .save_change_set(genesis_block.header(), storage)
             .unwrap();
-        (genesis_root, balance)
+        (genesis_root, chain_id, balance)
     };
 
     let storage = {
         let stf: StfBlueprintTest = StfBlueprint::new();
 
-        let txs = simulate_da_with_bad_serialization();
-        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS, [0; 32]);
+        let txs = simulate_da_with_bad_serialization(chain_id);
+        let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
         let blob_sender = blob.sender();
         let mut blobs = [blob];
 
@@ -359,3 +369,298 @@ fn test_tx_bad_serialization() {
         assert_eq!(sequencer_balance_before, sequencer_balance_after);
     }
 }
+
+#[test]
+fn test_bad_batch_encoding() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+
+    let config = get_genesis_config_for_tests();
+
+    let genesis_block = MockBlock::default();
+    let block_1 = genesis_block.next_mock();
+    let mut storage_manager = create_storage_manager_for_tests(path);
+    let stf: StfBlueprintTest = StfBlueprint::new();
+    let (genesis_root, storage) = stf.init_chain(
+        storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap(),
+        config,
+    );
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    // Too short for the length prefix of the list of transactions.
+    let blob =
+        MockBlob::with_data_hash(vec![1, 2, 3], MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS));
+    let blob_sender = blob.sender();
+    let mut blobs = [blob];
+
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let apply_block_result = stf.apply_slot(
+        &genesis_root,
+        storage,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut blobs,
+    );
+
+    assert_eq!(1, apply_block_result.batch_receipts.len());
+    let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
+    assert_eq!(
+        SequencerOutcome::Slashed {
+            reason: SlashingReason::InvalidBatchEncoding,
+            sequencer_da_address: blob_sender.clone(),
+        },
+        apply_blob_outcome.inner,
+        "Unexpected outcome: The batch should have failed to deserialize"
+    );
+    assert!(apply_blob_outcome.tx_receipts.is_empty());
+
+    // The genesis sequencer was slashed, so it's no longer allowed to submit batches.
+    let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+    let mut working_set = WorkingSet::new(apply_block_result.change_set);
+    let allowed_sequencer = runtime
+        .sequencer_registry
+        .sequencer_address(blob_sender, &mut working_set)
+        .unwrap();
+    assert!(allowed_sequencer.address.is_none());
+}
+
+#[test]
+fn test_blob_with_wrong_hash_is_ignored() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+
+    let config = get_genesis_config_for_tests();
+
+    let genesis_block = MockBlock::default();
+    let block_1 = genesis_block.next_mock();
+    let mut storage_manager = create_storage_manager_for_tests(path);
+    let stf: StfBlueprintTest = StfBlueprint::new();
+    let (genesis_root, storage) = stf.init_chain(
+        storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap(),
+        config,
+    );
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    // A malformed batch, which would get the sequencer slashed if its hash matched.
+    let blob = MockBlob::new(
+        vec![1, 2, 3],
+        MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS),
+        [0; 32],
+    );
+    let blob_sender = blob.sender();
+    let mut blobs = [blob];
+
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let apply_block_result = stf.apply_slot(
+        &genesis_root,
+        storage,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut blobs,
+    );
+
+    assert_eq!(1, apply_block_result.batch_receipts.len());
+    let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
+    assert_eq!(SequencerOutcome::Ignored, apply_blob_outcome.inner);
+    assert!(apply_blob_outcome.tx_receipts.is_empty());
+
+    let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+    let mut working_set = WorkingSet::new(apply_block_result.change_set);
+    let allowed_sequencer = runtime
+        .sequencer_registry
+        .sequencer_address(blob_sender, &mut working_set)
+        .unwrap();
+    assert!(allowed_sequencer.address.is_some());
+}
+
+#[test]
+fn test_tx_bad_serialization_skipped() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+
+    let config = get_genesis_config_for_tests();
+    let genesis_block = MockBlock::default();
+    let block_1 = genesis_block.next_mock();
+    let mut storage_manager = create_storage_manager_for_tests(path);
+    let stf: StfBlueprintTest =
+        StfBlueprint::new().with_decode_failure_policy(DecodeFailurePolicy::SkipTransaction);
+
+    let (genesis_root, storage) = stf.init_chain(
+        storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap(),
+        config,
+    );
+    let chain_id = get_chain_id_for_tests(&storage);
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    // The first transaction creates a token, the last one can't be decoded.
+    let txs = simulate_da_with_bad_serialization(chain_id);
+    let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
+    let mut blobs = [blob];
+
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let apply_block_result = stf.apply_slot(
+        &genesis_root,
+        storage,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut blobs,
+    );
+
+    assert_eq!(1, apply_block_result.batch_receipts.len());
+    let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
+    assert_eq!(
+        SequencerOutcome::Rewarded(0),
+        apply_blob_outcome.inner,
+        "Unexpected outcome: The undecodable transaction should have been skipped"
+    );
+    let tx_receipts = apply_blob_outcome.tx_receipts;
+    assert_eq!(2, tx_receipts.len());
+    assert_eq!(tx_receipts[0].receipt, TxEffect::Successful);
+    assert_eq!(tx_receipts[1].receipt, TxEffect::DecodeFailed);
+
+    // The rest of the batch has been applied.
+    let runtime = &mut Runtime::<DefaultContext, MockDaSpec>::default();
+    let mut working_set = WorkingSet::new(apply_block_result.change_set);
+    let resp = runtime
+        .bank
+        .balance_of(
+            None,
+            get_default_private_key().default_address(),
+            get_default_token_address(),
+            &mut working_set,
+        )
+        .unwrap();
+    assert_eq!(resp.amount, Some(1000));
+}
+
+#[test]
+fn test_expired_tx_skipped() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+
+    let config = get_genesis_config_for_tests();
+    let genesis_block = MockBlock::default();
+    let block_1 = genesis_block.next_mock();
+    let mut storage_manager = create_storage_manager_for_tests(path);
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let (genesis_root, storage) = stf.init_chain(
+        storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap(),
+        config,
+    );
+    let chain_id = get_chain_id_for_tests(&storage);
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    // The first transaction is still valid in the slot it expires at, the second one expired in
+    // the slot before.
+    let slot_height = block_1.header().height();
+    let txs = simulate_da_with_expiring_txs(&[slot_height, slot_height - 1], chain_id);
+    let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
+    let mut blobs = [blob];
+
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let apply_block_result = stf.apply_slot(
+        &genesis_root,
+        storage,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut blobs,
+    );
+
+    assert_eq!(1, apply_block_result.batch_receipts.len());
+    let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
+    assert_eq!(SequencerOutcome::Rewarded(0), apply_blob_outcome.inner);
+    let tx_receipts = apply_blob_outcome.tx_receipts;
+    assert_eq!(2, tx_receipts.len());
+    assert_eq!(tx_receipts[0].receipt, TxEffect::Successful);
+    assert_eq!(tx_receipts[1].receipt, TxEffect::Expired);
+    assert!(tx_receipts[1].events.is_empty());
+
+    // Only the token of the unexpired transaction has been created.
+    let runtime = Runtime::<DefaultContext, MockDaSpec>::default();
+    let mut working_set = WorkingSet::new(apply_block_result.change_set);
+    let minter_address = get_default_private_key().default_address();
+    let balance = |i: u64, working_set: &mut WorkingSet<DefaultContext>| {
+        let token_address = get_token_address::<DefaultContext>(
+            &format!("ExpiringToken{}", i),
+            minter_address.as_ref(),
+            i,
+        );
+        runtime
+            .bank
+            .balance_of(None, minter_address, token_address, working_set)
+            .unwrap()
+            .amount
+    };
+    assert_eq!(Some(1000), balance(0, &mut working_set));
+    assert_eq!(None, balance(1, &mut working_set));
+}
+
+#[test]
+fn test_tx_for_other_chain_skipped() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let path = tempdir.path();
+
+    let config = get_genesis_config_for_tests();
+    let genesis_block = MockBlock::default();
+    let block_1 = genesis_block.next_mock();
+    let mut storage_manager = create_storage_manager_for_tests(path);
+    let stf: StfBlueprintTest = StfBlueprint::new();
+
+    let (genesis_root, storage) = stf.init_chain(
+        storage_manager
+            .create_storage_on(genesis_block.header())
+            .unwrap(),
+        config,
+    );
+    let chain_id = get_chain_id_for_tests(&storage);
+    storage_manager
+        .save_change_set(genesis_block.header(), storage)
+        .unwrap();
+
+    // Both transactions have the same nonce, but only the second one is signed for this chain.
+    let mut txs = simulate_da_with_expiring_txs(&[u64::MAX], chain_id.wrapping_add(1));
+    txs.extend(simulate_da_with_expiring_txs(&[u64::MAX], chain_id));
+    let blob = new_test_blob_from_batch(Batch { txs }, &MOCK_SEQUENCER_DA_ADDRESS);
+    let mut blobs = [blob];
+
+    let storage = storage_manager.create_storage_on(block_1.header()).unwrap();
+    let apply_block_result = stf.apply_slot(
+        &genesis_root,
+        storage,
+        Default::default(),
+        &block_1.header,
+        &block_1.validity_cond,
+        &mut blobs,
+    );
+
+    assert_eq!(1, apply_block_result.batch_receipts.len());
+    let apply_blob_outcome = apply_block_result.batch_receipts[0].clone();
+    assert_eq!(SequencerOutcome::Rewarded(0), apply_blob_outcome.inner);
+    let tx_receipts = apply_blob_outcome.tx_receipts;
+    assert_eq!(2, tx_receipts.len());
+    assert_eq!(tx_receipts[0].receipt, TxEffect::WrongChainId);
+    assert!(tx_receipts[0].events.is_empty());
+    // The skipped transaction didn't use up the nonce.
+    assert_eq!(tx_receipts[1].receipt, TxEffect::Successful);
+}
@@ -1,16 +1,15 @@
 use std::net::SocketAddr;
 
-use borsh::BorshSerialize;
 use demo_stf::genesis_config::GenesisPaths;
 use demo_stf::runtime::RuntimeCall;
-use jsonrpsee::core::client::{Subscription, SubscriptionClientT};
+use jsonrpsee::core::client::{ClientT, Subscription, SubscriptionClientT};
 use jsonrpsee::rpc_params;
 use sov_bank::Coins;
 use sov_mock_da::MockDaSpec;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
 use sov_modules_api::transaction::Transaction;
-use sov_modules_api::{Address, PrivateKey, Spec};
+use sov_modules_api::{Address, PrivateKey, Spec, VersionedCall};
 use sov_modules_stf_blueprint::kernels::basic::BasicKernelGenesisPaths;
 use sov_sequencer::utils::SimpleClient;
 use sov_stf_runner::RollupProverConfig;
@@ -46,7 +45,11 @@ async fn bank_tx_tests() -> Result<(), anyhow::Error> {
     Ok(())
 }
 
-async fn build_create_token_tx(key: &DefaultPrivateKey, nonce: u64) -> Transaction<DefaultContext> {
+async fn build_create_token_tx(
+    key: &DefaultPrivateKey,
+    chain_id: u64,
+    nonce: u64,
+) -> Transaction<DefaultContext> {
     let user_address: <DefaultContext as Spec>::Address = key.to_address();
     let msg = RuntimeCall::<DefaultContext, MockDaSpec>::bank(sov_bank::CallMessage::<
         DefaultContext,
@@ -57,12 +60,11 @@ async fn build_create_token_tx(key: &DefaultPrivateKey, nonce: u64) -> Transacti
         minter_address: user_address,
         authorized_minters: vec![],
     });
-    let chain_id = 0;
     let gas_tip = 0;
     let gas_limit = 0;
     Transaction::<DefaultContext>::new_signed_tx(
         &key,
-        msg.try_to_vec().unwrap(),
+        msg.encode_versioned(),
         chain_id,
         gas_tip,
         gas_limit,
@@ -72,6 +74,7 @@ async fn build_create_token_tx(key: &DefaultPrivateKey, nonce: u64) -> Transacti
 
 async fn build_transfer_token_tx(
     key: &DefaultPrivateKey,
+    chain_id: u64,
     token_address: Address,
     recipient: <DefaultContext as Spec>::Address,
     amount: u64,
@@ -86,12 +89,11 @@ async fn build_transfer_token_tx(
             token_address,
         },
     });
-    let chain_id = 0;
     let gas_tip = 0;
     let gas_limit = 0;
     Transaction::<DefaultContext>::new_signed_tx(
         &key,
-        msg.try_to_vec().unwrap(),
+        msg.encode_versioned(),
         chain_id,
         gas_tip,
         gas_limit,
@@ -109,11 +111,15 @@ async fn send_test_bank_txs(rpc_address: SocketAddr) -> Result<(), anyhow::Error
         TOKEN_SALT,
     );
 
-    let tx = build_create_token_tx(&key, 0).await;
-
     let port = rpc_address.port();
     let client = SimpleClient::new("localhost", port).await?;
 
+    let chain_id: u64 = client
+        .http()
+        .request("rollup_chainId", rpc_params![])
+        .await?;
+    let tx = build_create_token_tx(&key, chain_id, 0).await;
+
     let mut slot_processed_subscription: Subscription<u64> = client
         .ws()
         .subscribe(
@@ -142,6 +148,7 @@ async fn send_test_bank_txs(rpc_address: SocketAddr) -> Result<(), anyhow::Error
 
     let tx = build_transfer_token_tx(
         &key,
+        chain_id,
         token_address.clone(),
         recipient_address.clone(),
         100,
@@ -174,6 +181,7 @@ async fn send_test_bank_txs(rpc_address: SocketAddr) -> Result<(), anyhow::Error
 
     let tx = build_transfer_token_tx(
         &key,
+        chain_id,
         token_address.clone(),
         recipient_address.clone(),
         200,
@@ -185,6 +185,31 @@ async fn execute(client: &TestClient) -> Result<(), Box<dyn std::error::Error>>
         assert_eq!(value, get_arg.as_u32());
     }
 
+    {
+        // A transaction sent ahead of its nonce waits for the preceding one, and both of them
+        // are executed in nonce order.
+        let nonce = client.eth_get_transaction_count(client.from_addr).await;
+        let second_req = client
+            .set_value_with_nonce(contract_address, 201, nonce + 1, None, None)
+            .await;
+        let first_req = client
+            .set_value_with_nonce(contract_address, 200, nonce, None, None)
+            .await;
+        client.send_publish_batch_request().await;
+
+        let first_receipt = first_req.await.unwrap().unwrap();
+        let second_receipt = second_req.await.unwrap().unwrap();
+        assert_eq!(Some(1.into()), first_receipt.status);
+        assert_eq!(Some(1.into()), second_receipt.status);
+        assert_eq!(first_receipt.block_number, second_receipt.block_number);
+        assert!(first_receipt.transaction_index < second_receipt.transaction_index);
+
+        let get_arg = client.query_contract(contract_address).await?;
+        assert_eq!(201, get_arg.as_u32());
+        let nonce_after = client.eth_get_transaction_count(client.from_addr).await;
+        assert_eq!(nonce + 2, nonce_after);
+    }
+
     {
         // get initial gas price
         let initial_gas_price = client.eth_gas_price().await;
@@ -127,7 +127,24 @@ impl TestClient {
         max_fee_per_gas: Option<u64>,
     ) -> PendingTransaction<'_, Http> {
         let nonce = self.eth_get_transaction_count(self.from_addr).await;
+        self.set_value_with_nonce(
+            contract_address,
+            set_arg,
+            nonce,
+            max_priority_fee_per_gas,
+            max_fee_per_gas,
+        )
+        .await
+    }
 
+    pub(crate) async fn set_value_with_nonce(
+        &self,
+        contract_address: H160,
+        set_arg: u32,
+        nonce: u64,
+        max_priority_fee_per_gas: Option<u64>,
+        max_fee_per_gas: Option<u64>,
+    ) -> PendingTransaction<'_, Http> {
         let req = Eip1559TransactionRequest::new()
             .from(self.from_addr)
             .to(contract_address)
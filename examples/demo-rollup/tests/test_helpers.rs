@@ -8,7 +8,9 @@ use sov_modules_stf_blueprint::kernels::basic::{
     BasicKernelGenesisConfig, BasicKernelGenesisPaths,
 };
 use sov_stf_runner::{
-    ProverServiceConfig, RollupConfig, RollupProverConfig, RpcConfig, RunnerConfig, StorageConfig,
+    ProverServiceConfig, RollupConfig, RollupProverConfig, RpcConfig, RunnerConfig,
+    SequencerConfig, StorageConfig, DEFAULT_COMMIT_INTERVAL, DEFAULT_FINALITY_DEPTH,
+    DEFAULT_REORDER_BUFFER_CAPACITY,
 };
 use tokio::sync::oneshot;
 
@@ -24,6 +26,7 @@ pub async fn start_rollup(
     let rollup_config = RollupConfig {
         storage: StorageConfig {
             path: temp_path.to_path_buf(),
+            read_cache_size: None,
         },
         runner: RunnerConfig {
             start_height: 1,
@@ -31,6 +34,9 @@ pub async fn start_rollup(
                 bind_host: "127.0.0.1".into(),
                 bind_port: 0,
             },
+            reorder_buffer_capacity: DEFAULT_REORDER_BUFFER_CAPACITY,
+            commit_interval: DEFAULT_COMMIT_INTERVAL,
+            finality_depth: DEFAULT_FINALITY_DEPTH,
         },
         da: MockDaConfig {
             sender_address: MockAddress::from([0; 32]),
@@ -38,6 +44,8 @@ pub async fn start_rollup(
         prover_service: ProverServiceConfig {
             aggregated_proof_block_jump: 1,
         },
+        metrics: None,
+        sequencer: SequencerConfig::default(),
     };
 
     let mock_demo_rollup = MockDemoRollup {};
@@ -4,7 +4,9 @@ use std::marker::PhantomData;
 
 use sha2::Digest;
 use sov_rollup_interface::da::{BlobReaderTrait, DaSpec};
-use sov_rollup_interface::stf::{BatchReceipt, SlotResult, StateTransitionFunction};
+use sov_rollup_interface::stf::{
+    BatchReceipt, Event, SlotResult, StateTransitionFunction, TransactionReceipt,
+};
 use sov_rollup_interface::zk::{ValidityCondition, Zkvm};
 
 /// An implementation of the [`StateTransitionFunction`]
@@ -23,6 +25,33 @@ pub enum ApplySlotResult {
     Success,
 }
 
+/// An event emitted by the [`CheckHashPreimageStf`].
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub enum PreimageEvent {
+    /// The preimage of the desired hash was posted on the DA.
+    PreimageMatched {
+        /// The hash of the posted preimage.
+        hash: [u8; 32],
+    },
+}
+
+impl From<&PreimageEvent> for Event {
+    fn from(event: &PreimageEvent) -> Self {
+        match event {
+            PreimageEvent::PreimageMatched { hash } => {
+                Event::new("PreimageMatched", &hex::encode(hash))
+            }
+        }
+    }
+}
+
+/// The receipt of a blob, holding the events it emitted.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct PreimageReceipt {
+    /// The events emitted while processing the blob.
+    pub events: Vec<PreimageEvent>,
+}
+
 impl<Vm: Zkvm, Cond: ValidityCondition, Da: DaSpec> StateTransitionFunction<Vm, Da>
     for CheckHashPreimageStf<Cond>
 {
@@ -35,7 +64,8 @@ impl<Vm: Zkvm, Cond: ValidityCondition, Da: DaSpec> StateTransitionFunction<Vm,
     type ChangeSet = ();
 
     // We could incorporate the concept of a transaction into the rollup, but we leave it as an exercise for the reader.
-    type TxReceiptContents = ();
+    // Each blob gets a single receipt, holding the events it emitted.
+    type TxReceiptContents = PreimageReceipt;
 
     // This is the type that will be returned as a result of `apply_blob`.
     type BatchReceiptContents = ApplySlotResult;
@@ -84,17 +114,27 @@ impl<Vm: Zkvm, Cond: ValidityCondition, Da: DaSpec> StateTransitionFunction<Vm,
                 151, 20, 133, 110, 226, 51, 179, 144, 42, 89, 29, 13, 95, 41, 37,
             ];
 
-            let result = if hash == desired_hash {
-                ApplySlotResult::Success
+            let (result, receipt) = if hash == desired_hash {
+                let receipt = PreimageReceipt {
+                    events: vec![PreimageEvent::PreimageMatched { hash }],
+                };
+                (ApplySlotResult::Success, receipt)
             } else {
-                ApplySlotResult::Failure
+                (ApplySlotResult::Failure, PreimageReceipt::default())
             };
 
-            // Return the `BatchReceipt`
+            // Return the `BatchReceipt`, with the events in a form the ledger can index.
             receipts.push(BatchReceipt {
                 batch_hash: hash,
-                tx_receipts: vec![],
+                tx_receipts: vec![TransactionReceipt {
+                    tx_hash: hash,
+                    body_to_save: None,
+                    events: receipt.events.iter().map(Event::from).collect(),
+                    receipt,
+                    gas_used: vec![],
+                }],
                 inner: result,
+                category: None,
             });
         }
 
@@ -1,8 +1,9 @@
-use demo_simple_stf::{ApplySlotResult, CheckHashPreimageStf};
+use demo_simple_stf::{ApplySlotResult, CheckHashPreimageStf, PreimageEvent};
+use sha2::Digest;
 use sov_mock_da::verifier::MockDaSpec;
 use sov_mock_da::{MockAddress, MockBlob, MockBlockHeader, MockValidityCond};
 use sov_mock_zkvm::MockZkvm;
-use sov_rollup_interface::stf::StateTransitionFunction;
+use sov_rollup_interface::stf::{Event, StateTransitionFunction};
 
 #[test]
 fn test_stf_success() {
@@ -44,3 +45,43 @@ fn test_stf_success() {
     let receipt = &result.batch_receipts[1];
     assert_eq!(receipt.inner, ApplySlotResult::Success);
 }
+
+#[test]
+fn test_stf_emits_preimage_event() {
+    let address = MockAddress::from([1; 32]);
+
+    let stf = &mut CheckHashPreimageStf::<MockValidityCond>::default();
+    StateTransitionFunction::<MockZkvm<MockValidityCond>, MockDaSpec>::init_chain(stf, (), ());
+
+    let incorrect_preimage = vec![1; 32];
+    let correct_preimage = vec![0; 32];
+    let mut blobs = [
+        MockBlob::new(incorrect_preimage, address, [0; 32]),
+        MockBlob::new(correct_preimage.clone(), address, [0; 32]),
+    ];
+    for blob in &mut blobs {
+        blob.data.advance(blob.data.total_len());
+    }
+
+    let result = StateTransitionFunction::<MockZkvm<MockValidityCond>, MockDaSpec>::apply_slot(
+        stf,
+        &[],
+        (),
+        (),
+        &MockBlockHeader::default(),
+        &MockValidityCond::default(),
+        &mut blobs,
+    );
+
+    // The incorrect preimage doesn't emit any event
+    let tx_receipt = &result.batch_receipts[0].tx_receipts[0];
+    assert!(tx_receipt.receipt.events.is_empty());
+    assert!(tx_receipt.events.is_empty());
+
+    let hash: [u8; 32] = sha2::Sha256::digest(&correct_preimage).into();
+    let expected_event = PreimageEvent::PreimageMatched { hash };
+
+    let tx_receipt = &result.batch_receipts[1].tx_receipts[0];
+    assert_eq!(tx_receipt.receipt.events, vec![expected_event.clone()]);
+    assert_eq!(tx_receipt.events, vec![Event::from(&expected_event)]);
+}
@@ -2,23 +2,27 @@ use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 use serde::Serialize;
+use sov_rollup_interface::da::BlockHeaderTrait;
 use sov_rollup_interface::services::da::SlotData;
 use sov_rollup_interface::stf::{BatchReceipt, Event};
+use sov_schema_db::schema::{KeyCodec, ValueCodec};
 use sov_schema_db::{Schema, SchemaBatch, SeekKeyEncoder, DB};
 
 use crate::rocks_db_config::gen_rocksdb_options;
 use crate::schema::tables::{
-    BatchByHash, BatchByNumber, EventByKey, EventByNumber, SlotByHash, SlotByNumber, TxByHash,
-    TxByNumber, LEDGER_TABLES,
+    BatchByCategory, BatchByHash, BatchByNumber, DaHeightBySlotNumber, EventByKey, EventByNumber,
+    SlotByHash, SlotByNumber, TxByHash, TxByNumber, LEDGER_TABLES,
 };
 use crate::schema::types::{
-    split_tx_for_storage, BatchNumber, EventNumber, SlotNumber, StoredBatch, StoredSlot,
-    StoredTransaction, TxNumber,
+    encode_receipt, split_tx_for_storage, BatchNumber, EventNumber, SlotNumber, StoredBatch,
+    StoredSlot, StoredTransaction, TxNumber,
 };
 
 mod rpc;
 
 const LEDGER_DB_PATH_SUFFIX: &str = "ledger";
+/// How many index entries [`LedgerDB::rebuild_indexes`] writes at once.
+const REBUILD_INDEXES_CHUNK_SIZE: usize = 10_000;
 
 #[derive(Clone, Debug)]
 /// A database which stores the ledger history (slots, transactions, events, etc).
@@ -118,6 +122,38 @@ impl LedgerDB {
         self.next_item_numbers.lock().unwrap().clone()
     }
 
+    /// Get the DA height of the most recent committed slot, if any.
+    pub fn get_last_processed_da_height(&self) -> anyhow::Result<Option<u64>> {
+        let mut iter = self.db.iter::<DaHeightBySlotNumber>()?;
+        iter.seek_to_last();
+
+        match iter.next() {
+            Some(Ok(item)) => Ok(Some(item.value)),
+            Some(Err(e)) => Err(e),
+            _ => Ok(None),
+        }
+    }
+
+    /// Get the DA height to resume processing from: the height following the last committed slot,
+    /// or `start_height` if no slot has been committed yet.
+    ///
+    /// Ledgers written before DA heights were recorded fall back to counting the committed slots
+    /// from `start_height`, which assumes that no DA height has been skipped.
+    pub fn resume_height(&self, start_height: u64) -> anyhow::Result<u64> {
+        match self.get_last_processed_da_height()? {
+            Some(height) => Ok(height + 1),
+            None => Ok(start_height + (self.get_next_items_numbers().slot_number - 1)),
+        }
+    }
+
+    /// Flushes the in-memory data of all the ledger tables to disk.
+    pub fn flush(&self) -> anyhow::Result<()> {
+        for table in LEDGER_TABLES {
+            self.db.flush_cf(table)?;
+        }
+        Ok(())
+    }
+
     /// Gets all slots with numbers `range.start` to `range.end`. If `range.end` is outside
     /// the range of the database, the result will smaller than the requested range.
     /// Note that this method blindly preallocates for the requested range, so it should not be exposed
@@ -215,10 +251,44 @@ impl LedgerDB {
 
     /// Commits a slot to the database by inserting its events, transactions, and batches before
     /// inserting the slot metadata.
+    ///
+    /// Events are numbered in the order they occur within the slot: by batch, then by transaction
+    /// within the batch, then by emission order within the transaction.
     pub fn commit_slot<S: SlotData, B: Serialize, T: Serialize>(
         &self,
         data_to_commit: SlotCommit<S, B, T>,
     ) -> Result<(), anyhow::Error> {
+        self.commit_slots(vec![data_to_commit])
+    }
+
+    /// Commits several consecutive slots to the database in a single write, so that either all of
+    /// them or none of them are stored. See [`LedgerDB::commit_slot`].
+    pub fn commit_slots<S: SlotData, B: Serialize, T: Serialize>(
+        &self,
+        slots_to_commit: Vec<SlotCommit<S, B, T>>,
+    ) -> Result<(), anyhow::Error> {
+        let mut schema_batch = SchemaBatch::new();
+        let mut slot_numbers = Vec::with_capacity(slots_to_commit.len());
+        for data_to_commit in slots_to_commit {
+            slot_numbers.push(self.put_slot_commit(data_to_commit, &mut schema_batch)?);
+        }
+
+        self.db.write_schemas(schema_batch)?;
+
+        for slot_number in slot_numbers {
+            // Notify subscribers. This call returns an error IFF there are no subscribers, so we don't need to check the result
+            let _ = self.slot_subscriptions.send(slot_number);
+        }
+
+        Ok(())
+    }
+
+    /// Adds the writes of a single slot to `schema_batch` and returns the number of the slot.
+    fn put_slot_commit<S: SlotData, B: Serialize, T: Serialize>(
+        &self,
+        data_to_commit: SlotCommit<S, B, T>,
+        schema_batch: &mut SchemaBatch,
+    ) -> Result<u64, anyhow::Error> {
         // Create a scope to ensure that the lock is released before we commit to the db
         let mut current_item_numbers = {
             let mut next_item_numbers = self.next_item_numbers.lock().unwrap();
@@ -231,8 +301,6 @@ impl LedgerDB {
             // The lock is released here
         };
 
-        let mut schema_batch = SchemaBatch::new();
-
         let first_batch_number = current_item_numbers.batch_number;
         let last_batch_number = first_batch_number + data_to_commit.batch_receipts.len() as u64;
         // Insert data from "bottom up" to ensure consistency if the application crashes during insertion
@@ -248,14 +316,14 @@ impl LedgerDB {
                         &event,
                         &EventNumber(current_item_numbers.event_number),
                         TxNumber(current_item_numbers.tx_number),
-                        &mut schema_batch,
+                        schema_batch,
                     )?;
                     current_item_numbers.event_number += 1;
                 }
                 self.put_transaction(
                     &tx_to_store,
                     &TxNumber(current_item_numbers.tx_number),
-                    &mut schema_batch,
+                    schema_batch,
                 )?;
                 current_item_numbers.tx_number += 1;
             }
@@ -264,15 +332,20 @@ impl LedgerDB {
             let batch_to_store = StoredBatch {
                 hash: batch_receipt.batch_hash,
                 txs: TxNumber(first_tx_number)..TxNumber(last_tx_number),
-                custom_receipt: bincode::serialize(&batch_receipt.inner)
-                    .expect("serialization to vec is infallible")
-                    .into(),
+                custom_receipt: encode_receipt(&batch_receipt.inner),
+                category: batch_receipt.category,
             };
             self.put_batch(
                 &batch_to_store,
                 &BatchNumber(current_item_numbers.batch_number),
-                &mut schema_batch,
+                schema_batch,
             )?;
+            if let Some(category) = batch_receipt.category {
+                schema_batch.put::<BatchByCategory>(
+                    &(category, BatchNumber(current_item_numbers.batch_number)),
+                    &(),
+                )?;
+            }
             current_item_numbers.batch_number += 1;
         }
 
@@ -286,17 +359,14 @@ impl LedgerDB {
         self.put_slot(
             &slot_to_store,
             &SlotNumber(current_item_numbers.slot_number),
-            &mut schema_batch,
+            schema_batch,
+        )?;
+        schema_batch.put::<DaHeightBySlotNumber>(
+            &SlotNumber(current_item_numbers.slot_number),
+            &data_to_commit.slot_data.header().height(),
         )?;
 
-        self.db.write_schemas(schema_batch)?;
-
-        // Notify subscribers. This call returns an error IFF there are no subscribers, so we don't need to check the result
-        let _ = self
-            .slot_subscriptions
-            .send(current_item_numbers.slot_number);
-
-        Ok(())
+        Ok(current_item_numbers.slot_number)
     }
 
     fn last_version_written<T: Schema<Key = U>, U: Into<u64>>(
@@ -324,4 +394,353 @@ impl LedgerDB {
             _ => Ok(None),
         }
     }
+
+    /// Repopulates the secondary indexes from the primary slot, batch, transaction and event tables,
+    /// so that a ledger committed before an index existed can be upgraded without resyncing.
+    /// The entries are written in chunks, so the memory used doesn't grow with the ledger.
+    ///
+    /// [`DaHeightBySlotNumber`] is derived from data which isn't kept in the primary tables, so it is
+    /// left untouched. Likewise, batches stored before their category was persisted aren't added to
+    /// [`BatchByCategory`].
+    pub fn rebuild_indexes(&self) -> anyhow::Result<()> {
+        let mut writer = ChunkedWriter::new(&self.db);
+
+        let mut slots = self.db.iter::<SlotByNumber>()?;
+        slots.seek_to_first();
+        for item in slots {
+            let (slot_number, slot) = item?.into_tuple();
+            writer.put::<SlotByHash>(&slot.hash, &slot_number)?;
+        }
+
+        let mut batches = self.db.iter::<BatchByNumber>()?;
+        batches.seek_to_first();
+        for item in batches {
+            let (batch_number, batch) = item?.into_tuple();
+            writer.put::<BatchByHash>(&batch.hash, &batch_number)?;
+            if let Some(category) = batch.category {
+                writer.put::<BatchByCategory>(&(category, batch_number), &())?;
+            }
+        }
+
+        let mut txs = self.db.iter::<TxByNumber>()?;
+        txs.seek_to_first();
+        for item in txs {
+            let (tx_number, tx) = item?.into_tuple();
+            writer.put::<TxByHash>(&tx.hash, &tx_number)?;
+            for event_number in tx.events.start.0..tx.events.end.0 {
+                let event_number = EventNumber(event_number);
+                let event = self
+                    .db
+                    .get::<EventByNumber>(&event_number)?
+                    .ok_or_else(|| {
+                        anyhow::anyhow!(
+                            "Event {} of transaction {} is missing from the ledger",
+                            event_number.0,
+                            tx_number.0
+                        )
+                    })?;
+                writer.put::<EventByKey>(&(event.key().clone(), tx_number, event_number), &())?;
+            }
+        }
+
+        writer.flush()
+    }
+}
+
+/// Writes entries to the database in batches of [`REBUILD_INDEXES_CHUNK_SIZE`].
+struct ChunkedWriter<'a> {
+    db: &'a DB,
+    schema_batch: SchemaBatch,
+    pending: usize,
+}
+
+impl<'a> ChunkedWriter<'a> {
+    fn new(db: &'a DB) -> Self {
+        Self {
+            db,
+            schema_batch: SchemaBatch::new(),
+            pending: 0,
+        }
+    }
+
+    fn put<S: Schema>(
+        &mut self,
+        key: &impl KeyCodec<S>,
+        value: &impl ValueCodec<S>,
+    ) -> anyhow::Result<()> {
+        self.schema_batch.put::<S>(key, value)?;
+        self.pending += 1;
+        if self.pending >= REBUILD_INDEXES_CHUNK_SIZE {
+            self.flush()?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> anyhow::Result<()> {
+        self.pending = 0;
+        self.db
+            .write_schemas(std::mem::take(&mut self.schema_batch))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use sov_mock_da::{MockBlock, MockBlockHeader};
+    use sov_rollup_interface::rpc::{LedgerRpcProvider, QueryMode, TxResponse};
+    use sov_rollup_interface::stf::TransactionReceipt;
+
+    use super::*;
+
+    fn commit_block(db: &LedgerDB, height: u64) {
+        let block = MockBlock {
+            header: MockBlockHeader::from_height(height),
+            ..Default::default()
+        };
+        db.commit_slot(SlotCommit::<_, u8, u8>::new(block)).unwrap();
+    }
+
+    #[test]
+    fn test_resume_after_restart() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let start_height = 1;
+
+        {
+            let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+            assert_eq!(db.resume_height(start_height).unwrap(), start_height);
+            for height in start_height..=5 {
+                let resume_height = db.resume_height(start_height).unwrap();
+                assert_eq!(resume_height, height);
+                commit_block(&db, resume_height);
+            }
+        }
+
+        // "Restart" the node by reopening the database.
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+        assert_eq!(db.get_last_processed_da_height().unwrap(), Some(5));
+        assert_eq!(db.resume_height(start_height).unwrap(), 6);
+
+        commit_block(&db, 6);
+        let (slot_number, _) = db.get_head_slot().unwrap().unwrap();
+        assert_eq!(slot_number, SlotNumber(6));
+        assert_eq!(db.resume_height(start_height).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_rebuild_indexes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+
+        for height in 1..=3u8 {
+            let mut slot = SlotCommit::<_, u8, u8>::new(MockBlock {
+                header: MockBlockHeader::from_height(height as u64),
+                ..Default::default()
+            });
+            slot.add_batch(BatchReceipt {
+                batch_hash: [height; 32],
+                tx_receipts: vec![TransactionReceipt {
+                    tx_hash: [height + 10; 32],
+                    body_to_save: None,
+                    events: vec![Event::new("key", &format!("{height}"))],
+                    receipt: height,
+                    gas_used: vec![],
+                }],
+                inner: 0,
+                category: Some(height % 2),
+            });
+            db.commit_slot(slot).unwrap();
+        }
+
+        // Drop the transaction, event and category indexes, as if they were introduced after these slots were committed
+        let mut schema_batch = SchemaBatch::new();
+        for height in 1..=3u8 {
+            schema_batch.delete::<TxByHash>(&[height + 10; 32]).unwrap();
+            schema_batch
+                .delete::<BatchByCategory>(&(height % 2, BatchNumber(height as u64)))
+                .unwrap();
+            schema_batch
+                .delete::<EventByKey>(&(
+                    Event::new("key", "").key().clone(),
+                    TxNumber(height as u64),
+                    EventNumber(height as u64),
+                ))
+                .unwrap();
+        }
+        db.db.write_schemas(schema_batch).unwrap();
+        assert!(db
+            .get_tx_by_hash::<u8>(&[12; 32], QueryMode::Compact)
+            .unwrap()
+            .is_none());
+
+        db.rebuild_indexes().unwrap();
+
+        for height in 1..=3u8 {
+            let tx = db
+                .get_tx_by_hash::<u8>(&[height + 10; 32], QueryMode::Compact)
+                .unwrap()
+                .unwrap();
+            assert_eq!(tx.custom_receipt, height);
+        }
+        let mut events_by_key = db.db.iter::<EventByKey>().unwrap();
+        events_by_key.seek_to_first();
+        let event_numbers: Vec<EventNumber> =
+            events_by_key.map(|item| item.unwrap().key.2).collect();
+        assert_eq!(
+            event_numbers,
+            vec![EventNumber(1), EventNumber(2), EventNumber(3)]
+        );
+        let mut batches_by_category = db.db.iter::<BatchByCategory>().unwrap();
+        batches_by_category.seek_to_first();
+        let categories: Vec<(u8, BatchNumber)> =
+            batches_by_category.map(|item| item.unwrap().key).collect();
+        assert_eq!(
+            categories,
+            vec![
+                (0, BatchNumber(2)),
+                (1, BatchNumber(1)),
+                (1, BatchNumber(3))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_batches_stored_without_category_decode() {
+        // The format of batches committed before their category was persisted.
+        #[derive(borsh::BorshSerialize)]
+        struct StoredBatchV1 {
+            hash: [u8; 32],
+            txs: std::ops::Range<TxNumber>,
+            custom_receipt: Vec<u8>,
+        }
+
+        let old_batch = StoredBatchV1 {
+            hash: [1; 32],
+            txs: TxNumber(1)..TxNumber(3),
+            custom_receipt: vec![1, 5],
+        };
+        let encoded = borsh::BorshSerialize::try_to_vec(&old_batch).unwrap();
+        let batch = <StoredBatch as ValueCodec<BatchByNumber>>::decode_value(&encoded).unwrap();
+        assert_eq!(
+            batch,
+            StoredBatch {
+                hash: [1; 32],
+                txs: TxNumber(1)..TxNumber(3),
+                custom_receipt: vec![1, 5].into(),
+                category: None,
+            }
+        );
+
+        for category in [None, Some(4)] {
+            let batch = StoredBatch {
+                hash: [2; 32],
+                txs: TxNumber(3)..TxNumber(4),
+                custom_receipt: vec![1, 6].into(),
+                category,
+            };
+            let encoded = <StoredBatch as ValueCodec<BatchByNumber>>::encode_value(&batch).unwrap();
+            assert_eq!(
+                <StoredBatch as ValueCodec<BatchByNumber>>::decode_value(&encoded).unwrap(),
+                batch
+            );
+        }
+    }
+
+    #[test]
+    fn test_receipts_decode_after_receipt_changes() {
+        #[derive(serde::Serialize)]
+        enum ReceiptV1 {
+            Applied(u8),
+        }
+
+        // A later version of the rollup, which added a variant to its receipt.
+        #[derive(Debug, PartialEq, serde::Deserialize)]
+        enum ReceiptV2 {
+            Applied(u8),
+            #[allow(dead_code)]
+            Reverted {
+                reason: String,
+            },
+        }
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+        let mut slot = SlotCommit::<_, ReceiptV1, ReceiptV1>::new(MockBlock {
+            header: MockBlockHeader::from_height(1),
+            ..Default::default()
+        });
+        slot.add_batch(BatchReceipt {
+            batch_hash: [1; 32],
+            tx_receipts: vec![TransactionReceipt {
+                tx_hash: [2; 32],
+                body_to_save: None,
+                events: vec![],
+                receipt: ReceiptV1::Applied(7),
+                gas_used: vec![],
+            }],
+            inner: ReceiptV1::Applied(1),
+            category: None,
+        });
+        db.commit_slot(slot).unwrap();
+
+        let stored_tx = db.db.get::<TxByNumber>(&TxNumber(1)).unwrap().unwrap();
+        assert_eq!(
+            Some(&crate::schema::types::RECEIPT_VERSION),
+            stored_tx.custom_receipt.as_ref().first()
+        );
+
+        let tx = db
+            .get_tx_by_hash::<ReceiptV2>(&[2; 32], QueryMode::Compact)
+            .unwrap()
+            .unwrap();
+        assert_eq!(ReceiptV2::Applied(7), tx.custom_receipt);
+        let batch = db
+            .get_batch_by_hash::<ReceiptV2, ReceiptV2>(&[1; 32], QueryMode::Compact)
+            .unwrap()
+            .unwrap();
+        assert_eq!(ReceiptV2::Applied(1), batch.custom_receipt);
+
+        // Receipts of an unknown version are rejected rather than misread.
+        let mut unknown_version = stored_tx.custom_receipt.as_ref().to_vec();
+        unknown_version[0] = 2;
+        let stored_tx = StoredTransaction {
+            custom_receipt: unknown_version.into(),
+            ..stored_tx
+        };
+        let err = TxResponse::<ReceiptV2>::try_from(stored_tx).unwrap_err();
+        assert_eq!("Unknown receipt version 2", err.to_string());
+    }
+
+    #[test]
+    fn test_receipts_written_before_versioning_decode() {
+        #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+        enum Receipt {
+            Applied(u8),
+            Reverted { reason: String },
+        }
+
+        // The first byte of an unversioned receipt can be the current version.
+        for receipt in [
+            Receipt::Applied(7),
+            Receipt::Reverted {
+                reason: "out of gas".to_string(),
+            },
+        ] {
+            let stored_tx = StoredTransaction {
+                hash: [2; 32],
+                events: EventNumber(0)..EventNumber(0),
+                body: None,
+                custom_receipt: bincode::serialize(&receipt).unwrap().into(),
+            };
+            let tx = TxResponse::<Receipt>::try_from(stored_tx).unwrap();
+            assert_eq!(receipt, tx.custom_receipt);
+        }
+
+        // Receipts without any data were stored as an empty value.
+        let stored_tx = StoredTransaction {
+            hash: [2; 32],
+            events: EventNumber(0)..EventNumber(0),
+            body: None,
+            custom_receipt: Vec::<u8>::new().into(),
+        };
+        assert!(TxResponse::<()>::try_from(stored_tx).is_ok());
+    }
 }
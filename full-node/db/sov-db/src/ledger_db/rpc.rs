@@ -8,7 +8,8 @@ use sov_rollup_interface::stf::Event;
 use tokio::sync::broadcast::Receiver;
 
 use crate::schema::tables::{
-    BatchByHash, BatchByNumber, EventByNumber, SlotByHash, SlotByNumber, TxByHash, TxByNumber,
+    BatchByCategory, BatchByHash, BatchByNumber, EventByNumber, SlotByHash, SlotByNumber, TxByHash,
+    TxByNumber,
 };
 use crate::schema::types::{
     BatchNumber, EventNumber, SlotNumber, StoredBatch, StoredSlot, TxNumber,
@@ -234,8 +235,23 @@ impl LedgerRpcProvider for LedgerDB {
             "requested slot range too large. Max: {}",
             MAX_SLOTS_PER_REQUEST
         );
-        let ids: Vec<_> = (start..=end).map(SlotIdentifier::Number).collect();
-        self.get_slots(&ids, query_mode)
+        // Slots are stored contiguously, so we can iterate over the range instead of
+        // querying for each slot individually. The iterator stops at the current head.
+        let mut iter = self.db.iter::<SlotByNumber>()?;
+        iter.seek(&SlotNumber(start))?;
+        let mut out = Vec::with_capacity((end - start + 1) as usize);
+        for item in iter {
+            let (number, stored_slot) = item?.into_tuple();
+            if number.0 > end {
+                break;
+            }
+            out.push(Some(self.populate_slot_response(
+                number.into(),
+                stored_slot,
+                query_mode,
+            )?));
+        }
+        Ok(out)
     }
 
     fn get_batches_range<B: DeserializeOwned, T: DeserializeOwned>(
@@ -250,8 +266,48 @@ impl LedgerRpcProvider for LedgerDB {
             "requested batch range too large. Max: {}",
             MAX_BATCHES_PER_REQUEST
         );
-        let ids: Vec<_> = (start..=end).map(BatchIdentifier::Number).collect();
-        self.get_batches(&ids, query_mode)
+        // Batches are stored contiguously, so we can iterate over the range instead of
+        // querying for each batch individually. The iterator stops at the latest batch.
+        let mut iter = self.db.iter::<BatchByNumber>()?;
+        iter.seek(&BatchNumber(start))?;
+        let mut out = Vec::with_capacity((end - start + 1) as usize);
+        for item in iter {
+            let (number, stored_batch) = item?.into_tuple();
+            if number.0 > end {
+                break;
+            }
+            out.push(Some(
+                self.populate_batch_response(stored_batch, query_mode)?,
+            ));
+        }
+        Ok(out)
+    }
+
+    fn get_batches_by_category<B: DeserializeOwned, T: DeserializeOwned>(
+        &self,
+        category: u8,
+        start: u64,
+        end: u64,
+        query_mode: QueryMode,
+    ) -> Result<Vec<Option<BatchResponse<B, T>>>, anyhow::Error> {
+        anyhow::ensure!(start <= end, "start must be <= end");
+        anyhow::ensure!(
+            end - start <= MAX_BATCHES_PER_REQUEST,
+            "requested batch range too large. Max: {}",
+            MAX_BATCHES_PER_REQUEST
+        );
+        // The index is sorted by category first, so the batches of a category are adjacent.
+        let mut iter = self.db.iter::<BatchByCategory>()?;
+        iter.seek(&(category, BatchNumber(start)))?;
+        let mut out = Vec::new();
+        for item in iter {
+            let (item_category, number) = item?.key;
+            if item_category != category || number.0 > end {
+                break;
+            }
+            out.push(self.get_batch_by_number(number.0, query_mode)?);
+        }
+        Ok(out)
     }
 
     fn get_transactions_range<T: DeserializeOwned>(
@@ -429,9 +485,13 @@ impl LedgerDB {
 #[cfg(test)]
 mod tests {
     use sov_mock_da::{MockBlob, MockBlock};
-    use sov_rollup_interface::rpc::LedgerRpcProvider;
+    use sov_rollup_interface::rpc::{
+        EventIdentifier, LedgerRpcProvider, QueryMode, TxIdAndOffset, TxIdentifier,
+    };
+    use sov_rollup_interface::stf::{BatchReceipt, Event, TransactionReceipt};
 
     use crate::ledger_db::{LedgerDB, SlotCommit};
+
     #[test]
     fn test_slot_subscription() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -444,4 +504,142 @@ mod tests {
 
         assert_eq!(rx.blocking_recv().unwrap(), 1);
     }
+
+    #[test]
+    fn test_range_queries() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+
+        // Commit 5 slots, each holding a single batch
+        for i in 1..=5u8 {
+            let mut slot = SlotCommit::<_, u8, u8>::new(MockBlock::default());
+            slot.add_batch(BatchReceipt {
+                batch_hash: [i; 32],
+                tx_receipts: vec![],
+                inner: i,
+                category: None,
+            });
+            db.commit_slot(slot).unwrap();
+        }
+
+        let slots = db
+            .get_slots_range::<u8, u8>(2, 4, QueryMode::Compact)
+            .unwrap();
+        let slot_numbers: Vec<u64> = slots.iter().map(|s| s.as_ref().unwrap().number).collect();
+        assert_eq!(slot_numbers, vec![2, 3, 4]);
+
+        let batches = db
+            .get_batches_range::<u8, u8>(2, 3, QueryMode::Compact)
+            .unwrap();
+        let batch_receipts: Vec<(Vec<u8>, u8)> = batches
+            .into_iter()
+            .map(|b| {
+                let b = b.unwrap();
+                (b.hash.to_vec(), b.custom_receipt)
+            })
+            .collect();
+        assert_eq!(batch_receipts, vec![(vec![2; 32], 2), (vec![3; 32], 3)]);
+
+        // Range queries stop at the current head
+        let slots = db
+            .get_slots_range::<u8, u8>(4, 10, QueryMode::Compact)
+            .unwrap();
+        let slot_numbers: Vec<u64> = slots.iter().map(|s| s.as_ref().unwrap().number).collect();
+        assert_eq!(slot_numbers, vec![4, 5]);
+
+        let batches = db
+            .get_batches_range::<u8, u8>(6, 10, QueryMode::Compact)
+            .unwrap();
+        assert!(batches.is_empty());
+    }
+
+    #[test]
+    fn test_get_tx_by_hash() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+
+        let tx_receipts = (1..=2u8)
+            .map(|i| TransactionReceipt {
+                tx_hash: [i; 32],
+                body_to_save: Some(vec![i; 4]),
+                events: vec![],
+                receipt: i,
+                gas_used: vec![],
+            })
+            .collect();
+        let mut slot = SlotCommit::<_, u8, u8>::new(MockBlock::default());
+        slot.add_batch(BatchReceipt {
+            batch_hash: [0; 32],
+            tx_receipts,
+            inner: 0,
+            category: None,
+        });
+        db.commit_slot(slot).unwrap();
+
+        let tx = db
+            .get_tx_by_hash::<u8>(&[2; 32], QueryMode::Full)
+            .unwrap()
+            .unwrap();
+        assert_eq!(tx.hash, [2; 32]);
+        assert_eq!(tx.body, Some(vec![2; 4]));
+        assert_eq!(tx.custom_receipt, 2);
+
+        assert!(db
+            .get_tx_by_hash::<u8>(&[3; 32], QueryMode::Full)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_event_order_within_slot() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let db = LedgerDB::with_path(temp_dir.path()).unwrap();
+
+        // Two batches of two transactions, each emitting two events
+        let mut slot = SlotCommit::<_, u8, u8>::new(MockBlock::default());
+        for batch in 0..2u8 {
+            let tx_receipts = (0..2u8)
+                .map(|tx| TransactionReceipt {
+                    tx_hash: [batch * 2 + tx; 32],
+                    body_to_save: None,
+                    events: (0..2)
+                        .map(|event| Event::new(&format!("{batch}-{tx}-{event}"), "value"))
+                        .collect(),
+                    receipt: 0,
+                    gas_used: vec![],
+                })
+                .collect();
+            slot.add_batch(BatchReceipt {
+                batch_hash: [batch; 32],
+                tx_receipts,
+                inner: 0,
+                category: None,
+            });
+        }
+        db.commit_slot(slot).unwrap();
+
+        let ids: Vec<_> = (1..=8).map(EventIdentifier::Number).collect();
+        let keys: Vec<String> = db
+            .get_events(&ids)
+            .unwrap()
+            .into_iter()
+            .map(|event| String::from_utf8(event.unwrap().key().inner().clone()).unwrap())
+            .collect();
+        assert_eq!(
+            keys,
+            vec!["0-0-0", "0-0-1", "0-1-0", "0-1-1", "1-0-0", "1-0-1", "1-1-0", "1-1-1"]
+        );
+        assert!(db.get_event_by_number(9).unwrap().is_none());
+
+        // Looking an event up by its offset into a transaction agrees with the global order
+        let event = db
+            .get_events(&[EventIdentifier::TxIdAndOffset(TxIdAndOffset {
+                tx_id: TxIdentifier::Number(3),
+                offset: 1,
+            })])
+            .unwrap()
+            .pop()
+            .unwrap();
+        assert_eq!(event, db.get_event_by_number(6).unwrap());
+    }
 }
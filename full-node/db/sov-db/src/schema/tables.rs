@@ -4,10 +4,12 @@
 //! Slot Tables:
 //! - `SlotNumber -> StoredSlot`
 //! - `SlotNumber -> Vec<BatchNumber>`
+//! - `SlotNumber -> u64` (DA height)
 //!
 //! Batch Tables:
 //! - `BatchNumber -> StoredBatch`
 //! - `BatchHash -> BatchNumber`
+//! - `(BlobCategory, BatchNumber) -> ()`
 //!
 //! Tx Tables:
 //! - `TxNumber -> (TxHash,Tx)`
@@ -51,8 +53,10 @@ pub const STATE_TABLES: &[&str] = &[
 pub const LEDGER_TABLES: &[&str] = &[
     SlotByNumber::table_name(),
     SlotByHash::table_name(),
+    DaHeightBySlotNumber::table_name(),
     BatchByHash::table_name(),
     BatchByNumber::table_name(),
+    BatchByCategory::table_name(),
     TxByHash::table_name(),
     TxByNumber::table_name(),
     EventByKey::table_name(),
@@ -218,6 +222,11 @@ define_table_with_default_codec!(
     (SlotByHash) DbHash => SlotNumber
 );
 
+define_table_with_seek_key_codec!(
+    /// The DA height of each slot, written atomically with the slot itself
+    (DaHeightBySlotNumber) SlotNumber => u64
+);
+
 define_table_with_seek_key_codec!(
     /// The primary source for batch data
     (BatchByNumber) BatchNumber => StoredBatch
@@ -228,6 +237,11 @@ define_table_with_default_codec!(
     (BatchByHash) DbHash => BatchNumber
 );
 
+define_table_with_seek_key_codec!(
+    /// A "secondary index" for batch data by the rollup-defined category of their blob
+    (BatchByCategory) (u8, BatchNumber) => ()
+);
+
 define_table_with_seek_key_codec!(
     /// The primary source for transaction data
     (TxByNumber) TxNumber => StoredTransaction
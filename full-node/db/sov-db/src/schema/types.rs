@@ -1,3 +1,4 @@
+use std::io::Read;
 use std::sync::Arc;
 
 use borsh::{BorshDeserialize, BorshSerialize};
@@ -69,7 +70,7 @@ pub struct StoredSlot {
 
 /// The on-disk format for a batch. Stores the hash and identifies the range of transactions
 /// included in the batch.
-#[derive(Debug, PartialEq, BorshDeserialize, BorshSerialize)]
+#[derive(Debug, PartialEq, BorshSerialize)]
 #[cfg_attr(feature = "arbitrary", derive(proptest_derive::Arbitrary))]
 pub struct StoredBatch {
     /// The hash of the batch, as reported by the DA layer.
@@ -78,6 +79,28 @@ pub struct StoredBatch {
     pub txs: std::ops::Range<TxNumber>,
     /// A customer "receipt" for this batch defined by the rollup.
     pub custom_receipt: DbBytes,
+    /// The rollup-defined category of the blob of this batch, if the rollup uses categories.
+    pub category: Option<u8>,
+}
+
+impl BorshDeserialize for StoredBatch {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> std::io::Result<Self> {
+        let hash = DbHash::deserialize_reader(reader)?;
+        let txs = std::ops::Range::<TxNumber>::deserialize_reader(reader)?;
+        let custom_receipt = DbBytes::deserialize_reader(reader)?;
+        // Batches stored before their category was persisted end with their receipt.
+        let mut tag = [0u8; 1];
+        let category = match reader.read(&mut tag)? {
+            0 => None,
+            _ => Option::<u8>::deserialize_reader(&mut (&tag[..]).chain(reader))?,
+        };
+        Ok(Self {
+            hash,
+            txs,
+            custom_receipt,
+            category,
+        })
+    }
 }
 
 impl<B: DeserializeOwned, T> TryFrom<StoredBatch> for BatchResponse<B, T> {
@@ -85,7 +108,7 @@ impl<B: DeserializeOwned, T> TryFrom<StoredBatch> for BatchResponse<B, T> {
     fn try_from(value: StoredBatch) -> Result<Self, Self::Error> {
         Ok(Self {
             hash: value.hash,
-            custom_receipt: bincode::deserialize(&value.custom_receipt.0)?,
+            custom_receipt: decode_receipt(&value.custom_receipt)?,
             tx_range: value.txs.start.into()..value.txs.end.into(),
             txs: None,
         })
@@ -113,7 +136,7 @@ impl<R: DeserializeOwned> TryFrom<StoredTransaction> for TxResponse<R> {
             hash: value.hash,
             event_range: value.events.start.into()..value.events.end.into(),
             body: value.body,
-            custom_receipt: bincode::deserialize(&value.custom_receipt.0)?,
+            custom_receipt: decode_receipt(&value.custom_receipt)?,
         })
     }
 }
@@ -128,13 +151,42 @@ pub fn split_tx_for_storage<R: Serialize>(
         hash: tx.tx_hash,
         events: event_range,
         body: tx.body_to_save,
-        custom_receipt: DbBytes::new(
-            bincode::serialize(&tx.receipt).expect("Serialization to vec is infallible"),
-        ),
+        custom_receipt: encode_receipt(&tx.receipt),
     };
     (tx_for_storage, tx.events)
 }
 
+/// The version of the encoding of the receipts written to the ledger by this node.
+///
+/// Every stored receipt starts with its version byte, so a node can still read the receipts
+/// written before a change of the encoding.
+pub const RECEIPT_VERSION: u8 = 1;
+
+/// Serializes a batch or transaction receipt for storage, prefixed with [`RECEIPT_VERSION`].
+pub fn encode_receipt<R: Serialize>(receipt: &R) -> DbBytes {
+    let mut bytes = vec![RECEIPT_VERSION];
+    bincode::serialize_into(&mut bytes, receipt).expect("Serialization to vec is infallible");
+    DbBytes::new(bytes)
+}
+
+/// Deserializes a receipt written by [`encode_receipt`] by this or any earlier version of the node.
+///
+/// Receipts written before they were versioned are plain bincode, whose first byte can be anything.
+/// They are decoded as a whole when they don't start with a known version, or don't decode as one.
+pub fn decode_receipt<R: DeserializeOwned>(bytes: &DbBytes) -> anyhow::Result<R> {
+    let unversioned = || bincode::deserialize(bytes.as_ref());
+    match bytes.as_ref().split_first() {
+        Some((&RECEIPT_VERSION, receipt)) => match bincode::deserialize(receipt) {
+            Ok(receipt) => Ok(receipt),
+            Err(err) => unversioned().map_err(|_| err.into()),
+        },
+        Some((version, _)) => {
+            unversioned().map_err(|_| anyhow::anyhow!("Unknown receipt version {}", version))
+        }
+        None => Ok(unversioned()?),
+    }
+}
+
 /// An identifier that specifies a single event
 #[derive(Debug, PartialEq, Serialize, Deserialize)]
 pub enum EventIdentifier {
@@ -227,6 +279,7 @@ pub mod arbitrary {
                 hash: u.arbitrary()?,
                 txs: u.arbitrary()?,
                 custom_receipt: u.arbitrary()?,
+                category: u.arbitrary()?,
             })
         }
     }
@@ -103,6 +103,23 @@ impl<Q: QueryManager> StateDB<Q> {
         }
     }
 
+    /// Get all the keys starting with the given prefix that have ever been written to the database,
+    /// in lexicographic order. This scans every key preimage, so it should only be used by tooling.
+    ///
+    /// Keys are returned even if their value has since been deleted; use
+    /// [`StateDB::get_value_option_by_key`] to check if they are still set at a given version.
+    pub fn get_keys_with_prefix(&self, prefix: &[u8]) -> anyhow::Result<Vec<StateKey>> {
+        let mut keys: Vec<StateKey> = self
+            .db
+            .get_all::<KeyHashToKey>()?
+            .into_iter()
+            .map(|(_key_hash, key)| key)
+            .filter(|key| key.starts_with(prefix))
+            .collect();
+        keys.sort();
+        Ok(keys)
+    }
+
     /// Increment the `next_version` counter by 1.
     pub fn inc_next_version(&self) {
         let mut version = self.next_version.lock().unwrap();
@@ -216,4 +233,24 @@ mod state_db_tests {
         let found = db.get_value_option_by_key(0, &key).unwrap().unwrap();
         assert_eq!(found, value);
     }
+
+    #[test]
+    fn test_get_keys_with_prefix() {
+        let manager = ReadOnlyLock::new(Arc::new(RwLock::new(Default::default())));
+        let db_snapshot = DbSnapshot::<NoopQueryManager>::new(0, manager);
+        let db = StateDB::with_db_snapshot(db_snapshot).unwrap();
+        let first_key = vec![1u8, 2, 3];
+        let second_key = vec![1u8, 2];
+        let other_key = vec![2u8, 1];
+
+        db.put_preimages(vec![
+            (KeyHash([3u8; 32]), &first_key),
+            (KeyHash([1u8; 32]), &other_key),
+            (KeyHash([2u8; 32]), &second_key),
+        ])
+        .unwrap();
+
+        let keys = db.get_keys_with_prefix(&[1]).unwrap();
+        assert_eq!(keys, vec![second_key, first_key]);
+    }
 }
@@ -263,7 +263,6 @@ impl DB {
     }
 
     /// Flushes [MemTable](https://github.com/facebook/rocksdb/wiki/MemTable) data.
-    /// This is only used for testing `get_approximate_sizes_cf` in unit tests.
     pub fn flush_cf(&self, cf_name: &str) -> anyhow::Result<()> {
         Ok(self.inner.flush_cf(self.get_cf_handle(cf_name)?)?)
     }
@@ -170,6 +170,36 @@ impl<Q: QueryManager> DbSnapshot<Q> {
         Ok(None)
     }
 
+    /// Get all written key-value pairs for given [`Schema`], in reverse lexicographic order of keys.
+    /// Values from the current snapshot take precedence over its parents and deleted keys are skipped.
+    pub fn get_all<S: Schema>(&self) -> anyhow::Result<Vec<(S::Key, S::Value)>> {
+        let local_cache = self
+            .cache
+            .lock()
+            .expect("SchemaBatch lock must not be poisoned");
+        let local_cache_iter = local_cache.iter::<S>();
+
+        let parent = self
+            .parents_manager
+            .read()
+            .expect("Parent lock must not be poisoned");
+
+        let parent_iter = parent.iter::<S>(self.id)?;
+
+        let combined_iter: SnapshotIter<'_, S, _, _> = SnapshotIter {
+            local_cache_iter: local_cache_iter.peekable(),
+            parent_iter: parent_iter.peekable(),
+        };
+
+        combined_iter
+            .map(|(key, value)| {
+                let key = S::Key::decode_key(&key)?;
+                let value = S::Value::decode_value(&value)?;
+                Ok::<_, anyhow::Error>((key, value))
+            })
+            .collect()
+    }
+
     /// Get largest value in [`Schema`] that is smaller or equal than give `seek_key`
     pub fn get_prev<S: Schema>(
         &self,
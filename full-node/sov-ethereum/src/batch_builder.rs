@@ -26,16 +26,15 @@ impl<C: sov_modules_api::Context> EthBatchBuilder<C> {
         }
     }
 
-    /// Signs messages with the private key of the `EthBatchBuilder` and make them `transactions`.
-    /// Returns the blob of signed transactions.
-    fn make_blob(&mut self) -> Vec<Vec<u8>> {
+    /// Signs messages for `chain_id` with the private key of the `EthBatchBuilder` and make them
+    /// `transactions`. Returns the blob of signed transactions.
+    fn make_blob(&mut self, chain_id: u64) -> Vec<Vec<u8>> {
         let mut txs = Vec::new();
 
         let nonce = self.nonce.borrow_mut();
 
         while let Some(raw_message) = self.mempool.pop_front() {
-            // TODO define a strategy to expose chain id and gas tip for ethereum frontend
-            let chain_id = 0;
+            // TODO define a strategy to expose gas tip for ethereum frontend
             let gas_tip = 0;
             let gas_limit = 0;
 
@@ -64,13 +63,13 @@ impl<C: sov_modules_api::Context> EthBatchBuilder<C> {
         }
     }
 
-    /// Attempts to create a blob with a minimum size of `min_blob_size`.
-    pub fn get_next_blob(&mut self, min_blob_size: Option<usize>) -> Vec<Vec<u8>> {
+    /// Attempts to create a blob with a minimum size of `min_blob_size`, signed for `chain_id`.
+    pub fn get_next_blob(&mut self, min_blob_size: Option<usize>, chain_id: u64) -> Vec<Vec<u8>> {
         let min_blob_size = min_blob_size.or(self.min_blob_size);
 
         if let Some(min_blob_size) = min_blob_size {
             if self.mempool.len() >= min_blob_size {
-                return self.make_blob();
+                return self.make_blob(chain_id);
             }
         }
         Vec::default()
@@ -80,9 +79,10 @@ impl<C: sov_modules_api::Context> EthBatchBuilder<C> {
     pub fn add_messages_and_get_next_blob(
         &mut self,
         min_blob_size: Option<usize>,
+        chain_id: u64,
         messages: Vec<Vec<u8>>,
     ) -> Vec<Vec<u8>> {
         self.add_messages(messages);
-        self.get_next_blob(min_blob_size)
+        self.get_next_blob(min_blob_size, chain_id)
     }
 }
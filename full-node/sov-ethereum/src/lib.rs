@@ -3,6 +3,8 @@ mod batch_builder;
 #[cfg(feature = "experimental")]
 mod gas_price;
 #[cfg(feature = "experimental")]
+mod pending_pool;
+#[cfg(feature = "experimental")]
 pub use experimental::{get_ethereum_rpc, Ethereum};
 #[cfg(feature = "experimental")]
 pub use gas_price::gas_oracle::GasPriceOracleConfig;
@@ -19,14 +21,16 @@ pub mod experimental {
     use ethers::types::{Bytes, H256};
     use jsonrpsee::types::ErrorObjectOwned;
     use jsonrpsee::RpcModule;
-    use reth_primitives::{TransactionSignedNoHash as RethTransactionSignedNoHash, U128, U256};
+    use reth_primitives::{TransactionSignedEcRecovered, U128, U256};
     use reth_rpc_types::{CallRequest, TransactionRequest, TypedTransactionRequest};
     use sov_evm::{CallMessage, Evm, RlpEvmTransaction};
     use sov_modules_api::utils::to_jsonrpsee_error_object;
     use sov_modules_api::{EncodeCall, PrivateKey, WorkingSet};
+    use sov_modules_stf_blueprint::chain_id::get_chain_id;
     use sov_rollup_interface::services::da::DaService;
 
     use super::batch_builder::EthBatchBuilder;
+    use super::pending_pool::PendingPool;
     #[cfg(feature = "local")]
     use super::DevSigner;
     use crate::gas_price::gas_oracle::GasPriceOracle;
@@ -90,6 +94,7 @@ pub mod experimental {
     pub struct Ethereum<C: sov_modules_api::Context, Da: DaService> {
         da_service: Da,
         batch_builder: Arc<Mutex<EthBatchBuilder<C>>>,
+        pending_pool: Mutex<PendingPool>,
         gas_price_oracle: GasPriceOracle<C>,
         #[cfg(feature = "local")]
         eth_signer: DevSigner,
@@ -109,6 +114,7 @@ pub mod experimental {
             Self {
                 da_service,
                 batch_builder,
+                pending_pool: Mutex::new(PendingPool::default()),
                 gas_price_oracle,
                 #[cfg(feature = "local")]
                 eth_signer,
@@ -121,15 +127,59 @@ pub mod experimental {
         fn make_raw_tx(
             &self,
             raw_tx: RlpEvmTransaction,
-        ) -> Result<(H256, Vec<u8>), jsonrpsee::core::Error> {
-            let signed_transaction: RethTransactionSignedNoHash = raw_tx.clone().try_into()?;
-
-            let tx_hash = signed_transaction.hash();
+        ) -> Result<(TransactionSignedEcRecovered, Vec<u8>), jsonrpsee::core::Error> {
+            // Recovering the signer rejects transactions with invalid signatures before they are
+            // signed by the rollup tx signer and sent to the DA layer.
+            let signed_transaction: TransactionSignedEcRecovered = raw_tx.clone().try_into()?;
 
             let tx = CallMessage { tx: raw_tx };
             let message = <Runtime<C, Da::Spec> as EncodeCall<sov_evm::Evm<C>>>::encode_call(tx);
 
-            Ok((H256::from(tx_hash), message))
+            Ok((signed_transaction, message))
+        }
+
+        /// Adds an EVM transaction to the batch builder, or holds it in the pending pool until
+        /// the transactions of its sender with lower nonces arrive.
+        /// Returns the hash of the EVM transaction.
+        fn add_raw_tx(&self, raw_tx: RlpEvmTransaction) -> Result<H256, ErrorObjectOwned> {
+            let (signed_transaction, message) = self
+                .make_raw_tx(raw_tx)
+                .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
+            let sender = signed_transaction.signer();
+
+            // The pool stays locked from reading the account nonce until the released messages
+            // are added, so that concurrent requests can't act on a stale nonce or reorder them.
+            let mut pending_pool = self.pending_pool.lock().unwrap();
+            let account_nonce = self.account_nonce(sender)?;
+            let ready = pending_pool
+                .add(sender, signed_transaction.nonce(), account_nonce, message)
+                .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
+            self.add_messages(ready);
+
+            Ok(H256::from(signed_transaction.hash()))
+        }
+
+        /// Moves the pending transactions which became executable, because their senders' nonces
+        /// have advanced in the state, to the batch builder, and forgets the released transactions
+        /// which were executed.
+        fn promote_pending_txs(&self) -> Result<(), ErrorObjectOwned> {
+            let mut pending_pool = self.pending_pool.lock().unwrap();
+            for sender in pending_pool.tracked_senders() {
+                let account_nonce = self.account_nonce(sender)?;
+                let ready = pending_pool.promote(sender, account_nonce);
+                self.add_messages(ready);
+            }
+            Ok(())
+        }
+
+        fn account_nonce(
+            &self,
+            address: reth_primitives::Address,
+        ) -> Result<u64, ErrorObjectOwned> {
+            let evm = Evm::<C>::default();
+            let mut working_set = WorkingSet::<C>::read_only(self.storage.clone());
+            let nonce = evm.get_transaction_count(address, None, &mut working_set)?;
+            Ok(nonce.as_u64())
         }
 
         async fn build_and_submit_batch(
@@ -168,11 +218,16 @@ pub mod experimental {
             messages: Vec<Vec<u8>>,
             min_blob_size: Option<usize>,
         ) -> Result<Vec<Vec<u8>>, jsonrpsee::core::Error> {
+            // The chain id is only stored at genesis, which may run after this RPC is created.
+            let chain_id = get_chain_id::<C>(&mut WorkingSet::new(self.storage.clone()))
+                .ok_or_else(|| {
+                    to_jsonrpsee_error_object("The chain has not been initialized", ETH_RPC_ERROR)
+                })?;
             let batch = self
                 .batch_builder
                 .lock()
                 .unwrap()
-                .add_messages_and_get_next_blob(min_blob_size, messages);
+                .add_messages_and_get_next_blob(min_blob_size, chain_id, messages);
 
             Ok(batch)
         }
@@ -187,7 +242,7 @@ pub mod experimental {
     ) -> Result<(), jsonrpsee::core::Error> {
         rpc.register_async_method("eth_gasPrice", |_, ethereum| async move {
             let price = {
-                let mut working_set = WorkingSet::<C>::new(ethereum.storage.clone());
+                let mut working_set = WorkingSet::<C>::read_only(ethereum.storage.clone());
 
                 let suggested_tip = ethereum
                     .gas_price_oracle
@@ -218,6 +273,7 @@ pub mod experimental {
                 txs.push(tx)
             }
 
+            ethereum.promote_pending_txs()?;
             ethereum
                 .build_and_submit_batch(txs, Some(1))
                 .await
@@ -229,15 +285,11 @@ pub mod experimental {
         rpc.register_async_method(
             "eth_sendRawTransaction",
             |parameters, ethereum| async move {
-                let data: Bytes = parameters.one().unwrap();
+                let data: Bytes = parameters.one()?;
 
                 let raw_evm_tx = RlpEvmTransaction { rlp: data.to_vec() };
 
-                let (tx_hash, raw_message) = ethereum
-                    .make_raw_tx(raw_evm_tx)
-                    .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
-
-                ethereum.add_messages(vec![raw_message]);
+                let tx_hash = ethereum.add_raw_tx(raw_evm_tx)?;
 
                 Ok::<_, ErrorObjectOwned>(tx_hash)
             },
@@ -268,7 +320,7 @@ pub mod experimental {
             }
 
             let raw_evm_tx = {
-                let mut working_set = WorkingSet::<C>::new(ethereum.storage.clone());
+                let mut working_set = WorkingSet::<C>::read_only(ethereum.storage.clone());
 
                 // set nonce if none
                 if transaction_request.nonce.is_none() {
@@ -342,11 +394,7 @@ pub mod experimental {
                     rlp: signed_tx.envelope_encoded().to_vec(),
                 }
             };
-            let (tx_hash, raw_message) = ethereum
-                .make_raw_tx(raw_evm_tx)
-                .map_err(|e| to_jsonrpsee_error_object(e, ETH_RPC_ERROR))?;
-
-            ethereum.add_messages(vec![raw_message]);
+            let tx_hash = ethereum.add_raw_tx(raw_evm_tx)?;
 
             Ok::<_, ErrorObjectOwned>(tx_hash)
         })?;
@@ -464,4 +512,150 @@ pub mod experimental {
 
         (call_request, gas_price, max_fee_per_gas)
     }
+
+    #[cfg(test)]
+    mod tests {
+        use borsh::BorshDeserialize;
+        use reth_primitives::{Address, Transaction, TransactionKind, TxEip1559};
+        use secp256k1::{PublicKey, SecretKey};
+        use sov_mock_da::{MockAddress, MockDaService, MockDaSpec};
+        use sov_modules_api::default_context::DefaultContext;
+        use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
+        use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait};
+
+        use super::*;
+
+        fn eth_rpc(
+            storage_path: &std::path::Path,
+            da_service: MockDaService,
+        ) -> RpcModule<Ethereum<DefaultContext, MockDaService>> {
+            let storage = sov_prover_storage_manager::new_orphan_storage(storage_path).unwrap();
+            let eth_rpc_config = EthRpcConfig::<DefaultContext> {
+                min_blob_size: None,
+                sov_tx_signer_priv_key: DefaultPrivateKey::generate(),
+                gas_price_oracle_config: GasPriceOracleConfig::default(),
+                eth_signer: DevSigner::new(vec![]),
+            };
+            get_ethereum_rpc(da_service, eth_rpc_config, storage)
+        }
+
+        fn signed_raw_tx(nonce: u64) -> (H256, Bytes) {
+            let secret_key = SecretKey::from_slice(&[7; 32]).unwrap();
+            let public_key = PublicKey::from_secret_key(secp256k1::SECP256K1, &secret_key);
+            let address = reth_primitives::public_key_to_address(public_key);
+
+            let tx = Transaction::Eip1559(TxEip1559 {
+                chain_id: 1,
+                nonce,
+                gas_limit: 21_000,
+                max_fee_per_gas: u128::from(reth_primitives::constants::MIN_PROTOCOL_BASE_FEE),
+                to: TransactionKind::Call(Address::repeat_byte(1)),
+                ..Default::default()
+            });
+            let signed_tx = DevSigner::new(vec![secret_key])
+                .sign_transaction(tx, address)
+                .unwrap();
+
+            (
+                H256::from(signed_tx.hash()),
+                Bytes::from(signed_tx.envelope_encoded().to_vec()),
+            )
+        }
+
+        /// The runtime message calling the EVM with `raw_tx`.
+        fn evm_call_message(raw_tx: &Bytes) -> Vec<u8> {
+            let tx = CallMessage {
+                tx: RlpEvmTransaction {
+                    rlp: raw_tx.to_vec(),
+                },
+            };
+            <Runtime<DefaultContext, MockDaSpec> as EncodeCall<Evm<DefaultContext>>>::encode_call(
+                tx,
+            )
+        }
+
+        /// The runtime messages of the rollup transactions in the blob at `height`.
+        async fn submitted_messages(da_service: &MockDaService, height: u64) -> Vec<Vec<u8>> {
+            let mut submitted_block = da_service.get_block_at(height).await.unwrap();
+            let batch =
+                Vec::<Vec<u8>>::try_from_slice(submitted_block.blobs[0].full_data()).unwrap();
+            batch
+                .iter()
+                .map(|tx| {
+                    sov_modules_api::transaction::Transaction::<DefaultContext>::try_from_slice(tx)
+                        .unwrap()
+                        .runtime_msg()
+                        .to_vec()
+                })
+                .collect()
+        }
+
+        #[tokio::test]
+        async fn test_send_raw_transaction_reaches_da() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let da_service = MockDaService::new(MockAddress::default());
+            let rpc = eth_rpc(tmpdir.path(), da_service.clone());
+
+            let (expected_tx_hash, raw_tx) = signed_raw_tx(0);
+            let tx_hash: H256 = rpc
+                .call("eth_sendRawTransaction", [raw_tx.clone()])
+                .await
+                .unwrap();
+            assert_eq!(expected_tx_hash, tx_hash);
+
+            let arg: &[u8] = &[];
+            let _: String = rpc.call("eth_publishBatch", arg).await.unwrap();
+
+            // The EVM transaction is wrapped into a rollup transaction calling the EVM module.
+            assert_eq!(
+                vec![evm_call_message(&raw_tx)],
+                submitted_messages(&da_service, 1).await
+            );
+        }
+
+        #[tokio::test]
+        async fn test_transactions_after_nonce_gap_wait_for_it() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let da_service = MockDaService::new(MockAddress::default());
+            let rpc = eth_rpc(tmpdir.path(), da_service.clone());
+            let arg: &[u8] = &[];
+
+            let (_, first_tx) = signed_raw_tx(0);
+            let (second_tx_hash, second_tx) = signed_raw_tx(1);
+            let tx_hash: H256 = rpc
+                .call("eth_sendRawTransaction", [second_tx.clone()])
+                .await
+                .unwrap();
+            assert_eq!(second_tx_hash, tx_hash);
+
+            // The second transaction waits for the first one, so there's nothing to publish.
+            let _: String = rpc.call("eth_publishBatch", arg).await.unwrap();
+            assert_eq!(
+                0,
+                da_service.get_head_block_header().await.unwrap().height()
+            );
+
+            let _: H256 = rpc
+                .call("eth_sendRawTransaction", [first_tx.clone()])
+                .await
+                .unwrap();
+            let _: String = rpc.call("eth_publishBatch", arg).await.unwrap();
+            assert_eq!(
+                vec![evm_call_message(&first_tx), evm_call_message(&second_tx)],
+                submitted_messages(&da_service, 1).await
+            );
+        }
+
+        #[tokio::test]
+        async fn test_send_raw_transaction_rejects_invalid_rlp() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let da_service = MockDaService::new(MockAddress::default());
+            let rpc = eth_rpc(tmpdir.path(), da_service);
+
+            let result: Result<H256, _> = rpc
+                .call("eth_sendRawTransaction", [Bytes::from(vec![1, 2, 3])])
+                .await;
+            assert!(result.is_err());
+        }
+    }
 }
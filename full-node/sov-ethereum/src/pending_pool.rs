@@ -0,0 +1,264 @@
+use std::collections::{BTreeMap, HashMap};
+
+use reth_primitives::Address;
+
+/// How far ahead of the next nonce of its sender a transaction is queued by default.
+pub const DEFAULT_MAX_NONCE_GAP: u64 = 64;
+
+/// How many transactions are queued by default, across all senders.
+pub const DEFAULT_MAX_QUEUED_TXS: usize = 4096;
+
+/// Holds EVM transactions which arrived before a transaction with a lower nonce from the same
+/// sender, and releases them in nonce order once the gap is filled.
+pub struct PendingPool {
+    /// Transactions waiting for a preceding nonce, by sender and nonce.
+    queued: HashMap<Address, BTreeMap<u64, Vec<u8>>>,
+    /// The number of transactions in `queued`.
+    queued_txs: usize,
+    /// The nonce following the last transaction released for a sender, while it may be ahead of
+    /// the nonce of the account in the state.
+    next_nonces: HashMap<Address, u64>,
+    max_nonce_gap: u64,
+    max_queued_txs: usize,
+}
+
+impl Default for PendingPool {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_NONCE_GAP, DEFAULT_MAX_QUEUED_TXS)
+    }
+}
+
+impl PendingPool {
+    /// Creates a pool which rejects transactions more than `max_nonce_gap` nonces ahead of the
+    /// next nonce of their sender, and holds at most `max_queued_txs` transactions.
+    pub fn new(max_nonce_gap: u64, max_queued_txs: usize) -> Self {
+        Self {
+            queued: HashMap::new(),
+            queued_txs: 0,
+            next_nonces: HashMap::new(),
+            max_nonce_gap,
+            max_queued_txs,
+        }
+    }
+
+    /// Adds the `message` of a transaction of `sender` with the given `nonce`, where
+    /// `account_nonce` is the current nonce of the sender in the state.
+    /// Returns the messages which can be executed now, in nonce order. A transaction with a nonce
+    /// which was already executed is rejected, while a queued one is replaced. A transaction with
+    /// the nonce of a released one is returned right away, and at most one of them will execute.
+    /// A transaction which would have to wait is rejected if its nonce is too far ahead, or if the
+    /// pool is full.
+    pub fn add(
+        &mut self,
+        sender: Address,
+        nonce: u64,
+        account_nonce: u64,
+        message: Vec<u8>,
+    ) -> anyhow::Result<Vec<Vec<u8>>> {
+        if nonce < account_nonce {
+            anyhow::bail!(
+                "Nonce {} of {} is too low, the account nonce is {}",
+                nonce,
+                sender,
+                account_nonce
+            );
+        }
+        let next_nonce = self.next_nonce(sender, account_nonce);
+        if nonce < next_nonce {
+            return Ok(vec![message]);
+        }
+
+        if nonce > next_nonce {
+            if nonce - next_nonce > self.max_nonce_gap {
+                anyhow::bail!(
+                    "Nonce {} of {} is too far ahead, the next nonce is {}",
+                    nonce,
+                    sender,
+                    next_nonce
+                );
+            }
+            let is_replacement = self
+                .queued
+                .get(&sender)
+                .is_some_and(|queued| queued.contains_key(&nonce));
+            if !is_replacement && self.queued_txs >= self.max_queued_txs {
+                anyhow::bail!(
+                    "The pending pool is full, {} can't wait for nonce {}",
+                    sender,
+                    next_nonce
+                );
+            }
+        }
+
+        if self
+            .queued
+            .entry(sender)
+            .or_default()
+            .insert(nonce, message)
+            .is_none()
+        {
+            self.queued_txs += 1;
+        }
+        Ok(self.release(sender, account_nonce))
+    }
+
+    /// Releases the queued transactions of `sender` which can be executed now that its account
+    /// has reached `account_nonce`.
+    pub fn promote(&mut self, sender: Address, account_nonce: u64) -> Vec<Vec<u8>> {
+        self.release(sender, account_nonce)
+    }
+
+    /// Returns the senders with queued transactions.
+    pub fn senders(&self) -> Vec<Address> {
+        self.queued.keys().copied().collect()
+    }
+
+    /// Returns the senders with queued transactions, or with released ones which may be ahead of
+    /// the nonce of their account in the state. Promoting each of them forgets the released
+    /// transactions which were executed since.
+    pub fn tracked_senders(&self) -> Vec<Address> {
+        let mut senders: Vec<Address> = self.next_nonces.keys().copied().collect();
+        senders.extend(
+            self.queued
+                .keys()
+                .filter(|sender| !self.next_nonces.contains_key(sender)),
+        );
+        senders
+    }
+
+    fn next_nonce(&self, sender: Address, account_nonce: u64) -> u64 {
+        self.next_nonces
+            .get(&sender)
+            .map_or(account_nonce, |next_nonce| (*next_nonce).max(account_nonce))
+    }
+
+    /// Takes the queued transactions of `sender` with consecutive nonces starting from its next
+    /// nonce. Transactions with lower nonces can't be executed anymore and are dropped.
+    fn release(&mut self, sender: Address, account_nonce: u64) -> Vec<Vec<u8>> {
+        let mut next_nonce = self.next_nonce(sender, account_nonce);
+        let mut ready = Vec::new();
+
+        if let Some(queued) = self.queued.get_mut(&sender) {
+            let queued_before = queued.len();
+            *queued = queued.split_off(&next_nonce);
+            while let Some(message) = queued.remove(&next_nonce) {
+                ready.push(message);
+                next_nonce += 1;
+            }
+            self.queued_txs -= queued_before - queued.len();
+            if queued.is_empty() {
+                self.queued.remove(&sender);
+            }
+        }
+
+        if next_nonce > account_nonce {
+            self.next_nonces.insert(sender, next_nonce);
+        } else {
+            self.next_nonces.remove(&sender);
+        }
+        ready
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gap_is_filled_in_nonce_order() {
+        let mut pool = PendingPool::default();
+        let sender = Address::repeat_byte(1);
+
+        assert!(pool.add(sender, 3, 1, vec![3]).unwrap().is_empty());
+        assert!(pool.add(sender, 2, 1, vec![2]).unwrap().is_empty());
+        assert_eq!(vec![sender], pool.senders());
+
+        let ready = pool.add(sender, 1, 1, vec![1]).unwrap();
+        assert_eq!(vec![vec![1], vec![2], vec![3]], ready);
+        assert!(pool.senders().is_empty());
+
+        // The next transaction follows the released ones.
+        assert_eq!(vec![vec![4]], pool.add(sender, 4, 1, vec![4]).unwrap());
+    }
+
+    #[test]
+    fn test_executed_nonce_is_rejected() {
+        let mut pool = PendingPool::default();
+        let sender = Address::repeat_byte(1);
+
+        assert!(pool.add(sender, 1, 2, vec![1]).is_err());
+        assert_eq!(vec![vec![2]], pool.add(sender, 2, 2, vec![2]).unwrap());
+        // A competing transaction for a released nonce is forwarded as well.
+        assert_eq!(vec![vec![22]], pool.add(sender, 2, 2, vec![22]).unwrap());
+    }
+
+    #[test]
+    fn test_promote_after_nonce_advanced_in_state() {
+        let mut pool = PendingPool::default();
+        let sender = Address::repeat_byte(1);
+
+        assert!(pool.add(sender, 5, 3, vec![5]).unwrap().is_empty());
+        assert!(pool.add(sender, 6, 3, vec![6]).unwrap().is_empty());
+        assert!(pool.promote(sender, 4).is_empty());
+
+        // Nonces 3 and 4 were executed without passing through the pool.
+        assert_eq!(vec![vec![5], vec![6]], pool.promote(sender, 5));
+        assert!(pool.senders().is_empty());
+    }
+
+    #[test]
+    fn test_released_nonces_are_forgotten_once_executed() {
+        let mut pool = PendingPool::default();
+        let sender = Address::repeat_byte(1);
+        let other_sender = Address::repeat_byte(2);
+
+        assert_eq!(vec![vec![1]], pool.add(sender, 1, 1, vec![1]).unwrap());
+        assert!(pool.add(other_sender, 3, 1, vec![3]).unwrap().is_empty());
+        let mut tracked = pool.tracked_senders();
+        tracked.sort();
+        assert_eq!(vec![sender, other_sender], tracked);
+
+        // The state hasn't caught up with the released transaction yet.
+        assert!(pool.promote(sender, 1).is_empty());
+        assert!(pool.next_nonces.contains_key(&sender));
+
+        assert!(pool.promote(sender, 2).is_empty());
+        assert!(!pool.next_nonces.contains_key(&sender));
+        assert_eq!(vec![other_sender], pool.tracked_senders());
+    }
+
+    #[test]
+    fn test_nonce_gap_is_bounded() {
+        let mut pool = PendingPool::new(2, DEFAULT_MAX_QUEUED_TXS);
+        let sender = Address::repeat_byte(1);
+
+        assert!(pool.add(sender, 3, 1, vec![3]).unwrap().is_empty());
+        assert!(pool.add(sender, 4, 1, vec![4]).is_err());
+
+        // The gap is counted from the nonce following the released transactions.
+        assert_eq!(vec![vec![1]], pool.add(sender, 1, 1, vec![1]).unwrap());
+        assert!(pool.add(sender, 4, 1, vec![4]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_pool_size_is_bounded() {
+        let mut pool = PendingPool::new(DEFAULT_MAX_NONCE_GAP, 2);
+        let sender = Address::repeat_byte(1);
+        let other_sender = Address::repeat_byte(2);
+
+        assert!(pool.add(sender, 2, 0, vec![2]).unwrap().is_empty());
+        assert!(pool.add(other_sender, 1, 0, vec![1]).unwrap().is_empty());
+        assert!(pool.add(sender, 3, 0, vec![3]).is_err());
+        // Replacing a queued transaction and filling a gap don't need more room.
+        assert!(pool.add(sender, 2, 0, vec![22]).unwrap().is_empty());
+        assert_eq!(vec![vec![0]], pool.add(sender, 0, 0, vec![0]).unwrap());
+        assert_eq!(
+            vec![vec![1], vec![22]],
+            pool.add(sender, 1, 0, vec![1]).unwrap()
+        );
+
+        // The released transactions make room for new ones.
+        assert!(pool.add(sender, 4, 0, vec![4]).unwrap().is_empty());
+        assert!(pool.add(sender, 5, 0, vec![5]).is_err());
+    }
+}
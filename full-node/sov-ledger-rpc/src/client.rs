@@ -127,6 +127,17 @@ where
         query_mode: QueryMode,
     ) -> RpcResult<Vec<Option<Batch>>>;
 
+    /// Gets the batches in a range which were read from blobs of the given
+    /// rollup-defined category.
+    #[method(name = "getBatchesByCategory")]
+    async fn get_batches_by_category(
+        &self,
+        category: u8,
+        start: u64,
+        end: u64,
+        query_mode: QueryMode,
+    ) -> RpcResult<Vec<Option<Batch>>>;
+
     /// Gets a range of transactions. This query is the most efficient way to
     /// fetch large numbers of transactions, since it allows for easy batching of
     /// db queries for adjacent items.
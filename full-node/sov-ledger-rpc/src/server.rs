@@ -135,6 +135,12 @@ where
             .get_batches_range::<B, Tx>(args.0, args.1, args.2)
             .map_err(|e| to_jsonrpsee_error_object(e, LEDGER_RPC_ERROR))
     })?;
+    rpc.register_method("ledger_getBatchesByCategory", move |params, ledger| {
+        let args: CategoryRangeArgs = params.parse()?;
+        ledger
+            .get_batches_by_category::<B, Tx>(args.0, args.1, args.2, args.3)
+            .map_err(|e| to_jsonrpsee_error_object(e, LEDGER_RPC_ERROR))
+    })?;
     rpc.register_method("ledger_getTransactionsRange", move |params, ledger| {
         let args: RangeArgs = params.parse()?;
         ledger
@@ -184,6 +190,9 @@ where
 #[derive(serde::Deserialize)]
 struct RangeArgs(u64, u64, #[serde(default)] QueryMode);
 
+#[derive(serde::Deserialize)]
+struct CategoryRangeArgs(u8, u64, u64, #[serde(default)] QueryMode);
+
 /// A structure containing serialized query arguments for RPC queries.
 #[derive(serde::Deserialize)]
 struct QueryArgs<T>(T, #[serde(default)] QueryMode);
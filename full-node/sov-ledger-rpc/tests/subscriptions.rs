@@ -0,0 +1,46 @@
+use std::net::SocketAddr;
+
+use sov_db::ledger_db::{LedgerDB, SlotCommit};
+use sov_ledger_rpc::client::RpcClient;
+use sov_ledger_rpc::server::rpc_module;
+use sov_mock_da::{MockBlob, MockBlock};
+use sov_rollup_interface::rpc::{BatchResponse, SlotResponse, TxResponse};
+use tempfile::tempdir;
+
+async fn rpc_server(db: LedgerDB) -> (jsonrpsee::server::ServerHandle, SocketAddr) {
+    let rpc_module = rpc_module::<LedgerDB, u32, u32>(db).unwrap();
+
+    let server = jsonrpsee::server::ServerBuilder::default()
+        .build("127.0.0.1:0")
+        .await
+        .unwrap();
+    let addr = server.local_addr().unwrap();
+    (server.start(rpc_module), addr)
+}
+
+async fn rpc_client(
+    addr: SocketAddr,
+) -> impl RpcClient<SlotResponse<u32, u32>, BatchResponse<u32, u32>, TxResponse<u32>> {
+    jsonrpsee::ws_client::WsClientBuilder::new()
+        .build(format!("ws://{}", addr))
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn subscribe_slots_receives_committed_slots_in_order() {
+    let dir = tempdir().unwrap();
+    let db = LedgerDB::with_path(dir.path()).unwrap();
+    let (_server_handle, addr) = rpc_server(db.clone()).await;
+    let rpc_client = rpc_client(addr).await;
+
+    let mut subscription = rpc_client.subscribe_slots().await.unwrap();
+
+    for _ in 0..2 {
+        db.commit_slot(SlotCommit::<_, MockBlob, Vec<u8>>::new(MockBlock::default()))
+            .unwrap();
+    }
+
+    assert_eq!(subscription.next().await.unwrap().unwrap(), 1);
+    assert_eq!(subscription.next().await.unwrap().unwrap(), 2);
+}
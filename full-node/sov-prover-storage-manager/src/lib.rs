@@ -8,7 +8,8 @@ use sov_db::state_db::StateDB;
 use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec};
 use sov_rollup_interface::storage::HierarchicalStorageManager;
 use sov_schema_db::snapshot::{DbSnapshot, ReadOnlyLock, SnapshotId};
-use sov_state::{MerkleProofSpec, ProverStorage};
+use sov_state::storage::{StorageKey, StorageValue};
+use sov_state::{MerkleProofSpec, OrderedReadsAndWrites, ProverStorage, SharedReadCache, Storage};
 
 pub use crate::snapshot_manager::SnapshotManager;
 
@@ -36,6 +37,11 @@ pub struct ProverStorageManager<Da: DaSpec, S: MerkleProofSpec> {
     state_snapshot_manager: Arc<RwLock<SnapshotManager>>,
     accessory_snapshot_manager: Arc<RwLock<SnapshotManager>>,
 
+    // Read cache shared by the storages of all the blocks, if enabled
+    read_cache: Option<SharedReadCache>,
+    // Snapshot of the last finalized block, whose state is the one in the database
+    finalized_snapshot_id: SnapshotId,
+
     phantom_mp_spec: PhantomData<S>,
 }
 
@@ -59,6 +65,8 @@ where
             snapshot_id_to_parent,
             state_snapshot_manager: Arc::new(RwLock::new(state_snapshot_manager)),
             accessory_snapshot_manager: Arc::new(RwLock::new(accessory_snapshot_manager)),
+            read_cache: None,
+            finalized_snapshot_id: 0,
             phantom_mp_spec: Default::default(),
         }
     }
@@ -69,7 +77,9 @@ where
         let state_db = StateDB::<SnapshotManager>::setup_schema_db(&path)?;
         let native_db = NativeDB::<SnapshotManager>::setup_schema_db(&path)?;
 
-        Ok(Self::with_db_handles(state_db, native_db))
+        let mut storage_manager = Self::with_db_handles(state_db, native_db);
+        storage_manager.read_cache = config.read_cache_size.map(SharedReadCache::new);
+        Ok(storage_manager)
     }
 
     #[cfg(test)]
@@ -99,7 +109,22 @@ where
         );
 
         let native_db = NativeDB::with_db_snapshot(native_db_snapshot)?;
-        Ok(ProverStorage::with_db_handles(state_db, native_db))
+        let storage = ProverStorage::with_db_handles(state_db, native_db);
+        match &self.read_cache {
+            Some(cache) => {
+                // The state committed by a block is identified by its snapshot id, whether it's
+                // still in the snapshot or has been finalized into the database.
+                let parent_snapshot_id = self
+                    .snapshot_id_to_parent
+                    .read()
+                    .unwrap()
+                    .get(&snapshot_id)
+                    .copied()
+                    .unwrap_or(self.finalized_snapshot_id);
+                Ok(storage.with_shared_read_cache(cache.clone(), parent_snapshot_id, snapshot_id))
+            }
+            None => Ok(storage),
+        }
     }
 
     fn finalize_by_hash_pair(
@@ -138,6 +163,7 @@ where
         // Return error here, as underlying database can return error
         state_manager.commit_snapshot(snapshot_id)?;
         native_manager.commit_snapshot(snapshot_id)?;
+        self.finalized_snapshot_id = *snapshot_id;
 
         // All siblings of current snapshot
         let mut to_discard: Vec<_> = self
@@ -157,6 +183,9 @@ where
             snapshot_id_to_parent.remove(&snapshot_id);
             state_manager.discard_snapshot(&snapshot_id);
             native_manager.discard_snapshot(&snapshot_id);
+            if let Some(cache) = &self.read_cache {
+                cache.discard_state(snapshot_id);
+            }
 
             to_discard.extend(child_block_hashes);
         }
@@ -256,6 +285,8 @@ where
         );
 
         let native_db = NativeDB::with_db_snapshot(native_db_snapshot)?;
+        // The finalized storage reads the latest version, which isn't the state of any block,
+        // so it doesn't use the read cache.
         Ok(ProverStorage::with_db_handles(state_db, native_db))
     }
 
@@ -332,13 +363,69 @@ where
 pub fn new_orphan_storage<S: MerkleProofSpec>(
     path: impl AsRef<std::path::Path>,
 ) -> anyhow::Result<ProverStorage<S, SnapshotManager>> {
-    let state_db_raw = StateDB::<SnapshotManager>::setup_schema_db(path.as_ref())?;
-    let state_db_sm = Arc::new(RwLock::new(SnapshotManager::orphan(state_db_raw)));
-    let state_db_snapshot = DbSnapshot::<SnapshotManager>::new(0, state_db_sm.into());
+    let (state_db_sm, native_db_sm) = orphan_snapshot_managers(path.as_ref())?;
+    orphan_storage(&state_db_sm, &native_db_sm)
+}
+
+/// Creates a [`ProverStorage`] in the empty database at `path`, holding the `entries` exported
+/// from another storage with [`ProverStorage::export_snapshot`].
+/// The JMT is rebuilt from the entries in a single version, so the state root of the new storage
+/// matches the root of the exported state, while its history is not preserved.
+pub fn import_snapshot<S: MerkleProofSpec>(
+    path: impl AsRef<std::path::Path>,
+    entries: Vec<(StorageKey, StorageValue)>,
+) -> anyhow::Result<ProverStorage<S, SnapshotManager>> {
+    let path = path.as_ref();
+    let (state_db_sm, native_db_sm) = orphan_snapshot_managers(path)?;
+    let storage = orphan_storage::<S>(&state_db_sm, &native_db_sm)?;
+    anyhow::ensure!(
+        storage.is_empty(),
+        "Cannot import a snapshot into the non-empty storage at {}",
+        path.display()
+    );
+
+    let state_accesses = OrderedReadsAndWrites {
+        ordered_reads: Vec::new(),
+        ordered_writes: entries
+            .into_iter()
+            .map(|(key, value)| (key.into_cache_key(), Some(value.into_cache_value())))
+            .collect(),
+    };
+    storage.validate_and_commit(state_accesses, &Default::default())?;
+
+    // The orphan storage only writes to its snapshot, which is persisted here.
+    let (state_snapshot, native_snapshot) = storage.freeze()?;
+    for (snapshot_manager, snapshot) in [
+        (&state_db_sm, state_snapshot),
+        (&native_db_sm, native_snapshot),
+    ] {
+        let mut snapshot_manager = snapshot_manager.write().unwrap();
+        let snapshot_id = snapshot.get_id();
+        snapshot_manager.add_snapshot(snapshot);
+        snapshot_manager.commit_snapshot(&snapshot_id)?;
+    }
+
+    orphan_storage(&state_db_sm, &native_db_sm)
+}
+
+type OrphanSnapshotManagers = (Arc<RwLock<SnapshotManager>>, Arc<RwLock<SnapshotManager>>);
+
+fn orphan_snapshot_managers(path: &std::path::Path) -> anyhow::Result<OrphanSnapshotManagers> {
+    let state_db_raw = StateDB::<SnapshotManager>::setup_schema_db(path)?;
+    let native_db_raw = NativeDB::<SnapshotManager>::setup_schema_db(path)?;
+    Ok((
+        Arc::new(RwLock::new(SnapshotManager::orphan(state_db_raw))),
+        Arc::new(RwLock::new(SnapshotManager::orphan(native_db_raw))),
+    ))
+}
+
+fn orphan_storage<S: MerkleProofSpec>(
+    state_db_sm: &Arc<RwLock<SnapshotManager>>,
+    native_db_sm: &Arc<RwLock<SnapshotManager>>,
+) -> anyhow::Result<ProverStorage<S, SnapshotManager>> {
+    let state_db_snapshot = DbSnapshot::<SnapshotManager>::new(0, state_db_sm.clone().into());
     let state_db = StateDB::with_db_snapshot(state_db_snapshot)?;
-    let native_db_raw = NativeDB::<SnapshotManager>::setup_schema_db(path.as_ref())?;
-    let native_db_sm = Arc::new(RwLock::new(SnapshotManager::orphan(native_db_raw)));
-    let native_db_snapshot = DbSnapshot::<SnapshotManager>::new(0, native_db_sm.into());
+    let native_db_snapshot = DbSnapshot::<SnapshotManager>::new(0, native_db_sm.clone().into());
     let native_db = NativeDB::with_db_snapshot(native_db_snapshot)?;
     Ok(ProverStorage::with_db_handles(state_db, native_db))
 }
@@ -347,8 +434,8 @@ pub fn new_orphan_storage<S: MerkleProofSpec>(
 mod tests {
     use sov_mock_da::{MockBlockHeader, MockHash};
     use sov_rollup_interface::da::Time;
-    use sov_state::storage::{CacheKey, CacheValue};
-    use sov_state::{ArrayWitness, OrderedReadsAndWrites, Storage};
+    use sov_state::storage::{CacheKey, CacheValue, StorageValue};
+    use sov_state::{ArrayWitness, OrderedReadsAndWrites, ReadCacheStats, Storage, Witness};
 
     use super::*;
 
@@ -709,6 +796,127 @@ mod tests {
         (key_from(key), None)
     }
 
+    /// Writes a key, reads it back repeatedly and overwrites it, returning the final root
+    /// along with the read cache counters.
+    fn read_heavy_progression(
+        read_cache_size: Option<usize>,
+    ) -> (
+        <ProverStorage<S, SnapshotManager> as Storage>::Root,
+        Option<ReadCacheStats>,
+    ) {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let mut storage_manager = ProverStorageManager::<Da, S>::new(sov_state::config::Config {
+            path: tmpdir.path().to_path_buf(),
+            read_cache_size,
+        })
+        .unwrap();
+        let block_header = MockBlockHeader {
+            prev_hash: MockHash::from([1; 32]),
+            hash: MockHash::from([2; 32]),
+            height: 1,
+            time: Time::now(),
+        };
+        let storage = storage_manager.create_storage_on(&block_header).unwrap();
+
+        let witness = ArrayWitness::default();
+        let mut state_operations = OrderedReadsAndWrites::default();
+        state_operations.ordered_writes.push(write_op(1, 10));
+        storage
+            .validate_and_commit(state_operations, &witness)
+            .unwrap();
+
+        let witness = ArrayWitness::default();
+        let mut state_operations = OrderedReadsAndWrites::default();
+        for _ in 0..3 {
+            let value = storage.get(&key_from(1).into(), None, &witness);
+            assert_eq!(Some(value_from(10).into()), value);
+            // The read must still be part of the witness, even if it is served by the cache.
+            assert_eq!(value, witness.get_hint::<Option<StorageValue>>());
+            state_operations
+                .ordered_reads
+                .push((key_from(1), Some(value_from(10))));
+        }
+        state_operations.ordered_writes.push(write_op(1, 20));
+        let root = storage
+            .validate_and_commit(state_operations, &witness)
+            .unwrap();
+
+        // The commit invalidates the cache, so the new value is visible.
+        assert_eq!(
+            Some(value_from(20).into()),
+            storage.get(&key_from(1).into(), None, &witness)
+        );
+        (root, storage.read_cache_stats())
+    }
+
+    #[test]
+    fn read_cache_serves_repeated_reads() {
+        let (cached_root, cached_stats) = read_heavy_progression(Some(16));
+        let (uncached_root, uncached_stats) = read_heavy_progression(None);
+
+        assert_eq!(cached_root, uncached_root);
+        assert_eq!(uncached_stats, None);
+        // Only the first of the repeated reads and the one after the commit hit the database.
+        assert_eq!(cached_stats, Some(ReadCacheStats { hits: 2, misses: 2 }));
+    }
+
+    #[test]
+    fn read_cache_is_shared_by_consecutive_blocks() {
+        let tmpdir = tempfile::tempdir().unwrap();
+        let mut storage_manager = ProverStorageManager::<Da, S>::new(sov_state::config::Config {
+            path: tmpdir.path().to_path_buf(),
+            read_cache_size: Some(16),
+        })
+        .unwrap();
+        let header = |prev_hash: u8, hash: u8, height: u64| MockBlockHeader {
+            prev_hash: MockHash::from([prev_hash; 32]),
+            hash: MockHash::from([hash; 32]),
+            height,
+            time: Time::now(),
+        };
+        let block_1 = header(0, 1, 1);
+        let block_2 = header(1, 2, 2);
+        let block_3 = header(2, 3, 3);
+        let fork_block_2 = header(1, 22, 2);
+
+        // Reads every key in `reads`, checking its value, then writes `writes` and saves the block.
+        let mut execute = |block: &MockBlockHeader, reads: &[(u64, u64)], writes: &[(u64, u64)]| {
+            let storage = storage_manager.create_storage_on(block).unwrap();
+            let witness = ArrayWitness::default();
+            let mut state_operations = OrderedReadsAndWrites::default();
+            for &(key, value) in reads {
+                assert_eq!(
+                    Some(value_from(value).into()),
+                    storage.get(&key_from(key).into(), None, &witness)
+                );
+                state_operations
+                    .ordered_reads
+                    .push((key_from(key), Some(value_from(value))));
+            }
+            for &(key, value) in writes {
+                state_operations.ordered_writes.push(write_op(key, value));
+            }
+            storage
+                .validate_and_commit(state_operations, &witness)
+                .unwrap();
+            let stats = storage.read_cache_stats().unwrap();
+            storage_manager.save_change_set(block, storage).unwrap();
+            stats
+        };
+
+        execute(&block_1, &[], &[(1, 10), (2, 1)]);
+        let stats = execute(&block_2, &[(1, 10), (2, 1)], &[(2, 2)]);
+        assert_eq!(stats, ReadCacheStats { hits: 0, misses: 2 });
+
+        // Key 1 wasn't written by block 2, so it's still cached for block 3.
+        let stats = execute(&block_3, &[(1, 10), (2, 2)], &[]);
+        assert_eq!(stats, ReadCacheStats { hits: 1, misses: 3 });
+
+        // The fork reads the state of block 1 again, so it doesn't see the writes of block 2.
+        let stats = execute(&fork_block_2, &[(1, 10), (2, 1)], &[]);
+        assert_eq!(stats, ReadCacheStats { hits: 1, misses: 5 });
+    }
+
     #[test]
     fn linear_progression() {
         let tmpdir = tempfile::tempdir().unwrap();
@@ -1240,4 +1448,58 @@ mod tests {
             storage_last.get_accessory(&key_from(3).into(), None)
         );
     }
+
+    #[test]
+    fn export_and_import_snapshot() {
+        let source_dir = tempfile::tempdir().unwrap();
+        let (state_db_sm, native_db_sm) = orphan_snapshot_managers(source_dir.path()).unwrap();
+        let storage = orphan_storage::<S>(&state_db_sm, &native_db_sm).unwrap();
+        let witness = ArrayWitness::default();
+
+        let mut state_operations = OrderedReadsAndWrites::default();
+        for i in 1..=4 {
+            state_operations
+                .ordered_writes
+                .push((key_from(i), Some(value_from(i * 10))));
+        }
+        storage
+            .validate_and_commit(state_operations, &witness)
+            .unwrap();
+        // The exported snapshot holds the latest values only.
+        let mut state_operations = OrderedReadsAndWrites::default();
+        state_operations
+            .ordered_writes
+            .push((key_from(1), Some(value_from(100))));
+        state_operations.ordered_writes.push((key_from(3), None));
+        storage
+            .validate_and_commit(state_operations, &witness)
+            .unwrap();
+
+        let entries = storage.export_snapshot().unwrap();
+        let expected: Vec<(StorageKey, StorageValue)> = [(1, 100), (2, 20), (4, 40)]
+            .into_iter()
+            .map(|(k, v)| (key_from(k).into(), value_from(v).into()))
+            .collect();
+        assert_eq!(expected, entries);
+
+        let target_dir = tempfile::tempdir().unwrap();
+        let imported = import_snapshot::<S>(target_dir.path(), entries.clone()).unwrap();
+        assert_eq!(
+            storage.get_root_hash(storage.latest_version()).unwrap(),
+            imported.get_root_hash(imported.latest_version()).unwrap()
+        );
+        assert_eq!(
+            Some(value_from(100).into()),
+            imported.get(&key_from(1).into(), None, &witness)
+        );
+        assert_eq!(None, imported.get(&key_from(3).into(), None, &witness));
+        assert_eq!(entries, imported.export_snapshot().unwrap());
+
+        // The snapshot was persisted, so it survives reopening the database.
+        drop(imported);
+        let (state_db_sm, native_db_sm) = orphan_snapshot_managers(target_dir.path()).unwrap();
+        let reopened = orphan_storage::<S>(&state_db_sm, &native_db_sm).unwrap();
+        assert_eq!(entries, reopened.export_snapshot().unwrap());
+        assert!(import_snapshot::<S>(target_dir.path(), entries).is_err());
+    }
 }
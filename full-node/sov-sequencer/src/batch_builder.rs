@@ -1,5 +1,7 @@
+use std::cmp::Ordering;
 use std::collections::VecDeque;
 use std::io::Cursor;
+use std::path::Path;
 
 use anyhow::{bail, Context as ErrorContext};
 use borsh::BorshDeserialize;
@@ -42,11 +44,17 @@ where
     }
 }
 
-/// BatchBuilder that creates batches of transactions in the order they were submitted
+/// Compares the priority of two transactions: the greater one is included in a batch first,
+/// and evicted from a full mempool last.
+pub type TxPriority<C> = Box<dyn Fn(&Transaction<C>, &Transaction<C>) -> Ordering + Send + Sync>;
+
+/// BatchBuilder that creates batches of transactions in the order they were submitted,
+/// or in the order of their priority if one is set with [`FiFoStrictBatchBuilder::with_priority`].
 /// Only transactions that were successfully dispatched are included.
 pub struct FiFoStrictBatchBuilder<C: Context, R: DispatchCall<Context = C>> {
     mempool: VecDeque<PooledTransaction<C, R>>,
     mempool_max_txs_count: usize,
+    priority: Option<TxPriority<C>>,
     runtime: R,
     max_batch_size_bytes: usize,
     current_storage: C::Storage,
@@ -69,12 +77,77 @@ where
         Self {
             mempool: VecDeque::new(),
             mempool_max_txs_count,
+            priority: None,
             max_batch_size_bytes,
             runtime,
             current_storage,
             sequencer,
         }
     }
+
+    /// Orders the mempool by `priority` instead of the order of submission. Transactions with
+    /// the same priority keep the order in which they were submitted.
+    ///
+    /// When the mempool is full, a new transaction evicts the pending transaction with the
+    /// lowest priority if its own priority is higher, and is rejected otherwise.
+    pub fn with_priority(
+        mut self,
+        priority: impl Fn(&Transaction<C>, &Transaction<C>) -> Ordering + Send + Sync + 'static,
+    ) -> Self {
+        self.priority = Some(Box::new(priority));
+        self
+    }
+
+    /// Returns the number of transactions in the mempool.
+    pub fn pool_len(&self) -> usize {
+        self.mempool.len()
+    }
+
+    /// Returns the length of each pending transaction, in mempool order.
+    pub fn pending_tx_lens(&self) -> impl Iterator<Item = usize> + '_ {
+        self.mempool.iter().map(|pooled| pooled.raw.len())
+    }
+
+    /// Serializes the raw bytes of the pending transactions, in the order they will be
+    /// included in a batch.
+    pub fn serialize_mempool(&self) -> Vec<u8> {
+        let txs: Vec<&Vec<u8>> = self.mempool.iter().map(|pooled| &pooled.raw).collect();
+        borsh::to_vec(&txs).expect("Serialization to vec is infallible")
+    }
+
+    /// Adds the transactions produced by [`FiFoStrictBatchBuilder::serialize_mempool`] back
+    /// to the mempool, in their original order.
+    ///
+    /// Transactions go through the same checks as in [`BatchBuilder::accept_tx`].
+    pub fn restore_mempool(&mut self, serialized_mempool: &[u8]) -> anyhow::Result<()> {
+        let txs: Vec<Vec<u8>> = BorshDeserialize::try_from_slice(serialized_mempool)
+            .context("Failed to deserialize mempool")?;
+        for tx in txs {
+            self.accept_tx(tx)
+                .context("Failed to restore transaction into the mempool")?;
+        }
+        Ok(())
+    }
+
+    /// Writes the pending transactions to the file at `path`, so they can be reloaded with
+    /// [`FiFoStrictBatchBuilder::load_mempool`] after a restart.
+    pub fn persist_mempool(&self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        std::fs::write(path, self.serialize_mempool())
+            .with_context(|| format!("Failed to persist mempool to {}", path.display()))
+    }
+
+    /// Reloads the pending transactions persisted with [`FiFoStrictBatchBuilder::persist_mempool`].
+    /// Does nothing if the file doesn't exist.
+    pub fn load_mempool(&mut self, path: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = path.as_ref();
+        if !path.exists() {
+            return Ok(());
+        }
+        let serialized_mempool = std::fs::read(path)
+            .with_context(|| format!("Failed to read mempool from {}", path.display()))?;
+        self.restore_mempool(&serialized_mempool)
+    }
 }
 
 impl<C, R> BatchBuilder for FiFoStrictBatchBuilder<C, R>
@@ -85,10 +158,10 @@ where
     /// Attempt to add transaction to the mempool.
     ///
     /// The transaction is discarded if:
-    /// - mempool is full
+    /// - mempool is full, and the transaction doesn't have a higher priority than a pending one
     /// - transaction is invalid (deserialization, verification or decoding of the runtime message failed)
     fn accept_tx(&mut self, raw: Vec<u8>) -> anyhow::Result<()> {
-        if self.mempool.len() >= self.mempool_max_txs_count {
+        if self.mempool.len() >= self.mempool_max_txs_count && self.priority.is_none() {
             bail!("Mempool is full")
         }
 
@@ -112,15 +185,39 @@ where
             .map_err(anyhow::Error::new)
             .context("Failed to decode message in transaction")?;
 
-        self.mempool.push_back(PooledTransaction {
+        let pooled = PooledTransaction {
             raw,
             tx,
             msg: Some(msg),
-        });
+        };
+        match &self.priority {
+            None => self.mempool.push_back(pooled),
+            Some(priority) => {
+                if self.mempool.len() >= self.mempool_max_txs_count {
+                    // The mempool is ordered by priority, so the last transaction has the lowest.
+                    match self.mempool.back() {
+                        Some(lowest) if priority(&pooled.tx, &lowest.tx) == Ordering::Greater => {
+                            let evicted = self.mempool.pop_back().expect("mempool is not empty");
+                            info!(
+                                hash = hex::encode(evicted.calculate_hash()),
+                                "Transaction has been evicted from the mempool",
+                            );
+                        }
+                        _ => bail!("Mempool is full"),
+                    }
+                }
+                let position = self
+                    .mempool
+                    .iter()
+                    .position(|pending| priority(&pooled.tx, &pending.tx) == Ordering::Greater)
+                    .unwrap_or(self.mempool.len());
+                self.mempool.insert(position, pooled);
+            }
+        }
         Ok(())
     }
 
-    /// Builds a new batch of valid transactions in order they were added to mempool
+    /// Builds a new batch of valid transactions in mempool order
     /// Only transactions, which are dispatched successfully are included in the batch
     fn get_next_blob(&mut self) -> anyhow::Result<Vec<Vec<u8>>> {
         let mut working_set = WorkingSet::new(self.current_storage.clone());
@@ -213,10 +310,17 @@ mod tests {
     }
 
     fn generate_valid_tx(private_key: &DefaultPrivateKey, value: u32) -> Vec<u8> {
+        generate_valid_tx_with_tip(private_key, value, 0)
+    }
+
+    fn generate_valid_tx_with_tip(
+        private_key: &DefaultPrivateKey,
+        value: u32,
+        gas_tip: u64,
+    ) -> Vec<u8> {
         let msg = CallMessage::SetValue(value);
         let msg = <TestRuntime<C> as EncodeCall<ValueSetter<DefaultContext>>>::encode_call(msg);
         let chain_id = 0;
-        let gas_tip = 0;
         let gas_limit = 0;
         let nonce = 1;
 
@@ -390,6 +494,66 @@ mod tests {
         }
     }
 
+    mod persist_mempool {
+        use super::*;
+
+        #[test]
+        fn restores_pending_txs_in_order() {
+            let txs: Vec<_> = (0..5).map(|_| generate_random_valid_tx()).collect();
+
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (mut batch_builder, _) = create_batch_builder(usize::MAX, &tmpdir);
+            for tx in &txs {
+                batch_builder.accept_tx(tx.clone()).unwrap();
+            }
+            let serialized_mempool = batch_builder.serialize_mempool();
+
+            let restored_tmpdir = tempfile::tempdir().unwrap();
+            let (mut restored_batch_builder, _) =
+                create_batch_builder(usize::MAX, &restored_tmpdir);
+            restored_batch_builder
+                .restore_mempool(&serialized_mempool)
+                .unwrap();
+
+            let restored_txs: Vec<_> = restored_batch_builder
+                .mempool
+                .iter()
+                .map(|pooled| pooled.raw.clone())
+                .collect();
+            assert_eq!(txs, restored_txs);
+        }
+
+        #[test]
+        fn persists_and_loads_mempool_from_disk() {
+            let txs: Vec<_> = (0..3).map(|_| generate_random_valid_tx()).collect();
+
+            let mempool_dir = tempfile::tempdir().unwrap();
+            let mempool_path = mempool_dir.path().join("mempool");
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (mut batch_builder, _) = create_batch_builder(usize::MAX, &tmpdir);
+            // Nothing has been persisted yet.
+            batch_builder.load_mempool(&mempool_path).unwrap();
+            assert!(batch_builder.mempool.is_empty());
+
+            for tx in &txs {
+                batch_builder.accept_tx(tx.clone()).unwrap();
+            }
+            batch_builder.persist_mempool(&mempool_path).unwrap();
+
+            let restored_tmpdir = tempfile::tempdir().unwrap();
+            let (mut restored_batch_builder, _) =
+                create_batch_builder(usize::MAX, &restored_tmpdir);
+            restored_batch_builder.load_mempool(&mempool_path).unwrap();
+
+            let restored_txs: Vec<_> = restored_batch_builder
+                .mempool
+                .iter()
+                .map(|pooled| pooled.raw.clone())
+                .collect();
+            assert_eq!(txs, restored_txs);
+        }
+    }
+
     mod build_batch {
         use super::*;
 
@@ -470,4 +634,99 @@ mod tests {
             assert_eq!(1, batch_builder.mempool.len());
         }
     }
+
+    mod priority {
+        use super::*;
+
+        fn by_gas_tip(
+            batch_builder: FiFoStrictBatchBuilder<C, TestRuntime<C>>,
+        ) -> FiFoStrictBatchBuilder<C, TestRuntime<C>> {
+            batch_builder.with_priority(|a, b| a.gas_tip().cmp(&b.gas_tip()))
+        }
+
+        fn pooled_txs(batch_builder: &FiFoStrictBatchBuilder<C, TestRuntime<C>>) -> Vec<Vec<u8>> {
+            batch_builder
+                .mempool
+                .iter()
+                .map(|pooled| pooled.raw.clone())
+                .collect()
+        }
+
+        #[test]
+        fn builds_batch_in_priority_order() {
+            let value_setter_admin = DefaultPrivateKey::generate();
+            let txs: Vec<_> = [(1, 10), (2, 30), (3, 20), (4, 30)]
+                .into_iter()
+                .map(|(value, gas_tip)| {
+                    generate_valid_tx_with_tip(&value_setter_admin, value, gas_tip)
+                })
+                .collect();
+
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (batch_builder, storage) = create_batch_builder(usize::MAX, &tmpdir);
+            let mut batch_builder = by_gas_tip(batch_builder);
+            setup_runtime(storage, Some(value_setter_admin.pub_key()));
+
+            for tx in &txs {
+                batch_builder.accept_tx(tx.clone()).unwrap();
+            }
+            assert_eq!(txs.len(), batch_builder.pool_len());
+
+            // Transactions with the same tip keep the order in which they were accepted.
+            let blob = batch_builder.get_next_blob().unwrap();
+            assert_eq!(
+                vec![
+                    txs[1].clone(),
+                    txs[3].clone(),
+                    txs[2].clone(),
+                    txs[0].clone()
+                ],
+                blob
+            );
+            assert_eq!(0, batch_builder.pool_len());
+        }
+
+        #[test]
+        fn evicts_lowest_priority_tx_on_full_mempool() {
+            let private_key = DefaultPrivateKey::generate();
+            let low = generate_valid_tx_with_tip(&private_key, 1, 1);
+            let medium = generate_valid_tx_with_tip(&private_key, 2, 2);
+            let high = generate_valid_tx_with_tip(&private_key, 3, 3);
+
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (batch_builder, _) = create_batch_builder(usize::MAX, &tmpdir);
+            let mut batch_builder = by_gas_tip(batch_builder);
+            batch_builder.mempool_max_txs_count = 2;
+
+            batch_builder.accept_tx(medium.clone()).unwrap();
+            batch_builder.accept_tx(low).unwrap();
+            batch_builder.accept_tx(high.clone()).unwrap();
+            assert_eq!(2, batch_builder.pool_len());
+            assert_eq!(
+                vec![high.clone(), medium.clone()],
+                pooled_txs(&batch_builder)
+            );
+
+            // Only a strictly higher priority makes room in a full mempool.
+            for tx in [
+                generate_valid_tx_with_tip(&private_key, 4, 2),
+                generate_valid_tx_with_tip(&private_key, 5, 0),
+            ] {
+                let accept_result = batch_builder.accept_tx(tx);
+                assert_eq!("Mempool is full", accept_result.unwrap_err().to_string());
+            }
+            assert_eq!(vec![high, medium], pooled_txs(&batch_builder));
+        }
+
+        #[test]
+        fn zero_sized_mempool_cant_accept_tx() {
+            let tmpdir = tempfile::tempdir().unwrap();
+            let (batch_builder, _) = create_batch_builder(usize::MAX, &tmpdir);
+            let mut batch_builder = by_gas_tip(batch_builder);
+            batch_builder.mempool_max_txs_count = 0;
+
+            let accept_result = batch_builder.accept_tx(generate_random_valid_tx());
+            assert_eq!("Mempool is full", accept_result.unwrap_err().to_string());
+        }
+    }
 }
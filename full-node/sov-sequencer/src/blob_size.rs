@@ -0,0 +1,134 @@
+/// Bounds on the size of the blobs submitted by the [`crate::Sequencer`], in bytes of the
+/// serialized batch.
+///
+/// A bound set to `None` is not enforced, so the default limits accept blobs of any size.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BlobSizeLimits {
+    /// The triggers of the [`crate::flush_policy::FlushPolicy`] don't fire until the pending
+    /// transactions fill a blob of this size. Explicit `sequencer_publishBatch` requests and
+    /// transactions older than [`crate::flush_policy::FlushPolicy::max_age`] are not held back.
+    pub min_blob_size: Option<usize>,
+    /// Batches which don't fit into a blob of this size are split into several blobs before
+    /// they are sent to the DA layer. Transactions which don't fit on their own are rejected.
+    pub max_blob_size: Option<usize>,
+}
+
+impl BlobSizeLimits {
+    /// Checks that a blob can satisfy both bounds.
+    pub fn validate(&self) -> anyhow::Result<()> {
+        if let (Some(min_blob_size), Some(max_blob_size)) = (self.min_blob_size, self.max_blob_size)
+        {
+            anyhow::ensure!(
+                min_blob_size <= max_blob_size,
+                "The minimum blob size of {} bytes exceeds the maximum blob size of {} bytes",
+                min_blob_size,
+                max_blob_size
+            );
+        }
+        Ok(())
+    }
+}
+
+/// The size of the length prefix of a borsh-serialized `Vec`.
+const LEN_PREFIX_SIZE: usize = 4;
+
+/// Returns the size of the borsh-serialized blob of `txs` transactions, whose lengths add up to
+/// `tx_bytes`.
+pub(crate) fn blob_size(txs: usize, tx_bytes: usize) -> usize {
+    LEN_PREFIX_SIZE + txs * LEN_PREFIX_SIZE + tx_bytes
+}
+
+/// Splits `txs` into consecutive blobs which fit into `max_blob_size` bytes once serialized.
+///
+/// Fails if a transaction doesn't fit into a blob on its own.
+pub(crate) fn split_blob(
+    txs: Vec<Vec<u8>>,
+    max_blob_size: usize,
+) -> anyhow::Result<Vec<Vec<Vec<u8>>>> {
+    let mut blobs = Vec::new();
+    let mut blob: Vec<Vec<u8>> = Vec::new();
+    let mut size = blob_size(0, 0);
+
+    for tx in txs {
+        let tx_size = LEN_PREFIX_SIZE + tx.len();
+        anyhow::ensure!(
+            blob_size(1, tx.len()) <= max_blob_size,
+            "Transaction of {} bytes does not fit into a blob of at most {} bytes",
+            tx.len(),
+            max_blob_size
+        );
+        if size + tx_size > max_blob_size {
+            blobs.push(std::mem::take(&mut blob));
+            size = blob_size(0, 0);
+        }
+        size += tx_size;
+        blob.push(tx);
+    }
+    // An empty batch is still submitted as an empty blob.
+    if !blob.is_empty() || blobs.is_empty() {
+        blobs.push(blob);
+    }
+    Ok(blobs)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blob_size_matches_serialization() {
+        let txs = vec![vec![1, 2, 3], vec![], vec![4; 100]];
+        assert_eq!(
+            borsh::to_vec(&txs).unwrap().len(),
+            blob_size(txs.len(), txs.iter().map(Vec::len).sum())
+        );
+    }
+
+    #[test]
+    fn split_keeps_order_and_fills_blobs() {
+        let txs: Vec<Vec<u8>> = (1..=5).map(|i| vec![i; 10]).collect();
+        // Two transactions of 10 bytes fill a blob of 32 bytes exactly.
+        let blobs = split_blob(txs.clone(), 32).unwrap();
+        assert_eq!(
+            vec![2, 2, 1],
+            blobs.iter().map(Vec::len).collect::<Vec<_>>()
+        );
+        assert_eq!(txs, blobs.concat());
+        for blob in blobs {
+            assert!(borsh::to_vec(&blob).unwrap().len() <= 32);
+        }
+
+        assert_eq!(vec![txs.clone()], split_blob(txs, 1000).unwrap());
+        assert_eq!(vec![Vec::<Vec<u8>>::new()], split_blob(vec![], 10).unwrap());
+    }
+
+    #[test]
+    fn validate_rejects_min_above_max() {
+        let limits = BlobSizeLimits {
+            min_blob_size: Some(100),
+            max_blob_size: Some(99),
+        };
+        assert_eq!(
+            "The minimum blob size of 100 bytes exceeds the maximum blob size of 99 bytes",
+            limits.validate().unwrap_err().to_string()
+        );
+
+        for (min_blob_size, max_blob_size) in [(Some(99), Some(99)), (Some(100), None)] {
+            let limits = BlobSizeLimits {
+                min_blob_size,
+                max_blob_size,
+            };
+            assert!(limits.validate().is_ok());
+        }
+    }
+
+    #[test]
+    fn split_rejects_oversized_tx() {
+        let err = split_blob(vec![vec![1], vec![2; 20]], 27).unwrap_err();
+        assert_eq!(
+            "Transaction of 20 bytes does not fit into a blob of at most 27 bytes",
+            err.to_string()
+        );
+        assert!(split_blob(vec![vec![2; 20]], 28).is_ok());
+    }
+}
@@ -0,0 +1,154 @@
+use std::time::{Duration, Instant};
+
+/// Conditions under which the [`crate::Sequencer`] submits a batch on its own, without waiting
+/// for a `sequencer_publishBatch` call. Whichever trigger fires first flushes the batch.
+///
+/// A trigger set to `None` never fires, so the default policy only flushes on explicit requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlushPolicy {
+    /// Flush once this many transactions are pending.
+    pub max_txs: Option<usize>,
+    /// Flush once the pending transactions add up to this many bytes.
+    pub max_bytes: Option<usize>,
+    /// Flush once the oldest pending transaction has been waiting for this long.
+    pub max_age: Option<Duration>,
+}
+
+/// The reason a batch was flushed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlushTrigger {
+    /// [`FlushPolicy::max_txs`] was reached.
+    MaxTxs,
+    /// [`FlushPolicy::max_bytes`] was reached.
+    MaxBytes,
+    /// [`FlushPolicy::max_age`] has elapsed.
+    MaxAge,
+    /// The batch was requested through the `sequencer_publishBatch` RPC method.
+    Explicit,
+}
+
+/// The source of time for [`FlushPolicy::max_age`]. Can be replaced to control time in tests.
+pub trait Clock: Send + Sync {
+    /// Returns the current instant.
+    fn now(&self) -> Instant;
+}
+
+/// A [`Clock`] backed by [`Instant::now`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Transactions accepted since the last flush.
+#[derive(Debug, Default)]
+pub(crate) struct PendingBatch {
+    txs: usize,
+    bytes: usize,
+    first_tx_at: Option<Instant>,
+}
+
+impl PendingBatch {
+    pub(crate) fn record_tx(&mut self, tx_len: usize, now: Instant) {
+        self.txs += 1;
+        self.bytes += tx_len;
+        self.first_tx_at.get_or_insert(now);
+    }
+
+    /// Returns the first trigger of `policy` which fires for the pending transactions.
+    ///
+    /// [`FlushTrigger::MaxAge`] is checked first, because it's the only trigger which isn't held
+    /// back by [`crate::blob_size::BlobSizeLimits::min_blob_size`].
+    pub(crate) fn trigger(&self, policy: &FlushPolicy, now: Instant) -> Option<FlushTrigger> {
+        let first_tx_at = self.first_tx_at?;
+        if policy
+            .max_age
+            .is_some_and(|max| now.saturating_duration_since(first_tx_at) >= max)
+        {
+            return Some(FlushTrigger::MaxAge);
+        }
+        if policy.max_txs.is_some_and(|max| self.txs >= max) {
+            return Some(FlushTrigger::MaxTxs);
+        }
+        if policy.max_bytes.is_some_and(|max| self.bytes >= max) {
+            return Some(FlushTrigger::MaxBytes);
+        }
+        None
+    }
+
+    /// Returns the size of the blob holding the pending transactions.
+    pub(crate) fn blob_size(&self) -> usize {
+        crate::blob_size::blob_size(self.txs, self.bytes)
+    }
+
+    pub(crate) fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nothing_fires_without_pending_txs() {
+        let policy = FlushPolicy {
+            max_txs: Some(0),
+            max_bytes: Some(0),
+            max_age: Some(Duration::ZERO),
+        };
+        assert_eq!(
+            None,
+            PendingBatch::default().trigger(&policy, Instant::now())
+        );
+    }
+
+    #[test]
+    fn each_trigger_fires_independently() {
+        let start = Instant::now();
+        let mut pending = PendingBatch::default();
+        pending.record_tx(10, start);
+        pending.record_tx(10, start);
+
+        let by_txs = FlushPolicy {
+            max_txs: Some(2),
+            ..Default::default()
+        };
+        let by_bytes = FlushPolicy {
+            max_bytes: Some(20),
+            ..Default::default()
+        };
+        let by_age = FlushPolicy {
+            max_age: Some(Duration::from_secs(5)),
+            ..Default::default()
+        };
+
+        assert_eq!(Some(FlushTrigger::MaxTxs), pending.trigger(&by_txs, start));
+        assert_eq!(
+            Some(FlushTrigger::MaxBytes),
+            pending.trigger(&by_bytes, start)
+        );
+        assert_eq!(None, pending.trigger(&by_age, start));
+        assert_eq!(
+            Some(FlushTrigger::MaxAge),
+            pending.trigger(&by_age, start + Duration::from_secs(5))
+        );
+        assert_eq!(None, pending.trigger(&FlushPolicy::default(), start));
+
+        let by_txs_or_age = FlushPolicy {
+            max_txs: Some(2),
+            max_age: Some(Duration::from_secs(5)),
+            ..Default::default()
+        };
+        assert_eq!(
+            Some(FlushTrigger::MaxAge),
+            pending.trigger(&by_txs_or_age, start + Duration::from_secs(5))
+        );
+
+        pending.clear();
+        assert_eq!(None, pending.trigger(&by_txs, start));
+    }
+}
@@ -1,9 +1,13 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex, MutexGuard};
 
 /// Concrete implementations of `[BatchBuilder]`
 pub mod batch_builder;
+/// Limits on the size of the submitted blobs
+pub mod blob_size;
+/// Policies deciding when the sequencer submits a batch
+pub mod flush_policy;
 /// Utilities for the sequencer rpc
 pub mod utils;
 
@@ -14,12 +18,19 @@ use sov_modules_api::utils::to_jsonrpsee_error_object;
 use sov_rollup_interface::services::batch_builder::BatchBuilder;
 use sov_rollup_interface::services::da::DaService;
 
+use crate::blob_size::{blob_size, split_blob, BlobSizeLimits};
+use crate::flush_policy::{Clock, FlushPolicy, FlushTrigger, PendingBatch, SystemClock};
+
 const SEQUENCER_RPC_ERROR: &str = "SEQUENCER_RPC_ERROR";
 
 /// Single data structure that manages mempool and batch producing.
 pub struct Sequencer<B: BatchBuilder, T: DaService> {
     batch_builder: Mutex<B>,
     da_service: T,
+    flush_policy: FlushPolicy,
+    blob_size_limits: BlobSizeLimits,
+    clock: Box<dyn Clock>,
+    pending: Mutex<PendingBatch>,
 }
 
 impl<B: BatchBuilder + Send + Sync, T: DaService + Send + Sync> Sequencer<B, T> {
@@ -28,44 +39,167 @@ impl<B: BatchBuilder + Send + Sync, T: DaService + Send + Sync> Sequencer<B, T>
         Self {
             batch_builder: Mutex::new(batch_builder),
             da_service,
+            flush_policy: FlushPolicy::default(),
+            blob_size_limits: BlobSizeLimits::default(),
+            clock: Box::new(SystemClock),
+            pending: Mutex::new(PendingBatch::default()),
+        }
+    }
+
+    /// Submits a batch on its own whenever a trigger of `flush_policy` fires.
+    pub fn with_flush_policy(mut self, flush_policy: FlushPolicy) -> Self {
+        self.flush_policy = flush_policy;
+        self
+    }
+
+    /// Keeps the submitted blobs within `blob_size_limits`.
+    ///
+    /// Fails if the minimum blob size exceeds the maximum one.
+    pub fn with_blob_size_limits(
+        mut self,
+        blob_size_limits: BlobSizeLimits,
+    ) -> anyhow::Result<Self> {
+        blob_size_limits.validate()?;
+        self.blob_size_limits = blob_size_limits;
+        Ok(self)
+    }
+
+    /// Replaces the clock used to measure [`FlushPolicy::max_age`].
+    pub fn with_clock(mut self, clock: impl Clock + 'static) -> Self {
+        self.clock = Box::new(clock);
+        self
+    }
+
+    /// Counts transactions which are already in the mempool of the batch builder, e.g. because
+    /// it was restored after a restart, towards the flush policy. Their age is measured from
+    /// now, using the clock set so far.
+    pub fn with_pending_txs(mut self, tx_lens: impl IntoIterator<Item = usize>) -> Self {
+        let now = self.clock.now();
+        {
+            let pending = self.pending.get_mut().unwrap_or_else(|e| e.into_inner());
+            for tx_len in tx_lens {
+                pending.record_tx(tx_len, now);
+            }
+        }
+        self
+    }
+
+    /// Locks the batch builder, e.g. to persist its mempool when the rollup stops.
+    pub fn batch_builder(&self) -> anyhow::Result<MutexGuard<'_, B>> {
+        self.batch_builder
+            .lock()
+            .map_err(|e| anyhow!("failed to lock mempool: {}", e.to_string()))
+    }
+
+    /// Submits a batch if any trigger of the flush policy has fired, returning that trigger.
+    ///
+    /// This is checked after every accepted transaction. Call it periodically as well,
+    /// so [`FlushPolicy::max_age`] fires even when no new transactions arrive.
+    pub async fn flush_if_triggered(&self) -> anyhow::Result<Option<FlushTrigger>> {
+        let trigger = {
+            let pending = self
+                .pending
+                .lock()
+                .map_err(|e| anyhow!("failed to lock pending batch: {}", e.to_string()))?;
+            let min_blob_size = self.blob_size_limits.min_blob_size.unwrap_or(0);
+            match pending.trigger(&self.flush_policy, self.clock.now()) {
+                // Expired transactions are submitted even if they don't fill a blob of the
+                // minimum size, so a quiet mempool isn't held back forever.
+                Some(FlushTrigger::MaxAge) => Some(FlushTrigger::MaxAge),
+                Some(_) if pending.blob_size() < min_blob_size => None,
+                trigger => trigger,
+            }
+        };
+        match trigger {
+            Some(trigger) => {
+                self.submit_batch(trigger).await?;
+                Ok(Some(trigger))
+            }
+            None => Ok(None),
         }
     }
 
-    async fn submit_batch(&self) -> anyhow::Result<usize> {
+    /// Submits the next batch of the batch builder, split into several blobs if it exceeds the
+    /// maximum blob size. Returns the number of submitted transactions.
+    ///
+    /// If the DA service fails to accept one of the blobs, the error reports how many of the
+    /// transactions have already been submitted. The transactions of the failed blob and of
+    /// the ones after it have been taken out of the mempool, and are dropped.
+    async fn submit_batch(&self, trigger: FlushTrigger) -> anyhow::Result<usize> {
         // Need to release lock before await, so the Future is `Send`.
         // But potentially it can create blobs that are sent out of order.
         // It can be improved with atomics,
         // so a new batch is only created after previous was submitted.
-        tracing::info!("Submit batch request has been received!");
+        tracing::info!(?trigger, "Submitting batch");
         let blob = {
             let mut batch_builder = self
                 .batch_builder
                 .lock()
                 .map_err(|e| anyhow!("failed to lock mempool: {}", e.to_string()))?;
-            batch_builder.get_next_blob()?
+            let blob = batch_builder.get_next_blob();
+            // Transactions which didn't fit into the batch stay in the mempool,
+            // but they no longer count towards the next flush.
+            self.pending
+                .lock()
+                .map_err(|e| anyhow!("failed to lock pending batch: {}", e.to_string()))?
+                .clear();
+            blob?
         };
         let num_txs = blob.len();
-        let blob: Vec<u8> = borsh::to_vec(&blob)?;
+        let blobs = match self.blob_size_limits.max_blob_size {
+            Some(max_blob_size) => split_blob(blob, max_blob_size)?,
+            None => vec![blob],
+        };
 
-        match self.da_service.send_transaction(&blob).await {
-            Ok(_) => Ok(num_txs),
-            Err(e) => Err(anyhow!("failed to submit batch: {:?}", e)),
+        let mut submitted_txs = 0;
+        for blob in blobs {
+            let blob_txs = blob.len();
+            let blob: Vec<u8> = borsh::to_vec(&blob)?;
+            if let Err(e) = self.da_service.send_transaction(&blob).await {
+                tracing::error!(
+                    submitted_txs,
+                    dropped_txs = num_txs - submitted_txs,
+                    error = ?e,
+                    "Failed to submit batch"
+                );
+                return Err(anyhow!(
+                    "failed to submit batch after {} of {} transactions: {:?}",
+                    submitted_txs,
+                    num_txs,
+                    e
+                ));
+            }
+            submitted_txs += blob_txs;
         }
+        Ok(num_txs)
     }
 
     fn accept_tx(&self, tx: Vec<u8>) -> anyhow::Result<()> {
         tracing::info!("Accepting tx: 0x{}", hex::encode(&tx));
+        let tx_len = tx.len();
+        if let Some(max_blob_size) = self.blob_size_limits.max_blob_size {
+            anyhow::ensure!(
+                blob_size(1, tx_len) <= max_blob_size,
+                "Transaction of {} bytes does not fit into a blob of at most {} bytes",
+                tx_len,
+                max_blob_size
+            );
+        }
         let mut batch_builder = self
             .batch_builder
             .lock()
             .map_err(|e| anyhow!("failed to lock mempool: {}", e.to_string()))?;
         batch_builder.accept_tx(tx)?;
+        self.pending
+            .lock()
+            .map_err(|e| anyhow!("failed to lock pending batch: {}", e.to_string()))?
+            .record_tx(tx_len, self.clock.now());
         Ok(())
     }
 }
 
 fn register_txs_rpc_methods<B, D>(
-    rpc: &mut RpcModule<Sequencer<B, D>>,
+    rpc: &mut RpcModule<Arc<Sequencer<B, D>>>,
 ) -> Result<(), jsonrpsee::core::Error>
 where
     B: BatchBuilder + Send + Sync + 'static,
@@ -81,19 +215,25 @@ where
                     .map_err(|e| to_jsonrpsee_error_object(e, SEQUENCER_RPC_ERROR))?;
             }
             let num_txs = batch_builder
-                .submit_batch()
+                .submit_batch(FlushTrigger::Explicit)
                 .await
                 .map_err(|e| to_jsonrpsee_error_object(e, SEQUENCER_RPC_ERROR))?;
 
             Ok::<String, ErrorObjectOwned>(format!("Submitted {} transactions", num_txs))
         },
     )?;
-    rpc.register_method("sequencer_acceptTx", move |params, sequencer| {
+    rpc.register_async_method("sequencer_acceptTx", |params, sequencer| async move {
         let tx: SubmitTransaction = params.one()?;
         let response = match sequencer.accept_tx(tx.body) {
             Ok(()) => SubmitTransactionResponse::Registered,
             Err(e) => SubmitTransactionResponse::Failed(e.to_string()),
         };
+        if response == SubmitTransactionResponse::Registered {
+            // The transaction stays in the mempool even if the batch can't be submitted.
+            if let Err(error) = sequencer.flush_if_triggered().await {
+                tracing::warn!(%error, "Failed to flush batch");
+            }
+        }
         Ok::<_, ErrorObjectOwned>(response)
     })?;
 
@@ -101,12 +241,23 @@ where
 }
 
 /// Creates an RPC module with the sequencer's methods
-pub fn get_sequencer_rpc<B, D>(batch_builder: B, da_service: D) -> RpcModule<Sequencer<B, D>>
+pub fn get_sequencer_rpc<B, D>(batch_builder: B, da_service: D) -> RpcModule<Arc<Sequencer<B, D>>>
+where
+    B: BatchBuilder + Send + Sync + 'static,
+    D: DaService,
+{
+    get_rpc_for_sequencer(Arc::new(Sequencer::new(batch_builder, da_service)))
+}
+
+/// Creates an RPC module with the methods of an existing sequencer, which can still be used
+/// elsewhere, e.g. to call [`Sequencer::flush_if_triggered`] on a timer.
+pub fn get_rpc_for_sequencer<B, D>(
+    sequencer: Arc<Sequencer<B, D>>,
+) -> RpcModule<Arc<Sequencer<B, D>>>
 where
     B: BatchBuilder + Send + Sync + 'static,
     D: DaService,
 {
-    let sequencer = Sequencer::new(batch_builder, da_service);
     let mut rpc = RpcModule::new(sequencer);
     register_txs_rpc_methods::<B, D>(&mut rpc).expect("Failed to register sequencer RPC methods");
     rpc
@@ -138,7 +289,7 @@ pub enum SubmitTransactionResponse {
 mod tests {
 
     use sov_mock_da::{MockAddress, MockDaService};
-    use sov_rollup_interface::da::BlobReaderTrait;
+    use sov_rollup_interface::da::{BlobReaderTrait, BlockHeaderTrait};
 
     use super::*;
 
@@ -239,6 +390,348 @@ mod tests {
         assert_eq!(expected, block_data);
     }
 
+    #[derive(Clone)]
+    struct MockClock(Arc<Mutex<std::time::Instant>>);
+
+    impl MockClock {
+        fn advance(&self, duration: std::time::Duration) {
+            *self.0.lock().unwrap() += duration;
+        }
+    }
+
+    impl Clock for MockClock {
+        fn now(&self) -> std::time::Instant {
+            *self.0.lock().unwrap()
+        }
+    }
+
+    async fn submitted_blocks(da_service: &MockDaService) -> u64 {
+        da_service.get_head_block_header().await.unwrap().height()
+    }
+
+    async fn accept_tx(rpc: &RpcModule<Arc<Sequencer<MockBatchBuilder, MockDaService>>>, tx: u8) {
+        let request = SubmitTransaction { body: vec![tx] };
+        let result: SubmitTransactionResponse =
+            rpc.call("sequencer_acceptTx", [request]).await.unwrap();
+        assert_eq!(SubmitTransactionResponse::Registered, result);
+    }
+
+    #[tokio::test]
+    async fn test_flush_on_max_txs() {
+        let da_service = MockDaService::new(MockAddress::default());
+        let sequencer = Sequencer::new(MockBatchBuilder { mempool: vec![] }, da_service.clone())
+            .with_flush_policy(FlushPolicy {
+                max_txs: Some(2),
+                ..Default::default()
+            });
+        let rpc = get_rpc_for_sequencer(Arc::new(sequencer));
+
+        accept_tx(&rpc, 1).await;
+        assert_eq!(0, submitted_blocks(&da_service).await);
+        accept_tx(&rpc, 2).await;
+        assert_eq!(1, submitted_blocks(&da_service).await);
+
+        let mut submitted_block = da_service.get_block_at(1).await.unwrap();
+        let blob: Vec<Vec<u8>> = vec![vec![1], vec![2]];
+        assert_eq!(
+            borsh::to_vec(&blob).unwrap(),
+            submitted_block.blobs[0].full_data()
+        );
+    }
+
+    #[tokio::test]
+    async fn test_flush_on_max_age() {
+        let da_service = MockDaService::new(MockAddress::default());
+        let clock = MockClock(Arc::new(Mutex::new(std::time::Instant::now())));
+        let sequencer = Arc::new(
+            Sequencer::new(MockBatchBuilder { mempool: vec![] }, da_service.clone())
+                .with_flush_policy(FlushPolicy {
+                    max_age: Some(std::time::Duration::from_secs(10)),
+                    ..Default::default()
+                })
+                .with_clock(clock.clone()),
+        );
+        let rpc = get_rpc_for_sequencer(sequencer.clone());
+
+        accept_tx(&rpc, 1).await;
+        clock.advance(std::time::Duration::from_secs(9));
+        assert_eq!(None, sequencer.flush_if_triggered().await.unwrap());
+        assert_eq!(0, submitted_blocks(&da_service).await);
+
+        clock.advance(std::time::Duration::from_secs(1));
+        assert_eq!(
+            Some(FlushTrigger::MaxAge),
+            sequencer.flush_if_triggered().await.unwrap()
+        );
+        assert_eq!(1, submitted_blocks(&da_service).await);
+        // The pending batch has been flushed.
+        assert_eq!(None, sequencer.flush_if_triggered().await.unwrap());
+    }
+
+    #[tokio::test]
+    async fn test_flush_on_explicit_request() {
+        let da_service = MockDaService::new(MockAddress::default());
+        let sequencer = Sequencer::new(MockBatchBuilder { mempool: vec![] }, da_service.clone())
+            .with_flush_policy(FlushPolicy {
+                max_txs: Some(10),
+                max_bytes: Some(100),
+                max_age: Some(std::time::Duration::from_secs(3600)),
+            });
+        let rpc = get_rpc_for_sequencer(Arc::new(sequencer));
+
+        accept_tx(&rpc, 1).await;
+        assert_eq!(0, submitted_blocks(&da_service).await);
+
+        let arg: &[u8] = &[];
+        let _: String = rpc.call("sequencer_publishBatch", arg).await.unwrap();
+        assert_eq!(1, submitted_blocks(&da_service).await);
+    }
+
+    #[tokio::test]
+    async fn test_reject_batch_above_max_blob_size() {
+        let da_service = MockDaService::new(MockAddress::default());
+        let sequencer = Sequencer::new(MockBatchBuilder { mempool: vec![] }, da_service.clone())
+            .with_blob_size_limits(BlobSizeLimits {
+                max_blob_size: Some(16),
+                ..Default::default()
+            })
+            .unwrap();
+        let rpc = get_rpc_for_sequencer(Arc::new(sequencer));
+
+        let oversized_tx: Vec<u8> = vec![1; 9];
+        let result: Result<String, jsonrpsee::core::Error> = rpc
+            .call("sequencer_publishBatch", [oversized_tx.clone()])
+            .await;
+        assert_eq!(
+            "ErrorObject { code: ServerError(-32001), message: \"SEQUENCER_RPC_ERROR\", data: Some(RawValue(\"Transaction of 9 bytes does not fit into a blob of at most 16 bytes\")) }",
+            result.unwrap_err().to_string()
+        );
+        assert_eq!(0, submitted_blocks(&da_service).await);
+
+        let request = SubmitTransaction { body: oversized_tx };
+        let result: SubmitTransactionResponse =
+            rpc.call("sequencer_acceptTx", [request]).await.unwrap();
+        assert_eq!(
+            SubmitTransactionResponse::Failed(
+                "Transaction of 9 bytes does not fit into a blob of at most 16 bytes".to_string()
+            ),
+            result
+        );
+    }
+
+    #[tokio::test]
+    async fn test_split_batch_above_max_blob_size() {
+        let da_service = MockDaService::new(MockAddress::default());
+        // Each blob fits two of the single byte transactions built by the mock batch builder.
+        let sequencer = Sequencer::new(MockBatchBuilder { mempool: vec![] }, da_service.clone())
+            .with_blob_size_limits(BlobSizeLimits {
+                max_blob_size: Some(14),
+                ..Default::default()
+            })
+            .unwrap();
+        let rpc = get_rpc_for_sequencer(Arc::new(sequencer));
+
+        for tx in 1..=3 {
+            accept_tx(&rpc, tx).await;
+        }
+        let arg: &[u8] = &[];
+        let response: String = rpc.call("sequencer_publishBatch", arg).await.unwrap();
+        assert_eq!("Submitted 3 transactions", response);
+        assert_eq!(2, submitted_blocks(&da_service).await);
+
+        for (height, blob) in [(1, vec![vec![1], vec![2]]), (2, vec![vec![3]])] {
+            let mut submitted_block = da_service.get_block_at(height).await.unwrap();
+            assert_eq!(
+                borsh::to_vec(&blob).unwrap(),
+                submitted_block.blobs[0].full_data()
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_min_blob_size_holds_back_flush() {
+        let da_service = MockDaService::new(MockAddress::default());
+        let sequencer = Sequencer::new(MockBatchBuilder { mempool: vec![] }, da_service.clone())
+            .with_flush_policy(FlushPolicy {
+                max_txs: Some(1),
+                ..Default::default()
+            })
+            .with_blob_size_limits(BlobSizeLimits {
+                min_blob_size: Some(14),
+                ..Default::default()
+            })
+            .unwrap();
+        let rpc = get_rpc_for_sequencer(Arc::new(sequencer));
+
+        accept_tx(&rpc, 1).await;
+        assert_eq!(0, submitted_blocks(&da_service).await);
+        accept_tx(&rpc, 2).await;
+        assert_eq!(1, submitted_blocks(&da_service).await);
+    }
+
+    #[tokio::test]
+    async fn test_restored_txs_count_towards_flush() {
+        let da_service = MockDaService::new(MockAddress::default());
+        let batch_builder = MockBatchBuilder {
+            mempool: vec![vec![1], vec![2]],
+        };
+        let sequencer = Sequencer::new(batch_builder, da_service.clone())
+            .with_flush_policy(FlushPolicy {
+                max_txs: Some(2),
+                ..Default::default()
+            })
+            .with_pending_txs([1, 1]);
+
+        assert_eq!(
+            Some(FlushTrigger::MaxTxs),
+            sequencer.flush_if_triggered().await.unwrap()
+        );
+        assert_eq!(1, submitted_blocks(&da_service).await);
+    }
+
+    #[tokio::test]
+    async fn test_max_age_bypasses_min_blob_size() {
+        let da_service = MockDaService::new(MockAddress::default());
+        let clock = MockClock(Arc::new(Mutex::new(std::time::Instant::now())));
+        let sequencer = Arc::new(
+            Sequencer::new(MockBatchBuilder { mempool: vec![] }, da_service.clone())
+                .with_flush_policy(FlushPolicy {
+                    max_txs: Some(1),
+                    max_age: Some(std::time::Duration::from_secs(10)),
+                    ..Default::default()
+                })
+                .with_blob_size_limits(BlobSizeLimits {
+                    min_blob_size: Some(1000),
+                    ..Default::default()
+                })
+                .unwrap()
+                .with_clock(clock.clone()),
+        );
+        let rpc = get_rpc_for_sequencer(sequencer.clone());
+
+        accept_tx(&rpc, 1).await;
+        assert_eq!(None, sequencer.flush_if_triggered().await.unwrap());
+
+        clock.advance(std::time::Duration::from_secs(10));
+        assert_eq!(
+            Some(FlushTrigger::MaxAge),
+            sequencer.flush_if_triggered().await.unwrap()
+        );
+        assert_eq!(1, submitted_blocks(&da_service).await);
+    }
+
+    #[tokio::test]
+    async fn test_reject_min_blob_size_above_max() {
+        let da_service = MockDaService::new(MockAddress::default());
+        let result = Sequencer::new(MockBatchBuilder { mempool: vec![] }, da_service)
+            .with_blob_size_limits(BlobSizeLimits {
+                min_blob_size: Some(15),
+                max_blob_size: Some(14),
+            });
+        assert!(result.is_err());
+    }
+
+    /// Accepts the first `successful_sends` blobs, then fails to send any other blob.
+    struct FailingDaService {
+        inner: MockDaService,
+        successful_sends: usize,
+        sends: std::sync::atomic::AtomicUsize,
+    }
+
+    #[async_trait::async_trait]
+    impl DaService for FailingDaService {
+        type Spec = sov_mock_da::MockDaSpec;
+        type Verifier = sov_mock_da::MockDaVerifier;
+        type FilteredBlock = sov_mock_da::MockBlock;
+        type HeaderStream = sov_mock_da::MockDaBlockHeaderStream;
+        type TransactionId = u64;
+        type Error = anyhow::Error;
+
+        async fn get_block_at(&self, height: u64) -> anyhow::Result<sov_mock_da::MockBlock> {
+            self.inner.get_block_at(height).await
+        }
+
+        async fn get_last_finalized_block_header(
+            &self,
+        ) -> anyhow::Result<sov_mock_da::MockBlockHeader> {
+            self.inner.get_last_finalized_block_header().await
+        }
+
+        async fn subscribe_finalized_header(
+            &self,
+        ) -> anyhow::Result<sov_mock_da::MockDaBlockHeaderStream> {
+            self.inner.subscribe_finalized_header().await
+        }
+
+        async fn get_head_block_header(&self) -> anyhow::Result<sov_mock_da::MockBlockHeader> {
+            self.inner.get_head_block_header().await
+        }
+
+        fn extract_relevant_blobs(
+            &self,
+            block: &sov_mock_da::MockBlock,
+        ) -> Vec<sov_mock_da::MockBlob> {
+            self.inner.extract_relevant_blobs(block)
+        }
+
+        async fn get_extraction_proof(
+            &self,
+            block: &sov_mock_da::MockBlock,
+            blobs: &[sov_mock_da::MockBlob],
+        ) -> ([u8; 32], ()) {
+            self.inner.get_extraction_proof(block, blobs).await
+        }
+
+        async fn send_transaction(&self, blob: &[u8]) -> anyhow::Result<u64> {
+            let sends = self.sends.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            if sends >= self.successful_sends {
+                anyhow::bail!("DA layer is unavailable");
+            }
+            self.inner.send_transaction(blob).await
+        }
+
+        async fn wait_for_finalization(&self, tx_id: &u64) -> anyhow::Result<u64> {
+            self.inner.wait_for_finalization(tx_id).await
+        }
+
+        async fn send_aggregated_zk_proof(&self, data: &[u8]) -> anyhow::Result<u64> {
+            self.inner.send_aggregated_zk_proof(data).await
+        }
+
+        async fn get_aggregated_proofs_at(&self, height: u64) -> anyhow::Result<Vec<Vec<u8>>> {
+            self.inner.get_aggregated_proofs_at(height).await
+        }
+    }
+
+    #[tokio::test]
+    async fn test_report_partially_submitted_batch() {
+        let inner = MockDaService::new(MockAddress::default());
+        let da_service = FailingDaService {
+            inner: inner.clone(),
+            successful_sends: 1,
+            sends: Default::default(),
+        };
+        // Each blob fits two of the single byte transactions built by the mock batch builder.
+        let sequencer = Sequencer::new(MockBatchBuilder { mempool: vec![] }, da_service)
+            .with_blob_size_limits(BlobSizeLimits {
+                max_blob_size: Some(14),
+                ..Default::default()
+            })
+            .unwrap();
+        let rpc = get_rpc_for_sequencer(Arc::new(sequencer));
+
+        let result: Result<String, jsonrpsee::core::Error> = rpc
+            .call("sequencer_publishBatch", [vec![1u8], vec![2], vec![3]])
+            .await;
+
+        let error = result.unwrap_err().to_string();
+        assert!(
+            error.contains("failed to submit batch after 2 of 3 transactions"),
+            "{error}"
+        );
+        assert_eq!(1, submitted_blocks(&inner).await);
+    }
+
     #[tokio::test]
     #[ignore = "TBD"]
     async fn test_full_flow() {}
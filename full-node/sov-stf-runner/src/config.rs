@@ -5,6 +5,8 @@ use std::path::{Path, PathBuf};
 use serde::de::DeserializeOwned;
 use serde::Deserialize;
 
+use crate::{DEFAULT_COMMIT_INTERVAL, DEFAULT_FINALITY_DEPTH, DEFAULT_REORDER_BUFFER_CAPACITY};
+
 /// Configuration for StateTransitionRunner.
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct RunnerConfig {
@@ -12,6 +14,30 @@ pub struct RunnerConfig {
     pub start_height: u64,
     /// RPC configuration.
     pub rpc_config: RpcConfig,
+    /// How many DA blocks which arrived ahead of the expected height are held until the blocks
+    /// before them arrive.
+    #[serde(default = "default_reorder_buffer_capacity")]
+    pub reorder_buffer_capacity: usize,
+    /// How many finalized slots are committed at once while the runner is behind the last
+    /// finalized DA block. Must be at least one.
+    #[serde(default = "default_commit_interval")]
+    pub commit_interval: usize,
+    /// How many DA blocks have to be built on top of a block before it's treated as final, in
+    /// addition to being finalized by the DA service.
+    #[serde(default = "default_finality_depth")]
+    pub finality_depth: u64,
+}
+
+const fn default_reorder_buffer_capacity() -> usize {
+    DEFAULT_REORDER_BUFFER_CAPACITY
+}
+
+const fn default_commit_interval() -> usize {
+    DEFAULT_COMMIT_INTERVAL
+}
+
+const fn default_finality_depth() -> u64 {
+    DEFAULT_FINALITY_DEPTH
 }
 
 /// RPC configuration.
@@ -23,11 +49,24 @@ pub struct RpcConfig {
     pub bind_port: u16,
 }
 
+/// Configuration of the endpoint serving Prometheus metrics.
+/// It's only served when the `metrics` feature is enabled.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MetricsConfig {
+    /// Metrics host.
+    pub bind_host: String,
+    /// Metrics port.
+    pub bind_port: u16,
+}
+
 /// Simple storage configuration
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct StorageConfig {
     /// Path that can be utilized by concrete implementation
     pub path: PathBuf,
+    /// Size of the in-memory read cache of the state storage. Disabled if unset.
+    #[serde(default)]
+    pub read_cache_size: Option<usize>,
 }
 
 /// Prover service configuration.
@@ -37,6 +76,42 @@ pub struct ProverServiceConfig {
     pub aggregated_proof_block_jump: u64,
 }
 
+/// Conditions under which the sequencer submits a batch on its own.
+/// Triggers left unset never fire, so by default batches are only submitted on request.
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+pub struct FlushPolicyConfig {
+    /// Flush once this many transactions are pending.
+    #[serde(default)]
+    pub max_txs: Option<usize>,
+    /// Flush once the pending transactions add up to this many bytes.
+    #[serde(default)]
+    pub max_bytes: Option<usize>,
+    /// Flush once the oldest pending transaction has been waiting for this many milliseconds.
+    /// Must be at least 1.
+    #[serde(default)]
+    pub max_age_ms: Option<u64>,
+}
+
+/// Sequencer configuration.
+#[derive(Debug, Clone, PartialEq, Deserialize, Default)]
+pub struct SequencerConfig {
+    /// File the mempool is saved to when the rollup stops, and restored from when it starts.
+    /// The mempool isn't persisted if unset.
+    #[serde(default)]
+    pub mempool_path: Option<PathBuf>,
+    /// When batches are submitted without a `sequencer_publishBatch` request.
+    #[serde(default)]
+    pub flush_policy: FlushPolicyConfig,
+    /// The flush policy doesn't fire until the pending transactions fill a blob of this many
+    /// bytes, unless its maximum age has elapsed.
+    #[serde(default)]
+    pub min_blob_size: Option<usize>,
+    /// Batches larger than this many bytes are split into several blobs. Must be at least
+    /// `min_blob_size`.
+    #[serde(default)]
+    pub max_blob_size: Option<usize>,
+}
+
 /// Rollup Configuration
 #[derive(Debug, Clone, PartialEq, Deserialize)]
 pub struct RollupConfig<DaServiceConfig> {
@@ -48,6 +123,12 @@ pub struct RollupConfig<DaServiceConfig> {
     pub da: DaServiceConfig,
     /// Prover service configuration.
     pub prover_service: ProverServiceConfig,
+    /// Metrics endpoint configuration. No metrics are served if unset.
+    #[serde(default)]
+    pub metrics: Option<MetricsConfig>,
+    /// Sequencer configuration.
+    #[serde(default)]
+    pub sequencer: SequencerConfig,
 }
 
 /// Reads toml file as a specific type.
@@ -96,6 +177,15 @@ mod tests {
             bind_port = 12345
             [prover_service]
             aggregated_proof_block_jump = 22
+            [metrics]
+            bind_host = "127.0.0.1"
+            bind_port = 9845
+            [sequencer]
+            mempool_path = "/tmp/mempool"
+            max_blob_size = 1048576
+            [sequencer.flush_policy]
+            max_txs = 100
+            max_age_ms = 2000
         "#;
 
         let config_file = create_config_from(config);
@@ -109,6 +199,9 @@ mod tests {
                     bind_host: "127.0.0.1".to_string(),
                     bind_port: 12345,
                 },
+                reorder_buffer_capacity: DEFAULT_REORDER_BUFFER_CAPACITY,
+                commit_interval: DEFAULT_COMMIT_INTERVAL,
+                finality_depth: DEFAULT_FINALITY_DEPTH,
             },
 
             da: sov_celestia_adapter::CelestiaConfig {
@@ -119,10 +212,25 @@ mod tests {
             },
             storage: StorageConfig {
                 path: PathBuf::from("/tmp"),
+                read_cache_size: None,
             },
             prover_service: ProverServiceConfig {
                 aggregated_proof_block_jump: 22,
             },
+            metrics: Some(MetricsConfig {
+                bind_host: "127.0.0.1".to_string(),
+                bind_port: 9845,
+            }),
+            sequencer: SequencerConfig {
+                mempool_path: Some(PathBuf::from("/tmp/mempool")),
+                flush_policy: FlushPolicyConfig {
+                    max_txs: Some(100),
+                    max_bytes: None,
+                    max_age_ms: Some(2000),
+                },
+                min_blob_size: None,
+                max_blob_size: Some(1048576),
+            },
         };
         assert_eq!(config, expected);
     }
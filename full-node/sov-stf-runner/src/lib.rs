@@ -3,6 +3,8 @@
 
 #[cfg(feature = "native")]
 mod config;
+#[cfg(feature = "metrics")]
+pub mod metrics;
 #[cfg(feature = "mock")]
 /// Testing utilities.
 #[cfg(feature = "mock")]
@@ -16,15 +18,24 @@ use std::path::Path;
 #[cfg(feature = "native")]
 use anyhow::Context;
 #[cfg(feature = "native")]
-pub use config::RpcConfig;
+pub use config::{MetricsConfig, RpcConfig};
 #[cfg(feature = "native")]
 pub use prover_service::*;
 #[cfg(feature = "native")]
 mod runner;
 #[cfg(feature = "native")]
-pub use config::{from_toml_path, ProverServiceConfig, RollupConfig, RunnerConfig, StorageConfig};
+pub use config::{
+    from_toml_path, FlushPolicyConfig, ProverServiceConfig, RollupConfig, RunnerConfig,
+    SequencerConfig, StorageConfig,
+};
 #[cfg(feature = "native")]
 pub use runner::*;
+#[cfg(feature = "native")]
+mod reorder_buffer;
+#[cfg(feature = "native")]
+mod sync_status;
+#[cfg(feature = "native")]
+pub use sync_status::{get_sync_status_rpc, SyncStatus};
 
 /// Implements the `StateTransitionVerifier` type for checking the validity of a state transition
 pub mod verifier;
@@ -0,0 +1,120 @@
+//! Prometheus metrics of the slots processed by the [`crate::StateTransitionRunner`].
+
+use std::convert::Infallible;
+use std::net::SocketAddr;
+
+use hyper::server::conn::AddrIncoming;
+use hyper::server::Builder;
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, Request, Response, StatusCode};
+use once_cell::sync::Lazy;
+use prometheus::{
+    exponential_buckets, register_histogram, register_int_counter, Encoder, Histogram, IntCounter,
+    TextEncoder,
+};
+use tokio::sync::oneshot;
+use tracing::info;
+
+/// The path the metrics are served on.
+pub const METRICS_PATH: &str = "/metrics";
+
+pub(crate) static SLOTS_PROCESSED: Lazy<IntCounter> = Lazy::new(|| {
+    register_int_counter!(
+        "sov_rollup_slots_processed_total",
+        "Number of slots applied by the rollup"
+    )
+    .unwrap()
+});
+
+pub(crate) static BLOBS_PER_SLOT: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "sov_rollup_blobs_per_slot",
+        "Number of relevant blobs in an applied slot",
+        exponential_buckets(/*start=*/ 1.0, /*factor=*/ 2.0, /*count=*/ 12).unwrap(),
+    )
+    .unwrap()
+});
+
+pub(crate) static TXS_PER_SLOT: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "sov_rollup_txs_per_slot",
+        "Number of transactions in an applied slot",
+        exponential_buckets(/*start=*/ 1.0, /*factor=*/ 2.0, /*count=*/ 16).unwrap(),
+    )
+    .unwrap()
+});
+
+pub(crate) static APPLY_SLOT_DURATION_SECONDS: Lazy<Histogram> = Lazy::new(|| {
+    register_histogram!(
+        "sov_rollup_apply_slot_duration_seconds",
+        "Time spent applying a slot to the state in seconds",
+        exponential_buckets(/*start=*/ 1e-4, /*factor=*/ 2.0, /*count=*/ 20).unwrap(),
+    )
+    .unwrap()
+});
+
+/// Records the metrics of a slot which was applied in `duration_seconds`.
+pub(crate) fn record_applied_slot(blob_count: usize, tx_count: usize, duration_seconds: f64) {
+    SLOTS_PROCESSED.inc();
+    BLOBS_PER_SLOT.observe(blob_count as f64);
+    TXS_PER_SLOT.observe(tx_count as f64);
+    APPLY_SLOT_DURATION_SECONDS.observe(duration_seconds);
+}
+
+async fn serve_metrics(request: Request<Body>) -> Result<Response<Body>, Infallible> {
+    if request.uri().path() != METRICS_PATH {
+        let mut response = Response::new(Body::empty());
+        *response.status_mut() = StatusCode::NOT_FOUND;
+        return Ok(response);
+    }
+
+    let encoder = TextEncoder::new();
+    let mut buffer = Vec::new();
+    encoder
+        .encode(&prometheus::gather(), &mut buffer)
+        .expect("Encoding the metrics into a buffer can't fail");
+    let response = Response::builder()
+        .header(hyper::header::CONTENT_TYPE, encoder.format_type())
+        .body(Body::from(buffer))
+        .expect("The response is well formed");
+    Ok(response)
+}
+
+/// Binds the metrics server to `listen_address`, so that the caller learns about an address
+/// which is taken before the server is spawned.
+pub(crate) fn bind_metrics_server(
+    listen_address: SocketAddr,
+) -> Result<Builder<AddrIncoming>, hyper::Error> {
+    hyper::Server::try_bind(&listen_address)
+}
+
+/// Serves the metrics on the address bound by `builder` until `shutdown_receiver` resolves.
+/// The address the server is bound to is sent to `channel`.
+pub(crate) async fn run_metrics_server(
+    builder: Builder<AddrIncoming>,
+    channel: Option<oneshot::Sender<SocketAddr>>,
+    shutdown_receiver: oneshot::Receiver<()>,
+) -> Result<(), hyper::Error> {
+    let make_service =
+        make_service_fn(|_| async { Ok::<_, Infallible>(service_fn(serve_metrics)) });
+    let server = builder.serve(make_service);
+
+    let bound_address = server.local_addr();
+    if let Some(channel) = channel {
+        channel.send(bound_address).unwrap();
+    }
+    info!(
+        "Starting metrics server at {}{}",
+        bound_address, METRICS_PATH
+    );
+
+    server
+        .with_graceful_shutdown(async {
+            if shutdown_receiver.await.is_ok() {
+                info!("Stopping metrics server at {}", bound_address);
+            } else {
+                futures::future::pending::<()>().await;
+            }
+        })
+        .await
+}
@@ -0,0 +1,72 @@
+use std::collections::BTreeMap;
+
+/// Holds DA blocks which arrived ahead of the height the runner expects next, so that they can be
+/// processed in strict height order.
+///
+/// The buffer is bounded: once full, the highest block is dropped in favour of a lower one, since
+/// the lower block is needed sooner and the dropped one can be fetched again.
+#[derive(Debug)]
+pub(crate) struct ReorderBuffer<B> {
+    capacity: usize,
+    blocks: BTreeMap<u64, B>,
+}
+
+impl<B> ReorderBuffer<B> {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            blocks: BTreeMap::new(),
+        }
+    }
+
+    /// Stores a block which arrived early. Returns `false` if the block was dropped to keep the
+    /// buffer within its capacity.
+    pub(crate) fn insert(&mut self, height: u64, block: B) -> bool {
+        if self.blocks.len() >= self.capacity && !self.blocks.contains_key(&height) {
+            match self.blocks.last_key_value() {
+                Some((&highest, _)) if highest > height => {
+                    self.blocks.remove(&highest);
+                }
+                _ => return false,
+            }
+        }
+        self.blocks.insert(height, block);
+        true
+    }
+
+    /// Removes and returns the block at `height`, if it arrived early.
+    pub(crate) fn take(&mut self, height: u64) -> Option<B> {
+        self.blocks.remove(&height)
+    }
+
+    /// Drops all blocks, for example once they no longer belong to the followed fork.
+    pub(crate) fn clear(&mut self) {
+        self.blocks.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_lowest_blocks_when_full() {
+        let mut buffer = ReorderBuffer::new(2);
+        assert!(buffer.insert(5, "five"));
+        assert!(buffer.insert(7, "seven"));
+        assert!(!buffer.insert(8, "eight"));
+        assert!(buffer.insert(6, "six"));
+
+        assert_eq!(None, buffer.take(7));
+        assert_eq!(Some("five"), buffer.take(5));
+        assert_eq!(Some("six"), buffer.take(6));
+        assert_eq!(None, buffer.take(6));
+    }
+
+    #[test]
+    fn zero_capacity_keeps_nothing() {
+        let mut buffer = ReorderBuffer::new(0);
+        assert!(!buffer.insert(1, ()));
+        assert_eq!(None, buffer.take(1));
+    }
+}
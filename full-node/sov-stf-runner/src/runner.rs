@@ -8,15 +8,35 @@ use sov_rollup_interface::services::da::{DaService, SlotData};
 use sov_rollup_interface::stf::StateTransitionFunction;
 use sov_rollup_interface::storage::HierarchicalStorageManager;
 use sov_rollup_interface::zk::{StateTransitionData, Zkvm, ZkvmHost};
+use sov_state::Storage;
 use tokio::sync::oneshot;
-use tracing::{debug, info};
+use tokio::task::JoinHandle;
+use tracing::{debug, info, Instrument};
 
+use crate::reorder_buffer::ReorderBuffer;
 use crate::verifier::StateTransitionVerifier;
 use crate::{ProofSubmissionStatus, ProverService, RunnerConfig};
 
 type StateRoot<ST, Vm, Da> = <ST as StateTransitionFunction<Vm, Da>>::StateRoot;
 type GenesisParams<ST, Vm, Da> = <ST as StateTransitionFunction<Vm, Da>>::GenesisParams;
 
+/// How many DA blocks which arrived ahead of the expected height the runner holds by default.
+pub const DEFAULT_REORDER_BUFFER_CAPACITY: usize = 16;
+
+/// How many finalized slots the runner commits at once by default while catching up.
+///
+/// Once the runner has caught up, every slot is committed as soon as it's finalized. A larger
+/// interval speeds up the initial sync, but more slots have to be executed again after a crash.
+pub const DEFAULT_COMMIT_INTERVAL: usize = 1;
+
+/// How many blocks have to be built on top of a DA block before the runner treats it as final by
+/// default. Zero means that the finality reported by the DA service is trusted.
+///
+/// Until a block is final, its slot can be reverted by a reorg and is neither committed to the
+/// ledger nor finalized in the storage. A non-zero depth protects against DA layers which can
+/// reorg blocks they have reported as finalized.
+pub const DEFAULT_FINALITY_DEPTH: u64 = 0;
+
 /// Combines `DaService` with `StateTransitionFunction` and "runs" the rollup.
 pub struct StateTransitionRunner<Stf, Sm, Da, Vm, Ps>
 where
@@ -27,6 +47,8 @@ where
     Ps: ProverService,
 {
     start_height: u64,
+    // Slots below this DA height are already in the ledger and are only executed again
+    first_uncommitted_height: u64,
     da_service: Da,
     stf: Stf,
     storage_manager: Sm,
@@ -34,6 +56,12 @@ where
     state_root: StateRoot<Stf, Vm, Da::Spec>,
     listen_address: SocketAddr,
     prover_service: Ps,
+    rpc_server_shutdown: Option<(oneshot::Sender<()>, JoinHandle<()>)>,
+    #[cfg(feature = "metrics")]
+    metrics_server_shutdown: Option<(oneshot::Sender<()>, JoinHandle<()>)>,
+    reorder_buffer_capacity: usize,
+    commit_interval: usize,
+    finality_depth: u64,
 }
 
 /// Represents the possible modes of execution for a zkVM program
@@ -70,6 +98,7 @@ where
     Da: DaService<Error = anyhow::Error> + Clone + Send + Sync + 'static,
     Vm: ZkvmHost,
     Sm: HierarchicalStorageManager<Da::Spec>,
+    Sm::NativeStorage: Storage,
     Stf: StateTransitionFunction<
         Vm,
         Da::Spec,
@@ -95,6 +124,10 @@ where
         init_variant: InitVariant<Stf, Vm, Da::Spec>,
         prover_service: Ps,
     ) -> Result<Self, anyhow::Error> {
+        anyhow::ensure!(
+            runner_config.commit_interval > 0,
+            "The commit interval must be at least one slot"
+        );
         let rpc_config = runner_config.rpc_config;
 
         let prev_state_root = match init_variant {
@@ -125,12 +158,30 @@ where
         let listen_address = SocketAddr::new(rpc_config.bind_host.parse()?, rpc_config.bind_port);
 
         // Start the main rollup loop
-        let item_numbers = ledger_db.get_next_items_numbers();
-        let last_slot_processed_before_shutdown = item_numbers.slot_number - 1;
-        let start_height = runner_config.start_height + last_slot_processed_before_shutdown;
+        let first_uncommitted_height = ledger_db.resume_height(runner_config.start_height)?;
+        // The receipts of a slot are written to the ledger before its state is finalized, so a crash
+        // in between leaves the ledger ahead of the state. The runner resumes from the first slot
+        // without finalized state, and doesn't commit the receipts of those slots again.
+        // Every slot finalizes one version of the state, on top of the genesis version.
+        let ledger_slots = ledger_db.get_next_items_numbers().slot_number - 1;
+        let finalized_slots = storage_manager.create_finalized_storage()?.latest_version();
+        anyhow::ensure!(
+            finalized_slots <= ledger_slots,
+            "The state has {} finalized slots, but only {} slots were committed to the ledger",
+            finalized_slots,
+            ledger_slots
+        );
+        let start_height = first_uncommitted_height - (ledger_slots - finalized_slots);
+        if start_height < first_uncommitted_height {
+            info!(
+                start_height,
+                first_uncommitted_height, "Executing the slots without finalized state again"
+            );
+        }
 
         Ok(Self {
             start_height,
+            first_uncommitted_height,
             da_service,
             stf,
             storage_manager,
@@ -138,17 +189,31 @@ where
             state_root: prev_state_root,
             listen_address,
             prover_service,
+            rpc_server_shutdown: None,
+            #[cfg(feature = "metrics")]
+            metrics_server_shutdown: None,
+            reorder_buffer_capacity: runner_config.reorder_buffer_capacity,
+            commit_interval: runner_config.commit_interval,
+            finality_depth: runner_config.finality_depth,
         })
     }
 
+    /// Returns the DA height the runner resumes processing from: the height following the last
+    /// slot whose state was finalized, or the configured start height on a fresh ledger.
+    pub fn resume_height(&self) -> u64 {
+        self.start_height
+    }
+
     /// Starts a RPC server with provided rpc methods.
+    /// The server is stopped when the runner is shut down.
     pub async fn start_rpc_server(
-        &self,
+        &mut self,
         methods: RpcModule<()>,
         channel: Option<oneshot::Sender<SocketAddr>>,
     ) {
         let listen_address = self.listen_address;
-        let _handle = tokio::spawn(async move {
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+        let handle = tokio::spawn(async move {
             let server = jsonrpsee::server::ServerBuilder::default()
                 .build([listen_address].as_ref())
                 .await
@@ -160,24 +225,89 @@ where
             }
             info!("Starting RPC server at {} ", &bound_address);
 
-            let _server_handle = server.start(methods);
-            futures::future::pending::<()>().await;
+            let server_handle = server.start(methods);
+            if shutdown_receiver.await.is_ok() {
+                info!("Stopping RPC server at {} ", &bound_address);
+                // Stopping only fails if the server has already been stopped.
+                let _ = server_handle.stop();
+                server_handle.stopped().await;
+            } else {
+                futures::future::pending::<()>().await;
+            }
         });
+        self.rpc_server_shutdown = Some((shutdown_sender, handle));
+    }
+
+    /// Starts a server exposing the Prometheus metrics of the rollup on
+    /// [`crate::metrics::METRICS_PATH`]. The address the server is bound to is sent to `channel`.
+    ///
+    /// Fails if the server can't be bound to the configured address.
+    #[cfg(feature = "metrics")]
+    pub async fn start_metrics_server(
+        &mut self,
+        config: &crate::MetricsConfig,
+        channel: Option<oneshot::Sender<SocketAddr>>,
+    ) -> Result<(), anyhow::Error> {
+        let listen_address = SocketAddr::new(config.bind_host.parse()?, config.bind_port);
+        let builder = crate::metrics::bind_metrics_server(listen_address).map_err(|e| {
+            anyhow::anyhow!(
+                "Failed to bind the metrics server to {}: {}",
+                listen_address,
+                e
+            )
+        })?;
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+        let handle = tokio::spawn(async move {
+            if let Err(e) =
+                crate::metrics::run_metrics_server(builder, channel, shutdown_receiver).await
+            {
+                tracing::error!("Metrics server at {} failed: {}", listen_address, e);
+            }
+        });
+        self.metrics_server_shutdown = Some((shutdown_sender, handle));
+        Ok(())
     }
 
     /// Runs the rollup.
     pub async fn run_in_process(&mut self) -> Result<(), anyhow::Error> {
+        // Nothing is ever sent, so the rollup runs until an error occurs.
+        let (_shutdown_sender, shutdown_receiver) = oneshot::channel();
+        self.run_in_process_until(shutdown_receiver).await
+    }
+
+    /// Runs the rollup until a shutdown signal is received on `shutdown_receiver`.
+    ///
+    /// The signal is handled between slots: the RPC server is stopped, the ledger DB is flushed
+    /// and `Ok(())` is returned. Dropping the sender without sending anything doesn't stop the rollup.
+    pub async fn run_in_process_until(
+        &mut self,
+        shutdown_receiver: oneshot::Receiver<()>,
+    ) -> Result<(), anyhow::Error> {
+        let shutdown = async move {
+            if shutdown_receiver.await.is_err() {
+                futures::future::pending::<()>().await;
+            }
+        };
+        futures::pin_mut!(shutdown);
+
         let mut seen_block_headers: VecDeque<<Da::Spec as DaSpec>::BlockHeader> = VecDeque::new();
         let mut seen_receipts: VecDeque<_> = VecDeque::new();
+        let mut reorder_buffer = ReorderBuffer::new(self.reorder_buffer_capacity);
         let mut height = self.start_height;
         loop {
             debug!("Requesting data for height {}", height);
-            let mut filtered_block = self.da_service.get_block_at(height).await?;
+            let mut filtered_block = tokio::select! {
+                biased;
+                _ = &mut shutdown => break,
+                block = self.get_block_in_order(height, &mut reorder_buffer) => block?,
+            };
 
             // Checking if reorg happened or not.
             if let Some(prev_block_header) = seen_block_headers.back() {
                 if prev_block_header.hash() != filtered_block.header().prev_hash() {
                     tracing::warn!("Block at height={} does not belong in current chain. Chain has forked. Traversing backwards", height);
+                    // Blocks which arrived early were taken from the abandoned fork.
+                    reorder_buffer.clear();
                     while let Some(seen_block_header) = seen_block_headers.pop_back() {
                         seen_receipts.pop_back();
                         let block = self
@@ -194,130 +324,246 @@ where
                 }
             }
 
-            let mut blobs = self.da_service.extract_relevant_blobs(&filtered_block);
-
-            info!(
-                "Extracted {} relevant blobs at height {}: {:?}",
-                blobs.len(),
-                height,
-                blobs
-                    .iter()
-                    .map(|b| format!(
-                        "sequencer={} blob_hash=0x{}",
-                        b.sender(),
-                        hex::encode(b.hash())
-                    ))
-                    .collect::<Vec<_>>()
-            );
-
-            let mut data_to_commit = SlotCommit::new(filtered_block.clone());
-
-            let pre_state = self
-                .storage_manager
-                .create_storage_on(filtered_block.header())?;
-            let slot_result = self.stf.apply_slot(
-                // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1247): incorrect pre-state root in case of re-org
-                &self.state_root,
-                pre_state,
-                Default::default(),
-                filtered_block.header(),
-                &filtered_block.validity_condition(),
-                &mut blobs,
-            );
+            // All the messages logged while processing the slot can be grouped by its height.
+            let slot_span = tracing::info_span!("slot", height);
+            async {
+                let mut blobs = self.da_service.extract_relevant_blobs(&filtered_block);
 
-            for receipt in slot_result.batch_receipts {
-                data_to_commit.add_batch(receipt);
-            }
+                info!(
+                    blob_count = blobs.len(),
+                    blobs = ?blobs
+                        .iter()
+                        .map(|b| format!(
+                            "sequencer={} blob_hash=0x{}",
+                            b.sender(),
+                            hex::encode(b.hash())
+                        ))
+                        .collect::<Vec<_>>(),
+                    "Extracted relevant blobs"
+                );
 
-            let (inclusion_proof, completeness_proof) = self
-                .da_service
-                .get_extraction_proof(&filtered_block, &blobs)
-                .await;
+                let mut data_to_commit = SlotCommit::new(filtered_block.clone());
 
-            let transition_data: StateTransitionData<Stf::StateRoot, Stf::Witness, Da::Spec> =
-                StateTransitionData {
+                let pre_state = self
+                    .storage_manager
+                    .create_storage_on(filtered_block.header())?;
+                #[cfg(feature = "metrics")]
+                let apply_slot_start = std::time::Instant::now();
+                let slot_result = self.stf.apply_slot(
                     // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1247): incorrect pre-state root in case of re-org
-                    initial_state_root: self.state_root.clone(),
-                    final_state_root: slot_result.state_root.clone(),
-                    da_block_header: filtered_block.header().clone(),
-                    inclusion_proof,
-                    completeness_proof,
-                    blobs,
-                    state_transition_witness: slot_result.witness,
-                };
+                    &self.state_root,
+                    pre_state,
+                    Default::default(),
+                    filtered_block.header(),
+                    &filtered_block.validity_condition(),
+                    &mut blobs,
+                );
 
-            self.storage_manager
-                .save_change_set(filtered_block.header(), slot_result.change_set)?;
+                let tx_count: usize = slot_result
+                    .batch_receipts
+                    .iter()
+                    .map(|receipt| receipt.tx_receipts.len())
+                    .sum();
+                #[cfg(feature = "metrics")]
+                crate::metrics::record_applied_slot(
+                    blobs.len(),
+                    tx_count,
+                    apply_slot_start.elapsed().as_secs_f64(),
+                );
+                info!(
+                    batch_count = slot_result.batch_receipts.len(),
+                    tx_count,
+                    state_root = %hex::encode(slot_result.state_root.as_ref()),
+                    "Applied slot"
+                );
 
-            // ----------------
-            // Create ZK proof.
-            {
-                let header_hash = transition_data.da_block_header.hash();
-                self.prover_service.submit_witness(transition_data).await;
-                // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1185):
-                //   This section will be moved and called upon block finalization once we have fork management ready.
-                self.prover_service
-                    .prove(header_hash.clone())
-                    .await
-                    .expect("The proof creation should succeed");
-
-                loop {
-                    let status = self
-                        .prover_service
-                        .send_proof_to_da(header_hash.clone())
-                        .await;
-
-                    match status {
-                        Ok(ProofSubmissionStatus::Success) => {
-                            break;
-                        }
-                        // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1185): Add timeout handling.
-                        Ok(ProofSubmissionStatus::ProofGenerationInProgress) => {
-                            tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
+                for receipt in slot_result.batch_receipts {
+                    data_to_commit.add_batch(receipt);
+                }
+
+                let (inclusion_proof, completeness_proof) = self
+                    .da_service
+                    .get_extraction_proof(&filtered_block, &blobs)
+                    .await;
+
+                let transition_data: StateTransitionData<Stf::StateRoot, Stf::Witness, Da::Spec> =
+                    StateTransitionData {
+                        // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1247): incorrect pre-state root in case of re-org
+                        initial_state_root: self.state_root.clone(),
+                        final_state_root: slot_result.state_root.clone(),
+                        da_block_header: filtered_block.header().clone(),
+                        inclusion_proof,
+                        completeness_proof,
+                        blobs,
+                        state_transition_witness: slot_result.witness,
+                    };
+
+                self.storage_manager
+                    .save_change_set(filtered_block.header(), slot_result.change_set)?;
+
+                // ----------------
+                // Create ZK proof.
+                {
+                    let header_hash = transition_data.da_block_header.hash();
+                    self.prover_service.submit_witness(transition_data).await;
+                    // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1185):
+                    //   This section will be moved and called upon block finalization once we have fork management ready.
+                    self.prover_service
+                        .prove(header_hash.clone())
+                        .await
+                        .expect("The proof creation should succeed");
+
+                    loop {
+                        let status = self
+                            .prover_service
+                            .send_proof_to_da(header_hash.clone())
+                            .await;
+
+                        match status {
+                            Ok(ProofSubmissionStatus::Success) => {
+                                break;
+                            }
+                            // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1185): Add timeout handling.
+                            Ok(ProofSubmissionStatus::ProofGenerationInProgress) => {
+                                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await
+                            }
+                            // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1185): Add handling for DA submission errors.
+                            Err(e) => panic!("{:?}", e),
                         }
-                        // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1185): Add handling for DA submission errors.
-                        Err(e) => panic!("{:?}", e),
                     }
                 }
+                let next_state_root = slot_result.state_root;
+
+                seen_receipts.push_back(data_to_commit);
+
+                self.state_root = next_state_root;
+                seen_block_headers.push_back(filtered_block.header().clone());
+                height += 1;
+
+                // ----------------
+                // Finalization. Done after seen block for proper handling of instant finality
+                // Can be moved to another thread to improve throughput
+                let last_finalized = self.da_service.get_last_finalized_block_header().await?;
+                let mut last_finalized_height = last_finalized.height();
+                if self.finality_depth > 0 {
+                    let head_height = self.da_service.get_head_block_header().await?.height();
+                    last_finalized_height =
+                        last_finalized_height.min(head_height.saturating_sub(self.finality_depth));
+                }
+                // For safety we finalize blocks one by one
+                tracing::info!(last_finalized_height, "Fetched last finalized header");
+                // Checking all seen blocks, in case if there was delay in getting last finalized header.
+                let finalized_slots = seen_block_headers
+                    .iter()
+                    .take_while(|header| header.height() <= last_finalized_height)
+                    .count();
+                // While there are finalized blocks left to process, slots are committed in batches of
+                // `commit_interval`. Once caught up, everything finalized is committed right away.
+                let caught_up = height > last_finalized_height;
+                let slots_to_commit = if caught_up {
+                    finalized_slots
+                } else {
+                    finalized_slots - finalized_slots % self.commit_interval
+                };
+                let mut receipts_to_commit = Vec::with_capacity(slots_to_commit);
+                let mut headers_to_finalize = Vec::with_capacity(slots_to_commit);
+                for _ in 0..slots_to_commit {
+                    let earliest_seen_header = seen_block_headers.pop_front().unwrap();
+                    let receipts = seen_receipts.pop_front().unwrap();
+                    if earliest_seen_header.height() >= self.first_uncommitted_height {
+                        receipts_to_commit.push(receipts);
+                    }
+                    headers_to_finalize.push(earliest_seen_header);
+                    if headers_to_finalize.len() == self.commit_interval {
+                        self.commit_finalized_slots(
+                            std::mem::take(&mut receipts_to_commit),
+                            std::mem::take(&mut headers_to_finalize),
+                        )?;
+                    }
+                }
+                if !headers_to_finalize.is_empty() {
+                    self.commit_finalized_slots(receipts_to_commit, headers_to_finalize)?;
+                }
+
+                Ok::<(), anyhow::Error>(())
+            }
+            .instrument(slot_span)
+            .await?;
+        }
+
+        self.shutdown().await
+    }
+
+    /// Writes the receipts of finalized slots to the ledger, and only then finalizes their state,
+    /// so that the ledger is never behind the state.
+    fn commit_finalized_slots(
+        &mut self,
+        receipts: Vec<
+            SlotCommit<Da::FilteredBlock, Stf::BatchReceiptContents, Stf::TxReceiptContents>,
+        >,
+        headers: Vec<<Da::Spec as DaSpec>::BlockHeader>,
+    ) -> anyhow::Result<()> {
+        if !receipts.is_empty() {
+            self.ledger_db.commit_slots(receipts)?;
+        }
+        for header in headers {
+            debug!(finalized_height = header.height(), "Finalizing seen header");
+            self.storage_manager.finalize(&header)?;
+        }
+        Ok(())
+    }
+
+    /// Fetches the block at `height`. Blocks at greater heights returned by the DA service in the
+    /// meantime are held in `reorder_buffer` until the runner reaches them.
+    async fn get_block_in_order(
+        &self,
+        height: u64,
+        reorder_buffer: &mut ReorderBuffer<Da::FilteredBlock>,
+    ) -> Result<Da::FilteredBlock, anyhow::Error> {
+        loop {
+            if let Some(block) = reorder_buffer.take(height) {
+                return Ok(block);
+            }
+            let block = self.da_service.get_block_at(height).await?;
+            let block_height = block.header().height();
+            if block_height == height {
+                return Ok(block);
             }
-            let next_state_root = slot_result.state_root;
-
-            seen_receipts.push_back(data_to_commit);
-
-            self.state_root = next_state_root;
-            seen_block_headers.push_back(filtered_block.header().clone());
-            height += 1;
-
-            // ----------------
-            // Finalization. Done after seen block for proper handling of instant finality
-            // Can be moved to another thread to improve throughput
-            let last_finalized = self.da_service.get_last_finalized_block_header().await?;
-            // For safety we finalize blocks one by one
-            tracing::info!(
-                "Last finalized header height is {}, ",
-                last_finalized.height()
+            if block_height < height {
+                debug!(
+                    "Ignoring block at height={} while waiting for height={}",
+                    block_height, height
+                );
+                continue;
+            }
+            debug!(
+                "Holding block at height={} until height={} arrives",
+                block_height, height
             );
-            // Checking all seen blocks, in case if there was delay in getting last finalized header.
-            while let Some(earliest_seen_header) = seen_block_headers.front() {
-                tracing::debug!(
-                    "Checking seen header height={}",
-                    earliest_seen_header.height()
+            if !reorder_buffer.insert(block_height, block) {
+                debug!(
+                    "Reordering buffer is full, dropping block at height={}",
+                    block_height
                 );
-                if earliest_seen_header.height() <= last_finalized.height() {
-                    tracing::debug!(
-                        "Finalizing seen header height={}",
-                        earliest_seen_header.height()
-                    );
-                    self.storage_manager.finalize(earliest_seen_header)?;
-                    seen_block_headers.pop_front();
-                    let receipts = seen_receipts.pop_front().unwrap();
-                    self.ledger_db.commit_slot(receipts)?;
-                    continue;
-                }
+            }
+        }
+    }
 
-                break;
+    async fn shutdown(&mut self) -> Result<(), anyhow::Error> {
+        info!("Shutting down the rollup");
+        if let Some((shutdown_sender, handle)) = self.rpc_server_shutdown.take() {
+            // Sending only fails if the RPC server task has already finished.
+            if shutdown_sender.send(()).is_ok() {
+                handle.await?;
+            }
+        }
+        #[cfg(feature = "metrics")]
+        if let Some((shutdown_sender, handle)) = self.metrics_server_shutdown.take() {
+            if shutdown_sender.send(()).is_ok() {
+                handle.await?;
             }
         }
+        self.ledger_db.flush()
     }
 
     /// Allows to read current state root
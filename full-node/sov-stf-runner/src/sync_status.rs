@@ -0,0 +1,62 @@
+use jsonrpsee::types::ErrorObjectOwned;
+use jsonrpsee::RpcModule;
+use serde::{Deserialize, Serialize};
+use sov_db::ledger_db::LedgerDB;
+use sov_rollup_interface::da::BlockHeaderTrait;
+use sov_rollup_interface::services::da::DaService;
+
+const SYNC_STATUS_RPC_ERROR: &str = "SYNC_STATUS_RPC_ERROR";
+
+/// How far the rollup has processed the DA layer, as returned by the `system_syncStatus` RPC method.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SyncStatus {
+    /// The DA height of the last slot committed to the ledger, or 0 if no slot has been committed yet.
+    pub current_slot: u64,
+    /// The height of the last finalized DA block.
+    pub da_tip_height: u64,
+    /// Whether every finalized DA block has been processed.
+    pub is_synced: bool,
+}
+
+impl SyncStatus {
+    /// Computes the sync status from the ledger head and the last finalized block of the DA layer.
+    pub async fn fetch<Da: DaService>(
+        ledger_db: &LedgerDB,
+        da_service: &Da,
+    ) -> anyhow::Result<Self> {
+        let current_slot = ledger_db
+            .get_last_processed_da_height()?
+            .unwrap_or_default();
+        let da_tip_height = da_service
+            .get_last_finalized_block_header()
+            .await
+            .map_err(|e| anyhow::anyhow!("Failed to fetch the last finalized DA block: {}", e))?
+            .height();
+
+        Ok(Self {
+            current_slot,
+            da_tip_height,
+            is_synced: current_slot >= da_tip_height,
+        })
+    }
+}
+
+/// Creates an RPC module with the `system_syncStatus` method.
+pub fn get_sync_status_rpc<Da: DaService>(
+    ledger_db: LedgerDB,
+    da_service: Da,
+) -> RpcModule<(LedgerDB, Da)> {
+    let mut rpc = RpcModule::new((ledger_db, da_service));
+    rpc.register_async_method("system_syncStatus", |_, context| async move {
+        let (ledger_db, da_service) = &*context;
+        SyncStatus::fetch(ledger_db, da_service).await.map_err(|e| {
+            ErrorObjectOwned::owned(
+                jsonrpsee::types::error::UNKNOWN_ERROR_CODE,
+                SYNC_STATUS_RPC_ERROR,
+                Some(e.to_string()),
+            )
+        })
+    })
+    .expect("Failed to register sync status RPC methods");
+    rpc
+}
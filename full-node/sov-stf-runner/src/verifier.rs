@@ -21,7 +21,8 @@ where
     Zk: ZkvmGuest,
     Stf: StateTransitionFunction<Zk, Da::Spec>,
 {
-    /// Create a [`StateTransitionVerifier`]
+    /// Create a [`StateTransitionVerifier`] which checks the DA proofs of every block.
+    /// The proofs are always checked, since the verifier produces the proof of the state transition.
     pub fn new(app: Stf, da_verifier: Da) -> Self {
         Self {
             app,
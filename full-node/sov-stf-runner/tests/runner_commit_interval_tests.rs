@@ -0,0 +1,273 @@
+use std::time::Duration;
+
+use sov_db::ledger_db::LedgerDB;
+use sov_db::schema::types::SlotNumber;
+use sov_mock_da::{
+    MockAddress, MockBlob, MockBlock, MockBlockHeader, MockDaService, MockDaSpec, MockValidityCond,
+};
+use sov_prover_storage_manager::ProverStorageManager;
+use sov_rollup_interface::da::BlockHeaderTrait;
+use sov_rollup_interface::rpc::{LedgerRpcProvider, QueryMode, SlotResponse};
+use sov_rollup_interface::services::da::DaService;
+use sov_rollup_interface::storage::HierarchicalStorageManager;
+use sov_state::{ProverStorage, Storage};
+use sov_stf_runner::{InitVariant, RunnerConfig};
+use tokio::sync::oneshot;
+
+mod hash_stf;
+mod runner_setup;
+
+use hash_stf::{get_result_from_blocks, Q, S};
+use runner_setup::{
+    new_runner_with_config, new_runner_with_storage_manager, runner_config, MockInitVariant,
+};
+
+const SLOTS_TO_SYNC: u64 = 10;
+
+struct SyncResult {
+    state_root: [u8; 32],
+    committed_slots: Vec<u64>,
+    slot_hashes: Vec<[u8; 32]>,
+}
+
+/// Syncs a rollup over [`SLOTS_TO_SYNC`] DA blocks which are all finalized before the runner starts.
+async fn sync_blocks(
+    genesis_params: &[u8],
+    blobs: &[Vec<u8>],
+    sequencer_address: MockAddress,
+    commit_interval: usize,
+) -> SyncResult {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let da_service = MockDaService::new(sequencer_address);
+    let genesis_header = da_service.get_last_finalized_block_header().await.unwrap();
+    for blob in blobs {
+        da_service.send_transaction(blob).await.unwrap();
+    }
+
+    let init_variant: MockInitVariant = InitVariant::Genesis {
+        block_header: genesis_header,
+        genesis_params: genesis_params.to_vec(),
+    };
+    let ledger_db = LedgerDB::with_path(tmpdir.path()).unwrap();
+    let runner_config = RunnerConfig {
+        commit_interval,
+        ..runner_config()
+    };
+    let mut runner = new_runner_with_config(
+        tmpdir.path(),
+        ledger_db.clone(),
+        da_service,
+        init_variant,
+        runner_config,
+    );
+
+    let mut slots = ledger_db.subscribe_slots().unwrap();
+    let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+    let run = runner.run_in_process_until(shutdown_receiver);
+    let shutdown = async {
+        let mut committed_slots = Vec::new();
+        while committed_slots.len() < blobs.len() {
+            committed_slots.push(slots.recv().await.unwrap());
+        }
+        shutdown_sender.send(()).unwrap();
+        committed_slots
+    };
+
+    let (result, committed_slots) = tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::join!(run, shutdown)
+    })
+    .await
+    .expect("The runner should sync all the blocks promptly");
+    result.unwrap();
+
+    let slot_hashes = (1..=SLOTS_TO_SYNC)
+        .map(|number| {
+            let slot: SlotResponse<(), ()> = ledger_db
+                .get_slot_by_number(number, QueryMode::Compact)
+                .unwrap()
+                .expect("Every synced slot should be committed");
+            slot.hash
+        })
+        .collect();
+
+    SyncResult {
+        state_root: *runner.get_state_root(),
+        committed_slots,
+        slot_hashes,
+    }
+}
+
+#[tokio::test]
+async fn test_batched_commits_match_per_slot_commits() {
+    let sequencer_address = MockAddress::new([11u8; 32]);
+    let genesis_params = vec![1, 2, 3, 4, 5];
+    let blobs: Vec<Vec<u8>> = (1..=SLOTS_TO_SYNC as u8).map(|i| vec![i; 4]).collect();
+
+    let per_slot = sync_blocks(&genesis_params, &blobs, sequencer_address, 1).await;
+    let batched = sync_blocks(&genesis_params, &blobs, sequencer_address, 5).await;
+
+    let expected_blocks: Vec<MockBlock> = blobs
+        .iter()
+        .enumerate()
+        .map(|(idx, blob)| MockBlock {
+            header: MockBlockHeader::from_height((idx + 1) as u64),
+            validity_cond: MockValidityCond::default(),
+            blobs: vec![MockBlob::new(
+                blob.clone(),
+                sequencer_address,
+                [idx as u8; 32],
+            )],
+        })
+        .collect();
+    let (expected_state_root, _) = get_result_from_blocks(&genesis_params, &expected_blocks);
+
+    let all_slots: Vec<u64> = (1..=SLOTS_TO_SYNC).collect();
+    assert_eq!(all_slots, per_slot.committed_slots);
+    assert_eq!(all_slots, batched.committed_slots);
+    assert_eq!(expected_state_root, per_slot.state_root);
+    assert_eq!(per_slot.state_root, batched.state_root);
+    assert_eq!(per_slot.slot_hashes, batched.slot_hashes);
+}
+
+/// Fails to finalize the state of the slot at `crash_height`, as if the node crashed right after
+/// committing the slot to the ledger.
+struct CrashingStorageManager {
+    inner: ProverStorageManager<MockDaSpec, S>,
+    crash_height: u64,
+}
+
+impl HierarchicalStorageManager<MockDaSpec> for CrashingStorageManager {
+    type NativeStorage = ProverStorage<S, Q>;
+    type NativeChangeSet = ProverStorage<S, Q>;
+
+    fn create_storage_on(
+        &mut self,
+        block_header: &MockBlockHeader,
+    ) -> anyhow::Result<Self::NativeStorage> {
+        self.inner.create_storage_on(block_header)
+    }
+
+    fn create_finalized_storage(&mut self) -> anyhow::Result<Self::NativeStorage> {
+        self.inner.create_finalized_storage()
+    }
+
+    fn save_change_set(
+        &mut self,
+        block_header: &MockBlockHeader,
+        change_set: Self::NativeChangeSet,
+    ) -> anyhow::Result<()> {
+        self.inner.save_change_set(block_header, change_set)
+    }
+
+    fn finalize(&mut self, block_header: &MockBlockHeader) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            block_header.height() != self.crash_height,
+            "Crashed before finalizing height {}",
+            self.crash_height
+        );
+        self.inner.finalize(block_header)
+    }
+}
+
+#[tokio::test]
+async fn test_restart_between_ledger_commit_and_state_finalization() {
+    const CRASH_HEIGHT: u64 = 4;
+    let tmpdir = tempfile::tempdir().unwrap();
+    let sequencer_address = MockAddress::new([11u8; 32]);
+    let genesis_params = vec![1, 2, 3, 4, 5];
+    let blobs: Vec<Vec<u8>> = (1..=SLOTS_TO_SYNC as u8).map(|i| vec![i; 4]).collect();
+
+    let da_service = MockDaService::new(sequencer_address);
+    let genesis_header = da_service.get_last_finalized_block_header().await.unwrap();
+    for blob in &blobs {
+        da_service.send_transaction(blob).await.unwrap();
+    }
+    let ledger_db = LedgerDB::with_path(tmpdir.path()).unwrap();
+    let storage_config = sov_state::config::Config {
+        path: tmpdir.path().to_path_buf(),
+        read_cache_size: None,
+    };
+
+    {
+        let storage_manager = CrashingStorageManager {
+            inner: ProverStorageManager::new(storage_config.clone()).unwrap(),
+            crash_height: CRASH_HEIGHT,
+        };
+        let init_variant: MockInitVariant = InitVariant::Genesis {
+            block_header: genesis_header,
+            genesis_params: genesis_params.clone(),
+        };
+        let mut runner = new_runner_with_storage_manager(
+            storage_manager,
+            ledger_db.clone(),
+            da_service.clone(),
+            init_variant,
+            runner_config(),
+        );
+        let result = tokio::time::timeout(Duration::from_secs(10), runner.run_in_process())
+            .await
+            .expect("The runner should reach the crash promptly");
+        assert!(result.is_err());
+    }
+    // The slot at the crash height is in the ledger, but its state isn't finalized.
+    assert_eq!(
+        ledger_db.get_last_processed_da_height().unwrap(),
+        Some(CRASH_HEIGHT)
+    );
+
+    // "Restart" the node on the same databases.
+    let mut storage_manager = ProverStorageManager::<MockDaSpec, S>::new(storage_config).unwrap();
+    let state_root = {
+        let storage = storage_manager.create_finalized_storage().unwrap();
+        assert_eq!(storage.latest_version(), CRASH_HEIGHT - 1);
+        storage.get_root_hash(storage.latest_version()).unwrap()
+    };
+    let mut runner = new_runner_with_storage_manager(
+        storage_manager,
+        ledger_db.clone(),
+        da_service,
+        InitVariant::Initialized(state_root.0),
+        runner_config(),
+    );
+    assert_eq!(runner.resume_height(), CRASH_HEIGHT);
+
+    let mut slots = ledger_db.subscribe_slots().unwrap();
+    let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+    let run = runner.run_in_process_until(shutdown_receiver);
+    let shutdown = async {
+        let mut committed_slots = Vec::new();
+        while committed_slots.len() < (SLOTS_TO_SYNC - CRASH_HEIGHT) as usize {
+            committed_slots.push(slots.recv().await.unwrap());
+        }
+        shutdown_sender.send(()).unwrap();
+        committed_slots
+    };
+    let (result, committed_slots) = tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::join!(run, shutdown)
+    })
+    .await
+    .expect("The restarted runner should sync the remaining blocks promptly");
+    result.unwrap();
+
+    // The slot at the crash height was executed again, but not committed twice.
+    let remaining_slots: Vec<u64> = (CRASH_HEIGHT + 1..=SLOTS_TO_SYNC).collect();
+    assert_eq!(remaining_slots, committed_slots);
+    let (head_slot, _) = ledger_db.get_head_slot().unwrap().unwrap();
+    assert_eq!(head_slot, SlotNumber(SLOTS_TO_SYNC));
+
+    let expected_blocks: Vec<MockBlock> = blobs
+        .iter()
+        .enumerate()
+        .map(|(idx, blob)| MockBlock {
+            header: MockBlockHeader::from_height((idx + 1) as u64),
+            validity_cond: MockValidityCond::default(),
+            blobs: vec![MockBlob::new(
+                blob.clone(),
+                sequencer_address,
+                [idx as u8; 32],
+            )],
+        })
+        .collect();
+    let (expected_state_root, _) = get_result_from_blocks(&genesis_params, &expected_blocks);
+    assert_eq!(expected_state_root, *runner.get_state_root());
+}
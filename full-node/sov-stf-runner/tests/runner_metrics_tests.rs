@@ -0,0 +1,135 @@
+use std::time::Duration;
+
+use sov_db::ledger_db::LedgerDB;
+use sov_mock_da::{MockAddress, MockDaService};
+use sov_rollup_interface::rpc::LedgerRpcProvider;
+use sov_rollup_interface::services::da::DaService;
+use sov_stf_runner::metrics::METRICS_PATH;
+use sov_stf_runner::{InitVariant, MetricsConfig};
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::oneshot;
+
+mod hash_stf;
+mod runner_setup;
+
+use runner_setup::{new_runner, MockInitVariant};
+
+async fn scrape(address: std::net::SocketAddr, path: &str) -> String {
+    let mut stream = TcpStream::connect(address).await.unwrap();
+    let request = format!(
+        "GET {} HTTP/1.1\r\nHost: {}\r\nConnection: close\r\n\r\n",
+        path, address
+    );
+    stream.write_all(request.as_bytes()).await.unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response
+}
+
+/// Returns the value of the sample with the given name, without labels.
+fn sample(metrics: &str, name: &str) -> Option<f64> {
+    metrics.lines().find_map(|line| {
+        let (sample_name, value) = line.split_once(' ')?;
+        (sample_name == name).then(|| value.parse().unwrap())
+    })
+}
+
+#[tokio::test]
+async fn test_metrics_advance_after_processing_a_slot() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let da_service = MockDaService::new(MockAddress::new([11u8; 32]));
+    let genesis_header = da_service.get_last_finalized_block_header().await.unwrap();
+    da_service.send_transaction(&[1, 1, 1, 1]).await.unwrap();
+
+    let init_variant: MockInitVariant = InitVariant::Genesis {
+        block_header: genesis_header,
+        genesis_params: vec![1, 2, 3, 4, 5],
+    };
+    let ledger_db = LedgerDB::with_path(tmpdir.path()).unwrap();
+    let mut runner = new_runner(tmpdir.path(), ledger_db.clone(), da_service, init_variant);
+
+    let (address_sender, address_receiver) = oneshot::channel();
+    let metrics_config = MetricsConfig {
+        bind_host: "127.0.0.1".to_string(),
+        bind_port: 0,
+    };
+    runner
+        .start_metrics_server(&metrics_config, Some(address_sender))
+        .await
+        .unwrap();
+    let metrics_address = address_receiver.await.unwrap();
+
+    let response = scrape(metrics_address, "/not-metrics").await;
+    assert!(response.starts_with("HTTP/1.1 404"));
+    let metrics = scrape(metrics_address, METRICS_PATH).await;
+    assert_eq!(None, sample(&metrics, "sov_rollup_slots_processed_total"));
+
+    let mut slots = ledger_db.subscribe_slots().unwrap();
+    let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+    let run = runner.run_in_process_until(shutdown_receiver);
+    // The metrics are scraped while the runner waits for the next block.
+    let shutdown = async {
+        assert_eq!(1, slots.recv().await.unwrap());
+        let metrics = scrape(metrics_address, METRICS_PATH).await;
+        shutdown_sender.send(()).unwrap();
+        metrics
+    };
+
+    let (result, metrics) = tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::join!(run, shutdown)
+    })
+    .await
+    .expect("The runner should process the block promptly");
+    result.unwrap();
+
+    assert!(metrics.starts_with("HTTP/1.1 200"));
+    assert_eq!(
+        Some(1.0),
+        sample(&metrics, "sov_rollup_slots_processed_total")
+    );
+    assert_eq!(
+        Some(1.0),
+        sample(&metrics, "sov_rollup_blobs_per_slot_count")
+    );
+    assert_eq!(Some(1.0), sample(&metrics, "sov_rollup_blobs_per_slot_sum"));
+    // The hash STF doesn't produce any batch receipts.
+    assert_eq!(Some(1.0), sample(&metrics, "sov_rollup_txs_per_slot_count"));
+    assert_eq!(Some(0.0), sample(&metrics, "sov_rollup_txs_per_slot_sum"));
+    assert_eq!(
+        Some(1.0),
+        sample(&metrics, "sov_rollup_apply_slot_duration_seconds_count")
+    );
+
+    // The metrics server is stopped with the rollup.
+    assert!(TcpStream::connect(metrics_address).await.is_err());
+}
+
+#[tokio::test]
+async fn test_metrics_server_reports_taken_address() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let da_service = MockDaService::new(MockAddress::new([11u8; 32]));
+    let genesis_header = da_service.get_last_finalized_block_header().await.unwrap();
+    let init_variant: MockInitVariant = InitVariant::Genesis {
+        block_header: genesis_header,
+        genesis_params: vec![1, 2, 3, 4, 5],
+    };
+    let ledger_db = LedgerDB::with_path(tmpdir.path()).unwrap();
+    let mut runner = new_runner(tmpdir.path(), ledger_db, da_service, init_variant);
+
+    let taken = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let metrics_config = MetricsConfig {
+        bind_host: "127.0.0.1".to_string(),
+        bind_port: taken.local_addr().unwrap().port(),
+    };
+    let error = runner
+        .start_metrics_server(&metrics_config, None)
+        .await
+        .unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .starts_with("Failed to bind the metrics server"),
+        "{error}"
+    );
+}
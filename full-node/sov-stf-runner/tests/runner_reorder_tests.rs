@@ -0,0 +1,131 @@
+use std::collections::HashSet;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use sov_db::ledger_db::LedgerDB;
+use sov_mock_da::{
+    MockAddress, MockBlob, MockBlock, MockBlockHeader, MockDaService, MockValidityCond,
+};
+use sov_rollup_interface::da::BlockHeaderTrait;
+use sov_rollup_interface::rpc::{LedgerRpcProvider, QueryMode, SlotResponse};
+use sov_rollup_interface::services::da::{DaService, SlotData};
+use sov_stf_runner::InitVariant;
+use tokio::sync::oneshot;
+
+mod hash_stf;
+mod runner_setup;
+
+use hash_stf::get_result_from_blocks;
+use runner_setup::{new_runner, DaServiceHooks, HookedDaService, MockInitVariant};
+
+/// Returns the block following the requested one on the first request for each of `early_heights`,
+/// like an asynchronous source delivering blocks slightly out of order.
+#[derive(Clone)]
+struct OutOfOrderBlocks {
+    early_heights: Arc<Mutex<HashSet<u64>>>,
+    delivered_heights: Arc<Mutex<Vec<u64>>>,
+}
+
+impl OutOfOrderBlocks {
+    fn new(early_heights: impl IntoIterator<Item = u64>) -> Self {
+        Self {
+            early_heights: Arc::new(Mutex::new(early_heights.into_iter().collect())),
+            delivered_heights: Default::default(),
+        }
+    }
+
+    fn delivered_heights(&self) -> Vec<u64> {
+        self.delivered_heights.lock().unwrap().clone()
+    }
+}
+
+impl DaServiceHooks for OutOfOrderBlocks {
+    fn block_height(&self, height: u64) -> u64 {
+        let height = if self.early_heights.lock().unwrap().remove(&height) {
+            height + 1
+        } else {
+            height
+        };
+        self.delivered_heights.lock().unwrap().push(height);
+        height
+    }
+}
+
+#[tokio::test]
+async fn test_blocks_delivered_out_of_order_are_processed_in_order() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let sequencer_address = MockAddress::new([11u8; 32]);
+    let genesis_params = vec![1, 2, 3, 4, 5];
+    let blobs = vec![vec![1, 1, 1, 1], vec![2, 2, 2, 2], vec![3, 3, 3, 3]];
+
+    let mock_da_service = MockDaService::new(sequencer_address);
+    let genesis_header = mock_da_service
+        .get_last_finalized_block_header()
+        .await
+        .unwrap();
+    for blob in &blobs {
+        mock_da_service.send_transaction(blob).await.unwrap();
+    }
+    // Asking for height 2 first returns height 3, so the blocks arrive as 1, 3, 2.
+    let da_service = HookedDaService {
+        inner: mock_da_service.clone(),
+        hooks: OutOfOrderBlocks::new([2]),
+    };
+
+    let init_variant: MockInitVariant = InitVariant::Genesis {
+        block_header: genesis_header,
+        genesis_params: genesis_params.clone(),
+    };
+    let ledger_db = LedgerDB::with_path(tmpdir.path()).unwrap();
+    let mut runner = new_runner(
+        tmpdir.path(),
+        ledger_db.clone(),
+        da_service.clone(),
+        init_variant,
+    );
+
+    let mut slots = ledger_db.subscribe_slots().unwrap();
+    let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+    let run = runner.run_in_process_until(shutdown_receiver);
+    let shutdown = async {
+        let mut committed_slots = Vec::new();
+        while committed_slots.len() < blobs.len() {
+            committed_slots.push(slots.recv().await.unwrap());
+        }
+        shutdown_sender.send(()).unwrap();
+        committed_slots
+    };
+
+    let (result, committed_slots) = tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::join!(run, shutdown)
+    })
+    .await
+    .expect("The runner should process all the blocks promptly");
+    result.unwrap();
+
+    assert_eq!([1, 3, 2], da_service.hooks.delivered_heights()[..3]);
+    assert_eq!(committed_slots, [1, 2, 3]);
+
+    // Every slot holds the block of the same height.
+    for height in 1..=blobs.len() as u64 {
+        let slot: SlotResponse<(), ()> = ledger_db
+            .get_slot_by_number(height, QueryMode::Compact)
+            .unwrap()
+            .unwrap();
+        let block = mock_da_service.get_block_at(height).await.unwrap();
+        assert_eq!(height, block.header().height());
+        assert_eq!(block.hash(), slot.hash);
+    }
+
+    let expected_blocks: Vec<MockBlock> = blobs
+        .into_iter()
+        .enumerate()
+        .map(|(idx, blob)| MockBlock {
+            header: MockBlockHeader::from_height((idx + 1) as u64),
+            validity_cond: MockValidityCond::default(),
+            blobs: vec![MockBlob::new(blob, sequencer_address, [idx as u8; 32])],
+        })
+        .collect();
+    let (expected_state_root, _) = get_result_from_blocks(&genesis_params, &expected_blocks);
+    assert_eq!(expected_state_root, *runner.get_state_root());
+}
@@ -1,25 +1,33 @@
+use sov_db::ledger_db::LedgerDB;
 use sov_mock_da::{
-    MockAddress, MockBlob, MockBlock, MockBlockHeader, MockDaConfig, MockDaService, MockDaSpec,
-    MockDaVerifier, MockValidityCond, PlannedFork,
-};
-use sov_mock_zkvm::MockZkvm;
-use sov_stf_runner::{
-    InitVariant, ParallelProverService, ProverServiceConfig, RollupConfig, RollupProverConfig,
-    RpcConfig, RunnerConfig, StateTransitionRunner, StorageConfig,
+    MockAddress, MockBlob, MockBlock, MockBlockHeader, MockDaService, MockDaSpec, MockDaVerifier,
+    MockValidityCond, PlannedFork,
 };
-
-mod hash_stf;
-
-use hash_stf::{get_result_from_blocks, HashStf, Q, S};
-use sov_db::ledger_db::LedgerDB;
 use sov_prover_storage_manager::ProverStorageManager;
 use sov_rollup_interface::services::da::DaService;
 use sov_rollup_interface::storage::HierarchicalStorageManager;
 use sov_state::storage::NativeStorage;
 use sov_state::{ProverStorage, Storage};
+use sov_stf_runner::{InitVariant, RunnerConfig, DEFAULT_FINALITY_DEPTH};
+
+mod hash_stf;
+mod runner_setup;
+
+use hash_stf::{get_result_from_blocks, Q, S};
+use runner_setup::{
+    new_runner_with_config, runner_config, DaServiceHooks, HookedDaService, MockInitVariant,
+};
+
+/// Reports the head block as finalized, like a DA layer claiming finality too early.
+#[derive(Clone)]
+struct Overconfident;
+
+impl DaServiceHooks for Overconfident {
+    fn head_is_finalized(&self) -> bool {
+        true
+    }
+}
 
-type MockInitVariant =
-    InitVariant<HashStf<MockValidityCond>, MockZkvm<MockValidityCond>, MockDaSpec>;
 #[tokio::test]
 async fn test_simple_reorg_case() {
     let tmpdir = tempfile::tempdir().unwrap();
@@ -67,7 +75,78 @@ async fn test_simple_reorg_case() {
         genesis_params,
     };
 
-    let (before, after) = runner_execution(tmpdir.path(), init_variant, da_service).await;
+    let (before, after) = runner_execution(
+        tmpdir.path(),
+        init_variant,
+        da_service,
+        DEFAULT_FINALITY_DEPTH,
+    )
+    .await;
+    assert_ne!(before, after);
+    assert_eq!(expected_state_root, after);
+
+    let committed_root_hash = get_saved_root_hash(tmpdir.path()).unwrap().unwrap();
+
+    assert_eq!(expected_committed_root_hash.unwrap(), committed_root_hash);
+}
+
+#[tokio::test]
+async fn test_reorg_of_blocks_within_finality_depth() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let sequencer_address = MockAddress::new([11u8; 32]);
+    let genesis_params = vec![1, 2, 3, 4, 5];
+
+    let main_chain_blobs = vec![
+        vec![1, 1, 1, 1],
+        vec![2, 2, 2, 2],
+        vec![3, 3, 3, 3],
+        vec![4, 4, 4, 4],
+    ];
+    let fork_blobs = vec![
+        vec![13, 13, 13, 13],
+        vec![14, 14, 14, 14],
+        vec![15, 15, 15, 15],
+    ];
+    let expected_final_blobs = vec![
+        vec![1, 1, 1, 1],
+        vec![2, 2, 2, 2],
+        vec![13, 13, 13, 13],
+        vec![14, 14, 14, 14],
+        vec![15, 15, 15, 15],
+    ];
+
+    let mut da_service = MockDaService::with_finality(sequencer_address, 4);
+    da_service.set_wait_attempts(2);
+
+    let genesis_header = da_service.get_last_finalized_block_header().await.unwrap();
+
+    let planned_fork = PlannedFork::new(5, 2, fork_blobs.clone());
+    da_service.set_planned_fork(planned_fork).await.unwrap();
+
+    for b in &main_chain_blobs {
+        da_service.send_transaction(b).await.unwrap();
+    }
+
+    let (expected_state_root, _expected_final_root_hash) =
+        get_expected_execution_hash_from(&genesis_params, expected_final_blobs);
+    // Only blocks buried under 2 more blocks are committed, so the head of the fork is not.
+    let (_expected_committed_state_root, expected_committed_root_hash) =
+        get_expected_execution_hash_from(
+            &genesis_params,
+            vec![vec![1, 1, 1, 1], vec![2, 2, 2, 2], vec![13, 13, 13, 13]],
+        );
+
+    let init_variant: MockInitVariant = InitVariant::Genesis {
+        block_header: genesis_header,
+        genesis_params,
+    };
+
+    // Every block is reported as finalized, including the ones replaced by the fork.
+    let da_service = HookedDaService {
+        inner: da_service,
+        hooks: Overconfident,
+    };
+    let (before, after) = runner_execution(tmpdir.path(), init_variant, da_service, 2).await;
     assert_ne!(before, after);
     assert_eq!(expected_state_root, after);
 
@@ -105,7 +184,13 @@ async fn test_instant_finality_data_stored() {
         genesis_params,
     };
 
-    let (before, after) = runner_execution(tmpdir.path(), init_variant, da_service).await;
+    let (before, after) = runner_execution(
+        tmpdir.path(),
+        init_variant,
+        da_service,
+        DEFAULT_FINALITY_DEPTH,
+    )
+    .await;
     assert_ne!(before, after);
     assert_eq!(expected_state_root, after);
 
@@ -114,64 +199,30 @@ async fn test_instant_finality_data_stored() {
     assert_eq!(expected_root_hash.unwrap(), saved_root_hash);
 }
 
-async fn runner_execution(
+async fn runner_execution<Da>(
     path: &std::path::Path,
     init_variant: MockInitVariant,
-    da_service: MockDaService,
-) -> ([u8; 32], [u8; 32]) {
-    let rollup_config = RollupConfig::<MockDaConfig> {
-        storage: StorageConfig {
-            path: path.to_path_buf(),
-        },
-        runner: RunnerConfig {
-            start_height: 1,
-            rpc_config: RpcConfig {
-                bind_host: "127.0.0.1".to_string(),
-                bind_port: 0,
-            },
-        },
-        da: MockDaConfig {
-            sender_address: da_service.get_sequencer_address(),
-        },
-        prover_service: ProverServiceConfig {
-            aggregated_proof_block_jump: 1,
-        },
-    };
-
+    da_service: Da,
+    finality_depth: u64,
+) -> ([u8; 32], [u8; 32])
+where
+    Da: DaService<
+            Spec = MockDaSpec,
+            Verifier = MockDaVerifier,
+            FilteredBlock = MockBlock,
+            Error = anyhow::Error,
+        > + Clone
+        + Send
+        + Sync
+        + 'static,
+{
     let ledger_db = LedgerDB::with_path(path).unwrap();
-
-    let stf = HashStf::<MockValidityCond>::new();
-
-    let storage_config = sov_state::config::Config {
-        path: rollup_config.storage.path.clone(),
+    let runner_config = RunnerConfig {
+        finality_depth,
+        ..runner_config()
     };
-    let mut storage_manager = ProverStorageManager::new(storage_config).unwrap();
-
-    let vm = MockZkvm::new(MockValidityCond::default());
-    let verifier = MockDaVerifier::default();
-    let prover_config = RollupProverConfig::Skip;
-
-    let prover_service = ParallelProverService::new(
-        vm,
-        stf.clone(),
-        verifier,
-        prover_config,
-        // Should be ZkStorage, but we don't need it for this test
-        storage_manager.create_finalized_storage().unwrap(),
-        1,
-        rollup_config.prover_service,
-    );
-
-    let mut runner = StateTransitionRunner::new(
-        rollup_config.runner,
-        da_service,
-        ledger_db,
-        stf,
-        storage_manager,
-        init_variant,
-        prover_service,
-    )
-    .unwrap();
+    let mut runner =
+        new_runner_with_config(path, ledger_db, da_service, init_variant, runner_config);
 
     let before = *runner.get_state_root();
     let end = runner.run_in_process().await;
@@ -186,6 +237,7 @@ fn get_saved_root_hash(
 ) -> anyhow::Result<Option<<ProverStorage<S, Q> as Storage>::Root>> {
     let storage_config = sov_state::config::Config {
         path: path.to_path_buf(),
+        read_cache_size: None,
     };
     let mut storage_manager = ProverStorageManager::<MockDaSpec, S>::new(storage_config).unwrap();
     let finalized_storage = storage_manager.create_finalized_storage()?;
@@ -0,0 +1,255 @@
+use std::path::Path;
+
+use sov_db::ledger_db::LedgerDB;
+use sov_mock_da::{MockBlock, MockDaService, MockDaSpec, MockDaVerifier, MockValidityCond};
+use sov_mock_zkvm::MockZkvm;
+use sov_prover_storage_manager::ProverStorageManager;
+use sov_rollup_interface::da::DaSpec;
+use sov_rollup_interface::services::da::DaService;
+use sov_rollup_interface::storage::HierarchicalStorageManager;
+use sov_state::{ArrayWitness, ProverStorage};
+use sov_stf_runner::{
+    InitVariant, ParallelProverService, ProverServiceConfig, RollupProverConfig, RpcConfig,
+    RunnerConfig, StateTransitionRunner, DEFAULT_COMMIT_INTERVAL, DEFAULT_FINALITY_DEPTH,
+    DEFAULT_REORDER_BUFFER_CAPACITY,
+};
+
+use crate::hash_stf::{HashStf, Q, S};
+
+pub type MockInitVariant =
+    InitVariant<HashStf<MockValidityCond>, MockZkvm<MockValidityCond>, MockDaSpec>;
+
+pub type MockProverService<Da> = ParallelProverService<
+    [u8; 32],
+    ArrayWitness,
+    Da,
+    MockZkvm<MockValidityCond>,
+    HashStf<MockValidityCond>,
+>;
+
+pub type MockRunner<Da> = StateTransitionRunner<
+    HashStf<MockValidityCond>,
+    ProverStorageManager<MockDaSpec, S>,
+    Da,
+    MockZkvm<MockValidityCond>,
+    MockProverService<Da>,
+>;
+
+/// A runner config which starts at the first DA block and binds the RPC server to any free port.
+pub fn runner_config() -> RunnerConfig {
+    RunnerConfig {
+        start_height: 1,
+        rpc_config: RpcConfig {
+            bind_host: "127.0.0.1".to_string(),
+            bind_port: 0,
+        },
+        reorder_buffer_capacity: DEFAULT_REORDER_BUFFER_CAPACITY,
+        commit_interval: DEFAULT_COMMIT_INTERVAL,
+        finality_depth: DEFAULT_FINALITY_DEPTH,
+    }
+}
+
+/// Creates a runner for the [`HashStf`], which keeps its state under `path` and skips proving.
+pub fn new_runner<Da>(
+    path: &Path,
+    ledger_db: LedgerDB,
+    da_service: Da,
+    init_variant: MockInitVariant,
+) -> MockRunner<Da>
+where
+    Da: DaService<
+            Spec = MockDaSpec,
+            Verifier = MockDaVerifier,
+            FilteredBlock = MockBlock,
+            Error = anyhow::Error,
+        > + Clone
+        + Send
+        + Sync
+        + 'static,
+{
+    new_runner_with_config(path, ledger_db, da_service, init_variant, runner_config())
+}
+
+/// Like [`new_runner`], with the given runner config.
+pub fn new_runner_with_config<Da>(
+    path: &Path,
+    ledger_db: LedgerDB,
+    da_service: Da,
+    init_variant: MockInitVariant,
+    runner_config: RunnerConfig,
+) -> MockRunner<Da>
+where
+    Da: DaService<
+            Spec = MockDaSpec,
+            Verifier = MockDaVerifier,
+            FilteredBlock = MockBlock,
+            Error = anyhow::Error,
+        > + Clone
+        + Send
+        + Sync
+        + 'static,
+{
+    let storage_config = sov_state::config::Config {
+        path: path.to_path_buf(),
+        read_cache_size: None,
+    };
+    let storage_manager = ProverStorageManager::new(storage_config).unwrap();
+    new_runner_with_storage_manager(
+        storage_manager,
+        ledger_db,
+        da_service,
+        init_variant,
+        runner_config,
+    )
+}
+
+/// Like [`new_runner_with_config`], keeping the state in the given storage manager.
+pub fn new_runner_with_storage_manager<Da, Sm>(
+    mut storage_manager: Sm,
+    ledger_db: LedgerDB,
+    da_service: Da,
+    init_variant: MockInitVariant,
+    runner_config: RunnerConfig,
+) -> StateTransitionRunner<
+    HashStf<MockValidityCond>,
+    Sm,
+    Da,
+    MockZkvm<MockValidityCond>,
+    MockProverService<Da>,
+>
+where
+    Da: DaService<
+            Spec = MockDaSpec,
+            Verifier = MockDaVerifier,
+            FilteredBlock = MockBlock,
+            Error = anyhow::Error,
+        > + Clone
+        + Send
+        + Sync
+        + 'static,
+    Sm: HierarchicalStorageManager<
+        MockDaSpec,
+        NativeStorage = ProverStorage<S, Q>,
+        NativeChangeSet = ProverStorage<S, Q>,
+    >,
+{
+    let stf = HashStf::<MockValidityCond>::new();
+    let prover_service = ParallelProverService::new(
+        MockZkvm::new(MockValidityCond::default()),
+        stf.clone(),
+        MockDaVerifier::default(),
+        RollupProverConfig::Skip,
+        // Should be ZkStorage, but we don't need it for this test
+        storage_manager.create_finalized_storage().unwrap(),
+        1,
+        ProverServiceConfig {
+            aggregated_proof_block_jump: 1,
+        },
+    );
+
+    StateTransitionRunner::new(
+        runner_config,
+        da_service,
+        ledger_db,
+        stf,
+        storage_manager,
+        init_variant,
+        prover_service,
+    )
+    .unwrap()
+}
+
+/// Changes what a [`HookedDaService`] returns from the [`MockDaService`] it wraps.
+pub trait DaServiceHooks: Clone + Send + Sync + 'static {
+    /// The height of the block returned when the block at `height` is requested.
+    fn block_height(&self, height: u64) -> u64 {
+        height
+    }
+
+    /// Whether the head block is reported as the last finalized one.
+    fn head_is_finalized(&self) -> bool {
+        false
+    }
+}
+
+/// Forwards every call to a [`MockDaService`], except for the ones its hooks change.
+#[derive(Clone)]
+pub struct HookedDaService<H> {
+    pub inner: MockDaService,
+    pub hooks: H,
+}
+
+#[async_trait::async_trait]
+impl<H: DaServiceHooks> DaService for HookedDaService<H> {
+    type Spec = MockDaSpec;
+    type Verifier = MockDaVerifier;
+    type FilteredBlock = MockBlock;
+    type HeaderStream = <MockDaService as DaService>::HeaderStream;
+    type TransactionId = <MockDaService as DaService>::TransactionId;
+    type Error = anyhow::Error;
+
+    async fn get_block_at(&self, height: u64) -> Result<Self::FilteredBlock, Self::Error> {
+        self.inner
+            .get_block_at(self.hooks.block_height(height))
+            .await
+    }
+
+    async fn get_last_finalized_block_header(
+        &self,
+    ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
+        if self.hooks.head_is_finalized() {
+            self.inner.get_head_block_header().await
+        } else {
+            self.inner.get_last_finalized_block_header().await
+        }
+    }
+
+    async fn subscribe_finalized_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        self.inner.subscribe_finalized_header().await
+    }
+
+    async fn get_head_block_header(
+        &self,
+    ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
+        self.inner.get_head_block_header().await
+    }
+
+    fn extract_relevant_blobs(
+        &self,
+        block: &Self::FilteredBlock,
+    ) -> Vec<<Self::Spec as DaSpec>::BlobTransaction> {
+        self.inner.extract_relevant_blobs(block)
+    }
+
+    async fn get_extraction_proof(
+        &self,
+        block: &Self::FilteredBlock,
+        blobs: &[<Self::Spec as DaSpec>::BlobTransaction],
+    ) -> (
+        <Self::Spec as DaSpec>::InclusionMultiProof,
+        <Self::Spec as DaSpec>::CompletenessProof,
+    ) {
+        self.inner.get_extraction_proof(block, blobs).await
+    }
+
+    async fn send_transaction(&self, blob: &[u8]) -> Result<Self::TransactionId, Self::Error> {
+        self.inner.send_transaction(blob).await
+    }
+
+    async fn wait_for_finalization(&self, tx_id: &Self::TransactionId) -> Result<u64, Self::Error> {
+        self.inner.wait_for_finalization(tx_id).await
+    }
+
+    async fn send_aggregated_zk_proof(
+        &self,
+        aggregated_proof_data: &[u8],
+    ) -> Result<u64, Self::Error> {
+        self.inner
+            .send_aggregated_zk_proof(aggregated_proof_data)
+            .await
+    }
+
+    async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
+        self.inner.get_aggregated_proofs_at(height).await
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+use sov_db::ledger_db::LedgerDB;
+use sov_mock_da::{MockAddress, MockDaService};
+use sov_rollup_interface::rpc::LedgerRpcProvider;
+use sov_rollup_interface::services::da::DaService;
+use sov_stf_runner::InitVariant;
+use tokio::sync::oneshot;
+
+mod hash_stf;
+mod runner_setup;
+
+use runner_setup::{new_runner, MockInitVariant};
+
+#[tokio::test]
+async fn test_runner_shuts_down_gracefully() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let da_service = MockDaService::new(MockAddress::new([11u8; 32]));
+    let genesis_header = da_service.get_last_finalized_block_header().await.unwrap();
+    da_service.send_transaction(&[1, 1, 1, 1]).await.unwrap();
+
+    let init_variant: MockInitVariant = InitVariant::Genesis {
+        block_header: genesis_header,
+        genesis_params: vec![1, 2, 3, 4, 5],
+    };
+    let ledger_db = LedgerDB::with_path(tmpdir.path()).unwrap();
+    let mut runner = new_runner(tmpdir.path(), ledger_db.clone(), da_service, init_variant);
+
+    let (address_sender, address_receiver) = oneshot::channel();
+    runner
+        .start_rpc_server(jsonrpsee::RpcModule::new(()), Some(address_sender))
+        .await;
+    let rpc_address = address_receiver.await.unwrap();
+
+    let mut slots = ledger_db.subscribe_slots().unwrap();
+    let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+    let run = runner.run_in_process_until(shutdown_receiver);
+    // The runner waits for the next block after the first slot, until it's asked to shut down.
+    let shutdown = async {
+        assert_eq!(1, slots.recv().await.unwrap());
+        shutdown_sender.send(()).unwrap();
+    };
+
+    let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::join!(run, shutdown)
+    })
+    .await
+    .expect("The runner should shut down promptly");
+    result.unwrap();
+
+    assert!(tokio::net::TcpStream::connect(rpc_address).await.is_err());
+}
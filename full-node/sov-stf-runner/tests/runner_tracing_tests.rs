@@ -0,0 +1,166 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use sov_db::ledger_db::LedgerDB;
+use sov_mock_da::{MockAddress, MockDaService};
+use sov_rollup_interface::rpc::LedgerRpcProvider;
+use sov_rollup_interface::services::da::DaService;
+use sov_stf_runner::InitVariant;
+use tokio::sync::oneshot;
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Subscriber};
+use tracing_subscriber::layer::{Context, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::util::SubscriberInitExt;
+use tracing_subscriber::Layer;
+
+mod hash_stf;
+mod runner_setup;
+
+use runner_setup::{new_runner, MockInitVariant};
+
+#[derive(Default, Debug, Clone)]
+struct Fields(HashMap<String, String>);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        self.0
+            .insert(field.name().to_string(), format!("{:?}", value));
+    }
+
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.0.insert(field.name().to_string(), value.to_string());
+    }
+}
+
+/// An event together with the name and fields of the span it was emitted in.
+#[derive(Debug)]
+struct CapturedEvent {
+    span: Option<(String, Fields)>,
+    fields: Fields,
+}
+
+/// Records every event, so the test can inspect the structured fields.
+#[derive(Default, Clone)]
+struct CaptureLayer {
+    events: Arc<Mutex<Vec<CapturedEvent>>>,
+}
+
+impl CaptureLayer {
+    fn events_with_message(&self, message: &str) -> Vec<(Option<(String, Fields)>, Fields)> {
+        self.events
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|event| event.fields.0.get("message").map(String::as_str) == Some(message))
+            .map(|event| (event.span.clone(), event.fields.clone()))
+            .collect()
+    }
+}
+
+impl<S> Layer<S> for CaptureLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut fields = Fields::default();
+        attrs.record(&mut fields);
+        if let Some(span) = ctx.span(id) {
+            span.extensions_mut().insert(fields);
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let mut fields = Fields::default();
+        event.record(&mut fields);
+        let span = ctx.event_span(event).map(|span| {
+            let span_fields = span
+                .extensions()
+                .get::<Fields>()
+                .cloned()
+                .unwrap_or_default();
+            (span.name().to_string(), span_fields)
+        });
+        self.events
+            .lock()
+            .unwrap()
+            .push(CapturedEvent { span, fields });
+    }
+}
+
+#[tokio::test]
+async fn test_slot_processing_is_traced_with_structured_fields() {
+    let capture = CaptureLayer::default();
+    let _guard = tracing_subscriber::registry()
+        .with(capture.clone())
+        .set_default();
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let da_service = MockDaService::new(MockAddress::new([11u8; 32]));
+    let genesis_header = da_service.get_last_finalized_block_header().await.unwrap();
+    da_service.send_transaction(&[1, 1, 1, 1]).await.unwrap();
+    da_service.send_transaction(&[2, 2, 2, 2]).await.unwrap();
+
+    let init_variant: MockInitVariant = InitVariant::Genesis {
+        block_header: genesis_header,
+        genesis_params: vec![1, 2, 3, 4, 5],
+    };
+    let ledger_db = LedgerDB::with_path(tmpdir.path()).unwrap();
+    let mut runner = new_runner(tmpdir.path(), ledger_db.clone(), da_service, init_variant);
+
+    let mut slots = ledger_db.subscribe_slots().unwrap();
+    let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+    let run = runner.run_in_process_until(shutdown_receiver);
+    let shutdown = async {
+        assert_eq!(1, slots.recv().await.unwrap());
+        assert_eq!(2, slots.recv().await.unwrap());
+        shutdown_sender.send(()).unwrap();
+    };
+
+    let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::join!(run, shutdown)
+    })
+    .await
+    .expect("The runner should process all the blocks promptly");
+    result.unwrap();
+
+    let extracted = capture.events_with_message("Extracted relevant blobs");
+    let applied = capture.events_with_message("Applied slot");
+    assert_eq!(2, extracted.len());
+    assert_eq!(2, applied.len());
+
+    for (idx, ((extracted_span, extracted_fields), (applied_span, applied_fields))) in
+        extracted.into_iter().zip(applied).enumerate()
+    {
+        let height = (idx + 1).to_string();
+        for span in [extracted_span, applied_span] {
+            let (name, fields) = span.expect("Slot events should be emitted inside a span");
+            assert_eq!("slot", name);
+            assert_eq!(Some(&height), fields.0.get("height"));
+        }
+
+        assert_eq!(
+            Some("1"),
+            extracted_fields.0.get("blob_count").map(String::as_str)
+        );
+        assert_eq!(
+            Some("0"),
+            applied_fields.0.get("batch_count").map(String::as_str)
+        );
+        assert_eq!(
+            Some("0"),
+            applied_fields.0.get("tx_count").map(String::as_str)
+        );
+        assert!(applied_fields.0.contains_key("state_root"));
+    }
+
+    // The last recorded root is the one the runner ended up with.
+    let (_, last_applied) = capture.events_with_message("Applied slot").pop().unwrap();
+    assert_eq!(
+        Some(&hex::encode(runner.get_state_root())),
+        last_applied.0.get("state_root")
+    );
+}
@@ -0,0 +1,70 @@
+use std::time::Duration;
+
+use sov_db::ledger_db::LedgerDB;
+use sov_mock_da::{MockAddress, MockDaService};
+use sov_rollup_interface::services::da::DaService;
+use sov_stf_runner::{get_sync_status_rpc, InitVariant, SyncStatus};
+use tokio::sync::oneshot;
+
+mod hash_stf;
+mod runner_setup;
+
+use runner_setup::{new_runner, MockInitVariant};
+
+#[tokio::test]
+async fn test_sync_status_follows_runner() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let da_service = MockDaService::new(MockAddress::new([11u8; 32]));
+    let genesis_header = da_service.get_last_finalized_block_header().await.unwrap();
+    for _ in 0..3 {
+        da_service.send_transaction(&[1, 1, 1, 1]).await.unwrap();
+    }
+
+    let ledger_db = LedgerDB::with_path(tmpdir.path()).unwrap();
+    let sync_status_rpc = get_sync_status_rpc(ledger_db.clone(), da_service.clone());
+    let sync_status = || async {
+        sync_status_rpc
+            .call::<_, SyncStatus>("system_syncStatus", jsonrpsee::rpc_params![])
+            .await
+            .unwrap()
+    };
+
+    assert_eq!(
+        sync_status().await,
+        SyncStatus {
+            current_slot: 0,
+            da_tip_height: 3,
+            is_synced: false,
+        }
+    );
+
+    let init_variant: MockInitVariant = InitVariant::Genesis {
+        block_header: genesis_header,
+        genesis_params: vec![1, 2, 3, 4, 5],
+    };
+    let mut runner = new_runner(tmpdir.path(), ledger_db.clone(), da_service, init_variant);
+
+    let mut slots = ledger_db.subscribe_slots().unwrap();
+    let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+    let run = runner.run_in_process_until(shutdown_receiver);
+    let shutdown = async {
+        while slots.recv().await.unwrap() < 3 {}
+        shutdown_sender.send(()).unwrap();
+    };
+
+    let (result, ()) = tokio::time::timeout(Duration::from_secs(10), async {
+        tokio::join!(run, shutdown)
+    })
+    .await
+    .expect("The runner should process all the blocks promptly");
+    result.unwrap();
+
+    assert_eq!(
+        sync_status().await,
+        SyncStatus {
+            current_slot: 3,
+            da_tip_height: 3,
+            is_synced: true,
+        }
+    );
+}
@@ -52,7 +52,6 @@
 //     let blob = new_test_blob_from_batch(
 //         sov_modules_stf_blueprint::Batch { txs: value_setter },
 //         &MOCK_SEQUENCER_DA_ADDRESS,
-//         [2; 32],
 //     );
 
 //     let slot_data: MockBlock = MockBlock {
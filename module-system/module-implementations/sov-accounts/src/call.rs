@@ -6,6 +6,16 @@ use crate::Accounts;
 /// To update the account's public key, the sender must sign this message as proof of possession of the new key.
 pub const UPDATE_ACCOUNT_MSG: [u8; 32] = [1; 32];
 
+const ROTATE_KEY_MSG_PREFIX: &[u8] = b"sov-accounts/rotate-key";
+
+/// Returns the message the new public key must sign to rotate the account at `address` to it.
+///
+/// Unlike [`UPDATE_ACCOUNT_MSG`], the message is bound to the account, so a signature published in
+/// a pending rotation can't be used to point another account to the same key first.
+pub fn rotate_key_message<C: Context>(address: &C::Address) -> Vec<u8> {
+    [ROTATE_KEY_MSG_PREFIX, address.as_ref()].concat()
+}
+
 /// Represents the available call messages for interacting with the sov-accounts module.
 #[cfg_attr(
     feature = "native",
@@ -31,6 +41,16 @@ pub enum CallMessage<C: Context> {
         /// A valid signature from the new public key
         C::Signature,
     ),
+    /// Rotates the sender's account to a new public key, keeping its address, nonce and any
+    /// state associated with the address.
+    /// The transaction is signed by the current key, and the new key must sign
+    /// [`rotate_key_message`] for the sender's address.
+    RotateKey(
+        /// The new public key
+        C::PublicKey,
+        /// A valid signature from the new public key over [`rotate_key_message`]
+        C::Signature,
+    ),
 }
 
 impl<C: Context> Accounts<C> {
@@ -43,25 +63,55 @@ impl<C: Context> Accounts<C> {
     ) -> Result<CallResponse> {
         self.exit_if_account_exists(&new_pub_key, working_set)?;
 
-        let pub_key = self.public_keys.get_or_err(context.sender(), working_set)?;
+        // Proof that the sender is in possession of the `new_pub_key`.
+        signature.verify(&new_pub_key, &UPDATE_ACCOUNT_MSG)?;
 
-        let account = self.accounts.remove_or_err(&pub_key, working_set)?;
-        // Sanity check
-        ensure!(
-            context.sender() == &account.addr,
-            "Inconsistent account data"
-        );
+        self.move_account(context.sender(), &new_pub_key, working_set)?;
+        Ok(CallResponse::default())
+    }
+
+    pub(crate) fn rotate_key(
+        &self,
+        new_pub_key: C::PublicKey,
+        signature: C::Signature,
+        context: &C,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<CallResponse> {
+        if let Some(account) = self.accounts.get(&new_pub_key, working_set) {
+            ensure!(
+                context.sender() == &account.addr,
+                "New PublicKey already belongs to account {}",
+                account.addr
+            );
+            // The key is already the current key of the sender's account.
+            return Ok(CallResponse::default());
+        }
 
         // Proof that the sender is in possession of the `new_pub_key`.
-        signature.verify(&new_pub_key, &UPDATE_ACCOUNT_MSG)?;
+        signature.verify(&new_pub_key, &rotate_key_message::<C>(context.sender()))?;
 
-        // Update the public key (account data remains the same).
-        self.accounts.set(&new_pub_key, &account, working_set);
-        self.public_keys
-            .set(context.sender(), &new_pub_key, working_set);
+        self.move_account(context.sender(), &new_pub_key, working_set)?;
         Ok(CallResponse::default())
     }
 
+    /// Points the account at `address` to `new_pub_key`. The account data remains the same.
+    fn move_account(
+        &self,
+        address: &C::Address,
+        new_pub_key: &C::PublicKey,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<()> {
+        let pub_key = self.public_keys.get_or_err(address, working_set)?;
+
+        let account = self.accounts.remove_or_err(&pub_key, working_set)?;
+        // Sanity check
+        ensure!(address == &account.addr, "Inconsistent account data");
+
+        self.accounts.set(new_pub_key, &account, working_set);
+        self.public_keys.set(address, new_pub_key, working_set);
+        Ok(())
+    }
+
     fn exit_if_account_exists(
         &self,
         new_pub_key: &C::PublicKey,
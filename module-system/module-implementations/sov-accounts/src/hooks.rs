@@ -55,13 +55,16 @@ impl<C: Context> TxHooks for Accounts<C> {
 
     fn post_dispatch_tx_hook(
         &self,
-        tx: &Transaction<Self::Context>,
-        _ctx: &C,
+        _tx: &Transaction<Self::Context>,
+        ctx: &C,
         working_set: &mut WorkingSet<C>,
     ) -> anyhow::Result<()> {
-        let mut account = self.accounts.get_or_err(tx.pub_key(), working_set)?;
+        // The transaction may have moved the sender's account to a new key, so it is looked up
+        // by address rather than by the key which signed the transaction.
+        let pub_key = self.public_keys.get_or_err(ctx.sender(), working_set)?;
+        let mut account = self.accounts.get_or_err(&pub_key, working_set)?;
         account.nonce += 1;
-        self.accounts.set(tx.pub_key(), &account, working_set);
+        self.accounts.set(&pub_key, &account, working_set);
         Ok(())
     }
 }
@@ -13,7 +13,7 @@ pub use query::*;
 #[cfg(test)]
 mod tests;
 
-pub use call::{CallMessage, UPDATE_ACCOUNT_MSG};
+pub use call::{rotate_key_message, CallMessage, UPDATE_ACCOUNT_MSG};
 pub use hooks::AccountsTxHook;
 use sov_modules_api::{Context, Error, ModuleInfo, WorkingSet};
 
@@ -75,6 +75,9 @@ impl<C: Context> sov_modules_api::Module for Accounts<C> {
             call::CallMessage::UpdatePublicKey(new_pub_key, sig) => {
                 Ok(self.update_public_key(new_pub_key, sig, context, working_set)?)
             }
+            call::CallMessage::RotateKey(new_pub_key, sig) => {
+                Ok(self.rotate_key(new_pub_key, sig, context, working_set)?)
+            }
         }
     }
 }
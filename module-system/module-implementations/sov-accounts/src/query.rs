@@ -42,4 +42,20 @@ impl<C: sov_modules_api::Context> Accounts<C> {
 
         Ok(response)
     }
+
+    #[rpc_method(name = "getNonce")]
+    /// Get the nonce expected on the next transaction sent from the account at `address`, or `0`
+    /// if the account doesn't exist yet.
+    pub fn get_nonce(
+        &self,
+        address: C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<u64> {
+        let nonce = self
+            .public_keys
+            .get(&address, working_set)
+            .and_then(|pub_key| self.accounts.get(&pub_key, working_set))
+            .map_or(0, |account| account.nonce);
+        Ok(nonce)
+    }
 }
@@ -1,12 +1,14 @@
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::default_signature::private_key::DefaultPrivateKey;
+use sov_modules_api::hooks::TxHooks;
+use sov_modules_api::transaction::Transaction;
 use sov_modules_api::{
     AddressBech32, Context, Module, PrivateKey, PublicKey, Spec, StateMapAccessor, WorkingSet,
 };
 use sov_prover_storage_manager::new_orphan_storage;
 
 use crate::query::{self, Response};
-use crate::{call, AccountConfig, Accounts};
+use crate::{call, rotate_key_message, AccountConfig, Accounts};
 
 type C = DefaultContext;
 
@@ -164,6 +166,167 @@ fn test_get_account_after_pub_key_update() {
     assert_eq!(acc.addr, sender_1_addr)
 }
 
+#[test]
+fn test_rotate_key() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let accounts = &mut Accounts::<C>::default();
+
+    let current_priv_key = DefaultPrivateKey::generate();
+    let sequencer = DefaultPrivateKey::generate().pub_key();
+    let sender_addr = current_priv_key
+        .pub_key()
+        .to_address::<<C as Spec>::Address>();
+
+    let new_priv_key = DefaultPrivateKey::generate();
+    let new_pub_key = new_priv_key.pub_key();
+    let sig = new_priv_key.sign(&rotate_key_message::<C>(&sender_addr));
+    let message = call::CallMessage::<C>::RotateKey(new_pub_key.clone(), sig);
+
+    // The rotation is sent in a transaction signed by the current key, going through the tx hooks
+    // like any other call.
+    let tx = Transaction::<C>::new_signed_tx(&current_priv_key, vec![], 0, 0, 0, 0);
+    let hook_result = accounts
+        .pre_dispatch_tx_hook(&tx, working_set, &sequencer)
+        .unwrap();
+    let sender_context = C::new(hook_result.sender, hook_result.sequencer, 1);
+    accounts
+        .call(message.clone(), &sender_context, working_set)
+        .unwrap();
+    accounts
+        .post_dispatch_tx_hook(&tx, &sender_context, working_set)
+        .unwrap();
+
+    // The account moved to the new key, with its address and nonce.
+    assert_eq!(
+        accounts
+            .get_account(tx.pub_key().clone(), working_set)
+            .unwrap(),
+        query::Response::AccountEmpty
+    );
+    assert_eq!(
+        accounts
+            .get_account(new_pub_key.clone(), working_set)
+            .unwrap(),
+        query::Response::AccountExists {
+            addr: AddressBech32::from(&sender_addr),
+            nonce: 1
+        }
+    );
+    assert_eq!(
+        accounts.public_keys.get(&sender_addr, working_set),
+        Some(new_pub_key)
+    );
+
+    // The next transaction is signed by the new key and continues from the preserved nonce.
+    let tx = Transaction::<C>::new_signed_tx(&new_priv_key, vec![], 0, 0, 0, 1);
+    let hook_result = accounts
+        .pre_dispatch_tx_hook(&tx, working_set, &sequencer)
+        .unwrap();
+    assert_eq!(hook_result.sender, sender_addr);
+
+    // Rotating to the key which is already the account's key changes nothing.
+    accounts
+        .call(message, &sender_context, working_set)
+        .unwrap();
+    assert_eq!(
+        accounts
+            .accounts
+            .get(tx.pub_key(), working_set)
+            .unwrap()
+            .addr,
+        sender_addr
+    );
+}
+
+#[test]
+fn test_rotate_key_fails() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let accounts = &mut Accounts::<C>::default();
+
+    let sender_1 = DefaultPrivateKey::generate().pub_key();
+    let sender_1_addr = sender_1.to_address::<<C as Spec>::Address>();
+    let sequencer = DefaultPrivateKey::generate().pub_key();
+    let sender_context_1 = C::new(sender_1_addr, sequencer.to_address(), 1);
+
+    accounts
+        .create_default_account(&sender_1, working_set)
+        .unwrap();
+
+    let priv_key = DefaultPrivateKey::generate();
+    let sender_2 = priv_key.pub_key();
+    accounts
+        .create_default_account(&sender_2, working_set)
+        .unwrap();
+
+    // The new public key already maps to a different account.
+    let sig = priv_key.sign(&rotate_key_message::<C>(&sender_1_addr));
+    let err = accounts
+        .call(
+            call::CallMessage::<C>::RotateKey(sender_2.clone(), sig),
+            &sender_context_1,
+            working_set,
+        )
+        .unwrap_err();
+    assert!(err.to_string().contains("already belongs to account"));
+
+    // A signature over the message of another account is rejected.
+    let new_priv_key = DefaultPrivateKey::generate();
+    let sig = new_priv_key.sign(&rotate_key_message::<C>(&sender_2.to_address()));
+    assert!(accounts
+        .call(
+            call::CallMessage::<C>::RotateKey(new_priv_key.pub_key(), sig),
+            &sender_context_1,
+            working_set
+        )
+        .is_err());
+
+    // Both accounts keep their keys.
+    assert_eq!(
+        accounts.public_keys.get(&sender_1_addr, working_set),
+        Some(sender_1)
+    );
+    assert_eq!(
+        accounts.accounts.get(&sender_2, working_set).unwrap().addr,
+        sender_2.to_address::<<C as Spec>::Address>()
+    );
+}
+
+#[test]
+fn test_get_nonce() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let accounts = &mut Accounts::<C>::default();
+
+    let priv_key = DefaultPrivateKey::generate();
+    let sender_addr = priv_key.pub_key().to_address::<<C as Spec>::Address>();
+    let sequencer = DefaultPrivateKey::generate().pub_key();
+
+    // The account doesn't exist yet.
+    assert_eq!(accounts.get_nonce(sender_addr, working_set).unwrap(), 0);
+
+    for nonce in 0..2 {
+        // Each transaction is built with the nonce reported by the RPC.
+        assert_eq!(accounts.get_nonce(sender_addr, working_set).unwrap(), nonce);
+        let tx = Transaction::<C>::new_signed_tx(&priv_key, vec![], 0, 0, 0, nonce);
+        let hook_result = accounts
+            .pre_dispatch_tx_hook(&tx, working_set, &sequencer)
+            .unwrap();
+        let sender_context = C::new(hook_result.sender, hook_result.sequencer, 1);
+        accounts
+            .post_dispatch_tx_hook(&tx, &sender_context, working_set)
+            .unwrap();
+    }
+
+    assert_eq!(accounts.get_nonce(sender_addr, working_set).unwrap(), 2);
+    // A transaction reusing the last nonce is rejected.
+    let tx = Transaction::<C>::new_signed_tx(&priv_key, vec![], 0, 0, 0, 1);
+    assert!(accounts
+        .pre_dispatch_tx_hook(&tx, working_set, &sequencer)
+        .is_err());
+}
+
 #[test]
 fn test_response_serialization() {
     let addr: Vec<u8> = (1..=32).collect();
@@ -193,6 +193,10 @@ pub enum AttesterIncentiveErrors {
     #[error("Error when trying to mint the reward token")]
     /// An error occurred when trying to mint the reward token
     MintFailure,
+
+    #[error("Proof verification is not supported by the configured zkVM")]
+    /// The configured zkVM can't verify proofs in the current environment
+    VerificationUnsupported,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -246,6 +250,7 @@ where
                 // to prevent him from skipping the first phase
                 // unbonding if he bonds himself again.
                 self.unbonding_attesters.remove(user, working_set);
+                self.unbonding_amounts.remove(user, working_set);
 
                 &self.bonded_attesters
             }
@@ -424,6 +429,17 @@ where
                 },
                 working_set,
             );
+
+            // Keep track of the pending amount and when it can be withdrawn
+            let finality_period = self
+                .rollup_finality_period
+                .get(working_set)
+                .expect("Must be set at genesis");
+            self.unbonding_amounts.set(
+                context.sender(),
+                &(bond, finalized_height.saturating_add(finality_period)),
+                working_set,
+            );
         }
 
         Ok(CallResponse::default())
@@ -464,6 +480,7 @@ where
             self.bonded_attesters.remove(context.sender(), working_set);
             self.unbonding_attesters
                 .remove(context.sender(), working_set);
+            self.unbonding_amounts.remove(context.sender(), working_set);
 
             // Emit the unbonding event
             working_set.add_event("unbonded_challenger", {
@@ -805,6 +822,11 @@ where
         transition_num: &TransitionHeight,
         working_set: &mut WorkingSet<C>,
     ) -> anyhow::Result<CallResponse, AttesterIncentiveErrors> {
+        // Revert before any slashing if the proof can't be checked at all.
+        if !Vm::supports_verification() {
+            return Err(AttesterIncentiveErrors::VerificationUnsupported);
+        }
+
         // Get the challenger's old balance.
         // Revert if they aren't bonded
         let old_balance = self
@@ -84,6 +84,11 @@ where
     #[state]
     pub unbonding_attesters: sov_modules_api::StateMap<C::Address, UnbondingInfo>,
 
+    /// The pending unbonding amount of each unbonding attester, along with
+    /// the finalized height from which it can be withdrawn.
+    #[state]
+    pub unbonding_amounts: sov_modules_api::StateMap<C::Address, (Amount, TransitionHeight)>,
+
     /// The current maximum attestation height
     #[state]
     pub maximum_attested_height: sov_modules_api::StateValue<TransitionHeight>,
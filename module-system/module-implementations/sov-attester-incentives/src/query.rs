@@ -1,6 +1,6 @@
 //! Defines the query methods for the attester incentives module
 use serde::{Deserialize, Serialize};
-use sov_modules_api::{Spec, StateMapAccessor, ValidityConditionChecker, WorkingSet};
+use sov_modules_api::{StateMapAccessor, ValidityConditionChecker, WorkingSet};
 use sov_state::storage::{NativeStorage, Storage, StorageKey, StorageProof};
 
 use super::AttesterIncentives;
@@ -13,6 +13,15 @@ pub struct BondAmountResponse {
     pub value: u64,
 }
 
+/// The response type to the `getAllBonds` query.
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
+pub struct AllBondsResponse {
+    /// The value of the attester bond
+    pub attester: u64,
+    /// The value of the challenger bond
+    pub challenger: u64,
+}
+
 // TODO: implement rpc_gen macro
 impl<C, Vm, Da, Checker> AttesterIncentives<C, Vm, Da, Checker>
 where
@@ -44,6 +53,24 @@ where
         }
     }
 
+    /// Queries the attester and challenger bonds of an address at once.
+    pub fn get_all_bonds(
+        &self,
+        address: C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> AllBondsResponse {
+        AllBondsResponse {
+            attester: self
+                .bonded_attesters
+                .get(&address, working_set)
+                .unwrap_or_default(),
+            challenger: self
+                .bonded_challengers
+                .get(&address, working_set)
+                .unwrap_or_default(),
+        }
+    }
+
     /// Gives storage key for given address
     pub fn get_attester_storage_key(&self, address: C::Address) -> StorageKey {
         let prefix = self.bonded_attesters.prefix();
@@ -65,13 +92,16 @@ where
         working_set.get_with_proof(self.get_attester_storage_key(address))
     }
 
-    /// TODO: Make the unbonding amount queryable:
+    /// Returns the amount of tokens the attester is unbonding, or 0 if the attester is not
+    /// unbonding. The amount stays pending until the attester ends unbonding.
     pub fn get_unbonding_amount(
         &self,
-        _address: C::Address,
-        _witness: &<<C as Spec>::Storage as Storage>::Witness,
-        _working_set: &mut WorkingSet<C>,
+        address: C::Address,
+        working_set: &mut WorkingSet<C>,
     ) -> u64 {
-        todo!("Make the unbonding amount queryable: https://github.com/Sovereign-Labs/sovereign-sdk/issues/675")
+        self.unbonding_amounts
+            .get(&address, working_set)
+            .map(|(amount, _withdrawable_height)| amount)
+            .unwrap_or_default()
     }
 }
@@ -9,8 +9,8 @@ use sov_rollup_interface::zk::StateTransition;
 
 use crate::call::{AttesterIncentiveErrors, SlashingReason};
 use crate::tests::helpers::{
-    commit_get_new_working_set, execution_simulation, setup, BOND_AMOUNT, INITIAL_BOND_AMOUNT,
-    INIT_HEIGHT,
+    commit_get_new_working_set, execution_simulation, setup, setup_with_vm, UnverifiableZkvm,
+    BOND_AMOUNT, INITIAL_BOND_AMOUNT, INIT_HEIGHT,
 };
 
 /// Test that given an invalid transition, a challenger can successfully challenge it and get rewarded
@@ -66,7 +66,7 @@ fn test_valid_challenge() {
             initial_state_root: initial_transition.state_root,
             slot_hash: [1; 32].into(),
             final_state_root: transition_1.state_root,
-            validity_condition: MockValidityCond { is_valid: true },
+            validity_condition: MockValidityCond::default(),
         };
 
         let serialized_transition = transition.try_to_vec().unwrap();
@@ -194,7 +194,7 @@ fn test_invalid_challenge() {
         initial_state_root: initial_transition.state_root,
         slot_hash: [1; 32].into(),
         final_state_root: transition_1.state_root,
-        validity_condition: MockValidityCond { is_valid: true },
+        validity_condition: MockValidityCond::default(),
     };
 
     let serialized_transition = transition.try_to_vec().unwrap();
@@ -254,7 +254,7 @@ fn test_invalid_challenge() {
             initial_state_root: initial_transition.state_root,
             slot_hash: [2; 32].into(),
             final_state_root: transition_1.state_root,
-            validity_condition: MockValidityCond { is_valid: true },
+            validity_condition: MockValidityCond::default(),
         }
         .try_to_vec()
         .unwrap();
@@ -281,7 +281,10 @@ fn test_invalid_challenge() {
             initial_state_root: initial_transition.state_root,
             slot_hash: [1; 32].into(),
             final_state_root: transition_1.state_root,
-            validity_condition: MockValidityCond { is_valid: false },
+            validity_condition: MockValidityCond {
+                is_valid: false,
+                ..Default::default()
+            },
         }
         .try_to_vec()
         .unwrap();
@@ -308,7 +311,7 @@ fn test_invalid_challenge() {
             initial_state_root: transition_1.state_root,
             slot_hash: [1; 32].into(),
             final_state_root: transition_1.state_root,
-            validity_condition: MockValidityCond { is_valid: true },
+            validity_condition: MockValidityCond::default(),
         }
         .try_to_vec()
         .unwrap();
@@ -331,3 +334,47 @@ fn test_invalid_challenge() {
         );
     }
 }
+
+/// Test that a challenge is reverted, without slashing the challenger, if the zkVM can't verify proofs
+#[test]
+fn test_challenge_reverts_if_verification_unsupported() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let mut working_set = WorkingSet::new(storage);
+    let (module, _, _, challenger_address, sequencer) =
+        setup_with_vm::<UnverifiableZkvm>(&mut working_set);
+
+    module
+        .bond_user_helper(
+            BOND_AMOUNT,
+            &challenger_address,
+            crate::call::Role::Challenger,
+            &mut working_set,
+        )
+        .unwrap();
+
+    let context = DefaultContext::new(challenger_address, sequencer, INIT_HEIGHT + 2);
+    let proof = MockProof {
+        program_id: MockCodeCommitment([0; 32]),
+        is_valid: true,
+        log: &[],
+    }
+    .encode_to_vec();
+
+    let err = module
+        .process_challenge(&context, &proof, &(INIT_HEIGHT + 1), &mut working_set)
+        .unwrap_err();
+    assert_eq!(err, AttesterIncentiveErrors::VerificationUnsupported);
+
+    // The challenger isn't slashed for it
+    assert_eq!(
+        module
+            .get_bond_amount(
+                challenger_address,
+                crate::call::Role::Challenger,
+                &mut working_set
+            )
+            .value,
+        BOND_AMOUNT
+    );
+}
@@ -7,11 +7,13 @@ use sov_mock_zkvm::{MockCodeCommitment, MockZkvm};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{
-    Address, Genesis, KernelModule, KernelWorkingSet, Spec, ValidityConditionChecker, WorkingSet,
+    Address, Genesis, KernelModule, KernelWorkingSet, Spec, StateRoot, ValidityConditionChecker,
+    WorkingSet,
 };
 use sov_modules_core::runtime::capabilities::mocks::MockKernel;
 use sov_prover_storage_manager::SnapshotManager;
-use sov_rollup_interface::da::Time;
+use sov_rollup_interface::da::{DaSpec, Time};
+use sov_rollup_interface::zk::{StateTransition, Zkvm};
 use sov_state::storage::{NativeStorage, Storage, StorageProof};
 use sov_state::{DefaultStorageSpec, ProverStorage};
 
@@ -31,7 +33,7 @@ pub const INIT_HEIGHT: u64 = 0;
 pub(crate) fn commit_get_new_working_set(
     storage: &ProverStorage<DefaultStorageSpec, SnapshotManager>,
     working_set: WorkingSet<C>,
-) -> (jmt::RootHash, WorkingSet<C>) {
+) -> (StateRoot, WorkingSet<C>) {
     let (reads_writes, witness) = working_set.checkpoint().freeze();
 
     let prev_root = storage
@@ -73,6 +75,36 @@ pub(crate) fn create_bank_config_with_token(
     )
 }
 
+/// A zkVM which can't verify proofs in the current environment, like a guest zkVM running natively.
+pub(crate) struct UnverifiableZkvm;
+
+impl Zkvm for UnverifiableZkvm {
+    type CodeCommitment = MockCodeCommitment;
+
+    type Error = anyhow::Error;
+
+    fn verify<'a>(
+        _serialized_proof: &'a [u8],
+        _code_commitment: &Self::CodeCommitment,
+    ) -> Result<&'a [u8], Self::Error> {
+        anyhow::bail!("Proofs can't be verified in this environment")
+    }
+
+    fn verify_and_extract_output<
+        Da: DaSpec,
+        Root: serde::Serialize + serde::de::DeserializeOwned,
+    >(
+        _serialized_proof: &[u8],
+        _code_commitment: &Self::CodeCommitment,
+    ) -> Result<StateTransition<Da, Root>, Self::Error> {
+        anyhow::bail!("Proofs can't be verified in this environment")
+    }
+
+    fn supports_verification() -> bool {
+        false
+    }
+}
+
 /// Creates a bank config with a token, and a prover incentives module.
 /// Returns the prover incentives module and the attester and challenger's addresses.
 #[allow(clippy::type_complexity)]
@@ -89,6 +121,20 @@ pub(crate) fn setup(
     Address,
     Address,
     Address,
+) {
+    setup_with_vm(working_set)
+}
+
+/// Like [`setup`], with a module verifying the proofs of challenges with `Vm`.
+#[allow(clippy::type_complexity)]
+pub(crate) fn setup_with_vm<Vm: Zkvm<CodeCommitment = MockCodeCommitment>>(
+    working_set: &mut WorkingSet<C>,
+) -> (
+    AttesterIncentives<C, Vm, MockDaSpec, MockValidityCondChecker<MockValidityCond>>,
+    Address,
+    Address,
+    Address,
+    Address,
 ) {
     // Initialize bank
     let (bank_config, mut addresses) =
@@ -108,6 +154,7 @@ pub(crate) fn setup(
     let chain_state_config = sov_chain_state::ChainStateConfig {
         initial_slot_height: INIT_HEIGHT,
         current_time: Default::default(),
+        slot_hash_retention: sov_chain_state::DEFAULT_SLOT_HASH_RETENTION,
     };
 
     let chain_state = sov_chain_state::ChainState::<C, MockDaSpec>::default();
@@ -116,12 +163,8 @@ pub(crate) fn setup(
         .expect("Chain state genesis must succeed");
 
     // initialize prover incentives
-    let module = AttesterIncentives::<
-        C,
-        MockZkvm<MockValidityCond>,
-        MockDaSpec,
-        MockValidityCondChecker<MockValidityCond>,
-    >::default();
+    let module: AttesterIncentives<C, Vm, MockDaSpec, MockValidityCondChecker<MockValidityCond>> =
+        Default::default();
     let config = crate::AttesterIncentivesConfig {
         bonding_token_address: token_address,
         reward_token_supply_address: reward_supply,
@@ -150,7 +193,7 @@ pub(crate) fn setup(
 }
 
 pub(crate) struct ExecutionSimulationVars {
-    pub state_root: jmt::RootHash,
+    pub state_root: StateRoot,
     pub state_proof: StorageProof<SparseMerkleProof<<C as Spec>::Hasher>>,
 }
 
@@ -189,7 +232,7 @@ pub(crate) fn execution_simulation<Checker: ValidityConditionChecker<MockValidit
                 height: INIT_HEIGHT + u64::from(i + 1),
                 time: Time::now(),
             },
-            validity_cond: MockValidityCond { is_valid: true },
+            validity_cond: MockValidityCond::default(),
             blobs: Default::default(),
         };
         let kernel = MockKernel::<C, MockDaSpec>::new(i as u64, i as u64);
@@ -3,4 +3,5 @@ pub(crate) mod helpers;
 mod attestation_processing;
 mod challenger;
 mod invariant;
+mod query;
 mod unbonding;
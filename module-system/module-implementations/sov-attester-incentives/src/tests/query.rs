@@ -0,0 +1,31 @@
+use sov_modules_api::WorkingSet;
+use sov_prover_storage_manager::new_orphan_storage;
+
+use crate::tests::helpers::{setup, BOND_AMOUNT};
+use crate::AllBondsResponse;
+
+#[test]
+fn test_get_all_bonds() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let mut working_set = WorkingSet::new(storage.clone());
+    let (module, _, attester_address, challenger_address, _) = setup(&mut working_set);
+
+    // The attester is only bonded as an attester at genesis
+    assert_eq!(
+        module.get_all_bonds(attester_address, &mut working_set),
+        AllBondsResponse {
+            attester: BOND_AMOUNT,
+            challenger: 0,
+        }
+    );
+
+    // The challenger isn't bonded yet
+    assert_eq!(
+        module.get_all_bonds(challenger_address, &mut working_set),
+        AllBondsResponse {
+            attester: 0,
+            challenger: 0,
+        }
+    );
+}
@@ -163,3 +163,53 @@ fn test_two_phase_unbonding() {
         );
     }
 }
+
+#[test]
+fn test_get_unbonding_amount() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let mut working_set = WorkingSet::new(storage.clone());
+    let (module, _, attester_address, _, sequencer) = setup(&mut working_set);
+
+    let context = DefaultContext::new(attester_address, sequencer, INIT_HEIGHT + 2);
+
+    // The attester is bonded but not unbonding yet
+    assert_eq!(
+        module.get_unbonding_amount(attester_address, &mut working_set),
+        0
+    );
+
+    module
+        .begin_unbond_attester(&context, &mut working_set)
+        .unwrap();
+
+    assert_eq!(
+        module
+            .unbonding_amounts
+            .get(&attester_address, &mut working_set),
+        Some((BOND_AMOUNT, INIT_HEIGHT + DEFAULT_ROLLUP_FINALITY)),
+        "Invalid withdrawable height"
+    );
+    assert_eq!(
+        module.get_unbonding_amount(attester_address, &mut working_set),
+        BOND_AMOUNT
+    );
+
+    // Wait for the light client to finalize: the amount is pending until it is withdrawn
+    module
+        .light_client_finalized_height
+        .set(&(INIT_HEIGHT + DEFAULT_ROLLUP_FINALITY), &mut working_set);
+    assert_eq!(
+        module.get_unbonding_amount(attester_address, &mut working_set),
+        BOND_AMOUNT
+    );
+
+    module
+        .end_unbond_attester(&context, &mut working_set)
+        .unwrap();
+
+    assert_eq!(
+        module.get_unbonding_amount(attester_address, &mut working_set),
+        0
+    );
+}
@@ -3,7 +3,7 @@ use anyhow::{bail, Context, Result};
 use sov_modules_api::macros::CliWalletArg;
 use sov_modules_api::{CallResponse, StateMapAccessor, WorkingSet};
 
-use crate::{Amount, Bank, Coins, Token};
+use crate::{Amount, Bank, BankError, Coins, MintRecipient, Token};
 
 /// This enumeration represents the available call messages for interacting with the sov-bank module.
 #[cfg_attr(
@@ -60,6 +60,40 @@ pub enum CallMessage<C: sov_modules_api::Context> {
         /// Address of the token to be frozen
         token_address: C::Address,
     },
+
+    /// Allows `spender` to transfer up to `coins` from the sender's account, replacing any
+    /// previous allowance for the same token.
+    Approve {
+        /// The address allowed to spend the tokens.
+        spender: C::Address,
+        /// The token and the amount which may be spent.
+        coins: Coins<C>,
+    },
+
+    /// Transfers tokens from `owner` to `to`, spending the allowance `owner` approved for the sender.
+    TransferFrom {
+        /// The address from which the tokens will be transferred.
+        owner: C::Address,
+        /// The address to which the tokens will be transferred.
+        to: C::Address,
+        /// The amount of tokens to transfer.
+        coins: Coins<C>,
+    },
+
+    /// Unfreezes a frozen token so that its authorized minters can mint again
+    Unfreeze {
+        /// Address of the token to be unfrozen
+        token_address: C::Address,
+    },
+
+    /// Mints the specified amounts of a token to several addresses at once.
+    /// Every recipient is charged the gas of a single mint.
+    MintMany {
+        /// Address of the token to be minted.
+        token_address: C::Address,
+        /// The addresses to mint tokens to, with the amount each of them receives.
+        coins_per_recipient: Vec<MintRecipient<C>>,
+    },
 }
 
 impl<C: sov_modules_api::Context> Bank<C> {
@@ -124,11 +158,11 @@ impl<C: sov_modules_api::Context> Bank<C> {
         let mut token = self
             .tokens
             .get_or_err(&coins.token_address, working_set)
+            .map_err(BankError::TokenNotFound)
             .with_context(context_logger)?;
         token
             .burn(owner, coins.amount, working_set)
             .with_context(context_logger)?;
-        token.total_supply -= coins.amount;
         self.tokens.set(&coins.token_address, &token, working_set);
 
         Ok(())
@@ -180,6 +214,7 @@ impl<C: sov_modules_api::Context> Bank<C> {
         let mut token = self
             .tokens
             .get_or_err(&coins.token_address, working_set)
+            .map_err(BankError::TokenNotFound)
             .with_context(context_logger)?;
         token
             .mint(authorizer, mint_to_address, coins.amount, working_set)
@@ -189,6 +224,39 @@ impl<C: sov_modules_api::Context> Bank<C> {
         Ok(())
     }
 
+    /// Mints the token stored at `token_address` to each of the `recipients` if `authorizer` is an
+    /// allowed minter. Returns an error if the token address doesn't exist, `authorizer` is not
+    /// authorized to mint tokens, or a balance or the total supply would overflow.
+    ///
+    /// Nothing is minted unless all the `recipients` can be credited.
+    pub fn mint_many(
+        &self,
+        token_address: &C::Address,
+        recipients: &[MintRecipient<C>],
+        authorizer: &C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<()> {
+        let context_logger = || {
+            format!(
+                "Failed mint of token_address={} to {} recipients by authorizer {}",
+                token_address,
+                recipients.len(),
+                authorizer
+            )
+        };
+        let mut token = self
+            .tokens
+            .get_or_err(token_address, working_set)
+            .map_err(BankError::TokenNotFound)
+            .with_context(context_logger)?;
+        token
+            .mint_many(authorizer, recipients, working_set)
+            .with_context(context_logger)?;
+        self.tokens.set(token_address, &token, working_set);
+
+        Ok(())
+    }
+
     /// Tries to freeze the token address `token_address`.
     /// Returns an error if the token address doesn't exist,
     /// otherwise calls the [`Token::freeze`] function, and update the token set upon success.
@@ -208,6 +276,7 @@ impl<C: sov_modules_api::Context> Bank<C> {
         let mut token = self
             .tokens
             .get_or_err(&token_address, working_set)
+            .map_err(BankError::TokenNotFound)
             .with_context(context_logger)?;
         token
             .freeze(context.sender())
@@ -216,6 +285,99 @@ impl<C: sov_modules_api::Context> Bank<C> {
 
         Ok(CallResponse::default())
     }
+
+    /// Tries to unfreeze the token address `token_address`.
+    /// Returns an error if the token address doesn't exist,
+    /// otherwise calls the [`Token::unfreeze`] function, and update the token set upon success.
+    pub(crate) fn unfreeze(
+        &self,
+        token_address: C::Address,
+        context: &C,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<CallResponse> {
+        let context_logger = || {
+            format!(
+                "Failed unfreeze token_address={} by sender {}",
+                token_address,
+                context.sender()
+            )
+        };
+        let mut token = self
+            .tokens
+            .get_or_err(&token_address, working_set)
+            .map_err(BankError::TokenNotFound)
+            .with_context(context_logger)?;
+        token
+            .unfreeze(context.sender())
+            .with_context(context_logger)?;
+        self.tokens.set(&token_address, &token, working_set);
+
+        Ok(CallResponse::default())
+    }
+
+    /// Sets the amount of `coins` the address `spender` can transfer from the account of
+    /// `context.sender()`. Returns an error if the token address doesn't exist.
+    pub fn approve(
+        &self,
+        spender: C::Address,
+        coins: Coins<C>,
+        context: &C,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<CallResponse> {
+        self.tokens
+            .get_or_err(&coins.token_address, working_set)
+            .map_err(BankError::TokenNotFound)
+            .with_context(|| {
+                format!(
+                    "Failed approve of coins({}) by owner={} for spender={}",
+                    coins,
+                    context.sender(),
+                    spender
+                )
+            })?;
+        self.allowances.set(
+            &(coins.token_address, context.sender().clone(), spender),
+            &coins.amount,
+            working_set,
+        );
+
+        Ok(CallResponse::default())
+    }
+
+    /// Transfers the set of `coins` from the address `owner` to the address `to` on behalf of
+    /// `context.sender()`, and reduces the allowance of the sender by the transferred amount.
+    ///
+    /// Returns an error if the allowance or the balance of `owner` is insufficient.
+    pub fn transfer_from_allowance(
+        &self,
+        owner: C::Address,
+        to: C::Address,
+        coins: Coins<C>,
+        context: &C,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<CallResponse> {
+        let spender = context.sender();
+        let allowance_key = (coins.token_address.clone(), owner.clone(), spender.clone());
+        let remaining_allowance = self
+            .allowances
+            .get(&allowance_key, working_set)
+            .and_then(|allowance| allowance.checked_sub(coins.amount))
+            .ok_or_else(|| BankError::InsufficientAllowance {
+                owner: owner.to_string(),
+                spender: spender.to_string(),
+            })
+            .with_context(|| {
+                format!(
+                    "Failed transfer from={} to={} of coins({}) by spender={}",
+                    owner, to, coins, spender
+                )
+            })?;
+
+        let response = self.transfer_from(&owner, &to, coins, working_set)?;
+        self.allowances
+            .set(&allowance_key, &remaining_allowance, working_set);
+        Ok(response)
+    }
 }
 
 impl<C: sov_modules_api::Context> Bank<C> {
@@ -238,9 +400,11 @@ impl<C: sov_modules_api::Context> Bank<C> {
         let token = self
             .tokens
             .get_or_err(&coins.token_address, working_set)
+            .map_err(BankError::TokenNotFound)
             .with_context(context_logger)?;
         token
             .transfer(from, to, coins.amount, working_set)
+            .with_context(|| format!("Incorrect balance on={} for token={}", from, token.name))
             .with_context(context_logger)?;
         Ok(CallResponse::default())
     }
@@ -259,6 +423,20 @@ impl<C: sov_modules_api::Context> Bank<C> {
             .and_then(|token| token.balances.get(&user_address, working_set))
     }
 
+    /// Helper function used by the rpc method [`allowance`](Bank::allowance) to return the amount
+    /// of the token stored at `token_address` which `spender` can still transfer on behalf of
+    /// `owner`. Returns `None` if no allowance was ever approved.
+    pub fn get_allowance(
+        &self,
+        owner: C::Address,
+        spender: C::Address,
+        token_address: C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<Amount> {
+        self.allowances
+            .get(&(token_address, owner, spender), working_set)
+    }
+
     /// Get the name of a token by address
     pub fn get_token_name(
         &self,
@@ -279,6 +457,18 @@ impl<C: sov_modules_api::Context> Bank<C> {
             .get(token_address, working_set)
             .map(|token| token.total_supply)
     }
+
+    /// Whether the supply of the token stored at the address `token_address` is frozen.
+    /// Returns `None` if the token doesn't exist.
+    pub fn is_frozen(
+        &self,
+        token_address: &C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<bool> {
+        self.tokens
+            .get(token_address, working_set)
+            .map(|token| token.frozen)
+    }
 }
 
 /// Creates a new prefix from an already existing prefix `parent_prefix` and a `token_address`
@@ -0,0 +1,59 @@
+use sov_modules_api::StateMapError;
+use thiserror::Error;
+
+/// The reasons an operation of the [`crate::Bank`] module can fail.
+///
+/// The public methods of the bank return [`anyhow::Error`] with added context, which can be
+/// downcast to this type to match on the failure reason.
+#[derive(Debug, Error)]
+pub enum BankError {
+    /// The account doesn't hold enough tokens for the operation.
+    #[error("Insufficient funds for {owner}")]
+    InsufficientBalance {
+        /// The account which was short on tokens.
+        owner: String,
+    },
+    /// The spender isn't approved to move that many tokens on behalf of the owner.
+    #[error("Insufficient allowance of {spender} for the funds of {owner}")]
+    InsufficientAllowance {
+        /// The account whose tokens were to be spent.
+        owner: String,
+        /// The account which tried to spend them.
+        spender: String,
+    },
+    /// The account has never held the token.
+    #[error(transparent)]
+    AccountNotFound(StateMapError),
+    /// No token exists at the requested address.
+    #[error(transparent)]
+    TokenNotFound(StateMapError),
+    /// The token supply is frozen, so no more tokens can be minted.
+    #[error("Attempt to mint frozen token {token_name}")]
+    TokenFrozen {
+        /// The name of the frozen token.
+        token_name: String,
+    },
+    /// The token was frozen before.
+    #[error("Token {token_name} is already frozen")]
+    TokenAlreadyFrozen {
+        /// The name of the frozen token.
+        token_name: String,
+    },
+    /// The sender is not allowed to mint, freeze or unfreeze the token.
+    #[error("Sender {sender} is not an authorized minter of token {token_name}")]
+    UnauthorizedMinter {
+        /// The sender of the rejected operation.
+        sender: String,
+        /// The name of the token.
+        token_name: String,
+    },
+    /// A balance or the total supply of a token would exceed `u64::MAX` or drop below zero.
+    #[error("{0}")]
+    Overflow(&'static str),
+    /// The token is not frozen, so it can't be unfrozen.
+    #[error("Token {token_name} is not frozen")]
+    TokenNotFrozen {
+        /// The name of the token.
+        token_name: String,
+    },
+}
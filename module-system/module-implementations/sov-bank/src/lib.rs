@@ -1,6 +1,7 @@
 #![deny(missing_docs)]
 #![doc = include_str!("../README.md")]
 mod call;
+mod error;
 mod genesis;
 mod hooks;
 #[cfg(feature = "native")]
@@ -11,12 +12,13 @@ mod token;
 /// Util functions for bank
 pub mod utils;
 pub use call::*;
+pub use error::BankError;
 pub use genesis::*;
 pub use hooks::BankTxHook;
 use sov_modules_api::{CallResponse, Error, GasUnit, ModuleInfo, WorkingSet};
 use token::Token;
 /// Specifies an interface to interact with tokens.
-pub use token::{Amount, Coins};
+pub use token::{Amount, Coins, MintRecipient};
 /// Methods to get a token address.
 pub use utils::{get_genesis_token_address, get_token_address};
 
@@ -43,6 +45,7 @@ pub struct BankGasConfig<GU: GasUnit> {
 /// - Token creation.
 /// - Token transfers.
 /// - Token burn.
+/// - Allowances, which let an account spend tokens on behalf of another one.
 #[cfg_attr(feature = "native", derive(sov_modules_api::ModuleCallJsonSchema))]
 #[derive(ModuleInfo, Clone)]
 pub struct Bank<C: sov_modules_api::Context> {
@@ -57,6 +60,11 @@ pub struct Bank<C: sov_modules_api::Context> {
     /// A mapping of addresses to tokens in the sov-bank.
     #[state]
     pub(crate) tokens: sov_modules_api::StateMap<C::Address, Token<C>>,
+
+    /// The amounts spenders may transfer on behalf of owners, keyed by
+    /// `(token_address, owner, spender)`.
+    #[state]
+    pub(crate) allowances: sov_modules_api::StateMap<(C::Address, C::Address, C::Address), Amount>,
 }
 
 impl<C: sov_modules_api::Context> sov_modules_api::Module for Bank<C> {
@@ -118,10 +126,42 @@ impl<C: sov_modules_api::Context> sov_modules_api::Module for Bank<C> {
                 Ok(CallResponse::default())
             }
 
+            call::CallMessage::MintMany {
+                token_address,
+                coins_per_recipient,
+            } => {
+                // Each recipient costs as much as a single mint.
+                let mut gas = self.gas.mint.clone();
+                gas.scalar_product(coins_per_recipient.len() as u64);
+                self.charge_gas(working_set, &gas)?;
+                self.mint_many(
+                    &token_address,
+                    &coins_per_recipient,
+                    context.sender(),
+                    working_set,
+                )?;
+                Ok(CallResponse::default())
+            }
+
             call::CallMessage::Freeze { token_address } => {
                 self.charge_gas(working_set, &self.gas.freeze)?;
                 Ok(self.freeze(token_address, context, working_set)?)
             }
+
+            call::CallMessage::Unfreeze { token_address } => {
+                self.charge_gas(working_set, &self.gas.freeze)?;
+                Ok(self.unfreeze(token_address, context, working_set)?)
+            }
+
+            call::CallMessage::Approve { spender, coins } => {
+                self.charge_gas(working_set, &self.gas.transfer)?;
+                Ok(self.approve(spender, coins, context, working_set)?)
+            }
+
+            call::CallMessage::TransferFrom { owner, to, coins } => {
+                self.charge_gas(working_set, &self.gas.transfer)?;
+                Ok(self.transfer_from_allowance(owner, to, coins, context, working_set)?)
+            }
         }
     }
 }
@@ -19,6 +19,20 @@ pub struct TotalSupplyResponse {
     pub amount: Option<Amount>,
 }
 
+/// Structure returned by the `allowance` rpc method.
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone)]
+pub struct AllowanceResponse {
+    /// The amount a spender can still transfer on behalf of an owner. Equivalent to u64.
+    pub amount: Option<Amount>,
+}
+
+/// Structure returned by the `isTokenFrozen` rpc method.
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone)]
+pub struct FrozenResponse {
+    /// Whether the token supply is frozen. `None` if the token doesn't exist.
+    pub frozen: Option<bool>,
+}
+
 #[rpc_gen(client, server, namespace = "bank")]
 impl<C: sov_modules_api::Context> Bank<C> {
     #[rpc_method(name = "balanceOf")]
@@ -54,4 +68,40 @@ impl<C: sov_modules_api::Context> Bank<C> {
             amount: self.get_total_supply_of(&token_address, working_set),
         })
     }
+
+    #[rpc_method(name = "allowance")]
+    /// Rpc method that returns the amount of the token stored at the address `token_address` that
+    /// `spender_address` can transfer on behalf of `owner_address`.
+    pub fn allowance(
+        &self,
+        version: Option<u64>,
+        owner_address: C::Address,
+        spender_address: C::Address,
+        token_address: C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<AllowanceResponse> {
+        if let Some(v) = version {
+            working_set.set_archival_version(v)
+        }
+        Ok(AllowanceResponse {
+            amount: self.get_allowance(owner_address, spender_address, token_address, working_set),
+        })
+    }
+
+    #[rpc_method(name = "isTokenFrozen")]
+    /// Rpc method that returns whether the supply of the token stored at the address
+    /// `token_address` is frozen.
+    pub fn is_token_frozen(
+        &self,
+        version: Option<u64>,
+        token_address: C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<FrozenResponse> {
+        if let Some(v) = version {
+            working_set.set_archival_version(v)
+        }
+        Ok(FrozenResponse {
+            frozen: self.is_frozen(&token_address, working_set),
+        })
+    }
 }
@@ -1,11 +1,11 @@
 #[cfg(feature = "native")]
 use core::str::FromStr;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::fmt::Formatter;
 #[cfg(feature = "native")]
 use std::num::ParseIntError;
 
-use anyhow::{bail, Context, Result};
+use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use sov_modules_api::{StateMapAccessor, WorkingSet};
 use sov_state::Prefix;
@@ -13,6 +13,7 @@ use sov_state::Prefix;
 use thiserror::Error;
 
 use crate::call::prefix_from_address_with_parent;
+use crate::BankError;
 
 /// Type alias to store an amount of token.
 pub type Amount = u64;
@@ -93,6 +94,45 @@ impl<C: sov_modules_api::Context> FromStr for Coins<C> {
         })
     }
 }
+
+/// An `amount` of tokens to be minted to the `address`, used by the
+/// [`CallMessage::MintMany`](crate::CallMessage::MintMany) message.
+#[cfg_attr(
+    feature = "native",
+    derive(schemars::JsonSchema),
+    schemars(bound = "C::Address: ::schemars::JsonSchema", rename = "MintRecipient")
+)]
+#[derive(
+    borsh::BorshDeserialize,
+    borsh::BorshSerialize,
+    Debug,
+    Clone,
+    Serialize,
+    Deserialize,
+    PartialEq,
+    Eq,
+)]
+pub struct MintRecipient<C: sov_modules_api::Context> {
+    /// The address receiving the minted tokens.
+    pub address: C::Address,
+    /// The `amount` of tokens minted to the address.
+    pub amount: Amount,
+}
+
+/// Parses a `MintRecipient` from the `amount,address` format also used by [`Coins`].
+#[cfg(feature = "native")]
+impl<C: sov_modules_api::Context> FromStr for MintRecipient<C> {
+    type Err = CoinsFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let Coins {
+            amount,
+            token_address: address,
+        } = s.parse::<Coins<C>>()?;
+        Ok(Self { address, amount })
+    }
+}
+
 impl<C: sov_modules_api::Context> std::fmt::Display for Coins<C> {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         // implement Display for Coins
@@ -115,11 +155,11 @@ pub(crate) struct Token<C: sov_modules_api::Context> {
     pub(crate) balances: sov_modules_api::StateMap<C::Address, Amount>,
 
     /// Vector containing the authorized minters
-    /// Empty vector indicates that the token supply is frozen
-    /// Non empty vector indicates members of the vector can mint.
-    /// Freezing a token requires emptying the vector
+    /// Members of the vector can mint, freeze and unfreeze the token.
     /// NOTE: This is explicit so if a creator doesn't add themselves, then they can't mint
     pub(crate) authorized_minters: Vec<C::Address>,
+    /// Whether the token supply is frozen, in which case no tokens can be minted.
+    pub(crate) frozen: bool,
 }
 
 impl<C: sov_modules_api::Context> Token<C> {
@@ -132,49 +172,77 @@ impl<C: sov_modules_api::Context> Token<C> {
         to: &C::Address,
         amount: Amount,
         working_set: &mut WorkingSet<C>,
-    ) -> Result<()> {
+    ) -> Result<(), BankError> {
         if from == to {
             return Ok(());
         }
-        let from_balance = self
-            .check_balance(from, amount, working_set)
-            .with_context(|| format!("Incorrect balance on={} for token={}", from, self.name))?;
+        let from_balance = self.check_balance(from, amount, working_set)?;
 
-        // We can't overflow here because the sum must be smaller or eq to `total_supply` which is u64.
-        let to_balance = self.balances.get(to, working_set).unwrap_or_default() + amount;
+        // The sum can't exceed `total_supply`, so this only fails if the token state is corrupted.
+        let to_balance = self
+            .balances
+            .get(to, working_set)
+            .unwrap_or_default()
+            .checked_add(amount)
+            .ok_or(BankError::Overflow(
+                "Account balance overflow in the transfer method of bank module",
+            ))?;
 
         self.balances.set(from, &from_balance, working_set);
         self.balances.set(to, &to_balance, working_set);
         Ok(())
     }
     /// Burns a specified `amount` of token from the address `from`. First check that the address has enough token to burn,
-    /// if not returns an error. Otherwise, update the balances by substracting the amount burnt.
+    /// if not returns an error. Otherwise, update the balances and the `total_supply` by substracting the amount burnt.
     pub(crate) fn burn(
         &mut self,
         from: &C::Address,
         amount: Amount,
         working_set: &mut WorkingSet<C>,
-    ) -> Result<()> {
+    ) -> Result<(), BankError> {
         let new_balance = self.check_balance(from, amount, working_set)?;
+        // The balance is part of `total_supply`, so this only fails if the token state is corrupted.
+        let new_total_supply = self
+            .total_supply
+            .checked_sub(amount)
+            .ok_or(BankError::Overflow(
+                "Total Supply underflow in the burn method of bank module",
+            ))?;
+
         self.balances.set(from, &new_balance, working_set);
+        self.total_supply = new_total_supply;
 
         Ok(())
     }
 
-    /// Freezing a token requires emptying the authorized_minter vector
-    /// authorized_minter: Vec<Address> is used to determine if the token is frozen or not
-    /// If the vector is empty when the function is called, this means the token is already frozen
-    pub(crate) fn freeze(&mut self, sender: &C::Address) -> Result<()> {
-        if self.authorized_minters.is_empty() {
-            bail!("Token {} is already frozen", self.name)
+    /// Freezes the token supply. Only an authorized minter can freeze a token,
+    /// and freezing a token which is already frozen fails.
+    pub(crate) fn freeze(&mut self, sender: &C::Address) -> Result<(), BankError> {
+        if self.frozen {
+            return Err(BankError::TokenAlreadyFrozen {
+                token_name: self.name.clone(),
+            });
         }
         self.is_authorized_minter(sender)?;
-        self.authorized_minters = vec![];
+        self.frozen = true;
+        Ok(())
+    }
+
+    /// Lifts a previous [`Token::freeze`], so that the authorized minters can mint again.
+    /// Only an authorized minter can unfreeze a token, and the token has to be frozen.
+    pub(crate) fn unfreeze(&mut self, sender: &C::Address) -> Result<(), BankError> {
+        if !self.frozen {
+            return Err(BankError::TokenNotFrozen {
+                token_name: self.name.clone(),
+            });
+        }
+        self.is_authorized_minter(sender)?;
+        self.frozen = false;
         Ok(())
     }
 
     /// Mints a given `amount` of token sent by `sender` to the specified `mint_to_address`.
-    /// Checks that the `authorized_minters` set is not empty for the token and that the `sender`
+    /// Checks that the token is not frozen and that the `sender`
     /// is an `authorized_minter`. If so, update the balances of token for the `mint_to_address` by
     /// adding the minted tokens. Updates the `total_supply` of that token.
     pub(crate) fn mint(
@@ -183,9 +251,11 @@ impl<C: sov_modules_api::Context> Token<C> {
         mint_to_address: &C::Address,
         amount: Amount,
         working_set: &mut WorkingSet<C>,
-    ) -> Result<()> {
-        if self.authorized_minters.is_empty() {
-            bail!("Attempt to mint frozen token {}", self.name)
+    ) -> Result<(), BankError> {
+        if self.frozen {
+            return Err(BankError::TokenFrozen {
+                token_name: self.name.clone(),
+            });
         }
 
         self.is_authorized_minter(authorizer)?;
@@ -194,27 +264,72 @@ impl<C: sov_modules_api::Context> Token<C> {
             .get(mint_to_address, working_set)
             .unwrap_or_default()
             .checked_add(amount)
-            .ok_or(anyhow::Error::msg(
+            .ok_or(BankError::Overflow(
                 "Account balance overflow in the mint method of bank module",
             ))?;
-
-        self.balances.set(mint_to_address, &to_balance, working_set);
-        self.total_supply = self
+        let total_supply = self
             .total_supply
             .checked_add(amount)
-            .ok_or(anyhow::Error::msg(
+            .ok_or(BankError::Overflow(
                 "Total Supply overflow in the mint method of bank module",
             ))?;
+
+        self.balances.set(mint_to_address, &to_balance, working_set);
+        self.total_supply = total_supply;
         Ok(())
     }
 
-    fn is_authorized_minter(&self, sender: &C::Address) -> Result<()> {
+    /// Mints tokens to each of the `recipients` on behalf of `authorizer`, checking like
+    /// [`Token::mint`] that the token is not frozen and that `authorizer` is an authorized minter.
+    /// Either all the recipients are credited, or none is if a balance or the `total_supply` overflows.
+    pub(crate) fn mint_many(
+        &mut self,
+        authorizer: &C::Address,
+        recipients: &[MintRecipient<C>],
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<(), BankError> {
+        if self.frozen {
+            return Err(BankError::TokenFrozen {
+                token_name: self.name.clone(),
+            });
+        }
+
+        self.is_authorized_minter(authorizer)?;
+        let mut total_supply = self.total_supply;
+        // The new balances are only written once all of them are known to fit. They are keyed
+        // by the address bytes, since the same address may be listed more than once.
+        let mut new_balances: BTreeMap<&[u8], (&C::Address, Amount)> = BTreeMap::new();
+        for MintRecipient { address, amount } in recipients {
+            total_supply = total_supply
+                .checked_add(*amount)
+                .ok_or(BankError::Overflow(
+                    "Total Supply overflow in the mint_many method of bank module",
+                ))?;
+
+            let (_, balance) = new_balances.entry(address.as_ref()).or_insert_with(|| {
+                (
+                    address,
+                    self.balances.get(address, working_set).unwrap_or_default(),
+                )
+            });
+            *balance = balance.checked_add(*amount).ok_or(BankError::Overflow(
+                "Account balance overflow in the mint_many method of bank module",
+            ))?;
+        }
+
+        for (address, balance) in new_balances.into_values() {
+            self.balances.set(address, &balance, working_set);
+        }
+        self.total_supply = total_supply;
+        Ok(())
+    }
+
+    fn is_authorized_minter(&self, sender: &C::Address) -> Result<(), BankError> {
         if !self.authorized_minters.contains(sender) {
-            bail!(
-                "Sender {} is not an authorized minter of token {}",
-                sender,
-                self.name
-            )
+            return Err(BankError::UnauthorizedMinter {
+                sender: sender.to_string(),
+                token_name: self.name.clone(),
+            });
         }
         Ok(())
     }
@@ -226,13 +341,16 @@ impl<C: sov_modules_api::Context> Token<C> {
         from: &C::Address,
         amount: Amount,
         working_set: &mut WorkingSet<C>,
-    ) -> Result<Amount> {
-        let balance = self.balances.get_or_err(from, working_set)?;
-        let new_balance = match balance.checked_sub(amount) {
-            Some(from_balance) => from_balance,
-            None => bail!("Insufficient funds for {}", from),
-        };
-        Ok(new_balance)
+    ) -> Result<Amount, BankError> {
+        let balance = self
+            .balances
+            .get_or_err(from, working_set)
+            .map_err(BankError::AccountNotFound)?;
+        balance
+            .checked_sub(amount)
+            .ok_or_else(|| BankError::InsufficientBalance {
+                owner: from.to_string(),
+            })
     }
 
     /// Creates a token from a given set of parameters.
@@ -261,7 +379,7 @@ impl<C: sov_modules_api::Context> Token<C> {
 
         let total_supply = match total_supply {
             Some(total_supply) => total_supply,
-            None => bail!("Total supply overflow"),
+            None => return Err(BankError::Overflow("Total supply overflow").into()),
         };
 
         let mut indices = HashSet::new();
@@ -278,6 +396,7 @@ impl<C: sov_modules_api::Context> Token<C> {
             total_supply,
             balances,
             authorized_minters: auth_minter_list,
+            frozen: false,
         };
 
         Ok((token_address, token))
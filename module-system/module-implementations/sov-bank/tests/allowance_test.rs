@@ -0,0 +1,193 @@
+use helpers::{generate_address, C};
+use sov_bank::{get_token_address, Bank, BankConfig, BankError, CallMessage, Coins};
+use sov_modules_api::{Address, Context, Error, Module, WorkingSet};
+use sov_prover_storage_manager::new_orphan_storage;
+
+mod helpers;
+
+const INITIAL_BALANCE: u64 = 100;
+
+#[test]
+fn transfer_from_spends_allowance() {
+    let bank = Bank::<C>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let empty_bank_config = BankConfig::<C> { tokens: vec![] };
+    bank.genesis(&empty_bank_config, &mut working_set).unwrap();
+
+    let sequencer_address = generate_address("sequencer");
+    let owner_address = generate_address("owner");
+    let owner = C::new(owner_address, sequencer_address, 1);
+    let spender_address = generate_address("spender");
+    let spender = C::new(spender_address, sequencer_address, 1);
+    let receiver_address = generate_address("receiver");
+
+    let create_token = |token_name: &str, working_set: &mut WorkingSet<C>| -> Address {
+        let salt = 0;
+        let token_address = get_token_address::<C>(token_name, owner_address.as_ref(), salt);
+        let create_message = CallMessage::CreateToken {
+            salt,
+            token_name: token_name.to_owned(),
+            initial_balance: INITIAL_BALANCE,
+            minter_address: owner_address,
+            authorized_minters: vec![],
+        };
+        bank.call(create_message, &owner, working_set)
+            .expect("Failed to create token");
+        token_address
+    };
+    let token_address = create_token("Token1", &mut working_set);
+    let other_token_address = create_token("Token2", &mut working_set);
+
+    let coins = |amount| Coins {
+        amount,
+        token_address,
+    };
+    let transfer_from = |amount, working_set: &mut WorkingSet<C>| {
+        bank.call(
+            CallMessage::TransferFrom {
+                owner: owner_address,
+                to: receiver_address,
+                coins: coins(amount),
+            },
+            &spender,
+            working_set,
+        )
+    };
+    let query_allowance = |working_set: &mut WorkingSet<C>| {
+        bank.get_allowance(owner_address, spender_address, token_address, working_set)
+    };
+    let query_balance = |user_address, working_set: &mut WorkingSet<C>| {
+        bank.get_balance_of(user_address, token_address, working_set)
+    };
+
+    // Nothing can be spent before an approval.
+    let Error::ModuleError(err) = transfer_from(1, &mut working_set).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<BankError>(),
+        Some(BankError::InsufficientAllowance { .. })
+    ));
+    assert_eq!(None, query_allowance(&mut working_set));
+
+    let approved = 60;
+    bank.call(
+        CallMessage::Approve {
+            spender: spender_address,
+            coins: coins(approved),
+        },
+        &owner,
+        &mut working_set,
+    )
+    .expect("Failed to approve");
+    assert_eq!(Some(approved), query_allowance(&mut working_set));
+    // The approval only covers the approved token.
+    assert_eq!(
+        None,
+        bank.get_allowance(
+            owner_address,
+            spender_address,
+            other_token_address,
+            &mut working_set
+        )
+    );
+
+    // Partial spend.
+    let spent = 40;
+    transfer_from(spent, &mut working_set).expect("Failed to transfer from owner");
+    assert_eq!(Some(approved - spent), query_allowance(&mut working_set));
+    assert_eq!(
+        Some(INITIAL_BALANCE - spent),
+        query_balance(owner_address, &mut working_set)
+    );
+    assert_eq!(
+        Some(spent),
+        query_balance(receiver_address, &mut working_set)
+    );
+
+    // Over-spend of the remaining allowance.
+    let Error::ModuleError(err) =
+        transfer_from(approved - spent + 1, &mut working_set).unwrap_err();
+    assert_eq!(
+        format!(
+            "Failed transfer from={} to={} of coins({}) by spender={}",
+            owner_address,
+            receiver_address,
+            coins(approved - spent + 1),
+            spender_address
+        ),
+        err.to_string()
+    );
+    assert!(matches!(
+        err.downcast_ref::<BankError>(),
+        Some(BankError::InsufficientAllowance { owner, spender })
+            if *owner == owner_address.to_string() && *spender == spender_address.to_string()
+    ));
+    assert_eq!(Some(approved - spent), query_allowance(&mut working_set));
+
+    // The allowance doesn't let the spender move more than the owner holds.
+    bank.call(
+        CallMessage::Approve {
+            spender: spender_address,
+            coins: coins(INITIAL_BALANCE + 1),
+        },
+        &owner,
+        &mut working_set,
+    )
+    .expect("Failed to approve");
+    let Error::ModuleError(err) = transfer_from(INITIAL_BALANCE, &mut working_set).unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<BankError>(),
+        Some(BankError::InsufficientBalance { .. })
+    ));
+    assert_eq!(Some(INITIAL_BALANCE + 1), query_allowance(&mut working_set));
+
+    // Spending the whole allowance.
+    transfer_from(INITIAL_BALANCE - spent, &mut working_set)
+        .expect("Failed to transfer from owner");
+    assert_eq!(Some(spent + 1), query_allowance(&mut working_set));
+    assert_eq!(Some(0), query_balance(owner_address, &mut working_set));
+    assert_eq!(
+        Some(INITIAL_BALANCE),
+        query_balance(receiver_address, &mut working_set)
+    );
+}
+
+#[test]
+fn approve_unknown_token_fails() {
+    let bank = Bank::<C>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let empty_bank_config = BankConfig::<C> { tokens: vec![] };
+    bank.genesis(&empty_bank_config, &mut working_set).unwrap();
+
+    let owner = C::new(generate_address("owner"), generate_address("sequencer"), 1);
+    let spender_address = generate_address("spender");
+    let token_address = generate_address("non_existing_token");
+
+    let Error::ModuleError(err) = bank
+        .call(
+            CallMessage::Approve {
+                spender: spender_address,
+                coins: Coins {
+                    amount: 1,
+                    token_address,
+                },
+            },
+            &owner,
+            &mut working_set,
+        )
+        .unwrap_err();
+    assert!(matches!(
+        err.downcast_ref::<BankError>(),
+        Some(BankError::TokenNotFound(_))
+    ));
+    assert_eq!(
+        None,
+        bank.get_allowance(
+            *owner.sender(),
+            spender_address,
+            token_address,
+            &mut working_set
+        )
+    );
+}
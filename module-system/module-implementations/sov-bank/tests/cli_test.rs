@@ -0,0 +1,82 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use clap::Parser;
+use helpers::{generate_address, C};
+use sov_bank::{CallMessage, Coins};
+use sov_modules_api::CliWalletArg;
+
+mod helpers;
+
+type CliCallMessage = <CallMessage<C> as CliWalletArg>::CliStringRepr;
+
+/// Parses the arguments of a `bank` subcommand and round-trips the resulting message through
+/// the borsh encoding the runtime receives.
+fn parse_call_message(args: &[&str]) -> CallMessage<C> {
+    let message: CallMessage<C> = CliCallMessage::try_parse_from(args)
+        .expect("The arguments should parse")
+        .into();
+    let serialized = message.try_to_vec().unwrap();
+    let deserialized = CallMessage::<C>::try_from_slice(&serialized).unwrap();
+    assert_eq!(message, deserialized);
+    deserialized
+}
+
+#[test]
+fn transfer_from_cli_args() {
+    let to = generate_address("receiver");
+    let token_address = generate_address("token");
+
+    let message = parse_call_message(&[
+        "bank",
+        "transfer",
+        &to.to_string(),
+        &format!("100,{}", token_address),
+    ]);
+
+    assert_eq!(
+        CallMessage::Transfer {
+            to,
+            coins: Coins {
+                amount: 100,
+                token_address,
+            },
+        },
+        message
+    );
+}
+
+#[test]
+fn create_token_from_cli_args() {
+    let minter_address = generate_address("minter");
+    let authorized_minters = vec![generate_address("minter_1"), generate_address("minter_2")];
+
+    let message = parse_call_message(&[
+        "bank",
+        "create-token",
+        "11",
+        "Token1",
+        "1000",
+        &minter_address.to_string(),
+        &authorized_minters[0].to_string(),
+        &authorized_minters[1].to_string(),
+    ]);
+
+    assert_eq!(
+        CallMessage::CreateToken {
+            salt: 11,
+            token_name: "Token1".to_owned(),
+            initial_balance: 1000,
+            minter_address,
+            authorized_minters,
+        },
+        message
+    );
+}
+
+#[test]
+fn malformed_cli_args_are_rejected() {
+    let to = generate_address("receiver").to_string();
+
+    // The coins are missing their token address.
+    assert!(CliCallMessage::try_parse_from(["bank", "transfer", &to, "100"]).is_err());
+    assert!(CliCallMessage::try_parse_from(["bank", "unknown-call"]).is_err());
+}
@@ -1,5 +1,5 @@
 use helpers::*;
-use sov_bank::{get_token_address, Bank, CallMessage};
+use sov_bank::{get_token_address, Bank, BankConfig, CallMessage};
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Context, Module, WorkingSet};
 use sov_prover_storage_manager::new_orphan_storage;
@@ -69,3 +69,45 @@ fn overflow_max_supply() {
         genesis_result.unwrap_err().to_string()
     );
 }
+
+#[test]
+fn same_token_name_and_salt_from_different_senders() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let bank = Bank::<C>::default();
+    let empty_bank_config = BankConfig::<C> { tokens: vec![] };
+    bank.genesis(&empty_bank_config, &mut working_set).unwrap();
+
+    let sequencer_address = generate_address::<C>("sequencer");
+    let token_name = "Token1".to_owned();
+    let salt = 0;
+    let create_token = |sender_name: &str, working_set: &mut WorkingSet<C>| {
+        let sender_address = generate_address::<C>(sender_name);
+        let sender_context = C::new(sender_address, sequencer_address, 1);
+        let create_token_message = CallMessage::CreateToken::<C> {
+            salt,
+            token_name: token_name.clone(),
+            initial_balance: 100,
+            minter_address: sender_address,
+            authorized_minters: vec![sender_address],
+        };
+        bank.call(create_token_message, &sender_context, working_set)
+            .map(|_| get_token_address::<C>(&token_name, sender_address.as_ref(), salt))
+    };
+
+    let first_token_address =
+        create_token("first_sender", &mut working_set).expect("Failed to create token");
+    // Another sender can still create a token with the same name and salt.
+    let second_token_address =
+        create_token("second_sender", &mut working_set).expect("Failed to create token");
+    assert_ne!(first_token_address, second_token_address);
+    for token_address in [first_token_address, second_token_address] {
+        assert_eq!(
+            Some(100),
+            bank.get_total_supply_of(&token_address, &mut working_set)
+        );
+    }
+
+    // The same sender can't create it twice.
+    assert!(create_token("first_sender", &mut working_set).is_err());
+}
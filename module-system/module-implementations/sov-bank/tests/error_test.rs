@@ -0,0 +1,262 @@
+use helpers::{generate_address, C};
+use sov_bank::{get_token_address, Bank, BankConfig, BankError, CallMessage, Coins};
+use sov_modules_api::{Address, Context, Error, Module, WorkingSet};
+use sov_modules_core::assert_no_state_change;
+use sov_prover_storage_manager::new_orphan_storage;
+
+mod helpers;
+
+const INITIAL_BALANCE: u64 = 100;
+
+struct TestBank {
+    bank: Bank<C>,
+    working_set: WorkingSet<C>,
+    minter: C,
+    token_address: Address,
+    _tmpdir: tempfile::TempDir,
+}
+
+fn setup() -> TestBank {
+    let bank = Bank::<C>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let empty_bank_config = BankConfig::<C> { tokens: vec![] };
+    bank.genesis(&empty_bank_config, &mut working_set).unwrap();
+
+    let minter_address = generate_address("minter");
+    let minter = C::new(minter_address, generate_address("sequencer"), 1);
+
+    let salt = 0;
+    let token_name = "Token1".to_owned();
+    let token_address = get_token_address::<C>(&token_name, minter_address.as_ref(), salt);
+    let create_message = CallMessage::CreateToken {
+        salt,
+        token_name,
+        initial_balance: INITIAL_BALANCE,
+        minter_address,
+        authorized_minters: vec![minter_address],
+    };
+    bank.call(create_message, &minter, &mut working_set)
+        .expect("Failed to create token");
+
+    TestBank {
+        bank,
+        working_set,
+        minter,
+        token_address,
+        _tmpdir: tmpdir,
+    }
+}
+
+impl TestBank {
+    fn call_err(&mut self, message: CallMessage<C>, context: &C) -> anyhow::Error {
+        let Error::ModuleError(err) = self
+            .bank
+            .call(message, context, &mut self.working_set)
+            .expect_err("The call should fail");
+        err
+    }
+
+    fn mint_message(&self, amount: u64) -> CallMessage<C> {
+        CallMessage::Mint {
+            coins: Coins {
+                amount,
+                token_address: self.token_address,
+            },
+            minter_address: *self.minter.sender(),
+        }
+    }
+}
+
+fn bank_error(err: &anyhow::Error) -> &BankError {
+    err.downcast_ref::<BankError>()
+        .expect("The error should be a BankError")
+}
+
+#[test]
+fn transfer_errors() {
+    let mut test_bank = setup();
+    let minter = test_bank.minter.clone();
+    let receiver = generate_address("receiver");
+
+    let err = test_bank.call_err(
+        CallMessage::Transfer {
+            to: receiver,
+            coins: Coins {
+                amount: INITIAL_BALANCE + 1,
+                token_address: test_bank.token_address,
+            },
+        },
+        &minter,
+    );
+    assert!(matches!(
+        bank_error(&err),
+        BankError::InsufficientBalance { owner } if *owner == minter.sender().to_string()
+    ));
+
+    let unknown_sender = C::new(receiver, generate_address("sequencer"), 1);
+    let err = test_bank.call_err(
+        CallMessage::Transfer {
+            to: *minter.sender(),
+            coins: Coins {
+                amount: 1,
+                token_address: test_bank.token_address,
+            },
+        },
+        &unknown_sender,
+    );
+    assert!(matches!(bank_error(&err), BankError::AccountNotFound(_)));
+
+    let err = test_bank.call_err(
+        CallMessage::Transfer {
+            to: receiver,
+            coins: Coins {
+                amount: 1,
+                token_address: generate_address("non_existing_token"),
+            },
+        },
+        &minter,
+    );
+    assert!(matches!(bank_error(&err), BankError::TokenNotFound(_)));
+}
+
+#[test]
+fn failed_transfer_leaves_no_state_change() {
+    let mut test_bank = setup();
+    let minter = test_bank.minter.clone();
+    let transfer_message = CallMessage::Transfer {
+        to: generate_address("receiver"),
+        coins: Coins {
+            amount: INITIAL_BALANCE + 1,
+            token_address: test_bank.token_address,
+        },
+    };
+
+    let bank = &test_bank.bank;
+    let result = assert_no_state_change(&mut test_bank.working_set, |working_set| {
+        bank.call(transfer_message, &minter, working_set)
+    });
+    assert!(result.is_err());
+}
+
+#[test]
+fn burn_errors() {
+    let mut test_bank = setup();
+    let minter = test_bank.minter.clone();
+
+    let err = test_bank.call_err(
+        CallMessage::Burn {
+            coins: Coins {
+                amount: INITIAL_BALANCE + 1,
+                token_address: test_bank.token_address,
+            },
+        },
+        &minter,
+    );
+    assert!(matches!(
+        bank_error(&err),
+        BankError::InsufficientBalance { .. }
+    ));
+}
+
+#[test]
+fn mint_errors() {
+    let mut test_bank = setup();
+    let minter = test_bank.minter.clone();
+
+    let err = test_bank.call_err(test_bank.mint_message(u64::MAX), &minter);
+    assert!(matches!(bank_error(&err), BankError::Overflow(_)));
+
+    let intruder = C::new(
+        generate_address("intruder"),
+        generate_address("sequencer"),
+        1,
+    );
+    let err = test_bank.call_err(test_bank.mint_message(1), &intruder);
+    assert!(matches!(
+        bank_error(&err),
+        BankError::UnauthorizedMinter { token_name, .. } if token_name == "Token1"
+    ));
+}
+
+#[test]
+fn mint_near_max_supply_fails_without_state_change() {
+    let mut test_bank = setup();
+    let minter = test_bank.minter.clone();
+    let token_address = test_bank.token_address;
+    let holder = generate_address("holder");
+
+    // The new holder's balance fits, but the total supply doesn't.
+    let mint_message = CallMessage::Mint {
+        coins: Coins {
+            amount: u64::MAX - INITIAL_BALANCE + 1,
+            token_address,
+        },
+        minter_address: holder,
+    };
+    let bank = &test_bank.bank;
+    let result = assert_no_state_change(&mut test_bank.working_set, |working_set| {
+        bank.call(mint_message, &minter, working_set)
+    });
+    let Error::ModuleError(err) = result.expect_err("The mint should overflow");
+    assert!(matches!(
+        bank_error(&err),
+        BankError::Overflow("Total Supply overflow in the mint method of bank module")
+    ));
+
+    // Minting up to the limit still works.
+    test_bank
+        .bank
+        .call(
+            test_bank.mint_message(u64::MAX - INITIAL_BALANCE),
+            &minter,
+            &mut test_bank.working_set,
+        )
+        .expect("Minting up to u64::MAX should succeed");
+    assert_eq!(
+        Some(u64::MAX),
+        test_bank
+            .bank
+            .get_total_supply_of(&token_address, &mut test_bank.working_set)
+    );
+    assert_eq!(
+        None,
+        test_bank
+            .bank
+            .get_balance_of(holder, token_address, &mut test_bank.working_set)
+    );
+}
+
+#[test]
+fn freeze_errors() {
+    let mut test_bank = setup();
+    let minter = test_bank.minter.clone();
+    let freeze_message = CallMessage::Freeze {
+        token_address: test_bank.token_address,
+    };
+
+    let intruder = C::new(
+        generate_address("intruder"),
+        generate_address("sequencer"),
+        1,
+    );
+    let err = test_bank.call_err(freeze_message.clone(), &intruder);
+    assert!(matches!(
+        bank_error(&err),
+        BankError::UnauthorizedMinter { .. }
+    ));
+
+    test_bank
+        .bank
+        .call(freeze_message.clone(), &minter, &mut test_bank.working_set)
+        .expect("Failed to freeze token");
+
+    let err = test_bank.call_err(freeze_message, &minter);
+    assert!(matches!(
+        bank_error(&err),
+        BankError::TokenAlreadyFrozen { .. }
+    ));
+
+    let err = test_bank.call_err(test_bank.mint_message(1), &minter);
+    assert!(matches!(bank_error(&err), BankError::TokenFrozen { .. }));
+}
@@ -1,5 +1,8 @@
 use helpers::C;
-use sov_bank::{get_token_address, Bank, BankConfig, CallMessage, Coins, TotalSupplyResponse};
+use sov_bank::{
+    get_token_address, Bank, BankConfig, BankError, CallMessage, Coins, FrozenResponse,
+    TotalSupplyResponse,
+};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Address, Context, Error, Module, WorkingSet};
@@ -187,3 +190,147 @@ fn freeze_token() {
 
     assert_eq!(Some(110), bal);
 }
+
+#[test]
+fn unfreeze_token() {
+    let bank = Bank::<C>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let empty_bank_config = BankConfig::<C> { tokens: vec![] };
+    bank.genesis(&empty_bank_config, &mut working_set).unwrap();
+
+    let minter_address = generate_address::<DefaultContext>("minter");
+    let sequencer_address = generate_address::<DefaultContext>("sequencer");
+    let minter_context = C::new(minter_address, sequencer_address, 1);
+    let unauthorized_context = C::new(
+        generate_address::<C>("unauthorized_address"),
+        sequencer_address,
+        1,
+    );
+
+    let salt = 0;
+    let token_name = "Token1".to_owned();
+    let initial_balance = 100;
+    let token_address = get_token_address::<C>(&token_name, minter_address.as_ref(), salt);
+    bank.call(
+        CallMessage::CreateToken {
+            salt,
+            token_name,
+            initial_balance,
+            minter_address,
+            authorized_minters: vec![minter_address],
+        },
+        &minter_context,
+        &mut working_set,
+    )
+    .expect("Failed to create token");
+
+    let query_frozen =
+        |token_address: Address, working_set: &mut WorkingSet<DefaultContext>| -> Option<bool> {
+            let frozen: FrozenResponse = bank
+                .is_token_frozen(None, token_address, working_set)
+                .unwrap();
+            frozen.frozen
+        };
+    let unfreeze_error = |context: &C, working_set: &mut WorkingSet<DefaultContext>| {
+        let Error::ModuleError(err) = bank
+            .call(
+                CallMessage::Unfreeze { token_address },
+                context,
+                working_set,
+            )
+            .expect_err("The unfreeze should fail");
+        err
+    };
+
+    assert_eq!(Some(false), query_frozen(token_address, &mut working_set));
+    assert_eq!(
+        None,
+        query_frozen(
+            generate_address::<C>("non_existing_token"),
+            &mut working_set
+        )
+    );
+
+    // A token which isn't frozen can't be unfrozen
+    let err = unfreeze_error(&minter_context, &mut working_set);
+    assert!(matches!(
+        err.downcast_ref::<BankError>(),
+        Some(BankError::TokenNotFrozen { .. })
+    ));
+
+    bank.call(
+        CallMessage::Freeze { token_address },
+        &minter_context,
+        &mut working_set,
+    )
+    .expect("Failed to freeze token");
+    assert_eq!(Some(true), query_frozen(token_address, &mut working_set));
+
+    // Only authorized minters can unfreeze
+    let err = unfreeze_error(&unauthorized_context, &mut working_set);
+    assert!(matches!(
+        err.downcast_ref::<BankError>(),
+        Some(BankError::UnauthorizedMinter { .. })
+    ));
+    assert_eq!(Some(true), query_frozen(token_address, &mut working_set));
+
+    bank.call(
+        CallMessage::Unfreeze { token_address },
+        &minter_context,
+        &mut working_set,
+    )
+    .expect("Failed to unfreeze token");
+    assert_eq!(Some(false), query_frozen(token_address, &mut working_set));
+
+    // The token can be minted and transferred again
+    let mint_amount = 10;
+    bank.call(
+        CallMessage::Mint {
+            coins: Coins {
+                amount: mint_amount,
+                token_address,
+            },
+            minter_address,
+        },
+        &minter_context,
+        &mut working_set,
+    )
+    .expect("Failed to mint unfrozen token");
+    assert_eq!(
+        Some(initial_balance + mint_amount),
+        bank.get_total_supply_of(&token_address, &mut working_set)
+    );
+
+    let receiver_address = generate_address::<C>("receiver");
+    let transfer_amount = 20;
+    bank.call(
+        CallMessage::Transfer {
+            to: receiver_address,
+            coins: Coins {
+                amount: transfer_amount,
+                token_address,
+            },
+        },
+        &minter_context,
+        &mut working_set,
+    )
+    .expect("Failed to transfer unfrozen token");
+    assert_eq!(
+        Some(transfer_amount),
+        bank.get_balance_of(receiver_address, token_address, &mut working_set)
+    );
+    assert_eq!(
+        Some(initial_balance + mint_amount - transfer_amount),
+        bank.get_balance_of(minter_address, token_address, &mut working_set)
+    );
+
+    // The token can be frozen again
+    bank.call(
+        CallMessage::Freeze { token_address },
+        &minter_context,
+        &mut working_set,
+    )
+    .expect("Failed to freeze token again");
+    assert_eq!(Some(true), query_frozen(token_address, &mut working_set));
+}
@@ -1,6 +1,7 @@
 use helpers::*;
 use sov_bank::{
-    get_genesis_token_address, Bank, BankConfig, BankGasConfig, CallMessage, TokenConfig,
+    get_genesis_token_address, Bank, BankConfig, BankGasConfig, CallMessage, MintRecipient,
+    TokenConfig,
 };
 use sov_modules_api::macros::config_constant;
 use sov_modules_api::utils::generate_address;
@@ -12,6 +13,8 @@ mod helpers;
 
 const CREATE_TOKEN_NATIVE_COST: u64 = 2;
 const CREATE_TOKEN_ZK_COST: u64 = 3;
+const MINT_NATIVE_COST: u64 = 1;
+const MINT_ZK_COST: u64 = 2;
 
 #[test]
 fn zeroed_price_wont_deduct_working_set() {
@@ -101,6 +104,41 @@ fn very_high_gas_price_wont_panic_or_overflow() {
     assert!(result.is_err(), "arithmetic overflow shoulnd't panic");
 }
 
+#[test]
+fn mint_many_is_charged_per_recipient() {
+    let sender_balance = 100;
+
+    let native_price = 2;
+    let zk_price = 3;
+    let gas_used = |recipients: usize| {
+        let coins_per_recipient = (0..recipients)
+            .map(|i| MintRecipient {
+                address: generate_address::<C>(&format!("recipient_{i}")),
+                amount: 10,
+            })
+            .collect();
+        let remaining_funds = BankGasTestCase::init(sender_balance)
+            .with_native_price(native_price)
+            .with_zk_price(zk_price)
+            .override_gas_config()
+            .with_message(CallMessage::MintMany {
+                token_address: get_genesis_token_address::<C>("sov-gas-token", 0),
+                coins_per_recipient,
+            })
+            .execute()
+            .unwrap();
+        sender_balance - remaining_funds
+    };
+
+    let mint_cost = native_price * MINT_NATIVE_COST + zk_price * MINT_ZK_COST;
+    assert_eq!(gas_used(1), mint_cost);
+    assert_eq!(
+        gas_used(3),
+        3 * mint_cost,
+        "every recipient should cost as much as a single mint"
+    );
+}
+
 #[allow(dead_code)]
 pub struct BankGasTestCase {
     ws: WorkingSet<C>,
@@ -130,7 +168,7 @@ impl BankGasTestCase {
         // generate a token configuration with the provided arguments
         let sender_address = generate_address::<C>("sender");
         let address_and_balances = vec![(sender_address, sender_balance)];
-        let authorized_minters = vec![];
+        let authorized_minters = vec![sender_address];
         let bank_config: BankConfig<C> = BankConfig {
             tokens: vec![TokenConfig {
                 token_name: base_token_name.to_string(),
@@ -184,12 +222,17 @@ impl BankGasTestCase {
             create_token: [CREATE_TOKEN_NATIVE_COST, CREATE_TOKEN_ZK_COST],
             transfer: Default::default(),
             burn: Default::default(),
-            mint: Default::default(),
+            mint: [MINT_NATIVE_COST, MINT_ZK_COST],
             freeze: Default::default(),
         });
         self
     }
 
+    pub fn with_message(mut self, message: CallMessage<C>) -> Self {
+        self.message = message;
+        self
+    }
+
     pub fn with_native_price(mut self, price: u64) -> Self {
         self.native_price = price;
         self
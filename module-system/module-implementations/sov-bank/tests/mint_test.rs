@@ -1,5 +1,8 @@
 use helpers::C;
-use sov_bank::{get_token_address, Bank, BankConfig, CallMessage, Coins, TotalSupplyResponse};
+use sov_bank::{
+    get_token_address, Bank, BankConfig, BankError, CallMessage, Coins, MintRecipient,
+    TotalSupplyResponse,
+};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Address, Context, Error, Module, WorkingSet};
@@ -283,3 +286,128 @@ fn mint_token() {
     let supply = query_total_supply(token_address, &mut working_set);
     assert_eq!(Some(120), supply);
 }
+
+#[test]
+fn mint_to_many_recipients() {
+    let bank = Bank::<C>::default();
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let empty_bank_config = BankConfig::<C> { tokens: vec![] };
+    bank.genesis(&empty_bank_config, &mut working_set).unwrap();
+
+    let minter_address = generate_address::<C>("minter");
+    let sequencer_address = generate_address::<C>("sequencer");
+    let minter_context = C::new(minter_address, sequencer_address, 1);
+
+    let salt = 0;
+    let token_name = "Token1".to_owned();
+    let initial_balance = 100;
+    let token_address = get_token_address::<C>(&token_name, minter_address.as_ref(), salt);
+    bank.call(
+        CallMessage::CreateToken {
+            salt,
+            token_name,
+            initial_balance,
+            minter_address,
+            authorized_minters: vec![minter_address],
+        },
+        &minter_context,
+        &mut working_set,
+    )
+    .expect("Failed to create token");
+
+    let recipients = [
+        (generate_address::<C>("recipient_1"), 10),
+        (generate_address::<C>("recipient_2"), 20),
+        (generate_address::<C>("recipient_3"), 30),
+    ];
+    let mint_many_message = |amounts: &[(Address, u64)]| CallMessage::MintMany {
+        token_address,
+        coins_per_recipient: amounts
+            .iter()
+            .map(|(address, amount)| MintRecipient {
+                address: *address,
+                amount: *amount,
+            })
+            .collect(),
+    };
+    let query_balances = |working_set: &mut WorkingSet<DefaultContext>| -> Vec<Option<u64>> {
+        recipients
+            .iter()
+            .map(|(address, _)| bank.get_balance_of(*address, token_address, working_set))
+            .collect()
+    };
+
+    // An unauthorized sender can't mint to anyone.
+    let unauthorized_context = C::new(
+        generate_address::<C>("unauthorized_address"),
+        sequencer_address,
+        1,
+    );
+    let Error::ModuleError(err) = bank
+        .call(
+            mint_many_message(&recipients),
+            &unauthorized_context,
+            &mut working_set,
+        )
+        .expect_err("Unauthorized minting should fail");
+    assert!(matches!(
+        err.downcast_ref::<BankError>(),
+        Some(BankError::UnauthorizedMinter { .. })
+    ));
+    assert_eq!(vec![None, None, None], query_balances(&mut working_set));
+
+    // The total supply would overflow with the last recipient, so nobody is credited.
+    let overflowing_recipients = [recipients[0], recipients[1], (recipients[2].0, u64::MAX)];
+    let Error::ModuleError(err) = bank
+        .call(
+            mint_many_message(&overflowing_recipients),
+            &minter_context,
+            &mut working_set,
+        )
+        .expect_err("Overflowing the total supply should fail");
+    assert!(matches!(
+        err.downcast_ref::<BankError>(),
+        Some(BankError::Overflow(_))
+    ));
+    assert_eq!(vec![None, None, None], query_balances(&mut working_set));
+    assert_eq!(
+        Some(initial_balance),
+        bank.get_total_supply_of(&token_address, &mut working_set)
+    );
+
+    bank.call(
+        mint_many_message(&recipients),
+        &minter_context,
+        &mut working_set,
+    )
+    .expect("Failed to mint to many recipients");
+    assert_eq!(
+        vec![Some(10), Some(20), Some(30)],
+        query_balances(&mut working_set)
+    );
+    assert_eq!(
+        Some(initial_balance + 60),
+        bank.get_total_supply_of(&token_address, &mut working_set)
+    );
+    assert_eq!(
+        Some(initial_balance),
+        bank.get_balance_of(minter_address, token_address, &mut working_set)
+    );
+
+    // A recipient listed twice is credited both amounts.
+    bank.call(
+        mint_many_message(&[recipients[0], recipients[0]]),
+        &minter_context,
+        &mut working_set,
+    )
+    .expect("Failed to mint to a repeated recipient");
+    assert_eq!(
+        vec![Some(30), Some(20), Some(30)],
+        query_balances(&mut working_set)
+    );
+    assert_eq!(
+        Some(initial_balance + 80),
+        bank.get_total_supply_of(&token_address, &mut working_set)
+    );
+}
@@ -4,7 +4,7 @@ use sov_modules_api::runtime::capabilities::{BlobRefOrOwned, BlobSelector};
 use sov_modules_api::{BlobReaderTrait, Context, DaSpec, KernelWorkingSet, WorkingSet};
 use tracing::info;
 
-use crate::{BlobStorage, DEFERRED_SLOTS_COUNT};
+use crate::{BlobPriority, BlobStorage, EqualPriority, DEFERRED_SLOTS_COUNT};
 
 impl<C: Context, Da: DaSpec> BlobStorage<C, Da> {
     fn filter_by_allowed_sender(
@@ -29,22 +29,37 @@ impl<C: Context, Da: DaSpec> BlobStorage<C, Da> {
             is_allowed
         }
     }
-}
 
-impl<C: Context, Da: DaSpec> BlobSelector<Da> for BlobStorage<C, Da> {
-    type Context = C;
+    // Orders the blobs from non-preferred senders by descending priority, keeping the DA order for
+    // equal priorities. Blobs from the preferred sequencer go first, in DA order.
+    fn sort_by_priority<P: BlobPriority<Da>>(
+        blobs: &mut [&mut Da::BlobTransaction],
+        preferred_sequencer: Option<&Da::Address>,
+        priority: &P,
+    ) {
+        blobs.sort_by_key(|b| {
+            if Some(&b.sender()) == preferred_sequencer {
+                (false, core::cmp::Reverse(0))
+            } else {
+                (true, core::cmp::Reverse(priority.priority(b)))
+            }
+        });
+    }
 
-    // This implementation returns three categories of blobs:
+    // This selection returns three categories of blobs:
     // 1. Any blobs sent by the preferred sequencer ("prority blobs")
     // 2. Any non-priority blobs which were sent `DEFERRED_SLOTS_COUNT` slots ago ("expiring deferred blobs")
     // 3. Some additional deferred blobs needed to fill the total requested by the sequencer, if applicable. ("bonus blobs")
-    fn get_blobs_for_this_slot<'a, 'k, I>(
+    // The current blobs from non-preferred senders are considered in the order given by `priority`.
+    fn select_blobs<'a, 'k, I, P>(
         &self,
         current_blobs: I,
+        priority: &P,
         working_set: &mut KernelWorkingSet<'k, C>,
     ) -> anyhow::Result<Vec<BlobRefOrOwned<'a, Da::BlobTransaction>>>
     where
         I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
+        P: BlobPriority<Da>,
     {
         // If `DEFERRED_SLOTS_COUNT` is 0, we don't never to do any deferred blob processing and this
         // function just sorts and filters the current blobs before returning
@@ -52,16 +67,15 @@ impl<C: Context, Da: DaSpec> BlobSelector<Da> for BlobStorage<C, Da> {
             let mut blobs = current_blobs
                 .into_iter()
                 .filter(|b| self.filter_by_allowed_sender(b, working_set.inner))
-                .map(Into::into)
                 .collect::<Vec<_>>();
-            if let Some(sequencer) = self.get_preferred_sequencer(working_set.inner) {
-                blobs.sort_by_key(|b: &BlobRefOrOwned<Da::BlobTransaction>| {
-                    b.as_ref().sender() != sequencer
-                });
-            }
+            let preferred_sequencer = self.get_preferred_sequencer(working_set.inner);
+            Self::sort_by_priority(&mut blobs, preferred_sequencer.as_ref(), priority);
             return Ok(blobs.into_iter().map(Into::into).collect());
         }
 
+        let mut current_blobs: Vec<&'a mut Da::BlobTransaction> =
+            current_blobs.into_iter().collect();
+
         // Calculate any expiring deferred blobs first, since these have to be processed no matter what (Case 2 above).
         // Note that we have to handle this case even if there is no preferred sequencer, since that sequencer might have
         // exited while there were deferred blobs waiting to be processed
@@ -72,18 +86,19 @@ impl<C: Context, Da: DaSpec> BlobSelector<Da> for BlobStorage<C, Da> {
             self.take_blobs_for_slot_height(slot_for_expiring_blobs, working_set.inner);
 
         // If there is no preferred sequencer, that's all we need to do
-        let preferred_sequencer =
-            if let Some(sequencer) = self.get_preferred_sequencer(working_set.inner) {
-                sequencer
-            } else {
-                // TODO: https://github.com/Sovereign-Labs/sovereign-sdk/issues/654
-                // Prevent double number of blobs being executed
-                return Ok(expiring_deferred_blobs
-                    .into_iter()
-                    .map(Into::into)
-                    .chain(current_blobs.into_iter().map(Into::into))
-                    .collect());
-            };
+        let preferred_sequencer = self.get_preferred_sequencer(working_set.inner);
+        Self::sort_by_priority(&mut current_blobs, preferred_sequencer.as_ref(), priority);
+        let preferred_sequencer = if let Some(sequencer) = preferred_sequencer {
+            sequencer
+        } else {
+            // TODO: https://github.com/Sovereign-Labs/sovereign-sdk/issues/654
+            // Prevent double number of blobs being executed
+            return Ok(expiring_deferred_blobs
+                .into_iter()
+                .map(Into::into)
+                .chain(current_blobs.into_iter().map(Into::into))
+                .collect());
+        };
 
         // If we reach this point, there is a preferred sequencer, so we need to handle cases 1 and 3.
 
@@ -165,3 +180,87 @@ impl<C: Context, Da: DaSpec> BlobSelector<Da> for BlobStorage<C, Da> {
             .collect())
     }
 }
+
+impl<C: Context, Da: DaSpec> BlobStorage<C, Da> {
+    /// Selects the blobs to execute in this slot like [`BlobSelector::get_blobs_for_this_slot`],
+    /// but orders the blobs from non-preferred senders by the given [`BlobPriority`].
+    pub fn get_blobs_for_this_slot_with_priority<'a, 'k, I, P>(
+        &self,
+        current_blobs: I,
+        priority: &P,
+        working_set: &mut KernelWorkingSet<'k, C>,
+    ) -> anyhow::Result<Vec<BlobRefOrOwned<'a, Da::BlobTransaction>>>
+    where
+        I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
+        P: BlobPriority<Da>,
+    {
+        let min_blob_bytes = self.get_min_blob_bytes(working_set.inner);
+        let current_blobs = current_blobs.into_iter().filter(|b| {
+            let is_large_enough = b.total_len() as u64 >= min_blob_bytes;
+            if !is_large_enough {
+                info!(
+                    "Blob hash=0x{} of {} bytes from sender {} is smaller than {} bytes and is going to be discarded",
+                    hex::encode(b.hash()),
+                    b.total_len(),
+                    b.sender(),
+                    min_blob_bytes
+                );
+            }
+            is_large_enough
+        });
+
+        let current_slot = self.get_true_slot_height(working_set);
+        let max_blobs = self.get_max_blobs_per_slot(working_set.inner) as usize;
+        let blobs_over_budget =
+            self.take_blobs_over_slot_budget(current_slot, max_blobs, working_set.inner)?;
+        let selected_blobs = self.select_blobs(current_blobs, priority, working_set)?;
+        let mut blobs: Vec<BlobRefOrOwned<'a, Da::BlobTransaction>> = blobs_over_budget
+            .into_iter()
+            .map(Into::into)
+            .chain(selected_blobs)
+            .collect();
+
+        if blobs.len() > max_blobs {
+            let to_carry_over = blobs.split_off(max_blobs);
+            info!(
+                "{} blob(s) exceed the budget of {} blobs per slot and are carried over to later slots",
+                to_carry_over.len(),
+                max_blobs
+            );
+            let to_carry_over: Vec<&Da::BlobTransaction> =
+                to_carry_over.iter().map(AsRef::as_ref).collect();
+            let dropped =
+                self.store_blobs_over_slot_budget(current_slot, &to_carry_over, working_set.inner)?;
+            if dropped > 0 {
+                info!(
+                    "{} blob(s) exceed the limit of {} blobs carried over and are going to be discarded",
+                    dropped,
+                    self.get_max_blobs_over_slot_budget(working_set.inner)
+                );
+            }
+        }
+
+        Ok(blobs)
+    }
+}
+
+impl<C: Context, Da: DaSpec> BlobSelector<Da> for BlobStorage<C, Da> {
+    type Context = C;
+
+    // Blobs left over from the previous slot are executed first, followed by the selection made by
+    // `select_blobs`. Anything beyond `MAX_BLOBS_PER_SLOT` is carried over to later slots, and
+    // dropped once more than `MAX_BLOBS_OVER_SLOT_BUDGET` blobs are waiting.
+    // The budget is counted in blobs rather than time, so native execution and the prover always agree on it.
+    // Blobs shorter than `MIN_BLOB_BYTES` are dropped before the selection.
+    // All blobs have the same priority, so they are selected in the order they appeared on the DA layer.
+    fn get_blobs_for_this_slot<'a, 'k, I>(
+        &self,
+        current_blobs: I,
+        working_set: &mut KernelWorkingSet<'k, C>,
+    ) -> anyhow::Result<Vec<BlobRefOrOwned<'a, Da::BlobTransaction>>>
+    where
+        I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
+    {
+        self.get_blobs_for_this_slot_with_priority(current_blobs, &EqualPriority, working_set)
+    }
+}
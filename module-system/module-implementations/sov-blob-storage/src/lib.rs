@@ -4,9 +4,12 @@
 mod call;
 pub use call::CallMessage;
 mod capabilities;
+mod priority;
+pub use priority::{BlobPriority, EqualPriority};
 #[cfg(feature = "native")]
 mod query;
 
+use anyhow::Context as _;
 #[cfg(feature = "native")]
 pub use query::*;
 use sov_chain_state::TransitionHeight;
@@ -19,6 +22,21 @@ use sov_modules_api::{
 #[config_constant]
 pub const DEFERRED_SLOTS_COUNT: u64;
 
+/// How many blobs are executed in a single slot at most.
+/// Blobs selected beyond this budget are carried over and executed first in the next slot.
+#[config_constant]
+pub const MAX_BLOBS_PER_SLOT: u64;
+
+/// How many blobs can be carried over to later slots at most, because they didn't fit into the [`MAX_BLOBS_PER_SLOT`] budget.
+/// Blobs selected beyond this are dropped.
+#[config_constant]
+pub const MAX_BLOBS_OVER_SLOT_BUDGET: u64;
+
+/// The smallest blob, in bytes, which is considered for execution.
+/// Smaller blobs are dropped by every node before blob selection, so they are neither executed nor deferred.
+#[config_constant]
+pub const MIN_BLOB_BYTES: u64;
+
 /// Blob storage contains only address and vector of blobs
 #[cfg_attr(feature = "native", derive(sov_modules_api::ModuleCallJsonSchema))]
 #[derive(Clone, KernelModuleInfo)]
@@ -41,6 +59,20 @@ pub struct BlobStorage<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec>
     #[state]
     pub(crate) deferred_blobs_requested_for_execution_next_slot: StateValue<u16>,
 
+    /// Blobs which were selected for execution but didn't fit into the [`MAX_BLOBS_PER_SLOT`] budget of their slot.
+    /// DA block number => vector of blobs, in the order they are executed.
+    /// They are executed before any other blobs in the following slots, oldest first.
+    #[state]
+    pub(crate) blobs_over_slot_budget: StateMap<u64, Vec<Vec<u8>>>,
+
+    /// The oldest DA block number in `blobs_over_slot_budget` which may still have blobs.
+    #[state]
+    pub(crate) oldest_slot_over_budget: StateValue<u64>,
+
+    /// The number of blobs in `blobs_over_slot_budget`, which is bounded by [`MAX_BLOBS_OVER_SLOT_BUDGET`].
+    #[state]
+    pub(crate) blobs_over_slot_budget_count: StateValue<u64>,
+
     #[module]
     pub(crate) sequencer_registry: sov_sequencer_registry::SequencerRegistry<C, Da>,
 
@@ -81,6 +113,91 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> BlobStorage<C, Da
             .collect()
     }
 
+    /// Store blobs which didn't fit into the budget of the given slot, after the ones already carried over.
+    /// Blobs beyond [`MAX_BLOBS_OVER_SLOT_BUDGET`] are dropped, and their number is returned.
+    pub fn store_blobs_over_slot_budget(
+        &self,
+        slot_height: TransitionHeight,
+        blobs: &[&Da::BlobTransaction],
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<usize> {
+        let count = self
+            .blobs_over_slot_budget_count
+            .get(working_set)
+            .unwrap_or_default();
+        let capacity = self
+            .get_max_blobs_over_slot_budget(working_set)
+            .saturating_sub(count) as usize;
+        let (blobs, dropped) = blobs.split_at(blobs.len().min(capacity));
+        if blobs.is_empty() {
+            return Ok(dropped.len());
+        }
+
+        let mut raw_blobs = self
+            .blobs_over_slot_budget
+            .get(&slot_height, working_set)
+            .unwrap_or_default();
+        for blob in blobs {
+            raw_blobs.push(bincode::serialize(blob)?);
+        }
+        self.blobs_over_slot_budget
+            .set(&slot_height, &raw_blobs, working_set);
+        if count == 0 {
+            self.oldest_slot_over_budget.set(&slot_height, working_set);
+        }
+        self.blobs_over_slot_budget_count
+            .set(&(count + blobs.len() as u64), working_set);
+        Ok(dropped.len())
+    }
+
+    /// Take up to `max_blobs` of the blobs which didn't fit into the budget of the slots up to the given one, oldest first.
+    /// Returned blobs are removed from the storage
+    pub fn take_blobs_over_slot_budget(
+        &self,
+        slot_height: TransitionHeight,
+        max_blobs: usize,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<Vec<Da::BlobTransaction>> {
+        let mut count = self
+            .blobs_over_slot_budget_count
+            .get(working_set)
+            .unwrap_or_default();
+        let mut slot = self
+            .oldest_slot_over_budget
+            .get(working_set)
+            .unwrap_or_default();
+        let mut blobs = Vec::new();
+        while count > 0 && blobs.len() < max_blobs {
+            anyhow::ensure!(
+                slot <= slot_height,
+                "{} blobs over the slot budget are missing from the storage",
+                count
+            );
+            let mut raw_blobs = self
+                .blobs_over_slot_budget
+                .remove(&slot, working_set)
+                .unwrap_or_default();
+            let remaining = max_blobs - blobs.len();
+            if raw_blobs.len() > remaining {
+                let to_keep = raw_blobs.split_off(remaining);
+                self.blobs_over_slot_budget
+                    .set(&slot, &to_keep, working_set);
+            } else {
+                slot += 1;
+            }
+            count = count.saturating_sub(raw_blobs.len() as u64);
+            for raw_blob in raw_blobs {
+                let blob = bincode::deserialize(&raw_blob)
+                    .context("malformed blob over the slot budget was stored previously")?;
+                blobs.push(blob);
+            }
+        }
+
+        self.blobs_over_slot_budget_count.set(&count, working_set);
+        self.oldest_slot_over_budget.set(&slot, working_set);
+        Ok(blobs)
+    }
+
     pub(crate) fn get_preferred_sequencer(
         &self,
         working_set: &mut WorkingSet<C>,
@@ -98,6 +215,18 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> BlobStorage<C, Da
     pub(crate) fn get_deferred_slots_count(&self, _working_set: &mut WorkingSet<C>) -> u64 {
         DEFERRED_SLOTS_COUNT
     }
+
+    pub(crate) fn get_max_blobs_per_slot(&self, _working_set: &mut WorkingSet<C>) -> u64 {
+        MAX_BLOBS_PER_SLOT
+    }
+
+    pub(crate) fn get_max_blobs_over_slot_budget(&self, _working_set: &mut WorkingSet<C>) -> u64 {
+        MAX_BLOBS_OVER_SLOT_BUDGET
+    }
+
+    pub(crate) fn get_min_blob_bytes(&self, _working_set: &mut WorkingSet<C>) -> u64 {
+        MIN_BLOB_BYTES
+    }
 }
 
 /// Empty module implementation
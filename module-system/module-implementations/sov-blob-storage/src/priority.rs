@@ -0,0 +1,21 @@
+use sov_modules_api::DaSpec;
+
+/// Decides the order in which blobs from non-preferred senders are selected for execution.
+///
+/// Blobs with a higher priority are selected first, and blobs with the same priority keep the
+/// order in which they appeared on the DA layer. A sequencer can, for example, return the fee
+/// attached to the blob.
+pub trait BlobPriority<Da: DaSpec> {
+    /// Returns the priority of the blob.
+    fn priority(&self, blob: &Da::BlobTransaction) -> u64;
+}
+
+/// Gives every blob the same priority, so blobs are selected in the order they appeared on the DA layer.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EqualPriority;
+
+impl<Da: DaSpec> BlobPriority<Da> for EqualPriority {
+    fn priority(&self, _blob: &Da::BlobTransaction) -> u64 {
+        0
+    }
+}
@@ -1,8 +1,13 @@
-use sov_blob_storage::BlobStorage;
-use sov_chain_state::{ChainState, ChainStateConfig};
-use sov_mock_da::{MockAddress, MockBlob, MockDaSpec};
+use sov_blob_storage::{
+    BlobPriority, BlobStorage, EqualPriority, MAX_BLOBS_OVER_SLOT_BUDGET, MAX_BLOBS_PER_SLOT,
+    MIN_BLOB_BYTES,
+};
+use sov_chain_state::{ChainState, ChainStateConfig, DEFAULT_SLOT_HASH_RETENTION};
+use sov_mock_da::{MockAddress, MockAddressN, MockBlob, MockDaSpec};
 use sov_modules_api::default_context::DefaultContext;
-use sov_modules_api::{KernelModule, WorkingSet};
+use sov_modules_api::runtime::capabilities::BlobSelector;
+use sov_modules_api::{BlobReaderTrait, KernelModule, KernelWorkingSet, WorkingSet};
+use sov_modules_core::runtime::capabilities::mocks::MockKernel;
 use sov_prover_storage_manager::new_orphan_storage;
 
 type C = DefaultContext;
@@ -19,6 +24,7 @@ fn empty_test() {
     let chain_state_config = ChainStateConfig {
         initial_slot_height,
         current_time: Default::default(),
+        slot_hash_retention: DEFAULT_SLOT_HASH_RETENTION,
     };
     chain_state
         .genesis(&chain_state_config, &mut working_set)
@@ -42,6 +48,7 @@ fn store_and_retrieve_standard() {
     let chain_state_config = ChainStateConfig {
         initial_slot_height,
         current_time: Default::default(),
+        slot_hash_retention: DEFAULT_SLOT_HASH_RETENTION,
     };
     chain_state
         .genesis(&chain_state_config, &mut working_set)
@@ -112,3 +119,304 @@ fn store_and_retrieve_standard() {
         .take_blobs_for_slot_height(4, &mut working_set)
         .is_empty());
 }
+
+#[test]
+fn blobs_over_slot_budget_are_carried_over() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+
+    let chain_state = ChainState::<C, Da>::default();
+    let initial_slot_height = 1;
+    let chain_state_config = ChainStateConfig {
+        initial_slot_height,
+        current_time: Default::default(),
+        slot_hash_retention: DEFAULT_SLOT_HASH_RETENTION,
+    };
+    chain_state
+        .genesis(&chain_state_config, &mut working_set)
+        .unwrap();
+
+    let blob_storage = BlobStorage::<C, Da>::default();
+    let kernel = MockKernel::<C, Da>::new(initial_slot_height, initial_slot_height);
+
+    let sender = MockAddress::from([1u8; 32]);
+    let max_blobs = MAX_BLOBS_PER_SLOT as usize;
+    let mut slot_1_blobs: Vec<B> = (0..max_blobs + 2)
+        .map(|i| B::new(vec![i as u8], sender, [i as u8; 32]))
+        .collect();
+    let expected_hashes: Vec<[u8; 32]> = slot_1_blobs.iter().map(|b| b.hash()).collect();
+
+    let selected_hashes: Vec<[u8; 32]> = blob_storage
+        .get_blobs_for_this_slot(
+            &mut slot_1_blobs,
+            &mut KernelWorkingSet::from_kernel(&kernel, &mut working_set),
+        )
+        .unwrap()
+        .iter()
+        .map(|b| b.as_ref().hash())
+        .collect();
+    assert_eq!(&expected_hashes[..max_blobs], &selected_hashes[..]);
+
+    // The excess blobs are executed in the next slot, ahead of the blobs which arrive in it.
+    let mut slot_2_blobs = vec![B::new(vec![2], sender, [255u8; 32])];
+    let selected_hashes: Vec<[u8; 32]> = blob_storage
+        .get_blobs_for_this_slot(
+            &mut slot_2_blobs,
+            &mut KernelWorkingSet::from_kernel(&kernel, &mut working_set),
+        )
+        .unwrap()
+        .iter()
+        .map(|b| b.as_ref().hash())
+        .collect();
+    assert_eq!(
+        vec![
+            expected_hashes[max_blobs],
+            expected_hashes[max_blobs + 1],
+            [255u8; 32]
+        ],
+        selected_hashes
+    );
+
+    assert!(blob_storage
+        .take_blobs_over_slot_budget(initial_slot_height, usize::MAX, &mut working_set)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn blobs_over_slot_budget_are_bounded() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let blob_storage = BlobStorage::<C, Da>::default();
+
+    let sender = MockAddress::from([1u8; 32]);
+    let max_carried_over = MAX_BLOBS_OVER_SLOT_BUDGET as usize;
+    let blobs: Vec<B> = (0..max_carried_over + 1)
+        .map(|i| {
+            B::new(
+                vec![i as u8],
+                sender,
+                (i as u64).to_le_bytes().repeat(4).try_into().unwrap(),
+            )
+        })
+        .collect();
+    let (slot_1_blobs, slot_2_blobs) = blobs.split_at(max_carried_over - 1);
+
+    let dropped = blob_storage
+        .store_blobs_over_slot_budget(
+            1,
+            &slot_1_blobs.iter().collect::<Vec<_>>(),
+            &mut working_set,
+        )
+        .unwrap();
+    assert_eq!(0, dropped);
+    // Only one more blob fits, the other one is dropped.
+    let dropped = blob_storage
+        .store_blobs_over_slot_budget(
+            2,
+            &slot_2_blobs.iter().collect::<Vec<_>>(),
+            &mut working_set,
+        )
+        .unwrap();
+    assert_eq!(1, dropped);
+
+    // The blobs are taken oldest first, across slots, and the rest is kept for later.
+    let first = blob_storage
+        .take_blobs_over_slot_budget(2, max_carried_over - 2, &mut working_set)
+        .unwrap();
+    assert_eq!(&blobs[..max_carried_over - 2], &first[..]);
+    let rest = blob_storage
+        .take_blobs_over_slot_budget(2, usize::MAX, &mut working_set)
+        .unwrap();
+    assert_eq!(&blobs[max_carried_over - 2..max_carried_over], &rest[..]);
+    assert!(blob_storage
+        .take_blobs_over_slot_budget(2, usize::MAX, &mut working_set)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn store_and_retrieve_with_short_da_addresses() {
+    type ShortDa = MockDaSpec<20>;
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+
+    let chain_state = ChainState::<C, ShortDa>::default();
+    let initial_slot_height = 1;
+    let chain_state_config = ChainStateConfig {
+        initial_slot_height,
+        current_time: Default::default(),
+        slot_hash_retention: DEFAULT_SLOT_HASH_RETENTION,
+    };
+    chain_state
+        .genesis(&chain_state_config, &mut working_set)
+        .unwrap();
+
+    let blob_storage = BlobStorage::<C, ShortDa>::default();
+    let kernel = MockKernel::<C, ShortDa>::new(initial_slot_height, initial_slot_height);
+
+    let sender = MockAddressN::<20>::from([1u8; 20]);
+    let slot_2_blobs = vec![
+        MockBlob::new(vec![1, 2, 3], sender, [2u8; 32]),
+        MockBlob::new(vec![4, 5, 6], sender, [3u8; 32]),
+    ];
+    let slot_2_blob_refs: Vec<&MockBlob<20>> = slot_2_blobs.iter().collect();
+    blob_storage
+        .store_blobs(2, &slot_2_blob_refs, &mut working_set)
+        .unwrap();
+
+    let retrieved = blob_storage.take_blobs_for_slot_height(2, &mut working_set);
+    assert_eq!(slot_2_blobs, retrieved);
+    for blob in &retrieved {
+        assert_eq!(20, blob.sender().as_ref().len());
+    }
+
+    // Blobs carried over to the next slot are stored and decoded with the short address as well.
+    let max_blobs = MAX_BLOBS_PER_SLOT as usize;
+    let mut slot_1_blobs: Vec<MockBlob<20>> = (0..max_blobs + 1)
+        .map(|i| MockBlob::new(vec![i as u8], sender, [i as u8; 32]))
+        .collect();
+    blob_storage
+        .get_blobs_for_this_slot(
+            &mut slot_1_blobs,
+            &mut KernelWorkingSet::from_kernel(&kernel, &mut working_set),
+        )
+        .unwrap();
+
+    let carried_over = blob_storage
+        .take_blobs_over_slot_budget(initial_slot_height, usize::MAX, &mut working_set)
+        .unwrap();
+    assert_eq!(1, carried_over.len());
+    assert_eq!(sender, carried_over[0].sender());
+    assert_eq!([max_blobs as u8; 32], carried_over[0].hash());
+}
+
+#[test]
+fn blobs_below_min_size_are_dropped() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+
+    let chain_state = ChainState::<C, Da>::default();
+    let initial_slot_height = 1;
+    let chain_state_config = ChainStateConfig {
+        initial_slot_height,
+        current_time: Default::default(),
+        slot_hash_retention: DEFAULT_SLOT_HASH_RETENTION,
+    };
+    chain_state
+        .genesis(&chain_state_config, &mut working_set)
+        .unwrap();
+
+    let blob_storage = BlobStorage::<C, Da>::default();
+    let kernel = MockKernel::<C, Da>::new(initial_slot_height, initial_slot_height);
+
+    let sender = MockAddress::from([1u8; 32]);
+    let min_blob_bytes = MIN_BLOB_BYTES as usize;
+    let small_blob = B::new(vec![1; min_blob_bytes - 1], sender, [1u8; 32]);
+    let exact_blob = B::new(vec![2; min_blob_bytes], sender, [2u8; 32]);
+    let large_blob = B::new(vec![3; min_blob_bytes + 1], sender, [3u8; 32]);
+    let mut slot_1_blobs = vec![small_blob.clone(), exact_blob, small_blob, large_blob];
+
+    let selected_hashes: Vec<[u8; 32]> = blob_storage
+        .get_blobs_for_this_slot(
+            &mut slot_1_blobs,
+            &mut KernelWorkingSet::from_kernel(&kernel, &mut working_set),
+        )
+        .unwrap()
+        .iter()
+        .map(|b| b.as_ref().hash())
+        .collect();
+    assert_eq!(vec![[2u8; 32], [3u8; 32]], selected_hashes);
+
+    // The dropped blobs are not deferred either.
+    assert!(blob_storage
+        .take_blobs_for_slot_height(initial_slot_height, &mut working_set)
+        .is_empty());
+    assert!(blob_storage
+        .take_blobs_over_slot_budget(initial_slot_height, usize::MAX, &mut working_set)
+        .unwrap()
+        .is_empty());
+}
+
+/// Uses the first byte of the blob data as its priority, like a fee attached to the blob.
+struct FirstBytePriority;
+
+impl BlobPriority<Da> for FirstBytePriority {
+    fn priority(&self, blob: &B) -> u64 {
+        blob.clone().full_data()[0] as u64
+    }
+}
+
+#[test]
+fn blobs_are_selected_by_descending_priority() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+
+    let chain_state = ChainState::<C, Da>::default();
+    let initial_slot_height = 1;
+    let chain_state_config = ChainStateConfig {
+        initial_slot_height,
+        current_time: Default::default(),
+        slot_hash_retention: DEFAULT_SLOT_HASH_RETENTION,
+    };
+    chain_state
+        .genesis(&chain_state_config, &mut working_set)
+        .unwrap();
+
+    let blob_storage = BlobStorage::<C, Da>::default();
+    let kernel = MockKernel::<C, Da>::new(initial_slot_height, initial_slot_height);
+
+    let sender = MockAddress::from([1u8; 32]);
+    // The first byte is the priority, the hash identifies the blob.
+    let make_blobs = || {
+        vec![
+            B::new(vec![1], sender, [1u8; 32]),
+            B::new(vec![5], sender, [2u8; 32]),
+            B::new(vec![3], sender, [3u8; 32]),
+            B::new(vec![5], sender, [4u8; 32]),
+            B::new(vec![1], sender, [5u8; 32]),
+        ]
+    };
+    let da_order: Vec<[u8; 32]> = make_blobs().iter().map(|b| b.hash()).collect();
+
+    // Higher priority first, the DA order breaks ties.
+    let selected_hashes: Vec<[u8; 32]> = blob_storage
+        .get_blobs_for_this_slot_with_priority(
+            &mut make_blobs(),
+            &FirstBytePriority,
+            &mut KernelWorkingSet::from_kernel(&kernel, &mut working_set),
+        )
+        .unwrap()
+        .iter()
+        .map(|b| b.as_ref().hash())
+        .collect();
+    assert_eq!(
+        vec![[2u8; 32], [4u8; 32], [3u8; 32], [1u8; 32], [5u8; 32]],
+        selected_hashes
+    );
+
+    // With equal priorities the blobs keep the DA order, which is also the default.
+    let selected_hashes: Vec<[u8; 32]> = blob_storage
+        .get_blobs_for_this_slot_with_priority(
+            &mut make_blobs(),
+            &EqualPriority,
+            &mut KernelWorkingSet::from_kernel(&kernel, &mut working_set),
+        )
+        .unwrap()
+        .iter()
+        .map(|b| b.as_ref().hash())
+        .collect();
+    assert_eq!(da_order, selected_hashes);
+
+    let selected_hashes: Vec<[u8; 32]> = blob_storage
+        .get_blobs_for_this_slot(
+            &mut make_blobs(),
+            &mut KernelWorkingSet::from_kernel(&kernel, &mut working_set),
+        )
+        .unwrap()
+        .iter()
+        .map(|b| b.as_ref().hash())
+        .collect();
+    assert_eq!(da_order, selected_hashes);
+}
@@ -5,6 +5,14 @@ use sov_modules_api::{StateValueAccessor, WorkingSet};
 
 use crate::{ChainState, TransitionHeight};
 
+/// The number of recent slot hashes kept when the configuration doesn't set it, matching the
+/// window of the EVM `BLOCKHASH` opcode.
+pub const DEFAULT_SLOT_HASH_RETENTION: u64 = 256;
+
+fn default_slot_hash_retention() -> u64 {
+    DEFAULT_SLOT_HASH_RETENTION
+}
+
 /// Initial configuration of the chain state
 #[derive(Debug, Clone, Serialize, Deserialize, Eq, PartialEq)]
 pub struct ChainStateConfig {
@@ -12,6 +20,9 @@ pub struct ChainStateConfig {
     pub initial_slot_height: TransitionHeight,
     /// The time at genesis
     pub current_time: Time,
+    /// The number of recent slot hashes to keep
+    #[serde(default = "default_slot_hash_retention")]
+    pub slot_hash_retention: u64,
 }
 
 impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainState<C, Da> {
@@ -27,6 +38,9 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainState<C, Da>
             .set(&config.initial_slot_height, working_set);
 
         self.time.set_genesis(&config.current_time, working_set);
+
+        self.slot_hash_retention
+            .set(&config.slot_hash_retention, working_set);
         Ok(())
     }
 }
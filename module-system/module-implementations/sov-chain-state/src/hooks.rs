@@ -1,12 +1,12 @@
 use sov_modules_api::da::BlockHeaderTrait;
 use sov_modules_api::hooks::FinalizeHook;
 use sov_modules_api::prelude::*;
-use sov_modules_api::{AccessoryWorkingSet, Context, Spec};
+use sov_modules_api::{AccessoryWorkingSet, Context, Spec, WorkingSet};
 use sov_state::storage::KernelWorkingSet;
 use sov_state::Storage;
 
 use super::ChainState;
-use crate::{StateTransitionId, TransitionInProgress};
+use crate::{StateTransitionId, TransitionInProgress, DEFAULT_SLOT_HASH_RETENTION};
 
 impl<C: Context, Da: sov_modules_api::DaSpec> ChainState<C, Da> {
     /// Update the chain state at the beginning of the slot
@@ -46,6 +46,7 @@ impl<C: Context, Da: sov_modules_api::DaSpec> ChainState<C, Da> {
 
         self.increment_true_slot_height(working_set);
         self.time.set_current(&slot_header.time(), working_set);
+        self.record_slot_hash(slot_header.hash().into(), working_set.inner);
 
         self.in_progress_transition.set(
             &TransitionInProgress {
@@ -56,6 +57,23 @@ impl<C: Context, Da: sov_modules_api::DaSpec> ChainState<C, Da> {
         );
     }
 
+    // Records the hash of the slot which just began and prunes the hash leaving the retention window.
+    fn record_slot_hash(&self, slot_hash: [u8; 32], working_set: &mut WorkingSet<C>) {
+        let height = self
+            .true_height
+            .get(working_set)
+            .expect("Block height must be set");
+        self.slot_hashes.set(&height, &slot_hash, working_set);
+
+        let retention = self
+            .slot_hash_retention
+            .get(working_set)
+            .unwrap_or(DEFAULT_SLOT_HASH_RETENTION);
+        if let Some(evicted_height) = height.checked_sub(retention) {
+            self.slot_hashes.delete(&evicted_height, working_set);
+        }
+    }
+
     /// Update the chain state at the end of each slot, if necessary
     pub fn end_slot_hook(&self, _working_set: &mut KernelWorkingSet<C>) {}
 }
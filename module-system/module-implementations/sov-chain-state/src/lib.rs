@@ -147,6 +147,15 @@ pub struct ChainState<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec>
         BcsCodec,
     >,
 
+    /// The hashes of the most recent slots, indexed by their true height. Only the last
+    /// `slot_hash_retention` slots are kept, older entries are pruned as new slots begin.
+    #[state]
+    slot_hashes: sov_modules_api::StateMap<TransitionHeight, [u8; 32]>,
+
+    /// The number of recent slot hashes to keep in `slot_hashes`.
+    #[state]
+    slot_hash_retention: sov_modules_api::StateValue<u64>,
+
     /// The transition that is currently processed
     #[state]
     in_progress_transition: sov_modules_api::KernelStateValue<TransitionInProgress<Da>, BcsCodec>,
@@ -211,6 +220,28 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainState<C, Da>
         self.historical_transitions
             .get(&transition_num, working_set)
     }
+
+    /// Returns the hash of the slot at `slot_height`, if it is recent enough to be retained.
+    pub fn get_slot_hash(
+        &self,
+        slot_height: TransitionHeight,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<[u8; 32]> {
+        self.slot_hashes.get(&slot_height, working_set)
+    }
+
+    /// Returns the validity condition of the slot at `slot_height`.
+    ///
+    /// The condition of a slot is recorded along with its transition, once the next slot begins,
+    /// so it is not available yet for the slot currently being processed.
+    pub fn get_validity_condition(
+        &self,
+        slot_height: TransitionHeight,
+        working_set: &mut WorkingSet<C>,
+    ) -> Option<Da::ValidityCondition> {
+        self.get_historical_transitions(slot_height, working_set)
+            .map(|transition| transition.validity_condition)
+    }
 }
 
 impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> sov_modules_api::KernelModule
@@ -1,8 +1,25 @@
-// use jsonrpsee::core::RpcResult;
+use borsh::BorshSerialize;
+use jsonrpsee::core::RpcResult;
 use sov_modules_api::macros::rpc_gen;
+use sov_modules_api::WorkingSet;
 
-// use sov_modules_api::WorkingSet;
-use crate::ChainState;
+use crate::{ChainState, TransitionHeight};
+
+/// Structure returned by the `getValidityCondition` rpc method.
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ValidityConditionResponse {
+    /// The borsh-serialized validity condition recorded for the slot, or `None` if no transition
+    /// has been recorded for that slot yet.
+    pub validity_condition: Option<Vec<u8>>,
+}
+
+/// Structure returned by the `getSlotHash` rpc method.
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone)]
+pub struct SlotHashResponse {
+    /// The hash of the slot, or `None` if the slot hasn't begun yet or was pruned from the
+    /// retention window.
+    pub slot_hash: Option<[u8; 32]>,
+}
 
 #[rpc_gen(client, server, namespace = "chainState")]
 impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainState<C, Da> {
@@ -16,4 +33,37 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> ChainState<C, Da>
     // ) -> RpcResult<TransitionHeight> {
     //     Ok(self.get_slot_height(working_set))
     // }
+
+    /// Get the validity condition the node recorded for the slot at `slot_height`, so that
+    /// external verifiers can compare it with their own.
+    #[rpc_method(name = "getValidityCondition")]
+    pub fn validity_condition(
+        &self,
+        slot_height: TransitionHeight,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<ValidityConditionResponse> {
+        let validity_condition = self
+            .get_validity_condition(slot_height, working_set)
+            .map(|condition| condition.try_to_vec())
+            .transpose()
+            .map_err(|e| {
+                sov_modules_api::utils::to_jsonrpsee_error_object(
+                    e,
+                    "Failed to serialize the validity condition",
+                )
+            })?;
+        Ok(ValidityConditionResponse { validity_condition })
+    }
+
+    /// Get the hash of the slot at `slot_height`, if it is among the most recent slots.
+    #[rpc_method(name = "getSlotHash")]
+    pub fn slot_hash(
+        &self,
+        slot_height: TransitionHeight,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<SlotHashResponse> {
+        Ok(SlotHashResponse {
+            slot_hash: self.get_slot_hash(slot_height, working_set),
+        })
+    }
 }
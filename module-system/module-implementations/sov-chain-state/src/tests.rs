@@ -1,6 +1,16 @@
+use borsh::BorshSerialize;
+use sov_mock_da::{MockBlockHeader, MockDaSpec, MockValidityCond};
 use sov_modules_api::da::{NanoSeconds, Time};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::{KernelModule, KernelWorkingSet, WorkingSet};
+use sov_modules_core::runtime::capabilities::mocks::MockKernel;
+use sov_prover_storage_manager::new_orphan_storage;
+use sov_state::Storage;
 
-use crate::ChainStateConfig;
+use crate::{
+    ChainState, ChainStateConfig, SlotHashResponse, ValidityConditionResponse,
+    DEFAULT_SLOT_HASH_RETENTION,
+};
 
 #[test]
 fn test_config_serialization() {
@@ -8,6 +18,7 @@ fn test_config_serialization() {
     let config = ChainStateConfig {
         initial_slot_height: 1,
         current_time: time,
+        slot_hash_retention: DEFAULT_SLOT_HASH_RETENTION,
     };
 
     let data = r#"
@@ -22,3 +33,144 @@ fn test_config_serialization() {
     let parsed_config: ChainStateConfig = serde_json::from_str(data).unwrap();
     assert_eq!(config, parsed_config)
 }
+
+#[test]
+fn test_validity_condition_per_slot() {
+    const INIT_HEIGHT: u64 = 10;
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let chain_state = ChainState::<DefaultContext, MockDaSpec>::default();
+
+    let mut working_set = WorkingSet::new(storage.clone());
+    let config = ChainStateConfig {
+        initial_slot_height: INIT_HEIGHT,
+        current_time: Default::default(),
+        slot_hash_retention: DEFAULT_SLOT_HASH_RETENTION,
+    };
+    chain_state.genesis(&config, &mut working_set).unwrap();
+    let (reads_writes, witness) = working_set.checkpoint().freeze();
+    let mut state_root = storage.validate_and_commit(reads_writes, &witness).unwrap();
+
+    let conditions = [
+        MockValidityCond {
+            is_valid: true,
+            should_combine: true,
+        },
+        MockValidityCond {
+            is_valid: false,
+            should_combine: true,
+        },
+        MockValidityCond {
+            is_valid: true,
+            should_combine: false,
+        },
+    ];
+    for (height, condition) in (INIT_HEIGHT + 1..).zip(conditions) {
+        let mut working_set = WorkingSet::new(storage.clone());
+        let kernel = MockKernel::<DefaultContext, MockDaSpec>::new(height, height);
+        let mut kernel_working_set = KernelWorkingSet::from_kernel(&kernel, &mut working_set);
+        chain_state.begin_slot_hook(
+            &MockBlockHeader::from_height(height),
+            &condition,
+            &state_root,
+            &mut kernel_working_set,
+        );
+        let (reads_writes, witness) = working_set.checkpoint().freeze();
+        state_root = storage.validate_and_commit(reads_writes, &witness).unwrap();
+    }
+
+    let mut working_set = WorkingSet::new(storage);
+    // The condition of the last slot is only recorded once the next slot begins.
+    let (last_condition, recorded_conditions) = conditions.split_last().unwrap();
+    for (height, condition) in (INIT_HEIGHT + 1..).zip(recorded_conditions) {
+        assert_eq!(
+            Some(*condition),
+            chain_state.get_validity_condition(height, &mut working_set)
+        );
+        assert_eq!(
+            ValidityConditionResponse {
+                validity_condition: Some(condition.try_to_vec().unwrap()),
+            },
+            chain_state
+                .validity_condition(height, &mut working_set)
+                .unwrap()
+        );
+    }
+
+    let last_height = INIT_HEIGHT + conditions.len() as u64;
+    assert_eq!(
+        None,
+        chain_state.get_validity_condition(last_height, &mut working_set)
+    );
+    assert_eq!(
+        None,
+        chain_state
+            .validity_condition(last_height, &mut working_set)
+            .unwrap()
+            .validity_condition
+    );
+    assert_eq!(
+        Some(*last_condition),
+        chain_state
+            .get_in_progress_transition(&mut KernelWorkingSet::from_kernel(
+                &MockKernel::<DefaultContext, MockDaSpec>::new(last_height, last_height),
+                &mut working_set,
+            ))
+            .map(|transition| transition.validity_condition)
+    );
+}
+
+#[test]
+fn test_old_slot_hashes_are_pruned() {
+    const INIT_HEIGHT: u64 = 10;
+    const RETENTION: u64 = 3;
+    const SLOTS: u64 = 5;
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let chain_state = ChainState::<DefaultContext, MockDaSpec>::default();
+
+    let mut working_set = WorkingSet::new(storage.clone());
+    let config = ChainStateConfig {
+        initial_slot_height: INIT_HEIGHT,
+        current_time: Default::default(),
+        slot_hash_retention: RETENTION,
+    };
+    chain_state.genesis(&config, &mut working_set).unwrap();
+    let (reads_writes, witness) = working_set.checkpoint().freeze();
+    let mut state_root = storage.validate_and_commit(reads_writes, &witness).unwrap();
+
+    let last_height = INIT_HEIGHT + SLOTS;
+    for height in INIT_HEIGHT + 1..=last_height {
+        let mut working_set = WorkingSet::new(storage.clone());
+        let kernel = MockKernel::<DefaultContext, MockDaSpec>::new(height, height);
+        let mut kernel_working_set = KernelWorkingSet::from_kernel(&kernel, &mut working_set);
+        chain_state.begin_slot_hook(
+            &MockBlockHeader::from_height(height),
+            &Default::default(),
+            &state_root,
+            &mut kernel_working_set,
+        );
+        let (reads_writes, witness) = working_set.checkpoint().freeze();
+        state_root = storage.validate_and_commit(reads_writes, &witness).unwrap();
+    }
+
+    let mut working_set = WorkingSet::new(storage);
+    for height in INIT_HEIGHT + 1..=last_height {
+        let expected = (height > last_height - RETENTION)
+            .then(|| MockBlockHeader::from_height(height).hash.into());
+        assert_eq!(
+            expected,
+            chain_state.get_slot_hash(height, &mut working_set)
+        );
+        assert_eq!(
+            SlotHashResponse {
+                slot_hash: expected
+            },
+            chain_state.slot_hash(height, &mut working_set).unwrap()
+        );
+    }
+    assert_eq!(
+        None,
+        chain_state.get_slot_hash(last_height + 1, &mut working_set)
+    );
+}
@@ -11,6 +11,7 @@ use super::DbAccount;
 pub(crate) struct EvmDb<'a, C: sov_modules_api::Context> {
     pub(crate) accounts: sov_modules_api::StateMap<Address, DbAccount, BcsCodec>,
     pub(crate) code: sov_modules_api::StateMap<H256, Bytes, BcsCodec>,
+    pub(crate) block_hashes: sov_modules_api::StateMap<u64, H256, BcsCodec>,
     pub(crate) working_set: &'a mut WorkingSet<C>,
 }
 
@@ -18,11 +19,13 @@ impl<'a, C: sov_modules_api::Context> EvmDb<'a, C> {
     pub(crate) fn new(
         accounts: sov_modules_api::StateMap<Address, DbAccount, BcsCodec>,
         code: sov_modules_api::StateMap<H256, Bytes, BcsCodec>,
+        block_hashes: sov_modules_api::StateMap<u64, H256, BcsCodec>,
         working_set: &'a mut WorkingSet<C>,
     ) -> Self {
         Self {
             accounts,
             code,
+            block_hashes,
             working_set,
         }
     }
@@ -60,7 +63,13 @@ impl<'a, C: sov_modules_api::Context> Database for EvmDb<'a, C> {
         Ok(storage_value)
     }
 
-    fn block_hash(&mut self, _number: U256) -> Result<B256, Self::Error> {
-        todo!("block_hash not yet implemented")
+    fn block_hash(&mut self, number: U256) -> Result<B256, Self::Error> {
+        // Blocks outside of the retained window hash to zero, like in Ethereum.
+        let block_hash = u64::try_from(number)
+            .ok()
+            .and_then(|number| self.block_hashes.get(&number, self.working_set))
+            .unwrap_or_default();
+
+        Ok(block_hash)
     }
 }
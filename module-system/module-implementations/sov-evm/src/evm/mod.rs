@@ -16,7 +16,7 @@ pub(crate) mod primitive_types;
 mod tests;
 
 pub(crate) use call::prepare_call_env;
-pub use primitive_types::RlpEvmTransaction;
+pub use primitive_types::{BlockStats, RlpEvmTransaction};
 use sov_state::codec::BcsCodec;
 
 // Stores information about an EVM account
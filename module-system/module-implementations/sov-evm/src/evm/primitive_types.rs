@@ -106,6 +106,22 @@ pub(crate) struct SealedBlock {
     pub(crate) transactions: Range<u64>,
 }
 
+/// Statistics of a block produced by the EVM, recorded in `end_slot_hook`.
+#[cfg_attr(
+    feature = "native",
+    derive(serde::Serialize),
+    derive(serde::Deserialize)
+)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct BlockStats {
+    /// The number of transactions in the block.
+    pub transaction_count: u64,
+    /// The total gas used by the transactions in the block.
+    pub gas_used: u64,
+    /// The base fee per gas of the block.
+    pub base_fee: u64,
+}
+
 #[cfg_attr(
     feature = "native",
     derive(serde::Serialize),
@@ -118,3 +134,32 @@ pub(crate) struct Receipt {
     pub(crate) log_index_start: u64,
     pub(crate) error: Option<EVMError<u8>>,
 }
+
+/// The stored format of a [`Receipt`].
+///
+/// The variant index is serialized as a leading version byte, so receipts stored before a change to
+/// [`Receipt`] can still be decoded: the old struct is kept as a variant, and a new variant holds
+/// the new one.
+#[cfg_attr(
+    feature = "native",
+    derive(serde::Serialize),
+    derive(serde::Deserialize)
+)]
+#[derive(Debug, PartialEq, Clone)]
+pub(crate) enum StoredReceipt {
+    V1(Receipt),
+}
+
+impl From<Receipt> for StoredReceipt {
+    fn from(receipt: Receipt) -> Self {
+        Self::V1(receipt)
+    }
+}
+
+impl From<StoredReceipt> for Receipt {
+    fn from(receipt: StoredReceipt) -> Self {
+        match receipt {
+            StoredReceipt::V1(receipt) => receipt,
+        }
+    }
+}
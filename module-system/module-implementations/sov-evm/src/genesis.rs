@@ -48,7 +48,8 @@ pub struct EvmConfig {
     pub chain_id: u64,
     /// Limits size of contract code size.
     pub limit_contract_code_size: Option<usize>,
-    /// List of EVM hardforks by block number
+    /// List of EVM hardforks by block number. Every entry must name a concrete hardfork,
+    /// [`SpecId::LATEST`] is rejected at genesis.
     pub spec: HashMap<u64, SpecId>,
     /// Coinbase where all the fees go
     pub coinbase: Address,
@@ -112,6 +113,11 @@ impl<C: sov_modules_api::Context> Evm<C> {
                 if *v == SpecId::CANCUN {
                     panic!("Cancun is not supported");
                 }
+                // `LATEST` points to a different hardfork depending on the revm version,
+                // which would make the rollup's behavior change on a dependency upgrade.
+                if *v == SpecId::LATEST {
+                    panic!("EVM spec must name a concrete hardfork, not LATEST");
+                }
 
                 (*k, *v)
             })
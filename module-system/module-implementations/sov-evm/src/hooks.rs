@@ -3,8 +3,8 @@ use sov_modules_api::prelude::*;
 use sov_modules_api::{AccessoryWorkingSet, Spec, WorkingSet};
 use sov_state::Storage;
 
-use crate::evm::primitive_types::{Block, BlockEnv};
-use crate::experimental::PendingTransaction;
+use crate::evm::primitive_types::{Block, BlockEnv, BlockStats};
+use crate::experimental::{PendingTransaction, BLOCK_HASH_HISTORY};
 use crate::Evm;
 
 impl<C: sov_modules_api::Context> Evm<C>
@@ -12,9 +12,13 @@ where
     <C::Storage as Storage>::Root: Into<[u8; 32]>,
 {
     /// Logic executed at the beginning of the slot. Here we set the root hash of the previous head.
+    /// The new block takes the `da_timestamp` of the slot, in seconds since the unix epoch, if it's
+    /// later than the parent block. Otherwise, the configured delta is added to the parent timestamp,
+    /// so that DA layers without timestamps still produce increasing ones.
     pub fn begin_slot_hook(
         &self,
         da_root_hash: [u8; 32],
+        da_timestamp: u64,
         pre_state_root: &<<C as Spec>::Storage as Storage>::Root,
         working_set: &mut WorkingSet<C>,
     ) {
@@ -26,11 +30,27 @@ where
         parent_block.header.state_root = H256(pre_state_root.clone().into());
         self.head.set(&parent_block, working_set);
 
+        let parent_number = parent_block.header.number;
+        self.latest_block_hashes.set(
+            &parent_number,
+            &parent_block.clone().seal().header.hash,
+            working_set,
+        );
+        if let Some(evicted_number) = parent_number.checked_sub(BLOCK_HASH_HISTORY) {
+            self.latest_block_hashes
+                .delete(&evicted_number, working_set);
+        }
+
         let cfg = self.cfg.get(working_set).unwrap_or_default();
+        let timestamp = if da_timestamp > parent_block.header.timestamp {
+            da_timestamp
+        } else {
+            parent_block.header.timestamp + cfg.block_timestamp_delta
+        };
         let new_pending_env = BlockEnv {
             number: parent_block.header.number + 1,
             coinbase: cfg.coinbase,
-            timestamp: parent_block.header.timestamp + cfg.block_timestamp_delta,
+            timestamp,
             prevrandao: da_root_hash.into(),
             basefee: parent_block
                 .header
@@ -126,6 +146,15 @@ where
 
         let mut accessory_state = working_set.accessory_state();
         self.pending_head.set(&block, &mut accessory_state);
+        self.block_stats.set(
+            &block.header.number,
+            &BlockStats {
+                transaction_count: pending_transactions.len() as u64,
+                gas_used,
+                base_fee: block_env.basefee,
+            },
+            &mut accessory_state,
+        );
 
         let mut tx_index = start_tx_index;
         for PendingTransaction {
@@ -134,7 +163,8 @@ where
         } in &pending_transactions
         {
             self.transactions.push(transaction, &mut accessory_state);
-            self.receipts.push(receipt, &mut accessory_state);
+            self.receipts
+                .push(&receipt.clone().into(), &mut accessory_state);
 
             self.transaction_hashes.set(
                 &transaction.signed_transaction.hash,
@@ -16,6 +16,12 @@ mod query;
 #[cfg(feature = "native")]
 #[cfg(feature = "experimental")]
 pub use query::*;
+#[cfg(feature = "native")]
+#[cfg(feature = "experimental")]
+mod snapshot;
+#[cfg(feature = "native")]
+#[cfg(feature = "experimental")]
+pub use snapshot::EvmSnapshot;
 #[cfg(feature = "experimental")]
 mod signer;
 #[cfg(feature = "experimental")]
@@ -42,13 +48,16 @@ mod experimental {
     use super::evm::db::EvmDb;
     use super::evm::{DbAccount, EvmChainConfig};
     use crate::evm::primitive_types::{
-        Block, BlockEnv, Receipt, SealedBlock, TransactionSignedAndRecovered,
+        Block, BlockEnv, BlockStats, Receipt, SealedBlock, StoredReceipt,
+        TransactionSignedAndRecovered,
     };
     use crate::EvmConfig;
 
     // Gas per transaction not creating a contract.
     pub(crate) const MIN_TRANSACTION_GAS: u64 = 21_000u64;
     pub(crate) const MIN_CREATE_GAS: u64 = 53_000u64;
+    // Number of recent blocks whose hashes are available to the `BLOCKHASH` opcode.
+    pub(crate) const BLOCK_HASH_HISTORY: u64 = 256;
 
     #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
     pub(crate) struct PendingTransaction {
@@ -82,6 +91,13 @@ mod experimental {
         #[state]
         pub(crate) block_env: sov_modules_api::StateValue<BlockEnv, BcsCodec>,
 
+        /// Hashes of the last `BLOCK_HASH_HISTORY` blocks, served to the `BLOCKHASH` opcode:
+        /// block_number => block_hash. A block is added in the `begin_slot_hook` of the next block,
+        /// once its `state_root` is known.
+        #[state]
+        pub(crate) latest_block_hashes:
+            sov_modules_api::StateMap<u64, reth_primitives::H256, BcsCodec>,
+
         /// Transactions that will be added to the current block.
         /// A valid transaction is added to the vec on every call message.
         #[state]
@@ -103,6 +119,10 @@ mod experimental {
         #[state]
         pub(crate) blocks: sov_modules_api::AccessoryStateVec<SealedBlock, BcsCodec>,
 
+        /// Used only by the RPC: block_number => statistics of the block, set in `end_slot_hook`.
+        #[state]
+        pub(crate) block_stats: sov_modules_api::AccessoryStateMap<u64, BlockStats, BcsCodec>,
+
         /// Used only by the RPC: block_hash => block_number mapping,
         #[state]
         pub(crate) block_hashes:
@@ -118,9 +138,9 @@ mod experimental {
         pub(crate) transaction_hashes:
             sov_modules_api::AccessoryStateMap<reth_primitives::H256, u64, BcsCodec>,
 
-        /// Used only by the RPC: Receipts.
+        /// Used only by the RPC: Receipts, prefixed with their version.
         #[state]
-        pub(crate) receipts: sov_modules_api::AccessoryStateVec<Receipt, BcsCodec>,
+        pub(crate) receipts: sov_modules_api::AccessoryStateVec<StoredReceipt, BcsCodec>,
     }
 
     impl<C: sov_modules_api::Context> sov_modules_api::Module for Evm<C> {
@@ -152,7 +172,12 @@ mod experimental {
 
     impl<C: sov_modules_api::Context> Evm<C> {
         pub(crate) fn get_db<'a>(&self, working_set: &'a mut WorkingSet<C>) -> EvmDb<'a, C> {
-            EvmDb::new(self.accounts.clone(), self.code.clone(), working_set)
+            EvmDb::new(
+                self.accounts.clone(),
+                self.code.clone(),
+                self.latest_block_hashes.clone(),
+                working_set,
+            )
         }
     }
 }
@@ -16,7 +16,9 @@ use tracing::info;
 use crate::call::get_cfg_env;
 use crate::error::rpc::{ensure_success, RevertError, RpcInvalidTransactionError};
 use crate::evm::db::EvmDb;
-use crate::evm::primitive_types::{BlockEnv, Receipt, SealedBlock, TransactionSignedAndRecovered};
+use crate::evm::primitive_types::{
+    BlockEnv, BlockStats, Receipt, SealedBlock, TransactionSignedAndRecovered,
+};
 use crate::evm::{executor, prepare_call_env};
 use crate::experimental::{MIN_CREATE_GAS, MIN_TRANSACTION_GAS};
 use crate::{EthApiError, Evm};
@@ -311,7 +313,8 @@ impl<C: sov_modules_api::Context> Evm<C> {
             let receipt = self
                 .receipts
                 .get(tx_number.unwrap() as usize, &mut accessory_state)
-                .expect("Receipt for known transaction must be set");
+                .expect("Receipt for known transaction must be set")
+                .into();
 
             build_rpc_receipt(block, tx, tx_number.unwrap(), receipt)
         });
@@ -373,6 +376,20 @@ impl<C: sov_modules_api::Context> Evm<C> {
         Ok(block_number)
     }
 
+    /// Handler for: `evm_getBlockStats`
+    #[rpc_method(name = "evm_getBlockStats")]
+    pub fn get_block_stats(
+        &self,
+        block_number: u64,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<Option<BlockStats>> {
+        info!("evm module: evm_getBlockStats");
+
+        Ok(self
+            .block_stats
+            .get(&block_number, &mut working_set.accessory_state()))
+    }
+
     /// Handler for: `eth_estimateGas`
     // https://github.com/paradigmxyz/reth/blob/main/crates/rpc/rpc/src/eth/api/call.rs#L172
     #[rpc_method(name = "eth_estimateGas")]
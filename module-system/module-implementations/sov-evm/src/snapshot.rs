@@ -0,0 +1,104 @@
+use jsonrpsee::core::RpcResult;
+use sov_modules_api::WorkingSet;
+use sov_state::storage::NativeStorage;
+
+use crate::Evm;
+
+/// A read-only view of the [`Evm`] state, pinned to the storage version it was taken at.
+///
+/// Every query builds its own [`WorkingSet`] on top of the pinned version, so a snapshot can be
+/// shared between threads and serve many read queries concurrently. Slots committed after the
+/// snapshot was taken are not visible through it.
+#[derive(Clone)]
+pub struct EvmSnapshot<C: sov_modules_api::Context> {
+    evm: Evm<C>,
+    storage: C::Storage,
+    version: u64,
+}
+
+impl<C: sov_modules_api::Context> EvmSnapshot<C>
+where
+    C::Storage: NativeStorage,
+{
+    /// Takes a snapshot of the latest committed state of `storage`.
+    pub fn new(evm: Evm<C>, storage: C::Storage) -> Self {
+        let version = storage.latest_version();
+        Self::at_version(evm, storage, version)
+    }
+
+    /// Takes a snapshot of `storage` at the given committed version.
+    pub fn at_version(evm: Evm<C>, storage: C::Storage, version: u64) -> Self {
+        Self {
+            evm,
+            storage,
+            version,
+        }
+    }
+
+    /// Returns the storage version this snapshot is pinned to.
+    pub fn version(&self) -> u64 {
+        self.version
+    }
+
+    fn working_set(&self) -> WorkingSet<C> {
+        let mut working_set = WorkingSet::new(self.storage.clone());
+        working_set.set_archival_version(self.version);
+        working_set
+    }
+
+    /// `eth_getBalance` against this snapshot.
+    pub fn get_balance(
+        &self,
+        address: reth_primitives::Address,
+        block_number: Option<String>,
+    ) -> RpcResult<reth_primitives::U256> {
+        self.evm
+            .get_balance(address, block_number, &mut self.working_set())
+    }
+
+    /// `eth_getStorageAt` against this snapshot.
+    pub fn get_storage_at(
+        &self,
+        address: reth_primitives::Address,
+        index: reth_primitives::U256,
+        block_number: Option<String>,
+    ) -> RpcResult<reth_primitives::U256> {
+        self.evm
+            .get_storage_at(address, index, block_number, &mut self.working_set())
+    }
+
+    /// `eth_getTransactionCount` against this snapshot.
+    pub fn get_transaction_count(
+        &self,
+        address: reth_primitives::Address,
+        block_number: Option<String>,
+    ) -> RpcResult<reth_primitives::U64> {
+        self.evm
+            .get_transaction_count(address, block_number, &mut self.working_set())
+    }
+
+    /// `eth_getCode` against this snapshot.
+    pub fn get_code(
+        &self,
+        address: reth_primitives::Address,
+        block_number: Option<String>,
+    ) -> RpcResult<reth_primitives::Bytes> {
+        self.evm
+            .get_code(address, block_number, &mut self.working_set())
+    }
+
+    /// `eth_call` against this snapshot. Any state changes made by the call are discarded.
+    pub fn get_call(
+        &self,
+        request: reth_rpc_types::CallRequest,
+        block_number: Option<String>,
+    ) -> RpcResult<reth_primitives::Bytes> {
+        self.evm
+            .get_call(request, block_number, None, None, &mut self.working_set())
+    }
+
+    /// `eth_blockNumber` against this snapshot.
+    pub fn block_number(&self) -> RpcResult<reth_primitives::U256> {
+        self.evm.block_number(&mut self.working_set())
+    }
+}
@@ -5,7 +5,7 @@ use sov_modules_api::utils::generate_address;
 use sov_modules_api::{Context, Module, StateMapAccessor, StateValueAccessor, StateVecAccessor};
 
 use crate::call::CallMessage;
-use crate::evm::primitive_types::Receipt;
+use crate::evm::primitive_types::{BlockStats, Receipt};
 use crate::smart_contracts::SimpleStorageContract;
 use crate::tests::genesis_tests::get_evm;
 use crate::tests::test_signer::TestSigner;
@@ -37,7 +37,7 @@ fn call_test() {
             .as_slice(),
     );
 
-    evm.begin_slot_hook([5u8; 32], &[10u8; 32].into(), &mut working_set);
+    evm.begin_slot_hook([5u8; 32], 0, &[10u8; 32].into(), &mut working_set);
 
     let set_arg = 999;
     {
@@ -65,6 +65,7 @@ fn call_test() {
     assert_eq!(
         evm.receipts
             .iter(&mut working_set.accessory_state())
+            .map(Receipt::from)
             .collect::<Vec<_>>(),
         [
             Receipt {
@@ -93,13 +94,201 @@ fn call_test() {
     )
 }
 
+#[test]
+fn end_slot_hook_records_block_stats() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let (evm, mut working_set) = get_evm(&config);
+
+    let contract_addr: Address = Address::from_slice(
+        hex::decode("819c5497b157177315e1204f52e588b393771719")
+            .unwrap()
+            .as_slice(),
+    );
+
+    evm.begin_slot_hook([5u8; 32], 0, &[10u8; 32].into(), &mut working_set);
+    {
+        let sender_address = generate_address::<C>("sender");
+        let sequencer_address = generate_address::<C>("sequencer");
+        let context = C::new(sender_address, sequencer_address, 1);
+
+        let messages = vec![
+            create_contract_message(&dev_signer, 0),
+            set_arg_message(contract_addr, &dev_signer, 1, 999),
+        ];
+        for tx in messages {
+            evm.call(tx, &context, &mut working_set).unwrap();
+        }
+    }
+    evm.end_slot_hook(&mut working_set);
+
+    let receipts: Vec<Receipt> = evm
+        .receipts
+        .iter(&mut working_set.accessory_state())
+        .map(Receipt::from)
+        .collect();
+    let base_fee = evm.block_env.get(&mut working_set).unwrap().basefee;
+
+    assert_eq!(
+        evm.get_block_stats(1, &mut working_set).unwrap(),
+        Some(BlockStats {
+            transaction_count: 2,
+            gas_used: receipts.iter().map(|receipt| receipt.gas_used).sum(),
+            base_fee,
+        })
+    );
+    // No stats are recorded for blocks that weren't produced yet
+    assert_eq!(evm.get_block_stats(2, &mut working_set).unwrap(), None);
+}
+
+#[test]
+fn blockhash_opcode_returns_previous_block_hash() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let (evm, mut working_set) = get_evm(&config);
+
+    let contract_addr: Address = Address::from_slice(
+        hex::decode("819c5497b157177315e1204f52e588b393771719")
+            .unwrap()
+            .as_slice(),
+    );
+    let sender_address = generate_address::<C>("sender");
+    let sequencer_address = generate_address::<C>("sequencer");
+    let context = C::new(sender_address, sequencer_address, 1);
+
+    // Init code copying the runtime code below into memory and returning it, followed by
+    // the runtime code `PUSH1 1 NUMBER SUB BLOCKHASH PUSH1 0 SSTORE STOP`, which stores
+    // the hash of the previous block in the storage slot 0.
+    let byte_code = hex::decode("6009600c60003960096000f3600143034060005500").unwrap();
+    evm.begin_slot_hook([5u8; 32], 0, &[10u8; 32].into(), &mut working_set);
+    let create_tx = dev_signer
+        .sign_default_transaction(TransactionKind::Create, byte_code, 0)
+        .unwrap();
+    evm.call(CallMessage { tx: create_tx }, &context, &mut working_set)
+        .unwrap();
+    evm.end_slot_hook(&mut working_set);
+
+    evm.begin_slot_hook([6u8; 32], 0, &[20u8; 32].into(), &mut working_set);
+    let call_tx = dev_signer
+        .sign_default_transaction(TransactionKind::Call(contract_addr), vec![], 1)
+        .unwrap();
+    evm.call(CallMessage { tx: call_tx }, &context, &mut working_set)
+        .unwrap();
+    evm.end_slot_hook(&mut working_set);
+
+    let block_1_hash = evm
+        .latest_block_hashes
+        .get(&1, &mut working_set)
+        .expect("The hash of block 1 should be recorded once its state root is known");
+    let db_account = evm.accounts.get(&contract_addr, &mut working_set).unwrap();
+    let storage_value = db_account
+        .storage
+        .get(&U256::ZERO, &mut working_set)
+        .unwrap();
+
+    assert_ne!(U256::ZERO, storage_value);
+    assert_eq!(U256::from_be_bytes(block_1_hash.0), storage_value);
+}
+
+#[test]
+fn timestamp_opcode_returns_slot_timestamp() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(1000000000),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let (evm, mut working_set) = get_evm(&config);
+
+    let contract_addr: Address = Address::from_slice(
+        hex::decode("819c5497b157177315e1204f52e588b393771719")
+            .unwrap()
+            .as_slice(),
+    );
+    let sender_address = generate_address::<C>("sender");
+    let sequencer_address = generate_address::<C>("sequencer");
+    let context = C::new(sender_address, sequencer_address, 1);
+
+    // Init code copying the runtime code below into memory and returning it, followed by
+    // the runtime code `TIMESTAMP PUSH1 0 SSTORE STOP`, which stores the timestamp of the
+    // block in the storage slot 0.
+    let byte_code = hex::decode("6005600c60003960056000f34260005500").unwrap();
+    let da_timestamp = 1_700_000_000;
+    evm.begin_slot_hook(
+        [5u8; 32],
+        da_timestamp,
+        &[10u8; 32].into(),
+        &mut working_set,
+    );
+    let create_tx = dev_signer
+        .sign_default_transaction(TransactionKind::Create, byte_code, 0)
+        .unwrap();
+    evm.call(CallMessage { tx: create_tx }, &context, &mut working_set)
+        .unwrap();
+    let call_tx = dev_signer
+        .sign_default_transaction(TransactionKind::Call(contract_addr), vec![], 1)
+        .unwrap();
+    evm.call(CallMessage { tx: call_tx }, &context, &mut working_set)
+        .unwrap();
+    evm.end_slot_hook(&mut working_set);
+
+    let db_account = evm.accounts.get(&contract_addr, &mut working_set).unwrap();
+    let storage_value = db_account
+        .storage
+        .get(&U256::ZERO, &mut working_set)
+        .unwrap();
+    assert_eq!(U256::from(da_timestamp), storage_value);
+
+    // A DA timestamp which doesn't move forward falls back to the configured delta.
+    evm.begin_slot_hook(
+        [6u8; 32],
+        da_timestamp - 1,
+        &[20u8; 32].into(),
+        &mut working_set,
+    );
+    let cfg = evm.cfg.get(&mut working_set).unwrap();
+    assert_eq!(
+        da_timestamp + cfg.block_timestamp_delta,
+        evm.block_env.get(&mut working_set).unwrap().timestamp
+    );
+}
+
 #[test]
 fn failed_transaction_test() {
     let dev_signer: TestSigner = TestSigner::new_random();
     let (evm, mut working_set) = get_evm(&EvmConfig::default());
     let working_set = &mut working_set;
 
-    evm.begin_slot_hook([5u8; 32], &[10u8; 32].into(), working_set);
+    evm.begin_slot_hook([5u8; 32], 0, &[10u8; 32].into(), working_set);
     {
         let sender_address = generate_address::<C>("sender");
         let sequencer_address = generate_address::<C>("sequencer");
@@ -123,7 +312,7 @@ fn failed_transaction_test() {
     assert_eq!(block.transactions.end, 0);
 }
 
-fn create_contract_message(dev_signer: &TestSigner, nonce: u64) -> CallMessage {
+pub(crate) fn create_contract_message(dev_signer: &TestSigner, nonce: u64) -> CallMessage {
     let contract = SimpleStorageContract::default();
     let signed_tx = dev_signer
         .sign_default_transaction(
@@ -125,6 +125,26 @@ fn genesis_cfg_cancun() {
     });
 }
 
+#[test]
+#[should_panic(expected = "EVM spec must name a concrete hardfork, not LATEST")]
+fn genesis_cfg_latest() {
+    get_evm(&EvmConfig {
+        spec: vec![(0, SpecId::LATEST)].into_iter().collect(),
+        ..Default::default()
+    });
+}
+
+#[test]
+fn genesis_cfg_explicit_spec() {
+    let (evm, mut working_set) = get_evm(&EvmConfig {
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    });
+
+    let cfg = evm.cfg.get(&mut working_set).unwrap();
+    assert_eq!(cfg.spec, vec![(0, SpecId::SHANGHAI)]);
+}
+
 #[test]
 fn genesis_block() {
     let (evm, mut working_set) = get_evm(&TEST_CONFIG);
@@ -4,11 +4,14 @@ use reth_primitives::{
     Address, Bloom, Bytes, Header, SealedHeader, Signature, TransactionSigned, EMPTY_OMMER_ROOT,
     H256, KECCAK_EMPTY, U256,
 };
-use sov_modules_api::{StateMapAccessor, StateValueAccessor, StateVecAccessor};
+use sov_modules_api::codec::BcsCodec;
+use sov_modules_api::{AccessoryStateVec, StateMapAccessor, StateValueAccessor, StateVecAccessor};
+use sov_state::storage::StateValueCodec;
+use sov_state::Prefix;
 
 use super::genesis_tests::{get_evm, TEST_CONFIG};
 use crate::evm::primitive_types::{
-    Block, BlockEnv, Receipt, SealedBlock, TransactionSignedAndRecovered,
+    Block, BlockEnv, Receipt, SealedBlock, StoredReceipt, TransactionSignedAndRecovered,
 };
 use crate::experimental::PendingTransaction;
 use crate::tests::genesis_tests::{BENEFICIARY, GENESIS_HASH};
@@ -20,7 +23,7 @@ lazy_static! {
 #[test]
 fn begin_slot_hook_creates_pending_block() {
     let (evm, mut working_set) = get_evm(&TEST_CONFIG);
-    evm.begin_slot_hook(DA_ROOT_HASH.0, &[10u8; 32].into(), &mut working_set);
+    evm.begin_slot_hook(DA_ROOT_HASH.0, 0, &[10u8; 32].into(), &mut working_set);
     let pending_block = evm.block_env.get(&mut working_set).unwrap();
     assert_eq!(
         pending_block,
@@ -38,7 +41,7 @@ fn begin_slot_hook_creates_pending_block() {
 #[test]
 fn end_slot_hook_sets_head() {
     let (evm, mut working_set) = get_evm(&TEST_CONFIG);
-    evm.begin_slot_hook(DA_ROOT_HASH.0, &[10u8; 32].into(), &mut working_set);
+    evm.begin_slot_hook(DA_ROOT_HASH.0, 0, &[10u8; 32].into(), &mut working_set);
 
     evm.pending_transactions.push(
         &create_pending_transaction(H256::from([1u8; 32]), 1),
@@ -97,7 +100,7 @@ fn end_slot_hook_sets_head() {
 #[test]
 fn end_slot_hook_moves_transactions_and_receipts() {
     let (evm, mut working_set) = get_evm(&TEST_CONFIG);
-    evm.begin_slot_hook(DA_ROOT_HASH.0, &[10u8; 32].into(), &mut working_set);
+    evm.begin_slot_hook(DA_ROOT_HASH.0, 0, &[10u8; 32].into(), &mut working_set);
 
     let tx1 = create_pending_transaction(H256::from([1u8; 32]), 1);
     evm.pending_transactions.push(&tx1, &mut working_set);
@@ -120,6 +123,7 @@ fn end_slot_hook_moves_transactions_and_receipts() {
     assert_eq!(
         evm.receipts
             .iter(&mut working_set.accessory_state())
+            .map(Receipt::from)
             .collect::<Vec<_>>(),
         [tx1.receipt, tx2.receipt]
     );
@@ -180,7 +184,7 @@ fn create_pending_transaction(hash: H256, index: u64) -> PendingTransaction {
 fn finalize_hook_creates_final_block() {
     let (evm, mut working_set) = get_evm(&TEST_CONFIG);
     let p = [10u8; 32].into();
-    evm.begin_slot_hook(DA_ROOT_HASH.0, &p, &mut working_set);
+    evm.begin_slot_hook(DA_ROOT_HASH.0, 0, &p, &mut working_set);
     evm.pending_transactions.push(
         &create_pending_transaction(H256::from([1u8; 32]), 1),
         &mut working_set,
@@ -197,7 +201,7 @@ fn finalize_hook_creates_final_block() {
     evm.finalize_hook(&root_hash, &mut accessory_state);
     assert_eq!(evm.blocks.len(&mut accessory_state), 2);
 
-    evm.begin_slot_hook(DA_ROOT_HASH.0, &root_hash, &mut working_set);
+    evm.begin_slot_hook(DA_ROOT_HASH.0, 0, &root_hash, &mut working_set);
 
     let mut accessory_state = working_set.accessory_state();
 
@@ -254,3 +258,59 @@ fn finalize_hook_creates_final_block() {
 
     assert_eq!(evm.pending_head.get(&mut accessory_state), None);
 }
+
+#[test]
+fn stored_v1_receipts_decode_after_the_receipt_changes() {
+    // A later version of the module, which extended the receipt with a new field.
+    #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct EvolvedReceipt {
+        receipt: Receipt,
+        effective_gas_price: u64,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    enum EvolvedStoredReceipt {
+        V1(Receipt),
+        #[allow(dead_code)]
+        V2(EvolvedReceipt),
+    }
+
+    impl From<EvolvedStoredReceipt> for EvolvedReceipt {
+        fn from(receipt: EvolvedStoredReceipt) -> Self {
+            match receipt {
+                EvolvedStoredReceipt::V1(receipt) => EvolvedReceipt {
+                    receipt,
+                    effective_gas_price: 0,
+                },
+                EvolvedStoredReceipt::V2(receipt) => receipt,
+            }
+        }
+    }
+
+    let (_, mut working_set) = get_evm(&TEST_CONFIG);
+    let receipt = create_pending_transaction(H256::from([1u8; 32]), 1).receipt;
+    let stored_receipt = StoredReceipt::from(receipt.clone());
+
+    // The variant index leads the encoding.
+    assert_eq!(0, BcsCodec.encode_value(&stored_receipt)[0]);
+
+    let prefix = Prefix::new(b"receipts".to_vec());
+    let receipts =
+        AccessoryStateVec::<StoredReceipt, BcsCodec>::with_codec(prefix.clone(), BcsCodec);
+    let evolved_receipts =
+        AccessoryStateVec::<EvolvedStoredReceipt, BcsCodec>::with_codec(prefix, BcsCodec);
+
+    let mut accessory_state = working_set.accessory_state();
+    receipts.push(&stored_receipt, &mut accessory_state);
+    let evolved_receipt: EvolvedReceipt = evolved_receipts
+        .get(0, &mut accessory_state)
+        .expect("The v1 receipt should be decoded")
+        .into();
+    assert_eq!(
+        EvolvedReceipt {
+            receipt,
+            effective_gas_price: 0,
+        },
+        evolved_receipt
+    );
+}
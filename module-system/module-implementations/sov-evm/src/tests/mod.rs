@@ -2,5 +2,7 @@ mod call_tests;
 mod cfg_tests;
 mod genesis_tests;
 mod hooks_tests;
+mod query_tests;
+mod snapshot_tests;
 pub(crate) mod test_signer;
 mod tx_tests;
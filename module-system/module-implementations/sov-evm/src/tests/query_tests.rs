@@ -0,0 +1,82 @@
+use reth_primitives::{Address, Bytes};
+use revm::primitives::{SpecId, KECCAK_EMPTY, U256};
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::utils::generate_address;
+use sov_modules_api::{Context, Module};
+
+use crate::tests::call_tests::create_contract_message;
+use crate::tests::genesis_tests::get_evm;
+use crate::tests::test_signer::TestSigner;
+use crate::{AccountData, EvmConfig};
+
+type C = DefaultContext;
+
+const INITIAL_BALANCE: u64 = 1000000000;
+
+#[test]
+fn balance_and_transaction_count_test() {
+    let dev_signer: TestSigner = TestSigner::new_random();
+    let config = EvmConfig {
+        data: vec![AccountData {
+            address: dev_signer.address(),
+            balance: U256::from(INITIAL_BALANCE),
+            code_hash: KECCAK_EMPTY,
+            code: Bytes::default(),
+            nonce: 0,
+        }],
+        // SHANGAI instead of LATEST
+        // https://github.com/Sovereign-Labs/sovereign-sdk/issues/912
+        spec: vec![(0, SpecId::SHANGHAI)].into_iter().collect(),
+        ..Default::default()
+    };
+
+    let (evm, mut working_set) = get_evm(&config);
+    let address = dev_signer.address();
+
+    let balance = evm.get_balance(address, None, &mut working_set).unwrap();
+    assert_eq!(U256::from(INITIAL_BALANCE), balance);
+    assert_eq!("\"0x3b9aca00\"", serde_json::to_string(&balance).unwrap());
+    let nonce = evm
+        .get_transaction_count(address, None, &mut working_set)
+        .unwrap();
+    assert_eq!(0, nonce.as_u64());
+    assert_eq!("\"0x0\"", serde_json::to_string(&nonce).unwrap());
+
+    evm.begin_slot_hook([5u8; 32], 0, &[10u8; 32].into(), &mut working_set);
+    {
+        let sender_address = generate_address::<C>("sender");
+        let sequencer_address = generate_address::<C>("sequencer");
+        let context = C::new(sender_address, sequencer_address, 1);
+
+        evm.call(
+            create_contract_message(&dev_signer, 0),
+            &context,
+            &mut working_set,
+        )
+        .unwrap();
+    }
+    evm.end_slot_hook(&mut working_set);
+
+    let nonce = evm
+        .get_transaction_count(address, None, &mut working_set)
+        .unwrap();
+    assert_eq!(1, nonce.as_u64());
+    assert_eq!("\"0x1\"", serde_json::to_string(&nonce).unwrap());
+    // The sender paid for the gas of the deployment.
+    let balance = evm.get_balance(address, None, &mut working_set).unwrap();
+    assert!(balance < U256::from(INITIAL_BALANCE));
+}
+
+#[test]
+fn absent_account_has_zero_balance_and_transaction_count() {
+    let (evm, mut working_set) = get_evm(&EvmConfig::default());
+    let address = Address::from_slice(&[7u8; 20]);
+
+    let balance = evm.get_balance(address, None, &mut working_set).unwrap();
+    assert_eq!(U256::ZERO, balance);
+    assert_eq!("\"0x0\"", serde_json::to_string(&balance).unwrap());
+    let nonce = evm
+        .get_transaction_count(address, None, &mut working_set)
+        .unwrap();
+    assert_eq!(0, nonce.as_u64());
+}
@@ -0,0 +1,46 @@
+use reth_primitives::Address;
+use revm::primitives::U256;
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::{Module, StateMapAccessor, WorkingSet};
+use sov_prover_storage_manager::new_orphan_storage;
+use sov_state::Storage;
+
+use crate::tests::genesis_tests::TEST_CONFIG;
+use crate::{Evm, EvmSnapshot};
+
+type C = DefaultContext;
+
+#[test]
+fn concurrent_balance_reads_against_snapshot() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let evm = Evm::<C>::default();
+    let address = Address::from([1u8; 20]);
+
+    let mut working_set = WorkingSet::new(storage.clone());
+    evm.genesis(&TEST_CONFIG, &mut working_set).unwrap();
+    let (reads_writes, witness) = working_set.checkpoint().freeze();
+    storage.validate_and_commit(reads_writes, &witness).unwrap();
+
+    let snapshot = EvmSnapshot::new(evm.clone(), storage.clone());
+
+    // Update the balance after the snapshot has been taken.
+    let mut working_set = WorkingSet::new(storage.clone());
+    let mut account = evm.accounts.get(&address, &mut working_set).unwrap();
+    account.info.balance = U256::from(5);
+    evm.accounts.set(&address, &account, &mut working_set);
+    let (reads_writes, witness) = working_set.checkpoint().freeze();
+    storage.validate_and_commit(reads_writes, &witness).unwrap();
+
+    let balances: Vec<U256> = std::thread::scope(|scope| {
+        let handles: Vec<_> = (0..8)
+            .map(|_| scope.spawn(|| snapshot.get_balance(address, None).unwrap()))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert_eq!(balances, vec![U256::from(1000000000); 8]);
+
+    let latest = EvmSnapshot::new(evm, storage);
+    assert_eq!(latest.version(), snapshot.version() + 1);
+    assert_eq!(latest.get_balance(address, None).unwrap(), U256::from(5));
+}
@@ -0,0 +1,76 @@
+use anyhow::Result;
+#[cfg(feature = "native")]
+use sov_modules_api::macros::CliWalletArg;
+use sov_modules_api::{CallResponse, Context, StateMapAccessor, WorkingSet};
+
+use crate::{NameService, NameServiceError};
+
+/// The minimum length of a name, in characters.
+pub const MIN_NAME_LENGTH: usize = 3;
+/// The maximum length of a name, in characters.
+pub const MAX_NAME_LENGTH: usize = 32;
+
+/// This enumeration represents the available call messages for interacting with the
+/// sov-name-service module.
+#[cfg_attr(feature = "native", derive(CliWalletArg), derive(schemars::JsonSchema))]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize),
+    derive(serde::Deserialize)
+)]
+#[derive(borsh::BorshDeserialize, borsh::BorshSerialize, Debug, PartialEq, Clone)]
+pub enum CallMessage {
+    /// Registers a name for the sender. The name is normalized with [`normalize_name`], and
+    /// must not be registered yet.
+    Register {
+        /// The name to register.
+        name: String,
+    },
+}
+
+/// Returns the canonical form of `name`, under which it is registered and resolved.
+///
+/// The name is converted to lowercase, must be between [`MIN_NAME_LENGTH`] and
+/// [`MAX_NAME_LENGTH`] characters long, and may only contain ASCII letters, digits, `-` and `_`.
+/// Restricting names to ASCII rules out distinct names which look the same.
+pub fn normalize_name(name: &str) -> Result<String, NameServiceError> {
+    if let Some(character) = name
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || *c == '-' || *c == '_'))
+    {
+        return Err(NameServiceError::InvalidCharacter {
+            name: name.to_owned(),
+            character,
+        });
+    }
+    // The name is ASCII, so its length in bytes is its length in characters.
+    if !(MIN_NAME_LENGTH..=MAX_NAME_LENGTH).contains(&name.len()) {
+        return Err(NameServiceError::InvalidLength {
+            name: name.to_owned(),
+        });
+    }
+    Ok(name.to_ascii_lowercase())
+}
+
+impl<C: Context> NameService<C> {
+    /// Assigns `name` to `owner`, if the name is valid and not registered yet.
+    pub(crate) fn register(
+        &self,
+        name: &str,
+        owner: &C::Address,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<CallResponse> {
+        let name = normalize_name(name)?;
+        if let Some(current_owner) = self.names.get(&name, working_set) {
+            return Err(NameServiceError::NameTaken {
+                name,
+                owner: current_owner.to_string(),
+            }
+            .into());
+        }
+
+        self.names.set(&name, owner, working_set);
+        working_set.add_event("register", &format!("name: {name}, owner: {owner}"));
+        Ok(CallResponse::default())
+    }
+}
@@ -0,0 +1,35 @@
+use thiserror::Error;
+
+/// The reasons a name can't be registered with the [`crate::NameService`] module.
+///
+/// The call errors of the module can be downcast to this type to match on the failure reason.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NameServiceError {
+    /// The name is shorter than [`crate::MIN_NAME_LENGTH`] or longer than
+    /// [`crate::MAX_NAME_LENGTH`] characters.
+    #[error(
+        "Name {name:?} must be between {} and {} characters long",
+        crate::MIN_NAME_LENGTH,
+        crate::MAX_NAME_LENGTH
+    )]
+    InvalidLength {
+        /// The rejected name.
+        name: String,
+    },
+    /// The name contains a character other than an ASCII letter, a digit, `-` or `_`.
+    #[error("Name {name:?} contains the invalid character {character:?}")]
+    InvalidCharacter {
+        /// The rejected name.
+        name: String,
+        /// The first invalid character of the name.
+        character: char,
+    },
+    /// The name was already registered.
+    #[error("Name {name:?} is already registered by {owner}")]
+    NameTaken {
+        /// The normalized name.
+        name: String,
+        /// The address which owns the name.
+        owner: String,
+    },
+}
@@ -0,0 +1,25 @@
+use anyhow::Result;
+use sov_modules_api::{Context, WorkingSet};
+
+use crate::NameService;
+
+/// Initial configuration for sov-name-service module.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(bound = "C::Address: serde::Serialize + serde::de::DeserializeOwned")]
+pub struct NameServiceConfig<C: Context> {
+    /// Names registered at genesis, with their owners.
+    pub names: Vec<(String, C::Address)>,
+}
+
+impl<C: Context> NameService<C> {
+    pub(crate) fn init_module(
+        &self,
+        config: &<Self as sov_modules_api::Module>::Config,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<()> {
+        for (name, owner) in config.names.iter() {
+            self.register(name, owner, working_set)?;
+        }
+        Ok(())
+    }
+}
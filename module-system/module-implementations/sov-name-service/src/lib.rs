@@ -0,0 +1,54 @@
+#![deny(missing_docs)]
+#![doc = include_str!("../README.md")]
+mod call;
+mod error;
+mod genesis;
+#[cfg(feature = "native")]
+mod query;
+#[cfg(feature = "native")]
+pub use query::*;
+
+pub use call::{normalize_name, CallMessage, MAX_NAME_LENGTH, MIN_NAME_LENGTH};
+pub use error::NameServiceError;
+pub use genesis::*;
+use sov_modules_api::{Context, Error, ModuleInfo, WorkingSet};
+
+/// A module mapping human-readable names to rollup addresses.
+#[cfg_attr(feature = "native", derive(sov_modules_api::ModuleCallJsonSchema))]
+#[derive(ModuleInfo, Clone)]
+pub struct NameService<C: Context> {
+    /// The address of the sov-name-service module.
+    #[address]
+    pub address: C::Address,
+
+    /// Mapping from a normalized name to the address which registered it.
+    #[state]
+    pub(crate) names: sov_modules_api::StateMap<String, C::Address>,
+}
+
+impl<C: Context> sov_modules_api::Module for NameService<C> {
+    type Context = C;
+
+    type Config = NameServiceConfig<C>;
+
+    type CallMessage = call::CallMessage;
+
+    type Event = ();
+
+    fn genesis(&self, config: &Self::Config, working_set: &mut WorkingSet<C>) -> Result<(), Error> {
+        Ok(self.init_module(config, working_set)?)
+    }
+
+    fn call(
+        &self,
+        msg: Self::CallMessage,
+        context: &Self::Context,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<sov_modules_api::CallResponse, Error> {
+        match msg {
+            call::CallMessage::Register { name } => {
+                Ok(self.register(&name, context.sender(), working_set)?)
+            }
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//! Defines rpc queries exposed by the name service module, along with the relevant types
+use jsonrpsee::core::RpcResult;
+use sov_modules_api::macros::rpc_gen;
+use sov_modules_api::{Context, StateMapAccessor, WorkingSet};
+
+use crate::{normalize_name, NameService};
+
+/// The response type to the `names_resolve` RPC method.
+#[derive(Debug, Eq, PartialEq, serde::Deserialize, serde::Serialize, Clone)]
+pub struct ResolveResponse<C: Context> {
+    /// The address owning the requested name.
+    pub address: Option<C::Address>,
+}
+
+#[rpc_gen(client, server, namespace = "names")]
+impl<C: Context> NameService<C> {
+    /// Returns the address which registered `name`.
+    ///
+    /// The name is normalized first, so the lookup is case-insensitive. The response only
+    /// contains an address if the name is registered, which is never the case for invalid names.
+    #[rpc_method(name = "resolve")]
+    pub fn resolve(
+        &self,
+        name: String,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<ResolveResponse<C>> {
+        let address = normalize_name(&name)
+            .ok()
+            .and_then(|name| self.names.get(&name, working_set));
+        Ok(ResolveResponse { address })
+    }
+}
@@ -0,0 +1,143 @@
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::{Address, Context, Error, Module, WorkingSet};
+use sov_name_service::{
+    normalize_name, CallMessage, NameService, NameServiceConfig, NameServiceError, ResolveResponse,
+    MAX_NAME_LENGTH,
+};
+use sov_prover_storage_manager::new_orphan_storage;
+
+type C = DefaultContext;
+
+const GENESIS_OWNER: Address = Address::new([1; 32]);
+const ALICE: Address = Address::new([2; 32]);
+const BOB: Address = Address::new([3; 32]);
+const SEQUENCER: Address = Address::new([4; 32]);
+
+fn setup(working_set: &mut WorkingSet<C>) -> NameService<C> {
+    let name_service = NameService::<C>::default();
+    let config = NameServiceConfig {
+        names: vec![("Genesis".to_owned(), GENESIS_OWNER)],
+    };
+    name_service.genesis(&config, working_set).unwrap();
+    name_service
+}
+
+fn register(
+    name_service: &NameService<C>,
+    name: &str,
+    sender: Address,
+    working_set: &mut WorkingSet<C>,
+) -> Result<(), NameServiceError> {
+    let context = C::new(sender, SEQUENCER, 1);
+    let message = CallMessage::Register {
+        name: name.to_owned(),
+    };
+    match name_service.call(message, &context, working_set) {
+        Ok(_) => Ok(()),
+        Err(Error::ModuleError(err)) => Err(err.downcast().unwrap()),
+    }
+}
+
+fn resolve(
+    name_service: &NameService<C>,
+    name: &str,
+    working_set: &mut WorkingSet<C>,
+) -> Option<Address> {
+    let ResolveResponse { address } = name_service.resolve(name.to_owned(), working_set).unwrap();
+    address
+}
+
+#[test]
+fn register_and_resolve() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let name_service = setup(&mut working_set);
+
+    assert_eq!(
+        Some(GENESIS_OWNER),
+        resolve(&name_service, "genesis", &mut working_set)
+    );
+    assert_eq!(None, resolve(&name_service, "alice", &mut working_set));
+
+    register(&name_service, "Alice", ALICE, &mut working_set).unwrap();
+    register(&name_service, "bob_2", BOB, &mut working_set).unwrap();
+
+    // Names are resolved regardless of their case.
+    for name in ["alice", "Alice", "ALICE"] {
+        assert_eq!(Some(ALICE), resolve(&name_service, name, &mut working_set));
+    }
+    assert_eq!(Some(BOB), resolve(&name_service, "bob_2", &mut working_set));
+    assert_eq!(None, resolve(&name_service, "bob", &mut working_set));
+    // An address can own several names.
+    register(&name_service, "alice-2", ALICE, &mut working_set).unwrap();
+    assert_eq!(
+        Some(ALICE),
+        resolve(&name_service, "alice-2", &mut working_set)
+    );
+}
+
+#[test]
+fn duplicate_names_are_rejected() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let name_service = setup(&mut working_set);
+
+    register(&name_service, "alice", ALICE, &mut working_set).unwrap();
+
+    // The first registration owns the name, also against a different case or the same sender.
+    for (name, sender) in [("alice", BOB), ("ALICE", BOB), ("alice", ALICE)] {
+        assert_eq!(
+            Err(NameServiceError::NameTaken {
+                name: "alice".to_owned(),
+                owner: ALICE.to_string(),
+            }),
+            register(&name_service, name, sender, &mut working_set)
+        );
+    }
+    assert!(matches!(
+        register(&name_service, "genesis", BOB, &mut working_set),
+        Err(NameServiceError::NameTaken { .. })
+    ));
+    assert_eq!(
+        Some(ALICE),
+        resolve(&name_service, "alice", &mut working_set)
+    );
+
+    // Genesis names are unique as well.
+    let name_service = NameService::<C>::default();
+    let config = NameServiceConfig {
+        names: vec![("name".to_owned(), ALICE), ("NAME".to_owned(), BOB)],
+    };
+    assert!(name_service.genesis(&config, &mut working_set).is_err());
+}
+
+#[test]
+fn invalid_names_are_rejected() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let name_service = setup(&mut working_set);
+
+    let too_long = "a".repeat(MAX_NAME_LENGTH + 1);
+    for name in ["", "ab", too_long.as_str()] {
+        assert_eq!(
+            Err(NameServiceError::InvalidLength {
+                name: name.to_owned()
+            }),
+            register(&name_service, name, ALICE, &mut working_set)
+        );
+        assert_eq!(None, resolve(&name_service, name, &mut working_set));
+    }
+    for (name, character) in [("alice bob", ' '), ("alicé", 'é'), ("alice.eth", '.')] {
+        assert_eq!(
+            Err(NameServiceError::InvalidCharacter {
+                name: name.to_owned(),
+                character
+            }),
+            register(&name_service, name, ALICE, &mut working_set)
+        );
+    }
+
+    assert_eq!(Ok("abc".to_owned()), normalize_name("aBc"));
+    let longest = "A".repeat(MAX_NAME_LENGTH);
+    assert_eq!(Ok(longest.to_lowercase()), normalize_name(&longest));
+}
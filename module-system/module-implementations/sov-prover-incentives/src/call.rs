@@ -5,8 +5,9 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use sov_bank::Coins;
 use sov_modules_api::prelude::*;
 use sov_modules_api::{CallResponse, WorkingSet};
+use sov_state::storage::Storage;
 
-use crate::ProverIncentives;
+use crate::{AcceptedProof, ProverIncentives};
 
 /// This enumeration represents the available call messages for interacting with the `ExampleModule` module.
 #[cfg_attr(feature = "native", derive(schemars::JsonSchema))]
@@ -20,9 +21,20 @@ pub enum CallMessage {
     UnbondProver,
     /// Verifies the provided proof (of format `Vec<u8>`)
     VerifyProof(Vec<u8>),
+    /// Allows proofs verified against the provided code commitment (serialized with `bincode`).
+    /// Can only be called by the admin.
+    AddAllowedCommitment(Vec<u8>),
+    /// Stops allowing proofs verified against the provided code commitment (serialized with `bincode`).
+    /// The last allowed commitment cannot be removed. Can only be called by the admin.
+    RemoveAllowedCommitment(Vec<u8>),
 }
 
-impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C, Vm> {
+impl<C, Vm, Da> ProverIncentives<C, Vm, Da>
+where
+    C: sov_modules_api::Context,
+    Vm: sov_modules_api::Zkvm,
+    Da: sov_modules_api::DaSpec,
+{
     /// A helper function for the `bond_prover` call. Also used to bond provers
     /// during genesis when no context is available.
     pub(super) fn bond_prover_helper(
@@ -113,6 +125,12 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
         context: &C,
         working_set: &mut WorkingSet<C>,
     ) -> Result<sov_modules_api::CallResponse> {
+        // Revert before touching the bond if the proof can't be checked at all.
+        anyhow::ensure!(
+            Vm::supports_verification(),
+            "Proof verification is not supported by the configured zkVM"
+        );
+
         // Get the prover's old balance.
         // Revert if they aren't bonded
         let old_balance = self
@@ -123,20 +141,34 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
         let minimum_bond = self.minimum_bond.get_or_err(working_set)?;
 
         anyhow::ensure!(old_balance >= minimum_bond, "Prover is not bonded");
-        let code_commitment = self
-            .commitment_of_allowed_verifier_method
-            .get_or_err(working_set)?;
+        let allowed_commitments: Vec<Vm::CodeCommitment> = self
+            .allowed_verifier_commitments
+            .iter(working_set)
+            .collect();
 
         // Lock the prover's bond amount.
         self.bonded_provers
             .set(context.sender(), &(old_balance - minimum_bond), working_set);
 
         // Don't return an error for invalid proofs - those are expected and shouldn't cause reverts.
-        if let Ok(_public_outputs) =
-            Vm::verify(proof, &code_commitment).map_err(|e| anyhow::format_err!("{:?}", e))
-        {
-            // TODO: decide what the proof output is and do something with it
-            //     https://github.com/Sovereign-Labs/sovereign-sdk/issues/272
+        // The proof is accepted if it verifies against any of the allowed commitments.
+        let public_outputs = allowed_commitments.iter().find_map(|code_commitment| {
+            Vm::verify_and_extract_output::<Da, <C::Storage as Storage>::Root>(
+                proof,
+                code_commitment,
+            )
+            .ok()
+        });
+        if let Some(state_transition) = public_outputs {
+            // Index the proof by the state root it starts from
+            self.proofs_by_initial_root.set(
+                &state_transition.initial_state_root.clone(),
+                &AcceptedProof {
+                    proof: proof.to_vec(),
+                    state_transition,
+                },
+                working_set,
+            );
 
             // Unlock the prover's bond
             // TODO: reward the prover with newly minted tokens as appropriate based on gas fees.
@@ -157,4 +189,89 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
 
         Ok(CallResponse::default())
     }
+
+    /// Allows proofs verified against `commitment`, if the sender is the admin.
+    pub(crate) fn add_allowed_commitment(
+        &self,
+        commitment: &[u8],
+        context: &C,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<sov_modules_api::CallResponse> {
+        self.ensure_admin(context, working_set)?;
+        let commitment: Vm::CodeCommitment = bincode::deserialize(commitment)?;
+
+        let (_, index) = self.find_allowed_commitment(&commitment, working_set)?;
+        anyhow::ensure!(index.is_none(), "The commitment is already allowed");
+        self.allowed_verifier_commitments
+            .push(&commitment, working_set);
+
+        working_set.add_event(
+            "added_allowed_commitment",
+            &format!("commitment: {commitment:?}"),
+        );
+
+        Ok(CallResponse::default())
+    }
+
+    /// Stops allowing proofs verified against `commitment`, if the sender is the admin.
+    pub(crate) fn remove_allowed_commitment(
+        &self,
+        commitment: &[u8],
+        context: &C,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<sov_modules_api::CallResponse> {
+        self.ensure_admin(context, working_set)?;
+        let commitment: Vm::CodeCommitment = bincode::deserialize(commitment)?;
+
+        let (mut allowed_commitments, index) =
+            self.find_allowed_commitment(&commitment, working_set)?;
+        let index = index.ok_or_else(|| anyhow::anyhow!("The commitment is not allowed"))?;
+        anyhow::ensure!(
+            allowed_commitments.len() > 1,
+            "The last allowed commitment cannot be removed"
+        );
+        allowed_commitments.remove(index);
+        self.allowed_verifier_commitments
+            .set_all(allowed_commitments, working_set);
+
+        working_set.add_event(
+            "removed_allowed_commitment",
+            &format!("commitment: {commitment:?}"),
+        );
+
+        Ok(CallResponse::default())
+    }
+
+    fn ensure_admin(&self, context: &C, working_set: &mut WorkingSet<C>) -> Result<()> {
+        let admin = self.admin.get_or_err(working_set)?;
+        anyhow::ensure!(
+            &admin == context.sender(),
+            "Only the admin can update the allowed commitments"
+        );
+        Ok(())
+    }
+
+    /// Returns the allowed commitments, along with the index of `commitment` among them if it is allowed.
+    /// Commitments are compared by their serialized representation.
+    fn find_allowed_commitment(
+        &self,
+        commitment: &Vm::CodeCommitment,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<(Vec<Vm::CodeCommitment>, Option<usize>)> {
+        let encoded_commitment = bincode::serialize(commitment)?;
+        let allowed_commitments: Vec<Vm::CodeCommitment> = self
+            .allowed_verifier_commitments
+            .iter(working_set)
+            .collect();
+
+        let mut index = None;
+        for (i, allowed_commitment) in allowed_commitments.iter().enumerate() {
+            if bincode::serialize(allowed_commitment)? == encoded_commitment {
+                index = Some(i);
+                break;
+            }
+        }
+
+        Ok((allowed_commitments, index))
+    }
 }
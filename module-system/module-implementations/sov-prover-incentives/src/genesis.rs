@@ -6,8 +6,8 @@ use sov_modules_api::{WorkingSet, Zkvm};
 use crate::ProverIncentives;
 
 /// Configuration of the prover incentives module. Specifies the
-/// address of the bonding token, the minimum bond, the commitment to
-/// the allowed verifier method and a set of initial provers with their
+/// address of the bonding token, the minimum bond, the admin, the commitments
+/// to the allowed verifier methods and a set of initial provers with their
 /// bonding amount.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ProverIncentivesConfig<C: sov_modules_api::Context, Vm: Zkvm> {
@@ -15,15 +15,22 @@ pub struct ProverIncentivesConfig<C: sov_modules_api::Context, Vm: Zkvm> {
     pub bonding_token_address: C::Address,
     /// The minimum bond for a prover.
     pub minimum_bond: u64,
-    /// A code commitment to be used for verifying proofs
-    pub commitment_of_allowed_verifier_method: Vm::CodeCommitment,
+    /// The address allowed to update the allowed verifier commitments.
+    pub admin: C::Address,
+    /// The code commitments to be used for verifying proofs
+    pub allowed_verifier_commitments: Vec<Vm::CodeCommitment>,
     /// A list of initial provers and their bonded amount.
     pub initial_provers: Vec<(C::Address, u64)>,
 }
 
-impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C, Vm> {
+impl<C, Vm, Da> ProverIncentives<C, Vm, Da>
+where
+    C: sov_modules_api::Context,
+    Vm: sov_modules_api::Zkvm,
+    Da: sov_modules_api::DaSpec,
+{
     /// Init the [`ProverIncentives`] module using the provided `config`.
-    /// Sets the minimum amount necessary to bond, the commitment to the verifier circuit
+    /// Sets the minimum amount necessary to bond, the admin, the commitments to the verifier circuits,
     /// the bonding token address and builds the set of initial provers.
     pub(crate) fn init_module(
         &self,
@@ -35,9 +42,15 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
             "At least one prover must be set at genesis!"
         );
 
+        anyhow::ensure!(
+            !config.allowed_verifier_commitments.is_empty(),
+            "At least one verifier commitment must be allowed at genesis!"
+        );
+
         self.minimum_bond.set(&config.minimum_bond, working_set);
-        self.commitment_of_allowed_verifier_method
-            .set(&config.commitment_of_allowed_verifier_method, working_set);
+        self.admin.set(&config.admin, working_set);
+        self.allowed_verifier_commitments
+            .set_all(config.allowed_verifier_commitments.clone(), working_set);
         self.bonding_token_address
             .set(&config.bonding_token_address, working_set);
 
@@ -14,8 +14,21 @@ pub use genesis::*;
 /// The response type used by RPC queries.
 #[cfg(feature = "native")]
 pub use query::*;
-use sov_modules_api::{Context, Error, ModuleInfo, WorkingSet, Zkvm};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use sov_modules_api::{Context, DaSpec, Error, ModuleInfo, StateTransition, WorkingSet, Zkvm};
 use sov_state::codec::BcsCodec;
+use sov_state::storage::Storage;
+
+/// A proof accepted by the [`ProverIncentives`] module, along with the state transition it proves.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(bound = "Root: Serialize + DeserializeOwned")]
+pub struct AcceptedProof<Da: DaSpec, Root> {
+    /// The serialized proof, as submitted by the prover.
+    pub proof: Vec<u8>,
+    /// The public output of the proof.
+    pub state_transition: StateTransition<Da, Root>,
+}
 
 /// A new module:
 /// - Must derive `ModuleInfo`
@@ -23,7 +36,7 @@ use sov_state::codec::BcsCodec;
 /// - Can contain any number of ` #[state]` or `[module]` fields
 #[cfg_attr(feature = "native", derive(sov_modules_api::ModuleCallJsonSchema))]
 #[derive(ModuleInfo)]
-pub struct ProverIncentives<C: Context, Vm: Zkvm> {
+pub struct ProverIncentives<C: Context, Vm: Zkvm, Da: DaSpec> {
     /// Address of the module.
     #[address]
     pub address: C::Address,
@@ -32,10 +45,14 @@ pub struct ProverIncentives<C: Context, Vm: Zkvm> {
     #[state]
     pub bonding_token_address: sov_modules_api::StateValue<C::Address>,
 
-    /// The code commitment to be used for verifying proofs
+    /// The address allowed to update the allowed verifier commitments
+    #[state]
+    pub admin: sov_modules_api::StateValue<C::Address>,
+
+    /// The code commitments to be used for verifying proofs. A proof is accepted if it
+    /// verifies against any of them, which allows overlapping zkVM image upgrades.
     #[state]
-    pub commitment_of_allowed_verifier_method:
-        sov_modules_api::StateValue<Vm::CodeCommitment, BcsCodec>,
+    pub allowed_verifier_commitments: sov_modules_api::StateVec<Vm::CodeCommitment, BcsCodec>,
 
     /// The set of registered provers and their bonded amount.
     #[state]
@@ -45,12 +62,21 @@ pub struct ProverIncentives<C: Context, Vm: Zkvm> {
     #[state]
     pub minimum_bond: sov_modules_api::StateValue<u64>,
 
+    /// The accepted proofs, indexed by the initial state root of their state transition.
+    /// If several proofs start from the same root, only the latest one is kept.
+    #[state]
+    pub proofs_by_initial_root: sov_modules_api::StateMap<
+        <C::Storage as Storage>::Root,
+        AcceptedProof<Da, <C::Storage as Storage>::Root>,
+        BcsCodec,
+    >,
+
     /// Reference to the Bank module.
     #[module]
     pub(crate) bank: sov_bank::Bank<C>,
 }
 
-impl<C: Context, Vm: Zkvm> sov_modules_api::Module for ProverIncentives<C, Vm> {
+impl<C: Context, Vm: Zkvm, Da: DaSpec> sov_modules_api::Module for ProverIncentives<C, Vm, Da> {
     type Context = C;
 
     type Config = ProverIncentivesConfig<C, Vm>;
@@ -78,6 +104,12 @@ impl<C: Context, Vm: Zkvm> sov_modules_api::Module for ProverIncentives<C, Vm> {
             call::CallMessage::VerifyProof(proof) => {
                 self.process_proof(&proof, context, working_set)
             }
+            call::CallMessage::AddAllowedCommitment(commitment) => {
+                self.add_allowed_commitment(&commitment, context, working_set)
+            }
+            call::CallMessage::RemoveAllowedCommitment(commitment) => {
+                self.remove_allowed_commitment(&commitment, context, working_set)
+            }
         }
         .map_err(|e| e.into())
     }
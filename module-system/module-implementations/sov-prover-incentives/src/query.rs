@@ -1,7 +1,11 @@
+use jsonrpsee::core::RpcResult;
 use serde::{Deserialize, Serialize};
+use sov_modules_api::macros::rpc_gen;
 use sov_modules_api::{StateMapAccessor, WorkingSet};
+use sov_state::storage::Storage;
 
 use super::ProverIncentives;
+use crate::AcceptedProof;
 
 /// The structure containing the response returned by the `get_bond_amount` query.
 #[derive(Serialize, Deserialize, Debug, Eq, PartialEq)]
@@ -10,7 +14,10 @@ pub struct Response {
     pub value: u64,
 }
 
-impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C, Vm> {
+#[rpc_gen(client, server, namespace = "prover_incentives")]
+impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm, Da: sov_modules_api::DaSpec>
+    ProverIncentives<C, Vm, Da>
+{
     /// Queries the state of the module and returns the bond amount of the address `address`.
     /// If the `address` is not bonded, returns a default value.
     pub fn get_bond_amount(
@@ -25,4 +32,17 @@ impl<C: sov_modules_api::Context, Vm: sov_modules_api::Zkvm> ProverIncentives<C,
                 .unwrap_or_default(), // self.value.get(working_set),
         }
     }
+
+    #[rpc_method(name = "getProofForRoot")]
+    /// Returns the latest accepted proof whose state transition starts from `initial_state_root`,
+    /// if any.
+    pub fn get_proof_for_root(
+        &self,
+        initial_state_root: <C::Storage as Storage>::Root,
+        working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<Option<AcceptedProof<Da, <C::Storage as Storage>::Root>>> {
+        Ok(self
+            .proofs_by_initial_root
+            .get(&initial_state_root, working_set))
+    }
 }
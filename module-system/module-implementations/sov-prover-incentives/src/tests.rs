@@ -1,9 +1,11 @@
-use sov_mock_da::MockValidityCond;
+use sov_mock_da::{MockDaSpec, MockValidityCond};
 use sov_mock_zkvm::{MockCodeCommitment, MockProof, MockZkvm};
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_api::digest::Digest;
 use sov_modules_api::prelude::*;
-use sov_modules_api::{Address, Context, Module, Spec, WorkingSet};
+use sov_modules_api::{
+    Address, Context, DaSpec, Module, Spec, StateRoot, StateTransition, WorkingSet, Zkvm,
+};
 use sov_prover_storage_manager::new_orphan_storage;
 
 use crate::ProverIncentives;
@@ -43,13 +45,67 @@ fn create_bank_config() -> (
     )
 }
 
+fn admin_address() -> <C as Spec>::Address {
+    generate_address("admin_pub_key")
+}
+
+/// Builds a mock state transition between the two roots.
+fn mock_transition(
+    initial_state_root: StateRoot,
+    final_state_root: StateRoot,
+) -> StateTransition<MockDaSpec, StateRoot> {
+    StateTransition {
+        initial_state_root,
+        final_state_root,
+        slot_hash: [1; 32].into(),
+        validity_condition: MockValidityCond::default(),
+    }
+}
+
+/// A zkVM that can't verify proofs in the current environment, like the risc0 guest
+/// outside of the zkVM.
+struct UnverifiableZkvm;
+
+impl Zkvm for UnverifiableZkvm {
+    type CodeCommitment = MockCodeCommitment;
+
+    type Error = anyhow::Error;
+
+    fn verify<'a>(
+        _serialized_proof: &'a [u8],
+        _code_commitment: &Self::CodeCommitment,
+    ) -> Result<&'a [u8], Self::Error> {
+        anyhow::bail!("Proofs can't be verified in this environment")
+    }
+
+    fn verify_and_extract_output<
+        Da: DaSpec,
+        Root: serde::Serialize + serde::de::DeserializeOwned,
+    >(
+        _serialized_proof: &[u8],
+        _code_commitment: &Self::CodeCommitment,
+    ) -> Result<StateTransition<Da, Root>, Self::Error> {
+        anyhow::bail!("Proofs can't be verified in this environment")
+    }
+
+    fn supports_verification() -> bool {
+        false
+    }
+}
+
 fn setup(
     working_set: &mut WorkingSet<C>,
 ) -> (
-    ProverIncentives<C, MockZkvm<MockValidityCond>>,
+    ProverIncentives<C, MockZkvm<MockValidityCond>, MockDaSpec>,
     Address,
     Address,
 ) {
+    setup_with_vm(working_set)
+}
+
+fn setup_with_vm<Vm: Zkvm<CodeCommitment = MockCodeCommitment>>(
+    working_set: &mut WorkingSet<C>,
+) -> (ProverIncentives<C, Vm, MockDaSpec>, Address, Address) {
     // Initialize bank
     let (bank_config, prover_address, sequencer) = create_bank_config();
     let bank = sov_bank::Bank::<C>::default();
@@ -62,11 +118,12 @@ fn setup(
     );
 
     // initialize prover incentives
-    let module = ProverIncentives::<C, MockZkvm<MockValidityCond>>::default();
+    let module = ProverIncentives::<C, Vm, MockDaSpec>::default();
     let config = crate::ProverIncentivesConfig {
         bonding_token_address: token_address,
         minimum_bond: BOND_AMOUNT,
-        commitment_of_allowed_verifier_method: MockCodeCommitment([0u8; 32]),
+        admin: admin_address(),
+        allowed_verifier_commitments: vec![MOCK_CODE_COMMITMENT],
         initial_provers: vec![(prover_address, BOND_AMOUNT)],
     };
 
@@ -112,6 +169,35 @@ fn test_burn_on_invalid_proof() {
     );
 }
 
+#[test]
+fn test_verification_unsupported() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let (module, prover_address, sequencer) = setup_with_vm::<UnverifiableZkvm>(&mut working_set);
+
+    let context = DefaultContext::new(prover_address, sequencer, 1);
+    let proof = MockProof {
+        program_id: MOCK_CODE_COMMITMENT,
+        is_valid: true,
+        log: &[],
+    };
+    let err = module
+        .process_proof(proof.encode_to_vec().as_ref(), &context, &mut working_set)
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "Proof verification is not supported by the configured zkVM"
+    );
+
+    // The prover isn't slashed for it
+    assert_eq!(
+        module
+            .get_bond_amount(prover_address, &mut working_set)
+            .value,
+        BOND_AMOUNT
+    );
+}
+
 #[test]
 fn test_valid_proof() {
     let tmpdir = tempfile::tempdir().unwrap();
@@ -129,10 +215,12 @@ fn test_valid_proof() {
     // Process a valid proof
     {
         let context = DefaultContext::new(prover_address, sequencer, 1);
+        let transition =
+            bincode::serialize(&mock_transition(StateRoot([0; 32]), StateRoot([1; 32]))).unwrap();
         let proof = MockProof {
             program_id: MOCK_CODE_COMMITMENT,
             is_valid: true,
-            log: &[],
+            log: &transition,
         };
         module
             .process_proof(proof.encode_to_vec().as_ref(), &context, &mut working_set)
@@ -232,3 +320,128 @@ fn test_prover_not_bonded() {
             .is_err())
     }
 }
+
+#[test]
+fn test_get_proof_for_root() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let (module, prover_address, sequencer) = setup(&mut working_set);
+    let context = DefaultContext::new(prover_address, sequencer, 1);
+
+    let initial_state_root = StateRoot([1; 32]);
+    let final_state_root = StateRoot([2; 32]);
+
+    // No proof has been accepted yet
+    assert_eq!(
+        module
+            .get_proof_for_root(initial_state_root, &mut working_set)
+            .unwrap(),
+        None
+    );
+
+    let transition =
+        bincode::serialize(&mock_transition(initial_state_root, final_state_root)).unwrap();
+    let proof = MockProof {
+        program_id: MOCK_CODE_COMMITMENT,
+        is_valid: true,
+        log: &transition,
+    }
+    .encode_to_vec();
+    module
+        .process_proof(&proof, &context, &mut working_set)
+        .expect("A valid proof is not an error");
+
+    let accepted_proof = module
+        .get_proof_for_root(initial_state_root, &mut working_set)
+        .unwrap()
+        .expect("The proof should be indexed by its initial root");
+    assert_eq!(accepted_proof.proof, proof);
+    assert_eq!(
+        accepted_proof.state_transition.initial_state_root,
+        initial_state_root
+    );
+    assert_eq!(
+        accepted_proof.state_transition.final_state_root,
+        final_state_root
+    );
+
+    // Proofs are not indexed by their final root
+    assert_eq!(
+        module
+            .get_proof_for_root(final_state_root, &mut working_set)
+            .unwrap(),
+        None
+    );
+}
+
+#[test]
+fn test_allowed_commitments_update() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let mut working_set = WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let (module, prover_address, sequencer) = setup(&mut working_set);
+    let admin_context = DefaultContext::new(admin_address(), sequencer, 1);
+    let prover_context = DefaultContext::new(prover_address, sequencer, 1);
+
+    let new_commitment = MockCodeCommitment([1u8; 32]);
+    let encoded_new_commitment = bincode::serialize(&new_commitment).unwrap();
+    let encoded_old_commitment = bincode::serialize(&MOCK_CODE_COMMITMENT).unwrap();
+
+    let transition =
+        bincode::serialize(&mock_transition(StateRoot([0; 32]), StateRoot([1; 32]))).unwrap();
+    let proof_for = |program_id| {
+        MockProof {
+            program_id,
+            is_valid: true,
+            log: &transition,
+        }
+        .encode_to_vec()
+    };
+
+    // Only the admin can update the allowed commitments
+    module
+        .add_allowed_commitment(&encoded_new_commitment, &prover_context, &mut working_set)
+        .expect_err("Only the admin can add a commitment");
+
+    module
+        .add_allowed_commitment(&encoded_new_commitment, &admin_context, &mut working_set)
+        .expect("The admin can add a commitment");
+
+    // A proof against the new image is accepted
+    module
+        .process_proof(
+            &proof_for(new_commitment),
+            &prover_context,
+            &mut working_set,
+        )
+        .expect("A valid proof is not an error");
+    assert_eq!(
+        module
+            .get_bond_amount(prover_address, &mut working_set)
+            .value,
+        BOND_AMOUNT
+    );
+
+    module
+        .remove_allowed_commitment(&encoded_old_commitment, &admin_context, &mut working_set)
+        .expect("The admin can remove a commitment");
+
+    // The last allowed commitment cannot be removed
+    module
+        .remove_allowed_commitment(&encoded_new_commitment, &admin_context, &mut working_set)
+        .expect_err("The last commitment cannot be removed");
+
+    // A proof against the old image is now rejected, and the prover is slashed
+    module
+        .process_proof(
+            &proof_for(MOCK_CODE_COMMITMENT),
+            &prover_context,
+            &mut working_set,
+        )
+        .expect("An invalid proof is not an error");
+    assert_eq!(
+        module
+            .get_bond_amount(prover_address, &mut working_set)
+            .value,
+        0
+    );
+}
@@ -1,4 +1,4 @@
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use serde::{Deserialize, Serialize};
 use sov_modules_api::prelude::*;
 use sov_modules_api::WorkingSet;
@@ -55,6 +55,24 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> SequencerRegistry
 
         Ok(())
     }
+
+    pub(crate) fn validate_config(
+        &self,
+        config: &<Self as sov_modules_api::Module>::Config,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<()> {
+        let token_address = &config.coins_to_lock.token_address;
+        ensure!(
+            self.bank
+                .get_total_supply_of(token_address, working_set)
+                .is_some(),
+            "The token {} to lock for the sequencer {} does not exist in the bank",
+            token_address,
+            config.seq_rollup_address
+        );
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
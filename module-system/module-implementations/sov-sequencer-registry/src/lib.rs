@@ -82,6 +82,14 @@ impl<C: sov_modules_api::Context, Da: sov_modules_api::DaSpec> sov_modules_api::
         Ok(self.init_module(config, working_set)?)
     }
 
+    fn validate_genesis(
+        &self,
+        config: &Self::Config,
+        working_set: &mut WorkingSet<C>,
+    ) -> Result<(), Error> {
+        Ok(self.validate_config(config, working_set)?)
+    }
+
     fn call(
         &self,
         message: Self::CallMessage,
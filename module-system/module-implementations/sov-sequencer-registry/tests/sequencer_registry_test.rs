@@ -1,11 +1,18 @@
 use helpers::*;
 use sov_mock_da::MockAddress;
-use sov_modules_api::{Context, Error, Module, ModuleInfo, WorkingSet};
+use sov_modules_api::macros::DefaultRuntime;
+use sov_modules_api::{Context, DaSpec, Error, Genesis, Module, ModuleInfo, WorkingSet};
 use sov_prover_storage_manager::new_orphan_storage;
 use sov_sequencer_registry::{CallMessage, SequencerRegistry};
 
 mod helpers;
 
+#[derive(Genesis, DefaultRuntime)]
+struct TestRuntime<C: Context, Da: DaSpec> {
+    bank: sov_bank::Bank<C>,
+    sequencer_registry: SequencerRegistry<C, Da>,
+}
+
 // Happy path for registration and exit
 // This test checks:
 //  - genesis sequencer is present after genesis
@@ -279,3 +286,34 @@ fn test_preferred_sequencer_returned_and_removed() {
         .get_preferred_sequencer(working_set)
         .is_none());
 }
+
+#[test]
+fn test_genesis_fails_when_token_to_lock_is_missing() {
+    let runtime = TestRuntime::<C, Da>::default();
+    let (bank_config, seq_rollup_address) = create_bank_config();
+    let token_address = generate_address("missing_token");
+    let config = GenesisConfig::new(
+        bank_config,
+        create_sequencer_config(seq_rollup_address, token_address),
+    );
+
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    let Error::ModuleError(err) = runtime
+        .genesis(&config, working_set)
+        .expect_err("Genesis should fail");
+    assert_eq!(
+        format!(
+            "The token {} to lock for the sequencer {} does not exist in the bank",
+            token_address, seq_rollup_address
+        ),
+        err.to_string()
+    );
+
+    // The same configuration with the token created by the bank passes.
+    let test_sequencer = create_test_sequencer();
+    let config = GenesisConfig::new(test_sequencer.bank_config, test_sequencer.sequencer_config);
+    let tmpdir = tempfile::tempdir().unwrap();
+    let working_set = &mut WorkingSet::new(new_orphan_storage(tmpdir.path()).unwrap());
+    runtime.genesis(&config, working_set).unwrap();
+}
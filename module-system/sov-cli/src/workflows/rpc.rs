@@ -11,8 +11,8 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 use sov_accounts::AccountsRpcClient;
 use sov_bank::{BalanceResponse, BankRpcClient};
-use sov_modules_api::clap;
 use sov_modules_api::transaction::Transaction;
+use sov_modules_api::{clap, VersionedCall};
 
 use crate::wallet_state::{AddressEntry, KeyIdentifier, WalletState};
 use crate::workflows::keys::load_key;
@@ -91,7 +91,7 @@ impl<C: sov_modules_api::Context + Serialize + DeserializeOwned + Send + Sync> R
         _app_dir: impl AsRef<Path>,
     ) -> Result<(), anyhow::Error>
     where
-        Tx: Serialize + DeserializeOwned + BorshSerialize + BorshDeserialize,
+        Tx: Serialize + DeserializeOwned + BorshSerialize + BorshDeserialize + VersionedCall,
     {
         // If the user is just setting the RPC url, we can skip the usual setup
         if let RpcWorkflows::SetUrl { rpc_url } = self {
@@ -156,7 +156,7 @@ impl<C: sov_modules_api::Context + Serialize + DeserializeOwned + Send + Sync> R
                     .map(|(offset, tx)| {
                         Transaction::<C>::new_signed_tx(
                             &private_key,
-                            tx.try_to_vec().unwrap(),
+                            tx.tx.encode_versioned(),
                             tx.chain_id,
                             tx.gas_tip,
                             tx.gas_limit,
@@ -33,7 +33,6 @@ pub struct BankMessageGenerator<C: Context> {
 const DEFAULT_TOKEN_NAME: &str = "Token1";
 const DEFAULT_SALT: u64 = 10;
 const DEFAULT_PVT_KEY: &str = "236e80cb222c4ed0431b093b3ac53e6aa7a2273fe1f4351cd354989a823432a27b758bf2e7670fafaf6bf0015ce0ff5aa802306fc7e3f45762853ffc37180fe6";
-const DEFAULT_CHAIN_ID: u64 = 0;
 const DEFAULT_GAS_TIP: u64 = 0;
 const DEFAULT_GAS_LIMIT: u64 = 0;
 const DEFAULT_INIT_BALANCE: u64 = 1000000;
@@ -236,7 +235,6 @@ impl<C: Context> MessageGenerator for BankMessageGenerator<C> {
             messages.push(Message::new(
                 mint_message.minter_pkey.clone(),
                 mint_token_tx::<C>(mint_message),
-                DEFAULT_CHAIN_ID,
                 DEFAULT_GAS_TIP,
                 DEFAULT_GAS_LIMIT,
                 nonce,
@@ -248,7 +246,6 @@ impl<C: Context> MessageGenerator for BankMessageGenerator<C> {
             messages.push(Message::new(
                 transfer_message.sender_pkey.clone(),
                 transfer_token_tx::<C>(transfer_message),
-                DEFAULT_CHAIN_ID,
                 DEFAULT_GAS_TIP,
                 DEFAULT_GAS_LIMIT,
                 nonce,
@@ -307,7 +304,6 @@ impl MessageGenerator for BadSerializationBankCallMessages {
                 minter_address,
                 authorized_minters: Vec::from([minter_address]),
             },
-            DEFAULT_CHAIN_ID,
             DEFAULT_GAS_TIP,
             DEFAULT_GAS_LIMIT,
             0,
@@ -321,7 +317,6 @@ impl MessageGenerator for BadSerializationBankCallMessages {
                     token_address: get_default_token_address(),
                 },
             },
-            DEFAULT_CHAIN_ID,
             DEFAULT_GAS_TIP,
             DEFAULT_GAS_LIMIT,
             0,
@@ -385,7 +380,6 @@ impl MessageGenerator for BadSignatureBankCallMessages {
                 minter_address,
                 authorized_minters: Vec::from([minter_address]),
             },
-            DEFAULT_CHAIN_ID,
             DEFAULT_GAS_TIP,
             DEFAULT_GAS_LIMIT,
             0,
@@ -464,7 +458,6 @@ impl MessageGenerator for BadNonceBankCallMessages {
                 minter_address,
                 authorized_minters: Vec::from([minter_address]),
             },
-            DEFAULT_CHAIN_ID,
             DEFAULT_GAS_TIP,
             DEFAULT_GAS_LIMIT,
             0,
@@ -14,11 +14,10 @@ pub mod value_setter_data;
 pub fn new_test_blob_from_batch(
     batch: Batch,
     address: &[u8],
-    hash: [u8; 32],
 ) -> <MockDaSpec as DaSpec>::BlobTransaction {
     let address = MockAddress::try_from(address).unwrap();
     let data = batch.try_to_vec().unwrap();
-    MockBlob::new(data, address, hash)
+    MockBlob::with_data_hash(data, address)
 }
 
 pub fn has_tx_events<A: RollupAddress>(
@@ -38,8 +37,6 @@ pub struct Message<C: Context, Mod: Module> {
     pub sender_key: Rc<<C as Spec>::PrivateKey>,
     /// The message content.
     pub content: Mod::CallMessage,
-    /// The ID of the chain.
-    pub chain_id: u64,
     /// The gas tip for the sequencer.
     pub gas_tip: u64,
     /// The gas limit for the transaction execution.
@@ -52,7 +49,6 @@ impl<C: Context, Mod: Module> Message<C, Mod> {
     fn new(
         sender_key: Rc<<C as Spec>::PrivateKey>,
         content: Mod::CallMessage,
-        chain_id: u64,
         gas_tip: u64,
         gas_limit: u64,
         nonce: u64,
@@ -60,7 +56,6 @@ impl<C: Context, Mod: Module> Message<C, Mod> {
         Self {
             sender_key,
             content,
-            chain_id,
             gas_tip,
             gas_limit,
             nonce,
@@ -100,8 +95,8 @@ pub trait MessageGenerator {
         is_last: bool,
     ) -> Transaction<Self::Context>;
 
-    /// Creates a vector of raw transactions from the module.
-    fn create_raw_txs<Encoder: EncodeCall<Self::Module>>(&self) -> Vec<RawTx> {
+    /// Creates a vector of raw transactions from the module, signed for the chain `chain_id`.
+    fn create_raw_txs<Encoder: EncodeCall<Self::Module>>(&self, chain_id: u64) -> Vec<RawTx> {
         let mut messages_iter = self.create_messages().into_iter().peekable();
         let mut serialized_messages = Vec::default();
         while let Some(message) = messages_iter.next() {
@@ -110,7 +105,7 @@ pub trait MessageGenerator {
             let tx = self.create_tx::<Encoder>(
                 &message.sender_key,
                 message.content,
-                message.chain_id,
+                chain_id,
                 message.gas_tip,
                 message.gas_limit,
                 message.nonce,
@@ -124,9 +119,9 @@ pub trait MessageGenerator {
         serialized_messages
     }
 
-    fn create_blobs<Encoder: EncodeCall<Self::Module>>(&self) -> Vec<u8> {
+    fn create_blobs<Encoder: EncodeCall<Self::Module>>(&self, chain_id: u64) -> Vec<u8> {
         let txs: Vec<Vec<u8>> = self
-            .create_raw_txs::<Encoder>()
+            .create_raw_txs::<Encoder>(chain_id)
             .into_iter()
             .map(|tx| tx.data)
             .collect();
@@ -8,7 +8,6 @@ use sov_value_setter::ValueSetter;
 use super::*;
 use crate::EncodeCall;
 
-const DEFAULT_CHAIN_ID: u64 = 0;
 const DEFAULT_GAS_TIP: u64 = 0;
 const DEFAULT_GAS_LIMIT: u64 = 0;
 
@@ -55,7 +54,6 @@ impl<C: Context> MessageGenerator for ValueSetterMessages<C> {
                 messages.push(Message::new(
                     admin.clone(),
                     set_value_msg,
-                    DEFAULT_CHAIN_ID,
                     DEFAULT_GAS_TIP,
                     DEFAULT_GAS_LIMIT,
                     value_setter_admin_nonce.try_into().unwrap(),
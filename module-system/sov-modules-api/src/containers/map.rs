@@ -1,6 +1,10 @@
 use std::marker::PhantomData;
 
 use sov_modules_core::{Context, Prefix, StateCodec, StateKeyCodec, StateValueCodec, WorkingSet};
+#[cfg(feature = "native")]
+use sov_modules_core::{
+    NativeStorage, StateKeyDecoder, StateReaderAndWriter, StorageKey, StorageValue,
+};
 use sov_state::codec::BorshCodec;
 
 use super::traits::StateMapAccessor;
@@ -72,6 +76,217 @@ where
     }
 }
 
+#[cfg(feature = "native")]
+impl<K, V, Codec> StateMap<K, V, Codec>
+where
+    Codec: StateCodec,
+    Codec::ValueCodec: StateValueCodec<V>,
+{
+    /// Rewrites every entry of this [`StateMap`] found in the backing storage with
+    /// the map's value codec, after decoding it with `old_value_codec`.
+    ///
+    /// This is meant to be used after changing the value codec of a map, so that
+    /// entries written with the previous codec remain readable. Only the values
+    /// are migrated: storage keys are kept as they are, so the key codec must not
+    /// have changed. Entries which are only present in `working_set` are not
+    /// visible to the scan and are left untouched, and so are the entries of nested
+    /// containers, with the caveat described in [`StateMap::iter`]. If any value fails
+    /// to decode, no entry is migrated.
+    ///
+    /// Returns the number of migrated entries.
+    pub fn migrate_codec<OldVC, C>(
+        &self,
+        old_value_codec: &OldVC,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<usize>
+    where
+        OldVC: StateValueCodec<V>,
+        Codec::KeyCodec: StateKeyDecoder<K>,
+        C: Context,
+        C::Storage: NativeStorage,
+    {
+        self.rewrite_values(working_set, |key, old_value| {
+            let value = old_value_codec
+                .try_decode_value(old_value.value())
+                .map_err(|err| {
+                    anyhow::anyhow!(
+                        "Failed to decode the value of key {} with the old codec: {:?}",
+                        key,
+                        err
+                    )
+                })?;
+            Ok(StorageValue::new(&value, self.codec.value_codec()))
+        })
+    }
+
+    /// Converts every entry of this [`StateMap`] found in the backing storage to a `NewV`
+    /// with `f`, and writes it back encoded with the map's value codec.
+    ///
+    /// This is meant to be used when a module changes the value type of a map: the migration
+    /// runs on a map with the old value type, after which the entries can be read by a map with
+    /// the same prefix and codec, and `NewV` as its value type. The same caveats as
+    /// [`StateMap::migrate_codec`] apply.
+    ///
+    /// Returns the number of migrated entries.
+    pub fn migrate<NewV, F, C>(
+        &self,
+        f: F,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<usize>
+    where
+        F: Fn(V) -> NewV,
+        Codec::KeyCodec: StateKeyDecoder<K>,
+        Codec::ValueCodec: StateValueCodec<NewV>,
+        C: Context,
+        C::Storage: NativeStorage,
+    {
+        self.rewrite_values(working_set, |key, old_value| {
+            let value: V = self
+                .codec
+                .value_codec()
+                .try_decode_value(old_value.value())
+                .map_err(|err| {
+                    anyhow::anyhow!(
+                        "Failed to decode the value of key {} with the old type: {:?}",
+                        key,
+                        err
+                    )
+                })?;
+            Ok(StorageValue::new(&f(value), self.codec.value_codec()))
+        })
+    }
+
+    /// Returns all the entries of this [`StateMap`], ordered by their encoded keys.
+    ///
+    /// The keys are found by scanning the backing storage, so entries which are only
+    /// present in `working_set` are not returned, while the values are read through
+    /// `working_set`, so entries updated or removed there are returned as they are in
+    /// `working_set`.
+    ///
+    /// The scan also finds the storage keys of nested containers, whose prefix extends
+    /// this map's prefix. They are skipped if the rest of the storage key, after this
+    /// map's prefix, isn't exactly the encoding of some key of this map. Otherwise,
+    /// as with fixed-size keys of the same length, they can't be told apart from the
+    /// entries of this map and are returned as such.
+    ///
+    /// Returns an error if the storage can't be scanned or a value can't be decoded.
+    pub fn iter<C>(
+        &self,
+        working_set: &mut WorkingSet<C>,
+    ) -> anyhow::Result<impl Iterator<Item = (K, V)>>
+    where
+        Codec::KeyCodec: StateKeyDecoder<K>,
+        C: Context,
+        C::Storage: NativeStorage,
+    {
+        let mut entries = Vec::new();
+
+        for (storage_key, key) in self.decoded_keys(working_set)? {
+            let value = match working_set.get(&storage_key) {
+                Some(value) => value,
+                // The entry was removed in the working set after it was committed.
+                None => continue,
+            };
+            let value = self
+                .codec
+                .value_codec()
+                .try_decode_value(value.value())
+                .map_err(|err| {
+                    anyhow::anyhow!(
+                        "Failed to decode the value of key {}: {:?}",
+                        storage_key,
+                        err
+                    )
+                })?;
+            entries.push((key, value));
+        }
+
+        Ok(entries.into_iter())
+    }
+
+    /// Returns all the keys of this [`StateMap`], with the same caveats as [`StateMap::iter`].
+    pub fn keys<C>(&self, working_set: &mut WorkingSet<C>) -> anyhow::Result<Vec<K>>
+    where
+        Codec::KeyCodec: StateKeyDecoder<K>,
+        C: Context,
+        C::Storage: NativeStorage,
+    {
+        let keys = self
+            .decoded_keys(working_set)?
+            .into_iter()
+            .filter(|(storage_key, _)| working_set.get(storage_key).is_some())
+            .map(|(_, key)| key)
+            .collect();
+
+        Ok(keys)
+    }
+
+    /// Replaces the value of every entry of this map found in the backing storage with the
+    /// result of `rewrite`, and returns the number of rewritten entries.
+    ///
+    /// Storage keys which aren't keys of this map are skipped, as described in [`StateMap::iter`].
+    /// Every value is rewritten before any of them is written, so nothing is written if one of
+    /// them fails.
+    fn rewrite_values<C>(
+        &self,
+        working_set: &mut WorkingSet<C>,
+        mut rewrite: impl FnMut(&StorageKey, &StorageValue) -> anyhow::Result<StorageValue>,
+    ) -> anyhow::Result<usize>
+    where
+        Codec::KeyCodec: StateKeyDecoder<K>,
+        C: Context,
+        C::Storage: NativeStorage,
+    {
+        let mut rewritten = Vec::new();
+
+        for (storage_key, _) in self.decoded_keys(working_set)? {
+            let old_value = match working_set.get(&storage_key) {
+                Some(value) => value,
+                // The entry was removed in the working set after it was committed.
+                None => continue,
+            };
+            let value = rewrite(&storage_key, &old_value)?;
+            rewritten.push((storage_key, value));
+        }
+
+        let count = rewritten.len();
+        for (storage_key, value) in rewritten {
+            working_set.set(&storage_key, value);
+        }
+        Ok(count)
+    }
+
+    /// Returns the storage keys under the prefix of this map which decode to keys of this map,
+    /// along with the decoded keys.
+    ///
+    /// A storage key is kept if the key codec decodes the rest of it, after the prefix, and
+    /// encodes the decoded key back to the same bytes. This only rejects the keys of nested
+    /// containers which can't be decoded, or which are decoded while ignoring trailing bytes.
+    fn decoded_keys<C>(&self, working_set: &WorkingSet<C>) -> anyhow::Result<Vec<(StorageKey, K)>>
+    where
+        Codec::KeyCodec: StateKeyDecoder<K>,
+        C: Context,
+        C::Storage: NativeStorage,
+    {
+        let prefix_len = self.prefix.len();
+        let keys = working_set
+            .get_keys_with_prefix(&self.prefix)?
+            .into_iter()
+            .filter_map(|storage_key| {
+                let encoded_key = &storage_key.as_ref()[prefix_len..];
+                let key = self.codec.key_codec().try_decode_key(encoded_key).ok()?;
+                // Codecs which ignore trailing bytes can decode the keys of nested containers.
+                if self.codec.key_codec().encode_key(&key) != encoded_key {
+                    return None;
+                }
+                Some((storage_key, key))
+            })
+            .collect();
+
+        Ok(keys)
+    }
+}
+
 #[cfg(feature = "arbitrary")]
 impl<'a, K, V, Codec> StateMap<K, V, Codec>
 where
@@ -97,6 +97,19 @@ where
         })
     }
 
+    /// Returns `true` if the map contains a value for the key.
+    ///
+    /// This is cheaper than checking the result of [`StateMapAccessor::get`], as the value
+    /// isn't deserialized, and it records the same witness.
+    fn contains_key<Q>(&self, key: &Q, working_set: &mut W) -> bool
+    where
+        Codec: StateCodec,
+        Codec::KeyCodec: EncodeKeyLike<Q, K>,
+        Q: ?Sized,
+    {
+        working_set.contains_value(self.prefix(), key, self.codec())
+    }
+
     /// Removes a key from the map, returning the corresponding value (or
     /// [`None`] if the key is absent).
     fn remove<Q>(&self, key: &Q, working_set: &mut W) -> Option<V>
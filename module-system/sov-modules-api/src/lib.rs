@@ -32,10 +32,11 @@ pub use clap;
 #[cfg(feature = "native")]
 pub use sov_modules_core::PrivateKey;
 pub use sov_modules_core::{
-    archival_state, runtime, AccessoryWorkingSet, Address, AddressBech32, CallResponse, Context,
-    DispatchCall, EncodeCall, GasUnit, Genesis, KernelModule, KernelWorkingSet, Module,
-    ModuleCallJsonSchema, ModuleError, ModuleError as Error, ModuleInfo, ModulePrefix, PublicKey,
-    Signature, Spec, StateCheckpoint, StateReaderAndWriter, VersionedWorkingSet, WorkingSet,
+    archival_state, runtime, AccessStats, AccessoryWorkingSet, Address, AddressBech32,
+    CallResponse, Context, DispatchCall, EncodeCall, GasUnit, Genesis, KernelModule,
+    KernelWorkingSet, Module, ModuleCallJsonSchema, ModuleError, ModuleError as Error, ModuleInfo,
+    ModulePrefix, PublicKey, Savepoint, Signature, Spec, StateCheckpoint, StateReaderAndWriter,
+    VersionedCall, VersionedWorkingSet, WorkingSet,
 };
 pub use sov_rollup_interface::da::{BlobReaderTrait, DaSpec};
 pub use sov_rollup_interface::services::da::SlotData;
@@ -43,7 +44,8 @@ pub use sov_rollup_interface::stf::Event;
 pub use sov_rollup_interface::zk::{
     StateTransition, ValidityCondition, ValidityConditionChecker, Zkvm,
 };
-pub use sov_rollup_interface::{digest, BasicAddress, RollupAddress};
+pub use sov_rollup_interface::{digest, BasicAddress, RollupAddress, StateRoot};
+pub use sov_state::codec;
 
 pub mod prelude {
     pub use super::{StateMapAccessor, StateValueAccessor, StateVecAccessor};
@@ -178,6 +180,22 @@ where
     Ok(sorted_values)
 }
 
+/// Panics if two modules of the runtime named `runtime_name` have the same prefix, since their
+/// state would be stored under the same keys. Each [`ModulePrefix`] is given with the name of the
+/// runtime field holding the module, which is used in the panic message.
+pub fn assert_unique_module_prefixes(runtime_name: &str, modules: &[(&str, ModulePrefix)]) {
+    for (i, (first_name, first_prefix)) in modules.iter().enumerate() {
+        for (second_name, second_prefix) in &modules[i + 1..] {
+            if first_prefix == second_prefix {
+                panic!(
+                    "Modules `{}` and `{}` of runtime `{}` have the same prefix {:?}, so their state would collide",
+                    first_name, second_name, runtime_name, first_prefix
+                );
+            }
+        }
+    }
+}
+
 /// This trait is implemented by types that can be used as arguments in the sov-cli wallet.
 /// The recommended way to implement this trait is using the provided derive macro (`#[derive(CliWalletArg)]`).
 /// Currently, this trait is a thin wrapper around [`clap::Parser`]
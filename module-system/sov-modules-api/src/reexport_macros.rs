@@ -66,6 +66,14 @@ pub use sov_modules_macros::ModuleCallJsonSchema;
 ///   - `#[state]` is used for state members.
 ///   - `#[module]` is used for module members.
 ///
+/// The codec of a state member is built with [`Default::default`] unless the
+/// attribute says otherwise:
+///   - `#[state(codec = "json")]` uses one of the codecs shipped in [`codec`]
+///     (`"bcs"`, `"bincode"`, `"borsh"` or `"json"`). The codec must match the
+///     one in the type of the field.
+///   - `#[state(codec_builder = "path::to::constructor")]` calls a custom
+///     constructor.
+///
 /// In addition to implementing [`ModuleInfo`], this macro will
 /// also generate so-called "prefix" methods.
 ///
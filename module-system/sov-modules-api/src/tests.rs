@@ -4,6 +4,7 @@ use sov_modules_core::{Address, PrivateKey, Signature};
 use crate::default_context::DefaultContext;
 use crate::default_signature::private_key::DefaultPrivateKey;
 use crate::default_signature::{DefaultPublicKey, DefaultSignature};
+use crate::transaction::Transaction;
 use crate::ModuleInfo;
 
 #[test]
@@ -180,3 +181,35 @@ fn test_default_signature_roundtrip() {
     sig.verify(&key.pub_key(), msg)
         .expect("Roundtrip verification failed");
 }
+
+#[test]
+fn test_expiring_tx_signature_does_not_cover_a_non_expiring_tx() {
+    let key = DefaultPrivateKey::generate();
+    let (msg, chain_id, gas_tip, gas_limit, nonce, valid_until_slot) =
+        (vec![1, 2, 3], 4, 5, 6, 7, 8);
+    let tx = Transaction::<DefaultContext>::new_signed_tx_with_expiry(
+        &key,
+        msg.clone(),
+        chain_id,
+        gas_tip,
+        gas_limit,
+        nonce,
+        Some(valid_until_slot),
+    );
+    tx.verify().unwrap();
+
+    // Moves the chain id into the message and every following field one slot to the left, so
+    // that the expiry becomes the nonce of a transaction which never expires.
+    let mut shifted_msg = msg;
+    shifted_msg.extend_from_slice(&chain_id.to_le_bytes());
+    let shifted_tx = Transaction::<DefaultContext>::new(
+        tx.pub_key().clone(),
+        shifted_msg,
+        tx.signature().clone(),
+        gas_tip,
+        gas_limit,
+        nonce,
+        valid_until_slot,
+    );
+    assert!(shifted_tx.verify().is_err());
+}
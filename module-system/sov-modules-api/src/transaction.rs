@@ -9,6 +9,8 @@ use sov_modules_macros::config_constant;
 use sov_zk_cycle_macros::cycle_tracker;
 
 const EXTEND_MESSAGE_LEN: usize = 4 * core::mem::size_of::<u64>();
+const EXPIRY_LEN: usize = core::mem::size_of::<u64>();
+const EXPIRY_TAG_LEN: usize = 1;
 
 /// A Transaction object that is compatible with the module-system/sov-default-stf.
 #[derive(
@@ -22,6 +24,7 @@ pub struct Transaction<C: Context> {
     gas_tip: u64,
     gas_limit: u64,
     nonce: u64,
+    valid_until_slot: Option<u64>,
 }
 
 /// An unsent transaction with the required data to be submitted to the DA layer
@@ -70,6 +73,17 @@ impl<C: Context> Transaction<C> {
         self.gas_limit
     }
 
+    /// The last slot height at which the transaction can be executed, if it expires.
+    pub const fn valid_until_slot(&self) -> Option<u64> {
+        self.valid_until_slot
+    }
+
+    /// Returns `true` if the transaction can't be executed anymore at `slot_height`.
+    pub fn is_expired(&self, slot_height: u64) -> bool {
+        self.valid_until_slot
+            .map_or(false, |valid_until_slot| valid_until_slot < slot_height)
+    }
+
     pub fn gas_fixed_cost(&self) -> C::GasUnit {
         #[config_constant]
         const GAS_TX_FIXED_COST: &[u64];
@@ -89,20 +103,32 @@ impl<C: Context> Transaction<C> {
     /// Check whether the transaction has been signed correctly.
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
     pub fn verify(&self) -> anyhow::Result<()> {
-        let mut serialized_tx = Vec::with_capacity(self.runtime_msg().len() + EXTEND_MESSAGE_LEN);
+        let mut serialized_tx = Vec::with_capacity(
+            self.runtime_msg().len() + EXTEND_MESSAGE_LEN + EXPIRY_LEN + EXPIRY_TAG_LEN,
+        );
 
         serialized_tx.extend_from_slice(self.runtime_msg());
         serialized_tx.extend_from_slice(&self.chain_id().to_le_bytes());
         serialized_tx.extend_from_slice(&self.gas_tip().to_le_bytes());
         serialized_tx.extend_from_slice(&self.gas_limit().to_le_bytes());
         serialized_tx.extend_from_slice(&self.nonce().to_le_bytes());
+        // The signed bytes end with a tag telling whether an expiry precedes it. Without it, the
+        // bytes signed for an expiring transaction could be read as those of a transaction which
+        // never expires and whose message is 8 bytes longer.
+        match self.valid_until_slot {
+            Some(valid_until_slot) => {
+                serialized_tx.extend_from_slice(&valid_until_slot.to_le_bytes());
+                serialized_tx.push(1);
+            }
+            None => serialized_tx.push(0),
+        }
 
         self.signature().verify(&self.pub_key, &serialized_tx)?;
 
         Ok(())
     }
 
-    /// New transaction.
+    /// New transaction, without an expiry.
     pub fn new(
         pub_key: C::PublicKey,
         message: Vec<u8>,
@@ -120,32 +146,61 @@ impl<C: Context> Transaction<C> {
             gas_tip,
             gas_limit,
             nonce,
+            valid_until_slot: None,
         }
     }
 }
 
 #[cfg(feature = "native")]
 impl<C: Context> Transaction<C> {
-    /// New signed transaction.
+    /// New signed transaction, without an expiry.
     pub fn new_signed_tx(
+        priv_key: &C::PrivateKey,
+        message: Vec<u8>,
+        chain_id: u64,
+        gas_tip: u64,
+        gas_limit: u64,
+        nonce: u64,
+    ) -> Self {
+        Self::new_signed_tx_with_expiry(
+            priv_key, message, chain_id, gas_tip, gas_limit, nonce, None,
+        )
+    }
+
+    /// New signed transaction, which is skipped by the STF once the slot height exceeds
+    /// `valid_until_slot`.
+    pub fn new_signed_tx_with_expiry(
         priv_key: &C::PrivateKey,
         mut message: Vec<u8>,
         chain_id: u64,
         gas_tip: u64,
         gas_limit: u64,
         nonce: u64,
+        valid_until_slot: Option<u64>,
     ) -> Self {
         // Since we own the message already, try to add the serialized nonce in-place.
         // This lets us avoid a copy if the message vec has at least 8 bytes of extra capacity.
         let len = message.len();
+        let extend_len = match valid_until_slot {
+            Some(_) => EXTEND_MESSAGE_LEN + EXPIRY_LEN + EXPIRY_TAG_LEN,
+            None => EXTEND_MESSAGE_LEN + EXPIRY_TAG_LEN,
+        };
 
         // resizes once to avoid potential multiple realloc
-        message.resize(len + EXTEND_MESSAGE_LEN, 0);
+        message.resize(len + extend_len, 0);
 
         message[len..len + 8].copy_from_slice(&chain_id.to_le_bytes());
         message[len + 8..len + 16].copy_from_slice(&gas_tip.to_le_bytes());
         message[len + 16..len + 24].copy_from_slice(&gas_limit.to_le_bytes());
         message[len + 24..len + 32].copy_from_slice(&nonce.to_le_bytes());
+        // Same layout as in `verify`: the expiry, if any, then the tag byte.
+        match valid_until_slot {
+            Some(valid_until_slot) => {
+                message[len + 32..len + 40].copy_from_slice(&valid_until_slot.to_le_bytes());
+                message[len + 40] = 1;
+            }
+            None => message[len + 32] = 0,
+        }
 
         let pub_key = priv_key.pub_key();
         let signature = priv_key.sign(&message);
@@ -161,6 +216,7 @@ impl<C: Context> Transaction<C> {
             gas_tip,
             gas_limit,
             nonce,
+            valid_until_slot,
         }
     }
 }
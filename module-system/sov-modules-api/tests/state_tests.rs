@@ -2,6 +2,7 @@ use borsh::{BorshDeserialize, BorshSerialize};
 use sov_modules_api::default_context::{DefaultContext, ZkDefaultContext};
 use sov_modules_api::*;
 use sov_prover_storage_manager::new_orphan_storage;
+use sov_state::codec::{BorshCodec, JsonCodec, SplitCodec};
 use sov_state::{ArrayWitness, DefaultStorageSpec, Prefix, Storage, ZkStorage};
 
 enum Operation {
@@ -200,6 +201,50 @@ fn test_witness_round_trip() {
     };
 }
 
+#[test]
+fn test_state_map_contains_key() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let state_map: StateMap<u32, String> = StateMap::new(Prefix::new(vec![0]));
+    let storage = new_orphan_storage::<DefaultStorageSpec>(tempdir.path()).unwrap();
+    let mut working_set: WorkingSet<DefaultContext> = WorkingSet::new(storage.clone());
+    state_map.set(&1, &String::from("one"), &mut working_set);
+    let (cache_log, witness) = working_set.checkpoint().freeze();
+    storage
+        .validate_and_commit(cache_log, &witness)
+        .expect("Native jmt validation should succeed");
+
+    // Checks a committed key and a missing one, then the missing key once it's set.
+    let check_keys = |use_get: bool| {
+        let mut working_set: WorkingSet<DefaultContext> = WorkingSet::new(storage.clone());
+        let contains_key = |key: u32, working_set: &mut WorkingSet<DefaultContext>| {
+            if use_get {
+                state_map.get(&key, working_set).is_some()
+            } else {
+                state_map.contains_key(&key, working_set)
+            }
+        };
+        let mut found = vec![
+            contains_key(1, &mut working_set),
+            contains_key(2, &mut working_set),
+        ];
+        state_map.set(&2, &String::from("two"), &mut working_set);
+        found.push(contains_key(2, &mut working_set));
+        let (_, witness) = working_set.checkpoint().freeze();
+        (found, bincode::serialize(&witness).unwrap())
+    };
+
+    let (found, witness) = check_keys(false);
+    assert_eq!(vec![true, false, true], found);
+    assert_eq!(check_keys(true), (found, witness.clone()));
+
+    // The zk execution replays the checks with the recorded witness.
+    let storage = ZkStorage::<DefaultStorageSpec>::new();
+    let mut working_set: WorkingSet<ZkDefaultContext> =
+        WorkingSet::with_witness(storage.clone(), bincode::deserialize(&witness).unwrap());
+    assert!(state_map.contains_key(&1, &mut working_set));
+    assert!(!state_map.contains_key(&2, &mut working_set));
+}
+
 fn create_state_vec<T: BorshDeserialize + BorshSerialize>(
     values: Vec<T>,
     working_set: &mut WorkingSet<DefaultContext>,
@@ -406,3 +451,242 @@ fn test_state_vec_diff_type() {
         assert_eq!(len, 2);
     }
 }
+
+#[test]
+fn test_state_map_migrate_codec() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let prefix = Prefix::new(vec![0]);
+    let entries = vec![(1u32, String::from("one")), (2, String::from("two"))];
+
+    let mut working_set: WorkingSet<DefaultContext> = WorkingSet::new(storage.clone());
+    let borsh_map: StateMap<u32, String, BorshCodec> = StateMap::new(prefix.clone());
+    for (key, value) in entries.iter() {
+        borsh_map.set(key, value, &mut working_set);
+    }
+    let mut working_set = Operation::Finalize
+        .execute(working_set, storage.clone())
+        .to_revertable();
+
+    // Keys stay borsh-encoded, values go from borsh to JSON.
+    let json_map: StateMap<u32, String, SplitCodec<BorshCodec, JsonCodec>> = StateMap::with_codec(
+        prefix,
+        SplitCodec {
+            key_codec: BorshCodec,
+            value_codec: JsonCodec,
+        },
+    );
+    let migrated = json_map
+        .migrate_codec(&BorshCodec, &mut working_set)
+        .unwrap();
+    assert_eq!(migrated, entries.len());
+
+    let mut working_set = Operation::Finalize
+        .execute(working_set, storage.clone())
+        .to_revertable();
+    for (key, value) in entries.iter() {
+        assert_eq!(json_map.get(key, &mut working_set).as_ref(), Some(value));
+    }
+}
+
+#[test]
+fn test_state_map_migrate_value_type() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let prefix = Prefix::new(vec![0]);
+    let entries = vec![(1u32, 10u32), (2, u32::MAX)];
+
+    let mut working_set: WorkingSet<DefaultContext> = WorkingSet::new(storage.clone());
+    let old_map: StateMap<u32, u32> = StateMap::new(prefix.clone());
+    for (key, value) in entries.iter() {
+        old_map.set(key, value, &mut working_set);
+    }
+    // Its prefix extends the prefix of the migrated map, so its keys are found by the scan too.
+    let nested_map: StateMap<u8, u32> = StateMap::new(Prefix::new(vec![0, 1]));
+    nested_map.set(&7, &7, &mut working_set);
+    let mut working_set = Operation::Finalize
+        .execute(working_set, storage.clone())
+        .to_revertable();
+
+    // The new values can't overflow anymore.
+    let migrated = old_map
+        .migrate(|value| u64::from(value) + 1, &mut working_set)
+        .unwrap();
+    assert_eq!(migrated, entries.len());
+
+    let mut working_set = Operation::Finalize
+        .execute(working_set, storage.clone())
+        .to_revertable();
+    let new_map: StateMap<u32, u64> = StateMap::new(prefix);
+    for (key, value) in entries.iter() {
+        assert_eq!(
+            new_map.get(key, &mut working_set),
+            Some(u64::from(*value) + 1)
+        );
+    }
+    assert_eq!(
+        new_map.iter(&mut working_set).unwrap().collect::<Vec<_>>(),
+        vec![(1, 11), (2, u64::from(u32::MAX) + 1)]
+    );
+    assert_eq!(nested_map.get(&7, &mut working_set), Some(7));
+}
+
+#[test]
+fn test_state_map_migrate_codec_is_all_or_nothing() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let json_codec = || SplitCodec {
+        key_codec: BorshCodec,
+        value_codec: JsonCodec,
+    };
+    let borsh_map: StateMap<u32, String> = StateMap::new(Prefix::new(vec![0]));
+    let json_map: StateMap<u32, String, _> =
+        StateMap::with_codec(Prefix::new(vec![0]), json_codec());
+    // Its prefix extends the prefix of the migrated map, so its keys are found by the scan too.
+    let nested_map: StateMap<u8, String> = StateMap::new(Prefix::new(vec![0, 1]));
+
+    let mut working_set: WorkingSet<DefaultContext> = WorkingSet::new(storage.clone());
+    borsh_map.set(&1, &String::from("one"), &mut working_set);
+    nested_map.set(&7, &String::from("nested"), &mut working_set);
+    let mut working_set = Operation::Finalize
+        .execute(working_set, storage.clone())
+        .to_revertable();
+
+    // The entries of the nested map are not migrated.
+    let migrated = json_map
+        .migrate_codec(&BorshCodec, &mut working_set)
+        .unwrap();
+    assert_eq!(migrated, 1);
+    assert_eq!(
+        json_map.get(&1, &mut working_set),
+        Some(String::from("one"))
+    );
+    assert_eq!(
+        nested_map.get(&7, &mut working_set),
+        Some(String::from("nested"))
+    );
+
+    // A value which can't be decoded fails the migration before any entry is rewritten.
+    let borsh_map: StateMap<u32, String> = StateMap::new(Prefix::new(vec![2]));
+    let json_map: StateMap<u32, String, _> =
+        StateMap::with_codec(Prefix::new(vec![2]), json_codec());
+    let byte_map: StateMap<u32, u8> = StateMap::new(Prefix::new(vec![2]));
+    borsh_map.set(&1, &String::from("one"), &mut working_set);
+    byte_map.set(&3, &3, &mut working_set);
+    let mut working_set = Operation::Finalize
+        .execute(working_set, storage.clone())
+        .to_revertable();
+
+    assert!(json_map
+        .migrate_codec(&BorshCodec, &mut working_set)
+        .is_err());
+    assert_eq!(
+        borsh_map.get(&1, &mut working_set),
+        Some(String::from("one"))
+    );
+}
+
+#[test]
+fn test_state_map_iter() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let state_map: StateMap<u32, String> = StateMap::new(Prefix::new(vec![0]));
+    // Its prefix extends the prefix of `state_map`, so its keys are found by the scan too.
+    let nested_map: StateMap<u8, String> = StateMap::new(Prefix::new(vec![0, 1]));
+
+    let mut working_set: WorkingSet<DefaultContext> = WorkingSet::new(storage.clone());
+    for (key, value) in [(3, "three"), (1, "one"), (2, "two")] {
+        state_map.set(&key, &value.to_string(), &mut working_set);
+    }
+    nested_map.set(&7, &String::from("nested"), &mut working_set);
+    let mut working_set = Operation::Finalize
+        .execute(working_set, storage.clone())
+        .to_revertable();
+
+    let entries: Vec<(u32, String)> = state_map.iter(&mut working_set).unwrap().collect();
+    assert_eq!(
+        entries,
+        vec![
+            (1, String::from("one")),
+            (2, String::from("two")),
+            (3, String::from("three"))
+        ]
+    );
+
+    // Updates and removals in the working set are reflected.
+    state_map.set(&2, &String::from("two, updated"), &mut working_set);
+    state_map.remove(&3, &mut working_set);
+    state_map.set(&4, &String::from("four"), &mut working_set);
+    let entries: Vec<(u32, String)> = state_map.iter(&mut working_set).unwrap().collect();
+    assert_eq!(
+        entries,
+        vec![(1, String::from("one")), (2, String::from("two, updated"))]
+    );
+
+    let mut working_set = Operation::Finalize
+        .execute(working_set, storage.clone())
+        .to_revertable();
+    assert_eq!(state_map.keys(&mut working_set).unwrap(), vec![1, 2, 4]);
+    assert_eq!(nested_map.keys(&mut working_set).unwrap(), vec![7]);
+}
+
+#[test]
+fn test_state_map_iter_returns_nested_keys_which_decode() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let state_map: StateMap<u16, String> = StateMap::new(Prefix::new(vec![0]));
+    // The rest of the storage key of `(prefix [0, 1], key 7)`, `[1, 7]`, is the exact encoding
+    // of a `u16`, so it can't be told apart from the keys of `state_map`.
+    let nested_map: StateMap<u8, String> = StateMap::new(Prefix::new(vec![0, 1]));
+    // `[1, 2, 7]` is one byte too long to be a `u16`, so it is skipped.
+    let deeper_map: StateMap<u8, String> = StateMap::new(Prefix::new(vec![0, 1, 2]));
+
+    let mut working_set: WorkingSet<DefaultContext> = WorkingSet::new(storage.clone());
+    state_map.set(&3, &String::from("three"), &mut working_set);
+    nested_map.set(&7, &String::from("nested"), &mut working_set);
+    deeper_map.set(&7, &String::from("deeper"), &mut working_set);
+    let mut working_set = Operation::Finalize
+        .execute(working_set, storage.clone())
+        .to_revertable();
+
+    let entries: Vec<(u16, String)> = state_map.iter(&mut working_set).unwrap().collect();
+    assert_eq!(
+        entries,
+        vec![
+            (u16::from_le_bytes([1, 7]), String::from("nested")),
+            (3, String::from("three"))
+        ]
+    );
+}
+
+#[test]
+fn test_read_only_working_set_rejects_writes() {
+    let tmpdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tmpdir.path()).unwrap();
+    let state_value: StateValue<u32> = StateValue::new(Prefix::new(vec![0]));
+    let accessory_value: AccessoryStateValue<u32> = AccessoryStateValue::new(Prefix::new(vec![1]));
+
+    let mut working_set: WorkingSet<DefaultContext> = WorkingSet::new(storage.clone());
+    state_value.set(&11, &mut working_set);
+    Operation::Finalize.execute(working_set, storage.clone());
+
+    let mut working_set: WorkingSet<DefaultContext> = WorkingSet::read_only(storage);
+    assert!(working_set.is_read_only());
+    assert_eq!(state_value.get(&mut working_set), Some(11));
+    assert_eq!(
+        accessory_value.get(&mut working_set.accessory_state()),
+        None
+    );
+
+    let writes: [&dyn Fn(&mut WorkingSet<DefaultContext>); 3] = [
+        &|working_set| state_value.set(&22, working_set),
+        &|working_set| state_value.delete(working_set),
+        &|working_set| accessory_value.set(&22, &mut working_set.accessory_state()),
+    ];
+    for write in writes {
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| write(&mut working_set)));
+        assert!(result.is_err(), "The write should be rejected");
+    }
+    assert_eq!(state_value.get(&mut working_set), Some(11));
+}
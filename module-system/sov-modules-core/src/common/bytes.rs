@@ -121,6 +121,24 @@ impl Prefix {
         }
     }
 
+    /// Creates a new prefix namespaced by the address of the module that owns the field.
+    ///
+    /// Both components are written with a big-endian `u32` length in front of them, so
+    /// the layout is unambiguous: two modules can never produce the same prefix, even if
+    /// they declare fields with the same name.
+    pub fn for_module(module_address: &[u8], field_name: &str) -> Self {
+        let mut prefix = Vec::with_capacity(
+            2 * core::mem::size_of::<u32>() + module_address.len() + field_name.len(),
+        );
+        for component in [module_address, field_name.as_bytes()] {
+            let len = u32::try_from(component.len())
+                .expect("Prefix components must be shorter than u32::MAX bytes");
+            prefix.extend(len.to_be_bytes());
+            prefix.extend(component);
+        }
+        Self::new(prefix)
+    }
+
     /// Returns a reference to the [`AlignedVec`] containing the prefix.
     pub fn as_aligned_vec(&self) -> &AlignedVec {
         &self.prefix
@@ -250,3 +268,35 @@ mod arbitrary_impls {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_prefixes_with_same_field_name_are_distinct() {
+        let first = Prefix::for_module(&[1; 32], "balances");
+        let second = Prefix::for_module(&[2; 32], "balances");
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_module_prefix_components_cannot_be_shifted() {
+        // Without length prefixes both would serialize to `ab` followed by `c`.
+        let first = Prefix::for_module(b"ab", "c");
+        let second = Prefix::for_module(b"a", "bc");
+
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn test_module_prefix_encoding_is_stable() {
+        let prefix = Prefix::for_module(&[0xaa, 0xbb], "map");
+
+        assert_eq!(
+            prefix.as_aligned_vec().as_ref(),
+            &vec![0, 0, 0, 2, 0xaa, 0xbb, 0, 0, 0, 3, b'm', b'a', b'p']
+        );
+    }
+}
@@ -1,5 +1,8 @@
 //! Runtime call message definitions.
 
+use alloc::vec::Vec;
+
+use borsh::BorshSerialize;
 use sov_rollup_interface::maybestd::io;
 
 use crate::common::ModuleError;
@@ -27,4 +30,38 @@ pub trait DispatchCall: Send + Sync {
 
     /// Returns an address of the dispatched module.
     fn module_address(&self, message: &Self::Decodable) -> &<Self::Context as Spec>::Address;
+
+    /// Returns the name of the runtime field holding the dispatched module.
+    fn module_name(&self, message: &Self::Decodable) -> &'static str;
+
+    /// Dispatches a call message like [`DispatchCall::dispatch_call`], and returns the name of
+    /// the module which handled it alongside the response.
+    fn dispatch_call_with_module_name(
+        &self,
+        message: Self::Decodable,
+        working_set: &mut WorkingSet<Self::Context>,
+        context: &Self::Context,
+    ) -> (&'static str, Result<CallResponse, ModuleError>) {
+        let module_name = self.module_name(&message);
+        (
+            module_name,
+            self.dispatch_call(message, working_set, context),
+        )
+    }
+}
+
+/// A runtime call message with a versioned wire format: the borsh encoding of the message is
+/// preceded by a version tag, so that [`DispatchCall::decode_call`] can tell messages encoded with
+/// an older layout apart instead of misinterpreting them.
+pub trait VersionedCall: BorshSerialize {
+    /// The version tag of the current wire format.
+    const VERSION: u8;
+
+    /// Encodes the message in the current wire format.
+    fn encode_versioned(&self) -> Vec<u8> {
+        let mut encoded = alloc::vec![Self::VERSION];
+        self.serialize(&mut encoded)
+            .expect("Serialization to vec is infallible");
+        encoded
+    }
 }
@@ -43,6 +43,19 @@ pub trait Module {
         Ok(())
     }
 
+    /// Checks the invariants the configuration of this module shares with other modules, e.g.
+    /// that a token referenced by the configuration exists in the bank.
+    ///
+    /// The runtime calls it once the modules this module depends on are initialized, right before
+    /// [`Module::genesis`].
+    fn validate_genesis(
+        &self,
+        _config: &Self::Config,
+        _working_set: &mut WorkingSet<Self::Context>,
+    ) -> Result<(), ModuleError> {
+        Ok(())
+    }
+
     /// Call allows interaction with the module and invokes state changes.
     /// It takes a module defined type and a context as parameters.
     fn call(
@@ -111,6 +124,15 @@ pub trait Genesis {
         config: &Self::Config,
         working_set: &mut WorkingSet<Self::Context>,
     ) -> Result<(), ModuleError>;
+
+    /// Checks the cross-module invariants of the configuration, see [`Module::validate_genesis`].
+    fn validate_genesis(
+        &self,
+        _config: &Self::Config,
+        _working_set: &mut WorkingSet<Self::Context>,
+    ) -> Result<(), ModuleError> {
+        Ok(())
+    }
 }
 
 impl<T> Genesis for T
@@ -128,4 +150,12 @@ where
     ) -> Result<(), ModuleError> {
         <Self as Module>::genesis(self, config, working_set)
     }
+
+    fn validate_genesis(
+        &self,
+        config: &Self::Config,
+        working_set: &mut WorkingSet<Self::Context>,
+    ) -> Result<(), ModuleError> {
+        <Self as Module>::validate_genesis(self, config, working_set)
+    }
 }
@@ -452,7 +452,7 @@ impl StorageInternalCache {
 
 /// A struct that contains the values read from the DB and the values to be written, both in
 /// deterministic order.
-#[derive(Debug, Default)]
+#[derive(Debug, Default, Clone)]
 pub struct OrderedReadsAndWrites {
     /// Ordered reads.
     pub ordered_reads: Vec<(CacheKey, Option<CacheValue>)>,
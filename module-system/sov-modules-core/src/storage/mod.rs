@@ -20,7 +20,7 @@ pub use scratchpad::*;
 
 /// The key type suitable for use in [`Storage::get`] and other getter methods of
 /// [`Storage`]. Cheaply-clonable.
-#[derive(Clone, PartialEq, Eq, Debug)]
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
 #[cfg_attr(
     feature = "sync",
     derive(Serialize, serde::Deserialize, BorshDeserialize, BorshSerialize)
@@ -35,6 +35,14 @@ impl From<CacheKey> for StorageKey {
     }
 }
 
+impl From<Vec<u8>> for StorageKey {
+    fn from(key: Vec<u8>) -> Self {
+        Self {
+            key: RefCount::new(key),
+        }
+    }
+}
+
 impl StorageKey {
     /// Returns a new [`RefCount`] reference to the bytes of this key.
     pub fn key(&self) -> RefCount<Vec<u8>> {
@@ -274,6 +282,46 @@ pub trait Storage: Clone {
         proof: StorageProof<Self::Proof>,
     ) -> Result<(StorageKey, Option<StorageValue>), anyhow::Error>;
 
+    /// Opens a storage access proof, validates it against a state root and checks that it was
+    /// generated for the expected key. It returns the proven value in case of success.
+    fn verify_proof(
+        &self,
+        state_root: Self::Root,
+        proof: StorageProof<Self::Proof>,
+        expected_key: &StorageKey,
+    ) -> Result<Option<StorageValue>, anyhow::Error> {
+        let (storage_key, storage_value) = Self::open_proof(state_root, proof)?;
+
+        anyhow::ensure!(
+            &storage_key == expected_key,
+            "The storage key from the proof doesn't match the expected storage key."
+        );
+
+        Ok(storage_value)
+    }
+
+    /// Verifies a batch of storage access proofs against the same state root, pairing each
+    /// proof with the key it is expected to prove.
+    ///
+    /// Verification stops at the first invalid proof, and the returned error reports its index
+    /// in `proofs`. On success, the proven values are returned in the same order as the proofs.
+    fn verify_proofs(
+        &self,
+        state_root: Self::Root,
+        proofs: Vec<(StorageProof<Self::Proof>, StorageKey)>,
+    ) -> Result<Vec<Option<StorageValue>>, anyhow::Error> {
+        proofs
+            .into_iter()
+            .enumerate()
+            .map(|(index, (proof, expected_key))| {
+                self.verify_proof(state_root.clone(), proof, &expected_key)
+                    .map_err(|err| {
+                        err.context(alloc::format!("Invalid storage proof at index {}", index))
+                    })
+            })
+            .collect()
+    }
+
     /// Indicates if storage is empty or not.
     /// Useful during initialization.
     fn is_empty(&self) -> bool;
@@ -306,4 +354,14 @@ pub trait NativeStorage: Storage {
 
     /// Get the root hash of the tree at the requested version
     fn get_root_hash(&self, version: Version) -> Result<Self::Root, anyhow::Error>;
+
+    /// Returns the version of the latest committed state.
+    fn latest_version(&self) -> Version;
+
+    /// Returns the keys starting with the given prefix that hold a value in the latest
+    /// committed version, in lexicographic order.
+    ///
+    /// This requires scanning the backing database, so it's meant for tooling
+    /// (e.g. migrations) rather than for regular state access.
+    fn get_keys_with_prefix(&self, prefix: &Prefix) -> Result<Vec<StorageKey>, anyhow::Error>;
 }
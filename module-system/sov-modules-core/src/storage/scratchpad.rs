@@ -1,6 +1,7 @@
 //! Runtime state machine definitions.
 
 use alloc::vec::Vec;
+use core::ops::{Deref, DerefMut};
 use core::{fmt, mem};
 
 pub use kernel_state::{KernelWorkingSet, VersionedWorkingSet};
@@ -89,6 +90,25 @@ pub trait StateReaderAndWriter {
         self.get_decoded(&storage_key, codec)
     }
 
+    /// Checks whether a value is stored with the provided prefix, without decoding it.
+    ///
+    /// The value is read like in [`StateReaderAndWriter::get_value`], so the same
+    /// witness is recorded.
+    fn contains_value<Q, K, Codec>(
+        &mut self,
+        prefix: &Prefix,
+        storage_key: &Q,
+        codec: &Codec,
+    ) -> bool
+    where
+        Q: ?Sized,
+        Codec: StateCodec,
+        Codec::KeyCodec: EncodeKeyLike<Q, K>,
+    {
+        let storage_key = StorageKey::new(prefix, storage_key, codec.key_codec());
+        self.get(&storage_key).is_some()
+    }
+
     /// Get a singleton value from the storage. For more information, check [StorageKey::singleton].
     fn get_singleton<V, Codec>(&mut self, prefix: &Prefix, codec: &Codec) -> Option<V>
     where
@@ -307,6 +327,8 @@ impl<C: Context> StateCheckpoint<C> {
             gas_meter: self.gas_meter,
             archival_working_set: None,
             archival_accessory_working_set: None,
+            access_stats: Default::default(),
+            read_only: false,
         }
     }
 
@@ -335,6 +357,18 @@ impl<C: Context> StateCheckpoint<C> {
     }
 }
 
+/// Counts of the provable state accesses made through a [`WorkingSet`]. See
+/// [`WorkingSet::access_stats`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AccessStats {
+    /// The number of values read.
+    pub reads: u64,
+    /// The number of values written.
+    pub writes: u64,
+    /// The number of values deleted.
+    pub deletes: u64,
+}
+
 /// This structure contains the read-write set and the events collected during the execution of a transaction.
 /// There are two ways to convert it into a StateCheckpoint:
 /// 1. By using the checkpoint() method, where all the changes are added to the underlying StateCheckpoint.
@@ -346,6 +380,8 @@ pub struct WorkingSet<C: Context> {
     gas_meter: GasMeter<C::GasUnit>,
     archival_working_set: Option<ArchivalJmtWorkingSet<C>>,
     archival_accessory_working_set: Option<ArchivalAccessoryWorkingSet<C>>,
+    access_stats: AccessStats,
+    read_only: bool,
 }
 
 impl<C: Context> WorkingSet<C> {
@@ -357,6 +393,30 @@ impl<C: Context> WorkingSet<C> {
         StateCheckpoint::new(inner).to_revertable()
     }
 
+    /// Creates a new [`WorkingSet`] instance backed by the given [`Storage`] which only allows
+    /// reads, e.g. to serve queries.
+    ///
+    /// # Panics
+    /// Any write or deletion through the working set or one of its handlers panics.
+    pub fn read_only(inner: <C as Spec>::Storage) -> Self {
+        Self {
+            read_only: true,
+            ..Self::new(inner)
+        }
+    }
+
+    /// Returns `true` if this working set was created with [`WorkingSet::read_only`].
+    pub const fn is_read_only(&self) -> bool {
+        self.read_only
+    }
+
+    fn assert_writable(&self) {
+        assert!(
+            !self.read_only,
+            "Attempted to write to a read-only working set"
+        );
+    }
+
     /// Returns a handler for the accessory state (non-JMT state).
     ///
     /// You can use this method when calling getters and setters on accessory
@@ -439,6 +499,24 @@ impl<C: Context> WorkingSet<C> {
         }
     }
 
+    /// Marks the current state of this [`WorkingSet`], so that the writes and events which follow
+    /// can be discarded without affecting the earlier ones.
+    ///
+    /// The returned [`Savepoint`] dereferences to this working set. Call [`Savepoint::commit`] to
+    /// keep the changes made through it or [`Savepoint::rollback`] to discard them; dropping it
+    /// rolls back. Savepoints can be nested, and the changes kept are only made permanent by
+    /// [`WorkingSet::checkpoint`]. Gas charged after the savepoint is not refunded on rollback.
+    pub fn savepoint(&mut self) -> Savepoint<C> {
+        Savepoint {
+            snapshot: Some(SavepointSnapshot {
+                provable_writes: self.delta.writes.clone(),
+                accessory_writes: self.accessory_delta.writes.clone(),
+                events_len: self.events.len(),
+            }),
+            ws: self,
+        }
+    }
+
     /// Adds an event to the working set.
     pub fn add_event(&mut self, key: &str, value: &str) {
         self.events.push(Event::new(key, value));
@@ -471,6 +549,21 @@ impl<C: Context> WorkingSet<C> {
         self.gas_meter.charge_gas(gas)
     }
 
+    /// Returns how many values of the provable state were read, written and deleted through this
+    /// working set since it was created or since the last [`WorkingSet::reset_access_stats`].
+    ///
+    /// Accesses to the accessory state are not counted, and rolling back a [`Savepoint`] doesn't
+    /// undo the accesses made through it.
+    pub const fn access_stats(&self) -> AccessStats {
+        self.access_stats
+    }
+
+    /// Resets the counters returned by [`WorkingSet::access_stats`], e.g. before executing the
+    /// next transaction.
+    pub fn reset_access_stats(&mut self) {
+        self.access_stats = AccessStats::default();
+    }
+
     /// Returns the gas price.
     pub const fn gas_price(&self) -> &C::GasUnit {
         self.gas_meter.gas_price()
@@ -492,10 +585,22 @@ impl<C: Context> WorkingSet<C> {
         // First inner is `RevertableWriter` and second inner is actually a `Storage` instance
         self.delta.inner.inner.get_with_proof(key)
     }
+
+    /// Returns the keys starting with the given prefix that are set in the underlying storage.
+    ///
+    /// Only committed state is scanned: writes still pending in this working set are not
+    /// reflected. See [`NativeStorage::get_keys_with_prefix`].
+    pub fn get_keys_with_prefix(&self, prefix: &Prefix) -> anyhow::Result<Vec<StorageKey>>
+    where
+        C::Storage: NativeStorage,
+    {
+        self.delta.inner.inner.get_keys_with_prefix(prefix)
+    }
 }
 
 impl<C: Context> StateReaderAndWriter for WorkingSet<C> {
     fn get(&mut self, key: &StorageKey) -> Option<StorageValue> {
+        self.access_stats.reads += 1;
         match &mut self.archival_working_set {
             None => self.delta.get(key),
             Some(ref mut archival_working_set) => archival_working_set.get(key),
@@ -503,6 +608,8 @@ impl<C: Context> StateReaderAndWriter for WorkingSet<C> {
     }
 
     fn set(&mut self, key: &StorageKey, value: StorageValue) {
+        self.assert_writable();
+        self.access_stats.writes += 1;
         match &mut self.archival_working_set {
             None => self.delta.set(key, value),
             Some(ref mut archival_working_set) => archival_working_set.set(key, value),
@@ -510,6 +617,8 @@ impl<C: Context> StateReaderAndWriter for WorkingSet<C> {
     }
 
     fn delete(&mut self, key: &StorageKey) {
+        self.assert_writable();
+        self.access_stats.deletes += 1;
         match &mut self.archival_working_set {
             None => self.delta.delete(key),
             Some(ref mut archival_working_set) => archival_working_set.delete(key),
@@ -517,6 +626,82 @@ impl<C: Context> StateReaderAndWriter for WorkingSet<C> {
     }
 }
 
+struct SavepointSnapshot {
+    provable_writes: HashMap<CacheKey, Option<CacheValue>>,
+    accessory_writes: HashMap<CacheKey, Option<CacheValue>>,
+    events_len: usize,
+}
+
+/// A scope of changes to a [`WorkingSet`] which can be discarded as a whole, created with
+/// [`WorkingSet::savepoint`].
+pub struct Savepoint<'a, C: Context> {
+    ws: &'a mut WorkingSet<C>,
+    snapshot: Option<SavepointSnapshot>,
+}
+
+impl<'a, C: Context> Savepoint<'a, C> {
+    /// Keeps the changes made since the savepoint.
+    pub fn commit(mut self) {
+        self.snapshot = None;
+    }
+
+    /// Discards the writes and events made since the savepoint.
+    pub fn rollback(self) {
+        // Dropping the savepoint restores the snapshot.
+    }
+}
+
+impl<'a, C: Context> Deref for Savepoint<'a, C> {
+    type Target = WorkingSet<C>;
+
+    fn deref(&self) -> &Self::Target {
+        self.ws
+    }
+}
+
+impl<'a, C: Context> DerefMut for Savepoint<'a, C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.ws
+    }
+}
+
+impl<'a, C: Context> Drop for Savepoint<'a, C> {
+    fn drop(&mut self) {
+        if let Some(snapshot) = self.snapshot.take() {
+            self.ws.delta.writes = snapshot.provable_writes;
+            self.ws.accessory_delta.writes = snapshot.accessory_writes;
+            self.ws.events.truncate(snapshot.events_len);
+        }
+    }
+}
+
+/// Runs `f` on the working set and panics if it leaves a net write in the provable or accessory
+/// state. Keys which are written and then restored to their previous value don't count, so this
+/// can check that a failed call reverted everything it touched.
+#[cfg(feature = "mocks")]
+pub fn assert_no_state_change<C: Context, T>(
+    working_set: &mut WorkingSet<C>,
+    f: impl FnOnce(&mut WorkingSet<C>) -> T,
+) -> T {
+    let provable_writes = working_set.delta.writes.clone();
+    let accessory_writes = working_set.accessory_delta.writes.clone();
+
+    let result = f(working_set);
+
+    let mut changed_keys = working_set.delta.net_writes_since(&provable_writes);
+    changed_keys.extend(
+        working_set
+            .accessory_delta
+            .net_writes_since(&accessory_writes),
+    );
+    assert!(
+        changed_keys.is_empty(),
+        "Expected no state change, but these keys were written: {:?}",
+        changed_keys
+    );
+    result
+}
+
 /// A wrapper over [`WorkingSet`] that only allows access to the accessory
 /// state (non-JMT state).
 pub struct AccessoryWorkingSet<'a, C: Context> {
@@ -536,6 +721,7 @@ impl<'a, C: Context> StateReaderAndWriter for AccessoryWorkingSet<'a, C> {
     }
 
     fn set(&mut self, key: &StorageKey, value: StorageValue) {
+        self.ws.assert_writable();
         match &mut self.ws.archival_accessory_working_set {
             None => self.ws.accessory_delta.set(key, value),
             Some(ref mut archival_working_set) => archival_working_set.set(key, value),
@@ -543,6 +729,7 @@ impl<'a, C: Context> StateReaderAndWriter for AccessoryWorkingSet<'a, C> {
     }
 
     fn delete(&mut self, key: &StorageKey) {
+        self.ws.assert_writable();
         match &mut self.ws.archival_accessory_working_set {
             None => self.ws.accessory_delta.delete(key),
             Some(ref mut archival_working_set) => archival_working_set.delete(key),
@@ -655,14 +842,19 @@ pub mod kernel_state {
 
     impl<'a, C: Context> StateReaderAndWriter for VersionedWorkingSet<'a, C> {
         fn get(&mut self, key: &StorageKey) -> Option<StorageValue> {
+            self.ws.access_stats.reads += 1;
             self.ws.delta.get(key)
         }
 
         fn set(&mut self, key: &StorageKey, value: StorageValue) {
+            self.ws.assert_writable();
+            self.ws.access_stats.writes += 1;
             self.ws.delta.set(key, value)
         }
 
         fn delete(&mut self, key: &StorageKey) {
+            self.ws.assert_writable();
+            self.ws.access_stats.deletes += 1;
             self.ws.delta.delete(key)
         }
     }
@@ -711,14 +903,19 @@ pub mod kernel_state {
 
     impl<'a, C: Context> StateReaderAndWriter for KernelWorkingSet<'a, C> {
         fn get(&mut self, key: &StorageKey) -> Option<StorageValue> {
+            self.inner.access_stats.reads += 1;
             self.inner.delta.get(key)
         }
 
         fn set(&mut self, key: &StorageKey, value: StorageValue) {
+            self.inner.assert_writable();
+            self.inner.access_stats.writes += 1;
             self.inner.delta.set(key, value)
         }
 
         fn delete(&mut self, key: &StorageKey) {
+            self.inner.assert_writable();
+            self.inner.access_stats.deletes += 1;
             self.inner.delta.delete(key)
         }
     }
@@ -765,6 +962,33 @@ where
     fn revert(self) -> T {
         self.inner
     }
+
+    /// Returns the keys whose pending value differs from the one they had when `before` was taken.
+    #[cfg(feature = "mocks")]
+    fn net_writes_since(
+        &mut self,
+        before: &HashMap<CacheKey, Option<CacheValue>>,
+    ) -> Vec<CacheKey> {
+        let written: Vec<(CacheKey, Option<CacheValue>)> = self
+            .writes
+            .iter()
+            .filter(|(key, value)| before.get(*key) != Some(*value))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect();
+
+        written
+            .into_iter()
+            .filter(|(key, value)| {
+                before.contains_key(key)
+                    || self
+                        .inner
+                        .get(&key.clone().into())
+                        .map(StorageValue::into_cache_value)
+                        != *value
+            })
+            .map(|(key, _)| key)
+            .collect()
+    }
 }
 
 impl<T: StateReaderAndWriter> StateReaderAndWriter for RevertableWriter<T> {
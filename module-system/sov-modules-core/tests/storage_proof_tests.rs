@@ -0,0 +1,47 @@
+use sov_modules_api::default_context::DefaultContext;
+use sov_modules_api::{StateMap, StateMapAccessor};
+use sov_modules_core::{
+    NativeStorage, Prefix, StateCodec, Storage, StorageKey, StorageValue, WorkingSet,
+};
+use sov_prover_storage_manager::new_orphan_storage;
+
+#[test]
+fn test_verify_proofs_reports_index_of_invalid_proof() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let storage = new_orphan_storage(tempdir.path()).unwrap();
+    let map: StateMap<u32, u32> = StateMap::new(Prefix::new(b"map".to_vec()));
+
+    let mut working_set = WorkingSet::<DefaultContext>::new(storage.clone());
+    for key in 0..3 {
+        map.set(&key, &(key * 10), &mut working_set);
+    }
+    let (reads_writes, witness) = working_set.checkpoint().freeze();
+    let state_root = storage
+        .validate_and_commit(reads_writes, &witness)
+        .expect("Should be able to commit");
+
+    let storage_key = |key: &u32| StorageKey::new(map.prefix(), key, map.codec().key_codec());
+    let mut proofs: Vec<_> = (0..3)
+        .map(|key| {
+            let key = storage_key(&key);
+            (storage.get_with_proof(key.clone()), key)
+        })
+        .collect();
+
+    // Untampered proofs are all valid.
+    let values = storage
+        .verify_proofs(state_root, proofs.clone())
+        .expect("All proofs should be valid");
+    let expected_values: Vec<_> = (0..3u32)
+        .map(|key| Some(StorageValue::new(&(key * 10), map.codec().value_codec())))
+        .collect();
+    assert_eq!(values, expected_values);
+
+    // Tamper with the value of the middle proof.
+    proofs[1].0.value = Some(StorageValue::new(&999u32, map.codec().value_codec()));
+
+    let err = storage
+        .verify_proofs(state_root, proofs)
+        .expect_err("The tampered proof should be rejected");
+    assert_eq!(err.to_string(), "Invalid storage proof at index 1");
+}
@@ -2,7 +2,8 @@ use sov_mock_da::MockDaSpec;
 use sov_modules_api::default_context::DefaultContext;
 use sov_modules_core::capabilities::mocks::MockKernel;
 use sov_modules_core::{
-    Address, Context, KernelWorkingSet, StateReaderAndWriter, StorageKey, StorageValue, WorkingSet,
+    AccessStats, Address, Context, KernelWorkingSet, StateReaderAndWriter, StorageKey,
+    StorageValue, WorkingSet,
 };
 use sov_prover_storage_manager::new_orphan_storage;
 use sov_state::codec::BcsCodec;
@@ -59,3 +60,123 @@ fn test_kernel_workingset_get() {
 
     assert_eq!(Some(storage_value), working_set.get(&storage_key));
 }
+
+#[test]
+fn test_savepoint_rollback() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let codec = BcsCodec {};
+    let storage = new_orphan_storage(tempdir.path()).unwrap();
+
+    let prefix = sov_modules_core::Prefix::new(vec![1, 2, 3]);
+    let key_before = StorageKey::new(&prefix, &vec![1], &codec);
+    let key_after = StorageKey::new(&prefix, &vec![2], &codec);
+    let value = |v: u8| StorageValue::new(&vec![v], &codec);
+
+    let mut working_set = WorkingSet::<DefaultContext>::new(storage);
+    working_set.set(&key_before, value(1));
+    working_set.add_event("before", "savepoint");
+
+    let mut savepoint = working_set.savepoint();
+    savepoint.set(&key_before, value(2));
+    savepoint.set(&key_after, value(3));
+    savepoint.add_event("after", "savepoint");
+    assert_eq!(Some(value(2)), savepoint.get(&key_before));
+    savepoint.rollback();
+
+    assert_eq!(Some(value(1)), working_set.get(&key_before));
+    assert_eq!(None, working_set.get(&key_after));
+    assert_eq!(1, working_set.events().len());
+
+    // Only the writes made before the savepoint reach the checkpoint.
+    let mut working_set = working_set.checkpoint().to_revertable();
+    assert_eq!(Some(value(1)), working_set.get(&key_before));
+    assert_eq!(None, working_set.get(&key_after));
+}
+
+#[test]
+fn test_nested_savepoints() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let codec = BcsCodec {};
+    let storage = new_orphan_storage(tempdir.path()).unwrap();
+
+    let prefix = sov_modules_core::Prefix::new(vec![1, 2, 3]);
+    let outer_key = StorageKey::new(&prefix, &vec![1], &codec);
+    let inner_key = StorageKey::new(&prefix, &vec![2], &codec);
+    let value = StorageValue::new(&vec![7, 8, 9], &codec);
+
+    let mut working_set = WorkingSet::<DefaultContext>::new(storage);
+    {
+        let mut outer = working_set.savepoint();
+        outer.set(&outer_key, value.clone());
+
+        let mut inner = outer.savepoint();
+        inner.set(&inner_key, value.clone());
+        inner.commit();
+        assert_eq!(Some(value.clone()), outer.get(&inner_key));
+
+        // Dropping a savepoint rolls it back, including the committed inner one.
+    }
+    assert_eq!(None, working_set.get(&outer_key));
+    assert_eq!(None, working_set.get(&inner_key));
+
+    let mut outer = working_set.savepoint();
+    outer.set(&outer_key, value.clone());
+    let mut inner = outer.savepoint();
+    inner.set(&inner_key, value.clone());
+    inner.rollback();
+    outer.commit();
+
+    assert_eq!(Some(value), working_set.get(&outer_key));
+    assert_eq!(None, working_set.get(&inner_key));
+}
+
+#[test]
+fn test_access_stats() {
+    let tempdir = tempfile::tempdir().unwrap();
+    let codec = BcsCodec {};
+    let storage = new_orphan_storage(tempdir.path()).unwrap();
+
+    let prefix = sov_modules_core::Prefix::new(vec![1, 2, 3]);
+    let key = StorageKey::new(&prefix, &vec![1], &codec);
+    let other_key = StorageKey::new(&prefix, &vec![2], &codec);
+    let value = StorageValue::new(&vec![7, 8, 9], &codec);
+
+    let mut working_set = WorkingSet::<DefaultContext>::new(storage);
+    assert_eq!(AccessStats::default(), working_set.access_stats());
+
+    working_set.set(&key, value.clone());
+    working_set.set(&other_key, value.clone());
+    working_set.get(&key);
+    working_set.get(&other_key);
+    working_set.get(&key);
+    working_set.delete(&other_key);
+    // Accesses through the versioned state are counted as well, but not the accessory ones.
+    working_set
+        .versioned_state(&DefaultContext::new(
+            Address::from([1; 32]),
+            Address::from([2; 32]),
+            1,
+        ))
+        .get(&key);
+    working_set.accessory_state().set(&key, value);
+
+    assert_eq!(
+        AccessStats {
+            reads: 4,
+            writes: 2,
+            deletes: 1,
+        },
+        working_set.access_stats()
+    );
+
+    working_set.reset_access_stats();
+    working_set.get(&key);
+    assert_eq!(
+        AccessStats {
+            reads: 1,
+            writes: 0,
+            deletes: 0,
+        },
+        working_set.access_stats()
+    );
+}
@@ -28,6 +28,12 @@ impl DefaultRuntimeMacro {
 
         let fields = self.field_extractor.get_fields_from_struct(&data)?;
         let runtime_fn_body = Self::make_default_runtime_fn_body(&fields);
+        let modules = fields.iter().map(|field| {
+            let name = &field.ident;
+            quote::quote! {
+                (stringify!(#name), runtime.#name.prefix())
+            }
+        });
 
         // Implements the Default Runtime Config trait
         Ok(quote::quote! {
@@ -35,9 +41,14 @@ impl DefaultRuntimeMacro {
             fn default() -> Self {
                 use ::sov_modules_api::ModuleInfo as _;
 
-                Self {
+                let runtime = Self {
                    #(#runtime_fn_body)*
-                }
+                };
+                ::sov_modules_api::assert_unique_module_prefixes(
+                    stringify!(#ident),
+                    &[#(#modules),*],
+                );
+                runtime
             }
         }
                 }
@@ -1,10 +1,76 @@
 use proc_macro2::Span;
-use syn::DeriveInput;
+use syn::{DeriveInput, Lit, Meta, NestedMeta};
 
 use crate::common::{
     get_generics_type_param, get_serialization_attrs, StructDef, StructFieldExtractor, CALL,
 };
 
+/// The wire format of the call messages, set with
+/// `#[call_message(version = 1, migrate = "path::to::migrate_fn")]`.
+struct CallMessageFormat {
+    /// The version tag of the current wire format, 0 by default.
+    version: u8,
+    /// A function decoding call messages tagged with any other version.
+    migrate: Option<syn::Path>,
+}
+
+fn get_call_message_format(input: &DeriveInput) -> Result<CallMessageFormat, syn::Error> {
+    let mut format = CallMessageFormat {
+        version: 0,
+        migrate: None,
+    };
+
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("call_message"))
+    {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            _ => {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "Expected `#[call_message(version = .., migrate = \"..\")]`",
+                ))
+            }
+        };
+        for nested in list.nested {
+            match nested {
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("version") => {
+                    format.version = match &nv.lit {
+                        Lit::Int(version) => version.base10_parse()?,
+                        lit => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "The call message version must be an integer",
+                            ))
+                        }
+                    };
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("migrate") => {
+                    format.migrate = match &nv.lit {
+                        Lit::Str(migrate) => Some(migrate.parse()?),
+                        lit => {
+                            return Err(syn::Error::new_spanned(
+                                lit,
+                                "The call message migration must be the path of a function, given as a string",
+                            ))
+                        }
+                    };
+                }
+                other => {
+                    return Err(syn::Error::new_spanned(
+                        other,
+                        "Unknown call_message attribute, expected `version` or `migrate`",
+                    ))
+                }
+            }
+        }
+    }
+
+    Ok(format)
+}
+
 impl<'a> StructDef<'a> {
     fn create_call_enum_legs(&self) -> Vec<proc_macro2::TokenStream> {
         self.fields
@@ -21,7 +87,7 @@ impl<'a> StructDef<'a> {
             .collect()
     }
 
-    fn create_call_dispatch(&self) -> proc_macro2::TokenStream {
+    fn create_call_dispatch(&self, format: &CallMessageFormat) -> proc_macro2::TokenStream {
         let enum_ident = self.enum_ident(CALL);
         let type_generics = &self.type_generics;
 
@@ -46,6 +112,16 @@ impl<'a> StructDef<'a> {
             )
         });
 
+        let match_legs_name = self.fields.iter().map(|field| {
+            let name = &field.ident;
+
+            quote::quote!(
+                #enum_ident::#name(_)=>{
+                   stringify!(#name)
+                },
+            )
+        });
+
         let ident = &self.ident;
         let impl_generics = &self.impl_generics;
         let where_clause = self.where_clause;
@@ -53,14 +129,38 @@ impl<'a> StructDef<'a> {
         let ty_generics = &self.type_generics;
         let call_enum = self.enum_ident(CALL);
 
+        let version = format.version;
+        let migrate = match &format.migrate {
+            Some(migrate) => quote::quote! { #migrate(version, message) },
+            None => quote::quote! {
+                ::core::result::Result::Err(::std::io::Error::new(
+                    ::std::io::ErrorKind::InvalidData,
+                    ::std::format!("Unsupported call message version {}, expected {}", version, #version),
+                ))
+            },
+        };
+
         quote::quote! {
+            impl #impl_generics ::sov_modules_api::VersionedCall for #call_enum #ty_generics #where_clause {
+                const VERSION: u8 = #version;
+            }
+
             impl #impl_generics ::sov_modules_api::DispatchCall for #ident #type_generics #where_clause {
                 type Context = #generic_param;
                 type Decodable = #call_enum #ty_generics;
 
                 fn decode_call(serialized_message: &[u8]) -> ::core::result::Result<Self::Decodable, std::io::Error> {
-                    let mut data = ::std::io::Cursor::new(serialized_message);
-                    <#call_enum #ty_generics as ::borsh::BorshDeserialize>::deserialize_reader(&mut data)
+                    match serialized_message.split_first() {
+                        ::core::option::Option::Some((&#version, message)) => {
+                            let mut data = ::std::io::Cursor::new(message);
+                            <#call_enum #ty_generics as ::borsh::BorshDeserialize>::deserialize_reader(&mut data)
+                        }
+                        ::core::option::Option::Some((&version, message)) => #migrate,
+                        ::core::option::Option::None => ::core::result::Result::Err(::std::io::Error::new(
+                            ::std::io::ErrorKind::InvalidData,
+                            "Empty call message, expected a version tag",
+                        )),
+                    }
                 }
 
                 fn dispatch_call(
@@ -82,6 +182,12 @@ impl<'a> StructDef<'a> {
                     }
                 }
 
+                fn module_name(&self, decodable: &Self::Decodable) -> &'static str {
+                    match decodable {
+                        #(#match_legs_name)*
+                    }
+                }
+
             }
         }
     }
@@ -103,6 +209,7 @@ impl DispatchCallMacro {
         input: DeriveInput,
     ) -> Result<proc_macro::TokenStream, syn::Error> {
         let serialization_methods = get_serialization_attrs(&input)?;
+        let format = get_call_message_format(&input)?;
 
         let DeriveInput {
             data,
@@ -127,7 +234,7 @@ impl DispatchCallMacro {
 
         let call_enum_legs = struct_def.create_call_enum_legs();
         let call_enum = struct_def.create_enum(&call_enum_legs, CALL, &serialization_methods);
-        let create_dispatch_impl = struct_def.create_call_dispatch();
+        let create_dispatch_impl = struct_def.create_call_dispatch(&format);
 
         Ok(quote::quote! {
             #[doc="This enum is generated from the underlying Runtime, the variants correspond to call messages from the relevant modules"]
@@ -63,7 +63,8 @@ impl GenesisMacro {
             let ident = &field.ident;
 
             quote::quote! {
-                #i => ::sov_modules_api::Genesis::genesis(&self.#ident, &config.#ident, working_set),
+                #i => ::sov_modules_api::Genesis::validate_genesis(&self.#ident, &config.#ident, working_set)
+                    .and_then(|_| ::sov_modules_api::Genesis::genesis(&self.#ident, &config.#ident, working_set)),
             }
         });
 
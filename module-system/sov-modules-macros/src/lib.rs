@@ -74,7 +74,12 @@ pub fn genesis(input: TokenStream) -> TokenStream {
     handle_macro_error(genesis_macro.derive_genesis(input))
 }
 
-#[proc_macro_derive(DispatchCall, attributes(serialization))]
+/// Call messages are decoded from the wire format of `sov_modules_api::VersionedCall`: a version
+/// tag followed by the borsh encoding of the message.
+/// `#[call_message(version = 1, migrate = "path::to::fn")]` sets the tag, 0 by default, and a
+/// function `fn(u8, &[u8]) -> Result<_, std::io::Error>` decoding the messages tagged with any
+/// other version, e.g. during an upgrade.
+#[proc_macro_derive(DispatchCall, attributes(serialization, call_message))]
 pub fn dispatch_call(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input);
     let call_macro = DispatchCallMacro::new("Call");
@@ -121,6 +126,8 @@ pub fn config_constant(_attr: TokenStream, item: TokenStream) -> TokenStream {
 /// 1. `#[rpc]` is renamed to `#[rpc_gen]` to avoid confusion with `jsonrpsee`'s `#[rpc]`
 /// 2. `#[rpc_gen]` is applied to an `impl` block instead of a trait
 /// 3. `#[method]` is renamed to with `#[rpc_method]` to avoid import confusion and clarify the purpose of the annotation
+/// 4. `#[rpc_method]` also accepts a `namespace`, which replaces the namespace given to `#[rpc_gen]` for that method,
+///    e.g. `#[rpc_method(name = "blockNumber", namespace = "eth")]` is served as `eth_blockNumber`
 ///
 /// ## Example
 /// ```
@@ -195,6 +202,11 @@ pub fn config_constant(_attr: TokenStream, item: TokenStream) -> TokenStream {
 ///     }
 /// }
 /// ```
+///
+/// Methods may also be `async fn`s, which queries that have to await external services need.
+/// They are awaited by the generated methods, and the generated `MyModuleRpcImpl` trait and
+/// server implementation are then annotated with `#[jsonrpsee::core::async_trait]`, as the server
+/// trait generated by jsonrpsee is.
 #[proc_macro_attribute]
 #[cfg(feature = "native")]
 pub fn rpc_gen(attr: TokenStream, item: TokenStream) -> TokenStream {
@@ -415,17 +415,22 @@ pub mod parsing {
             syn::Meta::List(l) if !l.nested.is_empty() => l,
             _ => return Err(syntax_err),
         };
+        if meta_list.nested.len() != 1 {
+            return Err(syntax_err);
+        }
         let name_value = match &meta_list.nested[0] {
             syn::NestedMeta::Meta(syn::Meta::NameValue(nv)) => nv,
             _ => return Err(syntax_err),
         };
+        let lit = match &name_value.lit {
+            syn::Lit::Str(lit) => lit,
+            _ => return Err(syntax_err),
+        };
 
-        if name_value.path.get_ident().map(Ident::to_string).as_deref() != Some("codec_builder") {
-            return Err(syntax_err);
-        }
-
-        let codec_builder_path = match &name_value.lit {
-            syn::Lit::Str(lit) => lit.parse_with(syn::Path::parse_mod_style)?,
+        let codec_builder_path = match name_value.path.get_ident().map(Ident::to_string).as_deref()
+        {
+            Some("codec_builder") => lit.parse_with(syn::Path::parse_mod_style)?,
+            Some("codec") => shipped_codec_builder(lit)?,
             _ => return Err(syntax_err),
         };
         Ok(ModuleFieldAttribute::State {
@@ -433,6 +438,28 @@ pub mod parsing {
         })
     }
 
+    // Maps the `codec = "..."` shorthand to the builder of one of the codecs shipped with
+    // `sov_modules_api`.
+    fn shipped_codec_builder(lit: &syn::LitStr) -> syn::Result<syn::Path> {
+        let codec = match lit.value().as_str() {
+            "bcs" => "BcsCodec",
+            "bincode" => "BincodeCodec",
+            "borsh" => "BorshCodec",
+            "json" => "JsonCodec",
+            other => {
+                return Err(syn::Error::new_spanned(
+                    lit,
+                    format!(
+                        "Unknown codec `{}`, expected one of `bcs`, `bincode`, `borsh` or `json`.",
+                        other
+                    ),
+                ))
+            }
+        };
+
+        syn::parse_str(&format!("::sov_modules_api::codec::{}::default", codec))
+    }
+
     fn parse_module_fields(data: &syn::Data) -> syn::Result<Vec<ModuleField>> {
         let data_struct = data_to_struct(data)?;
         let mut parsed_fields = vec![];
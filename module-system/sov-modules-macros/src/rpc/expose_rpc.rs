@@ -106,7 +106,7 @@ impl ExposeRpcMacro {
                     /// Get a working set on top of the current storage
                     fn get_working_set(&self) -> ::sov_modules_api::WorkingSet<#context_type>
                     {
-                        ::sov_modules_api::WorkingSet::new(self.storage.clone())
+                        ::sov_modules_api::WorkingSet::read_only(self.storage.clone())
                     }
                 }
             };
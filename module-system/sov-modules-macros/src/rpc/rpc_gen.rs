@@ -9,20 +9,77 @@ use syn::{
     Signature,
 };
 
+/// Joins a method name with its namespace the way jsonrpsee does.
+fn namespaced_name(namespace: Option<&str>, name: &str) -> String {
+    match namespace {
+        Some(namespace) => format!("{}_{}", namespace, name),
+        None => name.to_string(),
+    }
+}
+
+/// Removes the `namespace = "..."` argument from the `rpc_gen` arguments and returns its value.
+fn take_namespace(attrs: &mut Vec<syn::NestedMeta>) -> Result<Option<String>, syn::Error> {
+    let mut namespace = None;
+    let mut result = Ok(());
+    attrs.retain(|attr| {
+        if let syn::NestedMeta::Meta(Meta::NameValue(name_value)) = attr {
+            if name_value.path.is_ident("namespace") {
+                match &name_value.lit {
+                    syn::Lit::Str(lit) => namespace = Some(lit.value()),
+                    lit => result = Err(syn::Error::new_spanned(lit, "namespace must be a string")),
+                }
+                return false;
+            }
+        }
+        true
+    });
+    result.map(|_| namespace)
+}
+
 /// Returns an attribute with the name `rpc_method` replaced with `method`, and the index
 /// into the argument array where the attribute was found.
-fn get_method_attribute(attributes: &[Attribute]) -> Option<(Attribute, usize)> {
+///
+/// The method name is prefixed with the namespace of the method if it has one, or with the
+/// `default_namespace` of the module otherwise.
+fn get_method_attribute(
+    attributes: &[Attribute],
+    default_namespace: Option<&str>,
+) -> Result<Option<(Attribute, usize)>, syn::Error> {
     for (idx, attribute) in attributes.iter().enumerate() {
-        if let Ok(Meta::List(MetaList { path, .. })) = attribute.parse_meta() {
+        if let Ok(Meta::List(MetaList { path, nested, .. })) = attribute.parse_meta() {
             if path.is_ident("rpc_method") {
-                let mut new_attr = attribute.clone();
-                let path = &mut new_attr.path;
-                path.segments.last_mut().unwrap().ident = format_ident!("method");
-                return Some((new_attr, idx));
+                let mut name = None;
+                let mut namespace = default_namespace.map(ToString::to_string);
+                let mut other_args = vec![];
+                for arg in nested {
+                    match arg {
+                        syn::NestedMeta::Meta(Meta::NameValue(syn::MetaNameValue {
+                            path,
+                            lit: syn::Lit::Str(lit),
+                            ..
+                        })) if path.is_ident("name") || path.is_ident("namespace") => {
+                            if path.is_ident("name") {
+                                name = Some(lit.value());
+                            } else {
+                                namespace = Some(lit.value());
+                            }
+                        }
+                        arg => other_args.push(arg),
+                    }
+                }
+                let name = name.ok_or_else(|| {
+                    syn::Error::new_spanned(attribute, "rpc_method requires a `name`")
+                })?;
+                let name = namespaced_name(namespace.as_deref(), &name);
+
+                let new_attr: Attribute = syn::parse_quote! {
+                    #[method(name = #name #(, #other_args)*)]
+                };
+                return Ok(Some((new_attr, idx)));
             }
         }
     }
-    None
+    Ok(None)
 }
 
 fn jsonrpsee_rpc_macro_path() -> Path {
@@ -108,6 +165,11 @@ impl RpcImplBlock {
             let mut signature = method.method_signature.clone();
             let method_name = &method.method_name;
             let docs = &method.docs;
+            let maybe_await = if signature.asyncness.is_some() {
+                quote! { .await }
+            } else {
+                quote! {}
+            };
 
             let impl_trait_method = if let Some(idx) = method.idx_of_working_set_arg {
                 // If necessary, adjust the signature to remove the working set argument and replace it with one generated by the implementer.
@@ -128,7 +190,7 @@ impl RpcImplBlock {
                 quote! {
                     #( #docs )*
                     #signature {
-                        <#type_name #ty_generics as ::std::default::Default>::default().#method_name(#(#pre_working_set_args,)* &mut Self::get_working_set(self), #(#post_working_set_args),* )
+                        <#type_name #ty_generics as ::std::default::Default>::default().#method_name(#(#pre_working_set_args,)* &mut Self::get_working_set(self), #(#post_working_set_args),* ) #maybe_await
                     }
                 }
             } else {
@@ -138,7 +200,7 @@ impl RpcImplBlock {
                     .filter(|arg| arg.to_string() != quote! { self }.to_string());
                 quote! {
                     #signature {
-                        <#type_name  #ty_generics as ::std::default::Default>::default().#method_name(#(#arg_values),*)
+                        <#type_name  #ty_generics as ::std::default::Default>::default().#method_name(#(#arg_values),*) #maybe_await
                     }
                 }
             };
@@ -152,14 +214,14 @@ impl RpcImplBlock {
                 quote! {
                     #( #docs )*
                     #signature {
-                        <Self as #impl_trait_name #ty_generics >::#method_name(#(#pre_working_set_args,)* #(#post_working_set_args),* )
+                        <Self as #impl_trait_name #ty_generics >::#method_name(#(#pre_working_set_args,)* #(#post_working_set_args),* ) #maybe_await
                     }
                 }
             } else {
                 quote! {
                     #( #docs )*
                     #signature {
-                        <Self as #impl_trait_name #ty_generics >::#method_name(#(#arg_values),*)
+                        <Self as #impl_trait_name #ty_generics >::#method_name(#(#arg_values),*) #maybe_await
                     }
                 }
             };
@@ -167,10 +229,23 @@ impl RpcImplBlock {
             blanket_impl_methods.push(blanket_impl_method);
         }
 
+        // jsonrpsee turns the server trait into an `async_trait` if any of its methods is async,
+        // so the traits and impls generated here have to follow suit.
+        let maybe_async_trait = if self
+            .methods
+            .iter()
+            .any(|method| method.method_signature.asyncness.is_some())
+        {
+            quote! { #[::jsonrpsee::core::async_trait] }
+        } else {
+            quote! {}
+        };
+
         let rpc_impl_trait = if let Some(ref working_set_type) = self.working_set_type {
             quote! {
                 /// Allows a Runtime to be converted into a functional RPC server by simply implementing the two required methods -
                 /// `get_backing_impl(&self) -> MyModule` and `get_working_set(&self) -> ::sov_modules_api::WorkingSet<C>`
+                #maybe_async_trait
                 pub trait #impl_trait_name #generics #where_clause {
                     /// Get a clean working set on top of the latest state
                     fn get_working_set(&self) -> #working_set_type;
@@ -181,6 +256,7 @@ impl RpcImplBlock {
             quote! {
                 /// Allows a Runtime to be converted into a functional RPC server by simply implementing the two required methods -
                 /// `get_backing_impl(&self) -> MyModule` and `get_working_set(&self) -> ::sov_modules_api::WorkingSet<C>`
+                #maybe_async_trait
                 pub trait #impl_trait_name #generics #where_clause {
                     #(#impl_trait_methods)*
                 }
@@ -197,6 +273,7 @@ impl RpcImplBlock {
         .expect("Failed to parse generics without braces as token stream");
         let rpc_server_trait_name = format_ident!("{}RpcServer", self.type_name);
         let blanket_impl = quote! {
+            #maybe_async_trait
             impl <MacroGeneratedTypeWithLongNameToAvoidCollisions: #impl_trait_name #ty_generics
             + Send
             + Sync
@@ -231,6 +308,9 @@ fn build_rpc_trait(
     mut input: syn::ItemImpl,
 ) -> Result<proc_macro2::TokenStream, syn::Error> {
     let intermediate_trait_name = format_ident!("{}Rpc", type_name);
+    // The namespace is applied to the method names here rather than by jsonrpsee, so that
+    // methods can override it.
+    let namespace = take_namespace(&mut attrs)?;
     // If the user hasn't directly provided trait bounds, override jsonrpsee's defaults
     // with an empty bound. This prevents spurious compilation errors like `Context does not implement DeserializeOwned`
     add_server_bounds_attr_if_missing(&mut attrs);
@@ -263,7 +343,9 @@ fn build_rpc_trait(
     let mut simplified_impl_items = vec![];
     for item in input.items.into_iter() {
         if let ImplItem::Method(ref method) = item {
-            if let Some((attr, idx_of_rpc_attr)) = get_method_attribute(&method.attrs) {
+            if let Some((attr, idx_of_rpc_attr)) =
+                get_method_attribute(&method.attrs, namespace.as_deref())?
+            {
                 let mut intermediate_trait_inputs = method.sig.inputs.clone();
                 let working_set_arg = find_working_set_argument(&method.sig);
                 let idx_of_working_set_arg = if let Some((idx, ty)) = working_set_arg {
@@ -322,6 +404,8 @@ fn build_rpc_trait(
     };
 
     let doc_string = format!("Generated RPC trait for {}", type_name);
+    let health_name = namespaced_name(namespace.as_deref(), "health");
+    let module_address_name = namespaced_name(namespace.as_deref(), "moduleAddress");
     let (_, ty_generics, where_clause) = generics.split_for_impl();
 
     let rpc_output = quote! {
@@ -337,13 +421,13 @@ fn build_rpc_trait(
             #(#intermediate_trait_items)*
 
             /// Check the health of the RPC server
-            #[method(name = "health")]
+            #[method(name = #health_name)]
             fn health(&self) -> ::jsonrpsee::core::RpcResult<()> {
                 Ok(())
             }
 
             /// Get the address of this module
-            #[method(name = "moduleAddress")]
+            #[method(name = #module_address_name)]
             fn module_address(&self) -> ::jsonrpsee::core::RpcResult<String> {
                 Ok(<#type_name #ty_generics as ::sov_modules_api::ModuleInfo>::address(&<#type_name #ty_generics as ::core::default::Default>::default()).to_string())
             }
@@ -18,6 +18,7 @@ fn module_info_tests() {
     t.pass("tests/module_info/use_address_trait.rs");
     t.pass("tests/module_info/not_supported_attribute.rs");
     t.pass("tests/module_info/custom_codec_builder.rs");
+    t.pass("tests/module_info/shipped_codec.rs");
     t.pass("tests/custom_codec_must_be_used.rs");
     t.compile_fail("tests/module_info/derive_on_enum_not_supported.rs");
     t.compile_fail("tests/module_info/field_missing_attribute.rs");
@@ -34,6 +35,8 @@ fn module_dispatch_tests() {
     t.pass("tests/dispatch/derive_genesis.rs");
     t.pass("tests/dispatch/derive_dispatch.rs");
     t.pass("tests/dispatch/derive_event.rs");
+    t.pass("tests/dispatch/colliding_module_prefixes.rs");
+    t.pass("tests/dispatch/versioned_call_message.rs");
     t.compile_fail("tests/dispatch/missing_serialization.rs");
 }
 
@@ -43,6 +46,8 @@ fn rpc_tests() {
     let t = trybuild::TestCases::new();
     t.pass("tests/rpc/derive_rpc.rs");
     t.pass("tests/rpc/derive_rpc_with_where.rs");
+    t.pass("tests/rpc/derive_rpc_async.rs");
+    t.pass("tests/rpc/derive_rpc_method_namespace.rs");
     t.pass("tests/rpc/expose_rpc.rs");
     t.pass("tests/rpc/expose_rpc_associated_types.rs");
     t.pass("tests/rpc/expose_rpc_associated_types_nested.rs");
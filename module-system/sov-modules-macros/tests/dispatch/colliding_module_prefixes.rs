@@ -0,0 +1,23 @@
+mod modules;
+
+use modules::first_test_module;
+use sov_modules_api::default_context::ZkDefaultContext;
+use sov_modules_api::macros::DefaultRuntime;
+use sov_modules_api::Context;
+
+// Both fields hold the same module, so they share a prefix.
+#[derive(DefaultRuntime)]
+struct Runtime<C: Context> {
+    pub first: first_test_module::FirstTestStruct<C>,
+    pub second: first_test_module::FirstTestStruct<C>,
+}
+
+fn main() {
+    let err = std::panic::catch_unwind(|| Runtime::<ZkDefaultContext>::default())
+        .err()
+        .expect("Colliding prefixes should be rejected");
+    let message = err
+        .downcast_ref::<String>()
+        .expect("The panic message should be a String");
+    assert!(message.contains("Modules `first` and `second` of runtime `Runtime`"));
+}
@@ -0,0 +1,76 @@
+mod modules;
+
+use borsh::BorshDeserialize;
+use modules::{first_test_module, second_test_module};
+use sov_modules_api::default_context::ZkDefaultContext;
+use sov_modules_api::{Context, DispatchCall, EncodeCall, MessageCodec, VersionedCall};
+
+#[derive(DispatchCall, MessageCodec)]
+#[serialization(borsh::BorshDeserialize, borsh::BorshSerialize)]
+struct Runtime<C: Context> {
+    pub first: first_test_module::FirstTestStruct<C>,
+}
+
+// `Runtime` stands for the previous layout of `UpgradedRuntime`, whose messages were tagged with
+// version 0.
+#[derive(DispatchCall, MessageCodec)]
+#[serialization(borsh::BorshDeserialize, borsh::BorshSerialize)]
+#[call_message(version = 1, migrate = "migrate_call")]
+struct UpgradedRuntime<C: Context> {
+    pub second: second_test_module::SecondTestStruct<C>,
+    pub first: first_test_module::FirstTestStruct<C>,
+}
+
+fn migrate_call<C: Context>(
+    version: u8,
+    message: &[u8],
+) -> Result<UpgradedRuntimeCall<C>, std::io::Error> {
+    match version {
+        0 => RuntimeCall::<C>::try_from_slice(message).map(|call| match call {
+            RuntimeCall::first(message) => UpgradedRuntimeCall::first(message),
+        }),
+        _ => Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidData,
+            format!("Unknown call message version {}", version),
+        )),
+    }
+}
+
+fn main() {
+    type C = ZkDefaultContext;
+
+    // A message of the current version is decoded.
+    let message = <Runtime<C> as EncodeCall<first_test_module::FirstTestStruct<C>>>::encode_call(7);
+    assert_eq!(message[0], <RuntimeCall<C> as VersionedCall>::VERSION);
+    assert!(matches!(
+        Runtime::<C>::decode_call(&message),
+        Ok(RuntimeCall::first(7))
+    ));
+
+    // A message with a bumped version is rejected.
+    let mut bumped = message.clone();
+    bumped[0] += 1;
+    let err = Runtime::<C>::decode_call(&bumped).err().unwrap();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(
+        err.to_string(),
+        "Unsupported call message version 1, expected 0"
+    );
+    assert!(Runtime::<C>::decode_call(&[]).is_err());
+
+    // The migration hook accepts messages of the previous version.
+    let upgraded_message =
+        <UpgradedRuntime<C> as EncodeCall<first_test_module::FirstTestStruct<C>>>::encode_call(7);
+    assert_eq!(upgraded_message[0], 1);
+    assert!(matches!(
+        UpgradedRuntime::<C>::decode_call(&upgraded_message),
+        Ok(UpgradedRuntimeCall::first(7))
+    ));
+    assert!(matches!(
+        UpgradedRuntime::<C>::decode_call(&message),
+        Ok(UpgradedRuntimeCall::first(7))
+    ));
+    let mut bumped = upgraded_message;
+    bumped[0] += 1;
+    assert!(UpgradedRuntime::<C>::decode_call(&bumped).is_err());
+}
@@ -0,0 +1,33 @@
+use sov_modules_api::codec::JsonCodec;
+use sov_modules_api::default_context::ZkDefaultContext;
+use sov_modules_api::prelude::*;
+use sov_modules_api::{Context, ModuleInfo, StateReaderAndWriter, StateValue, WorkingSet};
+use sov_modules_core::StorageKey;
+use sov_state::{DefaultStorageSpec, ZkStorage};
+
+#[derive(ModuleInfo)]
+struct TestModule<C>
+where
+    C: Context,
+{
+    #[address]
+    address: C::Address,
+
+    #[state(codec = "json")]
+    state_value: StateValue<Vec<u32>, JsonCodec>,
+}
+
+fn main() {
+    let storage: ZkStorage<DefaultStorageSpec> = ZkStorage::new();
+    let module: TestModule<ZkDefaultContext> = TestModule::default();
+    let mut working_set: WorkingSet<ZkDefaultContext> = WorkingSet::new(storage);
+
+    let value = vec![1u32, 2, 3];
+    module.state_value.set(&value, &mut working_set);
+
+    let raw_value = working_set
+        .get(&StorageKey::singleton(module.state_value.prefix()))
+        .unwrap();
+    assert_eq!(raw_value.value(), serde_json::to_vec(&value).unwrap());
+    assert_eq!(module.state_value.get(&mut working_set), Some(value));
+}
@@ -0,0 +1,82 @@
+use jsonrpsee::core::RpcResult;
+use sov_modules_api::default_context::ZkDefaultContext;
+use sov_modules_api::macros::rpc_gen;
+use sov_modules_api::{Context, ModuleInfo, WorkingSet};
+use sov_state::ZkStorage;
+
+#[derive(ModuleInfo)]
+pub struct TestStruct<C: ::sov_modules_api::Context> {
+    #[address]
+    pub(crate) address: C::Address,
+}
+
+#[rpc_gen(client, server, namespace = "test")]
+impl<C: sov_modules_api::Context> TestStruct<C> {
+    #[rpc_method(name = "syncMethod")]
+    pub fn sync_method(&self, result: u32, _working_set: &mut WorkingSet<C>) -> RpcResult<u32> {
+        Ok(result)
+    }
+
+    #[rpc_method(name = "asyncMethod")]
+    pub async fn async_method(
+        &self,
+        result: u32,
+        _working_set: &mut WorkingSet<C>,
+    ) -> RpcResult<u32> {
+        let result = std::future::ready(result).await;
+        Ok(result + 1)
+    }
+
+    #[rpc_method(name = "asyncMethodWithoutWorkingSet")]
+    pub async fn async_method_without_working_set(&self, result: u32) -> RpcResult<u32> {
+        Ok(result + 2)
+    }
+}
+
+// This is generated by a macro annotating the state transition runner,
+// but we do not have that in scope here so generating the struct manually.
+struct RpcStorage<C: Context> {
+    pub storage: C::Storage,
+}
+
+impl TestStructRpcImpl<ZkDefaultContext> for RpcStorage<ZkDefaultContext> {
+    fn get_working_set(&self) -> ::sov_modules_api::WorkingSet<ZkDefaultContext> {
+        ::sov_modules_api::WorkingSet::new(self.storage.clone())
+    }
+}
+
+fn main() {
+    let storage = ZkStorage::new();
+    let r: RpcStorage<ZkDefaultContext> = RpcStorage {
+        storage: storage.clone(),
+    };
+
+    {
+        let result =
+            <RpcStorage<ZkDefaultContext> as TestStructRpcServer<ZkDefaultContext>>::sync_method(
+                &r, 11,
+            )
+            .unwrap();
+        assert_eq!(result, 11);
+    }
+
+    {
+        let result =
+            futures::executor::block_on(<RpcStorage<ZkDefaultContext> as TestStructRpcServer<
+                ZkDefaultContext,
+            >>::async_method(&r, 22))
+            .unwrap();
+        assert_eq!(result, 23);
+    }
+
+    {
+        let result =
+            futures::executor::block_on(<RpcStorage<ZkDefaultContext> as TestStructRpcServer<
+                ZkDefaultContext,
+            >>::async_method_without_working_set(&r, 33))
+            .unwrap();
+        assert_eq!(result, 35);
+    }
+
+    println!("All tests passed!");
+}
@@ -0,0 +1,68 @@
+use jsonrpsee::core::RpcResult;
+use sov_modules_api::default_context::ZkDefaultContext;
+use sov_modules_api::macros::rpc_gen;
+use sov_modules_api::{Context, ModuleInfo, WorkingSet};
+use sov_state::ZkStorage;
+
+#[derive(ModuleInfo)]
+pub struct TestStruct<C: ::sov_modules_api::Context> {
+    #[address]
+    pub(crate) address: C::Address,
+}
+
+#[rpc_gen(client, server, namespace = "test")]
+impl<C: sov_modules_api::Context> TestStruct<C> {
+    #[rpc_method(name = "firstMethod")]
+    pub fn first_method(&self, _working_set: &mut WorkingSet<C>) -> RpcResult<u32> {
+        Ok(11)
+    }
+
+    #[rpc_method(name = "blockNumber", namespace = "eth")]
+    pub fn block_number(&self, _working_set: &mut WorkingSet<C>) -> RpcResult<u32> {
+        Ok(22)
+    }
+}
+
+// This is generated by a macro annotating the state transition runner,
+// but we do not have that in scope here so generating the struct manually.
+struct RpcStorage<C: Context> {
+    pub storage: C::Storage,
+}
+
+impl TestStructRpcImpl<ZkDefaultContext> for RpcStorage<ZkDefaultContext> {
+    fn get_working_set(&self) -> ::sov_modules_api::WorkingSet<ZkDefaultContext> {
+        ::sov_modules_api::WorkingSet::new(self.storage.clone())
+    }
+}
+
+fn main() {
+    let r: RpcStorage<ZkDefaultContext> = RpcStorage {
+        storage: ZkStorage::new(),
+    };
+    let module =
+        <RpcStorage<ZkDefaultContext> as TestStructRpcServer<ZkDefaultContext>>::into_rpc(r);
+
+    let mut method_names: Vec<_> = module.method_names().collect();
+    method_names.sort();
+    assert_eq!(
+        method_names,
+        [
+            "eth_blockNumber",
+            "test_firstMethod",
+            "test_health",
+            "test_moduleAddress"
+        ]
+    );
+
+    let result: u32 =
+        futures::executor::block_on(module.call("test_firstMethod", jsonrpsee::rpc_params![]))
+            .unwrap();
+    assert_eq!(result, 11);
+
+    let result: u32 =
+        futures::executor::block_on(module.call("eth_blockNumber", jsonrpsee::rpc_params![]))
+            .unwrap();
+    assert_eq!(result, 22);
+
+    println!("All tests passed!");
+}
@@ -4,6 +4,8 @@
 mod runtime_rpc;
 mod wallet;
 use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
 
 use async_trait::async_trait;
 pub use runtime_rpc::*;
@@ -17,7 +19,8 @@ use sov_rollup_interface::zk::ZkvmHost;
 use sov_state::storage::NativeStorage;
 use sov_state::Storage;
 use sov_stf_runner::{
-    InitVariant, ProverService, RollupConfig, RollupProverConfig, StateTransitionRunner,
+    InitVariant, MetricsConfig, ProverService, RollupConfig, RollupProverConfig, SequencerConfig,
+    StateTransitionRunner,
 };
 use tokio::sync::oneshot;
 pub use wallet::*;
@@ -64,13 +67,21 @@ pub trait RollupBlueprint: Sized + Send + Sync {
         DaService = Self::DaService,
     >;
 
-    /// Creates RPC methods for the rollup.
+    /// Creates RPC methods for the rollup, along with the sequencer serving them.
+    #[allow(clippy::type_complexity)]
     fn create_rpc_methods(
         &self,
         storage: &<Self::NativeContext as Spec>::Storage,
         ledger_db: &LedgerDB,
         da_service: &Self::DaService,
-    ) -> Result<jsonrpsee::RpcModule<()>, anyhow::Error>;
+        sequencer_config: &SequencerConfig,
+    ) -> Result<
+        (
+            jsonrpsee::RpcModule<()>,
+            Arc<RollupSequencer<Self::NativeRuntime, Self::NativeContext, Self::DaService>>,
+        ),
+        anyhow::Error,
+    >;
 
     /// Creates GenesisConfig from genesis files.
     #[allow(clippy::type_complexity)]
@@ -158,13 +169,20 @@ pub trait RollupBlueprint: Sized + Send + Sync {
         let mut storage_manager = self.create_storage_manager(&rollup_config)?;
         let prover_storage = storage_manager.create_finalized_storage()?;
 
+        // The ledger can be ahead of the finalized state after a crash, so the root is taken from
+        // the latest version of the state. The runner executes the missing slots again.
         let prev_root = ledger_db
             .get_head_slot()?
-            .map(|(number, _)| prover_storage.get_root_hash(number.0))
+            .map(|_| prover_storage.get_root_hash(prover_storage.latest_version()))
             .transpose()?;
 
         // TODO(https://github.com/Sovereign-Labs/sovereign-sdk/issues/1218)
-        let rpc_methods = self.create_rpc_methods(&prover_storage, &ledger_db, &da_service)?;
+        let (rpc_methods, sequencer) = self.create_rpc_methods(
+            &prover_storage,
+            &ledger_db,
+            &da_service,
+            &rollup_config.sequencer,
+        )?;
 
         let native_stf = StfBlueprint::new();
 
@@ -189,6 +207,9 @@ pub trait RollupBlueprint: Sized + Send + Sync {
         Ok(Rollup {
             runner,
             rpc_methods,
+            metrics_config: rollup_config.metrics,
+            sequencer,
+            mempool_path: rollup_config.sequencer.mempool_path,
         })
     }
 }
@@ -206,6 +227,14 @@ pub struct Rollup<S: RollupBlueprint> {
     >,
     /// Rpc methods for the rollup.
     pub rpc_methods: jsonrpsee::RpcModule<()>,
+    /// Configuration of the metrics endpoint, if metrics are served. Metrics are only served
+    /// when the `metrics` feature is enabled.
+    pub metrics_config: Option<MetricsConfig>,
+    /// The sequencer serving the rollup's transaction RPC.
+    #[allow(clippy::type_complexity)]
+    pub sequencer: Arc<RollupSequencer<S::NativeRuntime, S::NativeContext, S::DaService>>,
+    /// File the sequencer's mempool is persisted to when the rollup stops.
+    pub mempool_path: Option<PathBuf>,
 }
 
 impl<S: RollupBlueprint> Rollup<S> {
@@ -215,13 +244,38 @@ impl<S: RollupBlueprint> Rollup<S> {
     }
 
     /// Runs the rollup. Reports rpc port to the caller using the provided channel.
+    ///
+    /// The rollup stops on Ctrl-C, after which the sequencer's mempool is persisted if
+    /// [`Rollup::mempool_path`] is set.
     pub async fn run_and_report_rpc_port(
         self,
         channel: Option<oneshot::Sender<SocketAddr>>,
     ) -> Result<(), anyhow::Error> {
         let mut runner = self.runner;
         runner.start_rpc_server(self.rpc_methods, channel).await;
-        runner.run_in_process().await?;
-        Ok(())
+        if let Some(metrics_config) = &self.metrics_config {
+            #[cfg(feature = "metrics")]
+            runner.start_metrics_server(metrics_config, None).await?;
+            #[cfg(not(feature = "metrics"))]
+            tracing::warn!(
+                ?metrics_config,
+                "Metrics are configured, but the rollup was built without the `metrics` feature"
+            );
+        }
+
+        let (shutdown_sender, shutdown_receiver) = oneshot::channel();
+        tokio::spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                let _ = shutdown_sender.send(());
+            }
+        });
+        let result = runner.run_in_process_until(shutdown_receiver).await;
+
+        if let Some(mempool_path) = &self.mempool_path {
+            self.sequencer
+                .batch_builder()?
+                .persist_mempool(mempool_path)?;
+        }
+        result
     }
 }
@@ -1,17 +1,35 @@
+use std::sync::Arc;
+use std::time::Duration;
+
 use anyhow::Context as _;
 use sov_db::ledger_db::LedgerDB;
 use sov_modules_api::{Context, Spec};
 use sov_modules_stf_blueprint::{Runtime as RuntimeTrait, SequencerOutcome, TxEffect};
 use sov_rollup_interface::services::da::DaService;
 use sov_sequencer::batch_builder::FiFoStrictBatchBuilder;
+use sov_sequencer::blob_size::BlobSizeLimits;
+use sov_sequencer::flush_policy::FlushPolicy;
+use sov_sequencer::Sequencer;
+use sov_stf_runner::SequencerConfig;
+
+/// How often the sequencer checks whether [`FlushPolicy::max_age`] has elapsed, at most.
+const FLUSH_CHECK_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The sequencer which accepts transactions over the rollup's RPC.
+pub type RollupSequencer<RT, C, Da> = Sequencer<FiFoStrictBatchBuilder<C, RT>, Da>;
 
 /// Register rollup's default rpc methods.
+/// Also returns the sequencer, whose mempool is restored from
+/// [`SequencerConfig::mempool_path`] if it's set. If the flush policy has a maximum age, a task
+/// which flushes expired batches is spawned, so this must be called from a tokio runtime.
+#[allow(clippy::type_complexity)]
 pub fn register_rpc<RT, C, Da>(
     storage: &<C as Spec>::Storage,
     ledger_db: &LedgerDB,
     da_service: &Da,
     sequencer: C::Address,
-) -> Result<jsonrpsee::RpcModule<()>, anyhow::Error>
+    sequencer_config: &SequencerConfig,
+) -> Result<(jsonrpsee::RpcModule<()>, Arc<RollupSequencer<RT, C, Da>>), anyhow::Error>
 where
     RT: RuntimeTrait<C, <Da as DaService>::Spec> + Send + Sync + 'static,
     C: Context,
@@ -29,21 +47,77 @@ where
         >(ledger_db.clone())?)?;
     }
 
-    // sequencer rpc.
+    // chain id rpc.
+    {
+        rpc_methods
+            .merge(sov_modules_stf_blueprint::chain_id::get_chain_id_rpc::<C>(
+                storage.clone(),
+            ))
+            .context("Failed to merge chain id RPC modules")?;
+    }
+
+    // sync status rpc.
     {
-        let batch_builder = FiFoStrictBatchBuilder::new(
+        rpc_methods
+            .merge(sov_stf_runner::get_sync_status_rpc(
+                ledger_db.clone(),
+                da_service.clone(),
+            ))
+            .context("Failed to merge sync status RPC modules")?;
+    }
+
+    // sequencer rpc.
+    let sequencer = {
+        let mut batch_builder = FiFoStrictBatchBuilder::new(
             1024 * 100,
             u32::MAX as usize,
             RT::default(),
             storage.clone(),
             sequencer,
         );
+        if let Some(mempool_path) = &sequencer_config.mempool_path {
+            batch_builder.load_mempool(mempool_path)?;
+        }
+        // The restored transactions count towards the flush policy like new ones.
+        let restored_tx_lens: Vec<usize> = batch_builder.pending_tx_lens().collect();
 
-        let sequencer_rpc = sov_sequencer::get_sequencer_rpc(batch_builder, da_service.clone());
+        let flush_policy = &sequencer_config.flush_policy;
+        anyhow::ensure!(
+            flush_policy.max_age_ms != Some(0),
+            "The maximum age of the sequencer flush policy must be at least 1 ms"
+        );
+        let max_age = flush_policy.max_age_ms.map(Duration::from_millis);
+        let sequencer = Arc::new(
+            Sequencer::new(batch_builder, da_service.clone())
+                .with_pending_txs(restored_tx_lens)
+                .with_flush_policy(FlushPolicy {
+                    max_txs: flush_policy.max_txs,
+                    max_bytes: flush_policy.max_bytes,
+                    max_age,
+                })
+                .with_blob_size_limits(BlobSizeLimits {
+                    min_blob_size: sequencer_config.min_blob_size,
+                    max_blob_size: sequencer_config.max_blob_size,
+                })
+                .context("Invalid sequencer blob size limits")?,
+        );
+        if let Some(max_age) = max_age {
+            let sequencer = sequencer.clone();
+            tokio::spawn(async move {
+                let mut interval = tokio::time::interval(max_age.min(FLUSH_CHECK_INTERVAL));
+                loop {
+                    interval.tick().await;
+                    if let Err(error) = sequencer.flush_if_triggered().await {
+                        tracing::warn!(%error, "Failed to flush batch");
+                    }
+                }
+            });
+        }
         rpc_methods
-            .merge(sequencer_rpc)
+            .merge(sov_sequencer::get_rpc_for_sequencer(sequencer.clone()))
             .context("Failed to merge Txs RPC modules")?;
-    }
+        sequencer
+    };
 
-    Ok(rpc_methods)
+    Ok((rpc_methods, sequencer))
 }
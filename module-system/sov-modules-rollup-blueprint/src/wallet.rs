@@ -9,7 +9,7 @@ use sov_cli::workflows::transactions::TransactionWorkflow;
 use sov_cli::{clap, wallet_dir};
 use sov_modules_api::clap::Parser;
 use sov_modules_api::cli::{CliFrontEnd, CliTxImportArg, JsonStringArg};
-use sov_modules_api::{CliWallet, Context, DispatchCall};
+use sov_modules_api::{CliWallet, Context, DispatchCall, VersionedCall};
 
 use crate::RollupBlueprint;
 
@@ -45,7 +45,12 @@ where
     <Self as RollupBlueprint>::DaSpec: serde::Serialize + serde::de::DeserializeOwned,
 
     <<Self as RollupBlueprint>::NativeRuntime as DispatchCall>::Decodable:
-        serde::Serialize + serde::de::DeserializeOwned + BorshSerialize + Send + Sync,
+        serde::Serialize
+            + serde::de::DeserializeOwned
+            + BorshSerialize
+            + VersionedCall
+            + Send
+            + Sync,
 
     <<Self as RollupBlueprint>::NativeRuntime as CliWallet>::CliStringRepr<JsonStringArg>: TryInto<
         <<Self as RollupBlueprint>::NativeRuntime as DispatchCall>::Decodable,
@@ -0,0 +1,52 @@
+//! The rollup-level chain id, derived from the genesis state root.
+//!
+//! Signers and wallets can bind to the chain id to separate signatures between rollups,
+//! since two rollups only share a chain id if they were initialized with the same genesis.
+//! The chain id is part of the provable state, so it can be read from inside the zkVM as well.
+
+use sov_modules_api::digest::Digest;
+use sov_modules_api::{Context, Spec, StateValue, StateValueAccessor, WorkingSet};
+use sov_modules_core::Prefix;
+
+const CHAIN_ID_PREFIX: &[u8] = b"sov_modules_stf_blueprint/chain_id";
+
+fn chain_id_value() -> StateValue<u64> {
+    StateValue::new(Prefix::new(CHAIN_ID_PREFIX.to_vec()))
+}
+
+/// Derives the chain id from the state root computed at genesis, before the chain id itself is
+/// stored: the first 8 bytes of its hash, as a little-endian `u64`.
+pub fn derive_chain_id<C: Context>(genesis_root: &[u8]) -> u64 {
+    let hash: [u8; 32] = <C as Spec>::Hasher::digest(genesis_root).into();
+    let mut chain_id = [0u8; 8];
+    chain_id.copy_from_slice(&hash[..8]);
+    u64::from_le_bytes(chain_id)
+}
+
+pub(crate) fn set_chain_id<C: Context>(chain_id: u64, working_set: &mut WorkingSet<C>) {
+    chain_id_value().set(&chain_id, working_set);
+}
+
+/// Returns the chain id stored at genesis, or `None` if the chain has not been initialized.
+pub fn get_chain_id<C: Context>(working_set: &mut WorkingSet<C>) -> Option<u64> {
+    chain_id_value().get(working_set)
+}
+
+/// Creates an RPC module with the `rollup_chainId` method.
+#[cfg(feature = "native")]
+pub fn get_chain_id_rpc<C: Context>(
+    storage: <C as Spec>::Storage,
+) -> jsonrpsee::RpcModule<<C as Spec>::Storage> {
+    let mut rpc = jsonrpsee::RpcModule::new(storage);
+    rpc.register_method("rollup_chainId", |_, storage| {
+        let mut working_set = WorkingSet::<C>::new(storage.clone());
+        get_chain_id(&mut working_set).ok_or_else(|| {
+            sov_modules_api::utils::to_jsonrpsee_error_object(
+                "The chain has not been initialized",
+                "ROLLUP_CHAIN_ID_ERROR",
+            )
+        })
+    })
+    .expect("Failed to register chain id RPC methods");
+    rpc
+}
@@ -33,6 +33,8 @@ pub struct BasicKernelGenesisPaths {
 }
 
 /// The genesis configuration for the basic kernel
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(bound = "")]
 pub struct BasicKernelGenesisConfig<C: Context, Da: DaSpec> {
     /// The chain state genesis config
     pub chain_state: <ChainState<C, Da> as KernelModule>::Config,
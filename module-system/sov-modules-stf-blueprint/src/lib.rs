@@ -2,6 +2,7 @@
 #![doc = include_str!("../README.md")]
 
 mod batch;
+pub mod chain_id;
 pub mod kernels;
 mod stf_blueprint;
 mod tx_verifier;
@@ -11,7 +12,7 @@ use sov_modules_api::hooks::{ApplyBlobHooks, FinalizeHook, SlotHooks, TxHooks};
 use sov_modules_api::runtime::capabilities::{Kernel, KernelSlotHooks};
 use sov_modules_api::{
     BasicAddress, BlobReaderTrait, Context, DaSpec, DispatchCall, Genesis, KernelWorkingSet, Spec,
-    StateCheckpoint, Zkvm,
+    StateCheckpoint, WorkingSet, Zkvm,
 };
 pub use sov_rollup_interface::stf::BatchReceipt;
 use sov_rollup_interface::stf::{SlotResult, StateTransitionFunction};
@@ -73,6 +74,26 @@ pub enum TxEffect {
     Reverted,
     /// Batch was processed successfully.
     Successful,
+    /// The runtime message couldn't be decoded, so the transaction was skipped.
+    /// Only produced under [`DecodeFailurePolicy::SkipTransaction`].
+    DecodeFailed,
+    /// The transaction's `valid_until_slot` is below the current slot height, so it was skipped.
+    Expired,
+    /// The transaction was signed for another chain id than the one of this rollup, so it was
+    /// skipped.
+    WrongChainId,
+}
+
+/// How [`StfBlueprint`] handles a transaction whose runtime message cannot be decoded.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum DecodeFailurePolicy {
+    /// The whole batch is reverted and the sequencer is slashed with
+    /// [`SlashingReason::InvalidTransactionEncoding`].
+    #[default]
+    FailBatch,
+    /// The transaction is skipped and recorded as [`TxEffect::DecodeFailed`] in the batch
+    /// receipt, and the rest of the batch is processed.
+    SkipTransaction,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
@@ -93,6 +114,7 @@ pub enum SequencerOutcome<A: BasicAddress> {
 }
 
 /// Genesis parameters for a blueprint
+#[derive(serde::Serialize, serde::Deserialize)]
 pub struct GenesisParams<RT, K> {
     /// The runtime genesis parameters
     pub runtime: RT,
@@ -119,6 +141,23 @@ where
     RT: Runtime<C, Da>,
     K: KernelSlotHooks<C, Da>,
 {
+    fn run_genesis(
+        &self,
+        pre_state: C::Storage,
+        params: &GenesisParams<<RT as Genesis>::Config, <K as Kernel<C, Da>>::GenesisConfig>,
+    ) -> WorkingSet<C> {
+        let mut working_set = StateCheckpoint::new(pre_state).to_revertable();
+
+        self.kernel
+            .genesis(&params.kernel, &mut working_set)
+            .expect("Kernel initialization must succeed");
+        self.runtime
+            .genesis(&params.runtime, &mut working_set)
+            .expect("Runtime initialization must succeed");
+
+        working_set
+    }
+
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
     fn begin_slot(
         &self,
@@ -150,6 +189,7 @@ where
         &self,
         storage: C::Storage,
         checkpoint: StateCheckpoint<C>,
+        commit: bool,
     ) -> (
         <<C as Spec>::Storage as Storage>::Root,
         <<C as Spec>::Storage as Storage>::Witness,
@@ -175,10 +215,120 @@ where
         let mut checkpoint = working_set.checkpoint();
         let accessory_log = checkpoint.freeze_non_provable();
 
-        storage.commit(&state_update, &accessory_log);
+        if commit {
+            storage.commit(&state_update, &accessory_log);
+        }
 
         (root_hash, witness, storage)
     }
+
+    /// Executes `blobs` like [`StateTransitionFunction::apply_slot`], and returns the receipts and
+    /// the state root the slot would produce, without writing anything to `pre_state`.
+    ///
+    /// This allows simulating transactions against the current state of the rollup.
+    #[allow(clippy::type_complexity)]
+    pub fn dry_run_slot<'a, I>(
+        &self,
+        pre_state_root: &<C::Storage as Storage>::Root,
+        pre_state: C::Storage,
+        slot_header: &Da::BlockHeader,
+        validity_condition: &Da::ValidityCondition,
+        blobs: I,
+    ) -> SlotResult<
+        <C::Storage as Storage>::Root,
+        C::Storage,
+        SequencerOutcome<<Da::BlobTransaction as BlobReaderTrait>::Address>,
+        TxEffect,
+        <C::Storage as Storage>::Witness,
+    >
+    where
+        I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
+    {
+        self.execute_slot(
+            pre_state_root,
+            pre_state,
+            Default::default(),
+            slot_header,
+            validity_condition,
+            blobs,
+            false,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments, clippy::type_complexity)]
+    fn execute_slot<'a, I>(
+        &self,
+        pre_state_root: &<C::Storage as Storage>::Root,
+        pre_state: C::Storage,
+        witness: <C::Storage as Storage>::Witness,
+        slot_header: &Da::BlockHeader,
+        validity_condition: &Da::ValidityCondition,
+        blobs: I,
+        commit: bool,
+    ) -> SlotResult<
+        <C::Storage as Storage>::Root,
+        C::Storage,
+        SequencerOutcome<<Da::BlobTransaction as BlobReaderTrait>::Address>,
+        TxEffect,
+        <C::Storage as Storage>::Witness,
+    >
+    where
+        I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
+    {
+        let checkpoint = StateCheckpoint::with_witness(pre_state.clone(), witness);
+        let checkpoint =
+            self.begin_slot(checkpoint, slot_header, validity_condition, pre_state_root);
+
+        // Initialize batch workspace
+        let mut batch_workspace = checkpoint.to_revertable();
+        let mut kernel_working_set =
+            KernelWorkingSet::from_kernel(&self.kernel, &mut batch_workspace);
+        let selected_blobs = self
+            .kernel
+            .get_blobs_for_this_slot(blobs, &mut kernel_working_set)
+            .expect("blob selection must succeed, probably serialization failed");
+
+        info!(
+            "Selected {} blob(s) for execution in current slot",
+            selected_blobs.len()
+        );
+
+        let mut checkpoint = batch_workspace.checkpoint();
+
+        let mut batch_receipts = vec![];
+
+        for (blob_idx, mut blob) in selected_blobs.into_iter().enumerate() {
+            let (apply_blob_result, checkpoint_after_blob) =
+                self.apply_blob(checkpoint, blob.as_mut_ref(), slot_header.height());
+            checkpoint = checkpoint_after_blob;
+            let batch_receipt = apply_blob_result.unwrap_or_else(Into::into);
+            info!(
+                "blob #{} from sequencer {} with blob_hash 0x{} has been applied with #{} transactions, sequencer outcome {:?}",
+                blob_idx,
+                blob.as_mut_ref().sender(),
+                hex::encode(batch_receipt.batch_hash),
+                batch_receipt.tx_receipts.len(),
+                batch_receipt.inner
+            );
+            for (i, tx_receipt) in batch_receipt.tx_receipts.iter().enumerate() {
+                info!(
+                    "tx #{} hash: 0x{} result {:?}",
+                    i,
+                    hex::encode(tx_receipt.tx_hash),
+                    tx_receipt.receipt
+                );
+            }
+            batch_receipts.push(batch_receipt);
+        }
+
+        let (state_root, witness, storage) = self.end_slot(pre_state, checkpoint, commit);
+        SlotResult {
+            state_root,
+            change_set: storage,
+            batch_receipts,
+            witness,
+        }
+    }
 }
 
 impl<C, RT, Vm, Da, K> StateTransitionFunction<Vm, Da> for StfBlueprint<C, Da, Vm, RT, K>
@@ -209,17 +359,21 @@ where
         pre_state: Self::PreState,
         params: Self::GenesisParams,
     ) -> (Self::StateRoot, Self::ChangeSet) {
-        let mut working_set = StateCheckpoint::new(pre_state.clone()).to_revertable();
+        let mut checkpoint = self.run_genesis(pre_state.clone(), &params).checkpoint();
+        let (mut log, witness) = checkpoint.freeze();
 
-        self.kernel
-            .genesis(&params.kernel, &mut working_set)
-            .expect("Kernel initialization must succeed");
-        self.runtime
-            .genesis(&params.runtime, &mut working_set)
-            .expect("Runtime initialization must succeed");
-
-        let mut checkpoint = working_set.checkpoint();
-        let (log, witness) = checkpoint.freeze();
+        // The chain id is derived from the root of the genesis state, and is then written along
+        // with it.
+        let (root_without_chain_id, _) = pre_state
+            .compute_state_update(log.clone(), &witness)
+            .expect("Storage update must succeed");
+        let mut chain_id_working_set = StateCheckpoint::<C>::new(pre_state.clone()).to_revertable();
+        chain_id::set_chain_id(
+            chain_id::derive_chain_id::<C>(root_without_chain_id.as_ref()),
+            &mut chain_id_working_set,
+        );
+        let (chain_id_log, _) = chain_id_working_set.checkpoint().freeze();
+        log.ordered_writes.extend(chain_id_log.ordered_writes);
 
         let (genesis_hash, state_update) = pre_state
             .compute_state_update(log, &witness)
@@ -257,58 +411,14 @@ where
     where
         I: IntoIterator<Item = &'a mut Da::BlobTransaction>,
     {
-        let checkpoint = StateCheckpoint::with_witness(pre_state.clone(), witness);
-        let checkpoint =
-            self.begin_slot(checkpoint, slot_header, validity_condition, pre_state_root);
-
-        // Initialize batch workspace
-        let mut batch_workspace = checkpoint.to_revertable();
-        let mut kernel_working_set =
-            KernelWorkingSet::from_kernel(&self.kernel, &mut batch_workspace);
-        let selected_blobs = self
-            .kernel
-            .get_blobs_for_this_slot(blobs, &mut kernel_working_set)
-            .expect("blob selection must succeed, probably serialization failed");
-
-        info!(
-            "Selected {} blob(s) for execution in current slot",
-            selected_blobs.len()
-        );
-
-        let mut checkpoint = batch_workspace.checkpoint();
-
-        let mut batch_receipts = vec![];
-
-        for (blob_idx, mut blob) in selected_blobs.into_iter().enumerate() {
-            let (apply_blob_result, checkpoint_after_blob) =
-                self.apply_blob(checkpoint, blob.as_mut_ref());
-            checkpoint = checkpoint_after_blob;
-            let batch_receipt = apply_blob_result.unwrap_or_else(Into::into);
-            info!(
-                "blob #{} from sequencer {} with blob_hash 0x{} has been applied with #{} transactions, sequencer outcome {:?}",
-                blob_idx,
-                blob.as_mut_ref().sender(),
-                hex::encode(batch_receipt.batch_hash),
-                batch_receipt.tx_receipts.len(),
-                batch_receipt.inner
-            );
-            for (i, tx_receipt) in batch_receipt.tx_receipts.iter().enumerate() {
-                info!(
-                    "tx #{} hash: 0x{} result {:?}",
-                    i,
-                    hex::encode(tx_receipt.tx_hash),
-                    tx_receipt.receipt
-                );
-            }
-            batch_receipts.push(batch_receipt);
-        }
-
-        let (state_root, witness, storage) = self.end_slot(pre_state, checkpoint);
-        SlotResult {
-            state_root,
-            change_set: storage,
-            batch_receipts,
+        self.execute_slot(
+            pre_state_root,
+            pre_state,
             witness,
-        }
+            slot_header,
+            validity_condition,
+            blobs,
+            true,
+        )
     }
 }
@@ -9,8 +9,11 @@ use sov_modules_core::WorkingSet;
 use sov_rollup_interface::stf::{BatchReceipt, TransactionReceipt};
 use tracing::{debug, error};
 
+use crate::chain_id::get_chain_id;
 use crate::tx_verifier::{verify_txs_stateless, TransactionAndRawHash};
-use crate::{Batch, Runtime, RuntimeTxHook, SequencerOutcome, SlashingReason, TxEffect};
+use crate::{
+    Batch, DecodeFailurePolicy, Runtime, RuntimeTxHook, SequencerOutcome, SlashingReason, TxEffect,
+};
 
 type ApplyBatchResult<T, A> = Result<T, ApplyBatchError<A>>;
 
@@ -30,17 +33,23 @@ pub struct StfBlueprint<C: Context, Da: DaSpec, Vm, RT: Runtime<C, Da>, K: Kerne
     /// The runtime includes all the modules that the rollup supports.
     pub(crate) runtime: RT,
     pub(crate) kernel: K,
+    decode_failure_policy: DecodeFailurePolicy,
+    blob_categories: bool,
     phantom_context: PhantomData<C>,
     phantom_vm: PhantomData<Vm>,
     phantom_da: PhantomData<Da>,
 }
 
 pub(crate) enum ApplyBatchError<A: BasicAddress> {
-    // Contains batch hash
-    Ignored([u8; 32]),
+    Ignored {
+        // Contains batch hash
+        hash: [u8; 32],
+        category: Option<u8>,
+    },
     Slashed {
         // Contains batch hash
         hash: [u8; 32],
+        category: Option<u8>,
         reason: SlashingReason,
         sequencer_da_address: A,
     },
@@ -49,13 +58,15 @@ pub(crate) enum ApplyBatchError<A: BasicAddress> {
 impl<A: BasicAddress> From<ApplyBatchError<A>> for BatchReceipt<SequencerOutcome<A>, TxEffect> {
     fn from(value: ApplyBatchError<A>) -> Self {
         match value {
-            ApplyBatchError::Ignored(hash) => BatchReceipt {
+            ApplyBatchError::Ignored { hash, category } => BatchReceipt {
                 batch_hash: hash,
                 tx_receipts: Vec::new(),
                 inner: SequencerOutcome::Ignored,
+                category,
             },
             ApplyBatchError::Slashed {
                 hash,
+                category,
                 reason,
                 sequencer_da_address,
             } => BatchReceipt {
@@ -65,6 +76,7 @@ impl<A: BasicAddress> From<ApplyBatchError<A>> for BatchReceipt<SequencerOutcome
                     reason,
                     sequencer_da_address,
                 },
+                category,
             },
         }
     }
@@ -94,23 +106,57 @@ where
         Self {
             runtime: RT::default(),
             kernel: K::default(),
+            decode_failure_policy: DecodeFailurePolicy::default(),
+            blob_categories: false,
             phantom_context: PhantomData,
             phantom_vm: PhantomData,
             phantom_da: PhantomData,
         }
     }
 
+    /// Sets how transactions whose runtime message cannot be decoded are handled.
+    /// Defaults to [`DecodeFailurePolicy::FailBatch`].
+    pub fn with_decode_failure_policy(mut self, policy: DecodeFailurePolicy) -> Self {
+        self.decode_failure_policy = policy;
+        self
+    }
+
+    /// Expects every blob to start with a rollup-defined category byte, followed by the batch.
+    /// The category is recorded in the batch receipt, so the ledger can index batches by it.
+    pub fn with_blob_categories(mut self) -> Self {
+        self.blob_categories = true;
+        self
+    }
+
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
     pub(crate) fn apply_blob(
         &self,
         checkpoint: StateCheckpoint<C>,
         blob: &mut Da::BlobTransaction,
+        slot_height: u64,
     ) -> (ApplyBatch<Da>, StateCheckpoint<C>) {
         debug!(
             "Applying batch from sequencer: 0x{}",
             hex::encode(blob.sender())
         );
 
+        let category = self.blob_category(blob);
+
+        if !blob.verify_hash() {
+            error!(
+                "Error: The hash 0x{} of the batch doesn't match its contents. Skipping batch without slashing the sequencer",
+                hex::encode(blob.hash())
+            );
+
+            return (
+                Err(ApplyBatchError::Ignored {
+                    hash: blob.hash(),
+                    category,
+                }),
+                checkpoint,
+            );
+        }
+
         let mut batch_workspace = checkpoint.to_revertable();
 
         // ApplyBlobHook: begin
@@ -121,7 +167,10 @@ where
             );
 
             return (
-                Err(ApplyBatchError::Ignored(blob.hash())),
+                Err(ApplyBatchError::Ignored {
+                    hash: blob.hash(),
+                    category,
+                }),
                 batch_workspace.revert(),
             );
         }
@@ -159,6 +208,7 @@ where
                 return (
                     Err(ApplyBatchError::Slashed {
                         hash: blob.hash(),
+                        category,
                         reason,
                         sequencer_da_address,
                     }),
@@ -187,6 +237,7 @@ where
             &mut tx_receipts,
             batch_workspace,
             &mut sequencer_reward,
+            slot_height,
         );
 
         // TODO: calculate the amount based of gas and fees
@@ -205,6 +256,7 @@ where
                 batch_hash: blob.hash(),
                 tx_receipts,
                 inner: sequencer_outcome,
+                category,
             }),
             batch_workspace.checkpoint(),
         )
@@ -218,7 +270,7 @@ where
     ) -> Result<
         (
             Vec<TransactionAndRawHash<C>>,
-            Vec<<RT as DispatchCall>::Decodable>,
+            Vec<Option<<RT as DispatchCall>::Decodable>>,
         ),
         SlashingReason,
     > {
@@ -234,19 +286,77 @@ where
     }
 
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
+    #[allow(clippy::too_many_arguments)]
     fn apply_txs(
         &self,
         txs: Vec<TransactionAndRawHash<C>>,
-        messages: Vec<<RT as DispatchCall>::Decodable>,
+        messages: Vec<Option<<RT as DispatchCall>::Decodable>>,
         gas_elastic_price: &[u64],
         tx_receipts: &mut Vec<TransactionReceipt<TxEffect>>,
         mut batch_workspace: WorkingSet<C>,
         sequencer_reward: &mut u64,
+        slot_height: u64,
     ) -> WorkingSet<C> {
+        let chain_id = get_chain_id(&mut batch_workspace);
+
         // Dispatching transactions
         for (TransactionAndRawHash { tx, raw_tx_hash }, msg) in
             txs.into_iter().zip(messages.into_iter())
         {
+            let msg = match msg {
+                Some(msg) => msg,
+                None => {
+                    // The message failed to decode under `DecodeFailurePolicy::SkipTransaction`:
+                    // the transaction is skipped without touching the state, and no gas is charged.
+                    tx_receipts.push(TransactionReceipt {
+                        tx_hash: raw_tx_hash,
+                        body_to_save: None,
+                        events: Vec::new(),
+                        receipt: TxEffect::DecodeFailed,
+                        gas_used: Vec::new(),
+                    });
+                    continue;
+                }
+            };
+
+            if tx.is_expired(slot_height) {
+                // Like an undecodable message, an expired transaction is skipped without touching
+                // the state, so its nonce can be reused.
+                debug!(
+                    "Tx {} expired at slot {}, current slot is {}",
+                    hex::encode(raw_tx_hash),
+                    tx.valid_until_slot().unwrap_or_default(),
+                    slot_height
+                );
+                tx_receipts.push(TransactionReceipt {
+                    tx_hash: raw_tx_hash,
+                    body_to_save: None,
+                    events: Vec::new(),
+                    receipt: TxEffect::Expired,
+                    gas_used: Vec::new(),
+                });
+                continue;
+            }
+
+            if chain_id != Some(tx.chain_id()) {
+                // A transaction signed for another rollup is skipped like an expired one, so that
+                // it can't be replayed here.
+                debug!(
+                    "Tx {} has chain id {}, expected {:?}",
+                    hex::encode(raw_tx_hash),
+                    tx.chain_id(),
+                    chain_id
+                );
+                tx_receipts.push(TransactionReceipt {
+                    tx_hash: raw_tx_hash,
+                    body_to_save: None,
+                    events: Vec::new(),
+                    receipt: TxEffect::WrongChainId,
+                    gas_used: Vec::new(),
+                });
+                continue;
+            }
+
             // Update the working set gas meter with the available funds
             let gas_price = C::GasUnit::from_arbitrary_dimensions(gas_elastic_price);
             let gas_limit = tx.gas_limit();
@@ -285,7 +395,9 @@ where
             // Commit changes after pre_dispatch_tx_hook
             batch_workspace = batch_workspace.checkpoint().to_revertable();
 
-            let tx_result = self.runtime.dispatch_call(msg, &mut batch_workspace, &ctx);
+            let (module_name, tx_result) =
+                self.runtime
+                    .dispatch_call_with_module_name(msg, &mut batch_workspace, &ctx);
 
             let remaining_gas = batch_workspace.gas_remaining_funds();
             let gas_reward = gas_limit
@@ -304,8 +416,9 @@ where
                 Ok(_) => TxEffect::Successful,
                 Err(e) => {
                     error!(
-                        "Tx 0x{} was reverted error: {}",
+                        "Tx 0x{} to module {} was reverted error: {}",
                         hex::encode(raw_tx_hash),
+                        module_name,
                         e
                     );
                     // The transaction causing invalid state transition is reverted
@@ -344,7 +457,14 @@ where
         &self,
         blob_data: &mut impl BlobReaderTrait,
     ) -> Result<Batch, SlashingReason> {
-        match Batch::try_from_slice(data_for_deserialization(blob_data)) {
+        let data = data_for_deserialization(blob_data);
+        // Skip the category byte. A blob without one fails to deserialize as a batch.
+        let data = if self.blob_categories {
+            data.get(1..).unwrap_or_default()
+        } else {
+            data
+        };
+        match Batch::try_from_slice(data) {
             Ok(batch) => Ok(batch),
             Err(e) => {
                 assert_eq!(blob_data.verified_data().len(), blob_data.total_len(), "Batch deserialization failed and some data was not provided. The prover might be malicious");
@@ -359,6 +479,14 @@ where
         }
     }
 
+    // Reads the category byte of the blob, if the rollup tags its blobs with one.
+    fn blob_category(&self, blob_data: &mut impl BlobReaderTrait) -> Option<u8> {
+        if !self.blob_categories {
+            return None;
+        }
+        data_for_deserialization(blob_data).first().copied()
+    }
+
     // Stateless verification of transaction, such as signature check
     // Single malformed transaction results in sequencer slashing.
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
@@ -376,19 +504,25 @@ where
     }
 
     // Checks that runtime message can be decoded from transaction.
-    // If a single message cannot be decoded, sequencer is slashed
+    // If a single message cannot be decoded, the sequencer is slashed or the message is skipped,
+    // depending on the decode failure policy.
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
     fn decode_txs(
         &self,
         txs: &[TransactionAndRawHash<C>],
-    ) -> Result<Vec<<RT as DispatchCall>::Decodable>, SlashingReason> {
+    ) -> Result<Vec<Option<<RT as DispatchCall>::Decodable>>, SlashingReason> {
         let mut decoded_messages = Vec::with_capacity(txs.len());
         for TransactionAndRawHash { tx, raw_tx_hash } in txs {
             match RT::decode_call(tx.runtime_msg()) {
-                Ok(msg) => decoded_messages.push(msg),
+                Ok(msg) => decoded_messages.push(Some(msg)),
                 Err(e) => {
                     error!("Tx 0x{} decoding error: {}", hex::encode(raw_tx_hash), e);
-                    return Err(SlashingReason::InvalidTransactionEncoding);
+                    match self.decode_failure_policy {
+                        DecodeFailurePolicy::FailBatch => {
+                            return Err(SlashingReason::InvalidTransactionEncoding)
+                        }
+                        DecodeFailurePolicy::SkipTransaction => decoded_messages.push(None),
+                    }
                 }
             }
         }
@@ -0,0 +1,54 @@
+use super::{StateCodec, StateKeyCodec, StateKeyDecoder};
+use crate::codec::StateValueCodec;
+
+/// A [`StateCodec`] that uses [`bincode`] for all keys and values.
+#[derive(Debug, Default, PartialEq, Eq, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BincodeCodec;
+
+impl<K> StateKeyCodec<K> for BincodeCodec
+where
+    K: serde::Serialize,
+{
+    fn encode_key(&self, key: &K) -> Vec<u8> {
+        bincode::serialize(key).expect("Failed to serialize key")
+    }
+}
+
+impl<K> StateKeyDecoder<K> for BincodeCodec
+where
+    K: serde::Serialize + for<'a> serde::Deserialize<'a>,
+{
+    type Error = bincode::Error;
+
+    fn try_decode_key(&self, bytes: &[u8]) -> Result<K, Self::Error> {
+        bincode::deserialize(bytes)
+    }
+}
+
+impl<V> StateValueCodec<V> for BincodeCodec
+where
+    V: serde::Serialize + for<'a> serde::Deserialize<'a>,
+{
+    type Error = bincode::Error;
+
+    fn encode_value(&self, value: &V) -> Vec<u8> {
+        bincode::serialize(value).expect("Failed to serialize value")
+    }
+
+    fn try_decode_value(&self, bytes: &[u8]) -> Result<V, Self::Error> {
+        bincode::deserialize(bytes)
+    }
+}
+
+impl StateCodec for BincodeCodec {
+    type KeyCodec = Self;
+    type ValueCodec = Self;
+
+    fn key_codec(&self) -> &Self::KeyCodec {
+        self
+    }
+
+    fn value_codec(&self) -> &Self::ValueCodec {
+        self
+    }
+}
@@ -1,15 +1,18 @@
 //! Serialization and deserialization -related logic.
 
-use sov_modules_core::{StateCodec, StateKeyCodec, StateValueCodec};
+use sov_modules_core::{StateCodec, StateKeyCodec, StateKeyDecoder, StateValueCodec};
 
 mod bcs_codec;
+mod bincode_codec;
 mod borsh_codec;
 mod json_codec;
 mod split_codec;
 
 pub use bcs_codec::BcsCodec;
+pub use bincode_codec::BincodeCodec;
 pub use borsh_codec::BorshCodec;
 pub use json_codec::JsonCodec;
+pub use split_codec::SplitCodec;
 
 #[cfg(test)]
 mod tests {
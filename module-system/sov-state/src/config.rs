@@ -8,4 +8,9 @@ use std::path::PathBuf;
 pub struct Config {
     /// Path to folder where storage files will be stored.
     pub path: PathBuf,
+    /// The maximum number of values kept in the in-memory read cache of
+    /// [`ProverStorage`](crate::ProverStorage), which is shared by the storages of consecutive
+    /// blocks. The cache is disabled if unset.
+    #[serde(default)]
+    pub read_cache_size: Option<usize>,
 }
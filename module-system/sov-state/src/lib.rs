@@ -6,12 +6,16 @@ pub mod codec;
 
 #[cfg(feature = "native")]
 mod prover_storage;
+#[cfg(feature = "native")]
+mod read_cache;
 
 mod witness;
 mod zk_storage;
 
 #[cfg(feature = "native")]
 pub use prover_storage::ProverStorage;
+#[cfg(feature = "native")]
+pub use read_cache::{ReadCacheStats, SharedReadCache};
 pub use zk_storage::ZkStorage;
 
 pub mod config;
@@ -7,11 +7,13 @@ use sov_db::native_db::NativeDB;
 use sov_db::schema::{QueryManager, ReadOnlyDbSnapshot};
 use sov_db::state_db::StateDB;
 use sov_modules_core::{
-    CacheKey, NativeStorage, OrderedReadsAndWrites, Storage, StorageKey, StorageProof,
+    CacheKey, NativeStorage, OrderedReadsAndWrites, Prefix, Storage, StorageKey, StorageProof,
     StorageValue, Witness,
 };
+use sov_rollup_interface::StateRoot;
 
 use crate::config::Config;
+use crate::read_cache::{CachedState, ReadCacheStats, SharedReadCache};
 use crate::MerkleProofSpec;
 
 /// A [`Storage`] implementation to be used by the prover in a native execution
@@ -19,14 +21,36 @@ use crate::MerkleProofSpec;
 pub struct ProverStorage<S: MerkleProofSpec, Q> {
     db: StateDB<Q>,
     native_db: NativeDB<Q>,
+    read_cache: Option<ReadCacheHandle>,
     _phantom_hasher: PhantomData<S::Hasher>,
 }
 
+/// The shared read cache of a storage, along with the states it reads and commits.
+#[derive(Clone)]
+struct ReadCacheHandle {
+    cache: SharedReadCache,
+    parent_state_id: u64,
+    state_id: u64,
+    initial_version: Version,
+}
+
+impl ReadCacheHandle {
+    /// The state a storage reads at `version`: its parent's until it commits, then its own.
+    fn state_at(&self, version: Version) -> CachedState {
+        if version == self.initial_version {
+            (self.parent_state_id, version)
+        } else {
+            (self.state_id, version)
+        }
+    }
+}
+
 impl<S: MerkleProofSpec, Q> Clone for ProverStorage<S, Q> {
     fn clone(&self) -> Self {
         Self {
             db: self.db.clone(),
             native_db: self.native_db.clone(),
+            read_cache: self.read_cache.clone(),
             _phantom_hasher: Default::default(),
         }
     }
@@ -38,10 +62,42 @@ impl<S: MerkleProofSpec, Q> ProverStorage<S, Q> {
         Self {
             db,
             native_db,
+            read_cache: None,
             _phantom_hasher: Default::default(),
         }
     }
 
+    /// Puts `cache` in front of the reads of the latest state values. Values which aren't
+    /// written on commit stay cached, so the storage of the next block can be given the same
+    /// cache.
+    ///
+    /// `parent_state_id` identifies the state this storage is created on, and `state_id` the
+    /// one it commits. A storage created on the state committed by another one must be given
+    /// that `state_id` as its `parent_state_id`, while different states must have different
+    /// ids. Reads at an explicit version bypass the cache.
+    ///
+    /// Reads are still added to the witness on cache hits, so the cache affects neither
+    /// the witness nor the state root.
+    pub fn with_shared_read_cache(
+        mut self,
+        cache: SharedReadCache,
+        parent_state_id: u64,
+        state_id: u64,
+    ) -> Self {
+        self.read_cache = Some(ReadCacheHandle {
+            cache,
+            parent_state_id,
+            state_id,
+            initial_version: self.db.get_next_version(),
+        });
+        self
+    }
+
+    /// Returns the hit and miss counters of the read cache, if it is enabled.
+    pub fn read_cache_stats(&self) -> Option<ReadCacheStats> {
+        self.read_cache.as_ref().map(|handle| handle.cache.stats())
+    }
+
     /// Converts it to pair of readonly [`ReadOnlyDbSnapshot`]s
     /// First is from [`StateDB`]
     /// Second is from [`NativeDB`]
@@ -54,12 +110,43 @@ impl<S: MerkleProofSpec, Q> ProverStorage<S, Q> {
 }
 
 impl<S: MerkleProofSpec, Q: QueryManager> ProverStorage<S, Q> {
+    /// Returns all the key-value pairs of the latest state, ordered by key.
+    ///
+    /// The entries can be imported into a fresh storage with the same state root, see
+    /// `sov_prover_storage_manager::import_snapshot`. This is meant for debugging and for
+    /// testing upgrades against a copy of the state. The accessory state is not exported.
+    pub fn export_snapshot(&self) -> anyhow::Result<Vec<(StorageKey, StorageValue)>> {
+        let version = self.db.get_next_version();
+        let mut entries = Vec::new();
+        for key in self.db.get_keys_with_prefix(&[])? {
+            // Deleted keys keep their preimage, so only export the ones which are still set.
+            if let Some(value) = self.db.get_value_option_by_key(version, &key)? {
+                entries.push((StorageKey::from(key), StorageValue::from(value)));
+            }
+        }
+        Ok(entries)
+    }
+
     fn read_value(&self, key: &StorageKey, version: Option<Version>) -> Option<StorageValue> {
-        let version_to_use = version.unwrap_or_else(|| self.db.get_next_version());
-        match self
-            .db
-            .get_value_option_by_key(version_to_use, key.as_ref())
-        {
+        match (version, &self.read_cache) {
+            (None, Some(handle)) => {
+                let version = self.db.get_next_version();
+                handle
+                    .cache
+                    .lock()
+                    .get_or_read(handle.state_at(version), key, || {
+                        self.read_value_from_db(key, version)
+                    })
+            }
+            _ => {
+                let version_to_use = version.unwrap_or_else(|| self.db.get_next_version());
+                self.read_value_from_db(key, version_to_use)
+            }
+        }
+    }
+
+    fn read_value_from_db(&self, key: &StorageKey, version: Version) -> Option<StorageValue> {
+        match self.db.get_value_option_by_key(version, key.as_ref()) {
             Ok(value) => value.map(Into::into),
             // It is ok to panic here, we assume the db is available and consistent.
             Err(e) => panic!("Unable to read value from db: {e}"),
@@ -76,7 +163,7 @@ impl<S: MerkleProofSpec, Q: QueryManager> Storage for ProverStorage<S, Q> {
     type Witness = S::Witness;
     type RuntimeConfig = Config;
     type Proof = jmt::proof::SparseMerkleProof<S::Hasher>;
-    type Root = jmt::RootHash;
+    type Root = StateRoot;
     type StateUpdate = ProverStateUpdate;
 
     fn get(
@@ -165,7 +252,7 @@ impl<S: MerkleProofSpec, Q: QueryManager> Storage for ProverStorage<S, Q> {
             key_preimages,
         };
 
-        Ok((new_root, state_update))
+        Ok((StateRoot(new_root.0), state_update))
     }
 
     fn commit(&self, state_update: &Self::StateUpdate, accessory_writes: &OrderedReadsAndWrites) {
@@ -197,7 +284,21 @@ impl<S: MerkleProofSpec, Q: QueryManager> Storage for ProverStorage<S, Q> {
             .expect("db write must succeed");
 
         // Finally, update our in-memory view of the current item numbers
+        let committed_version = self.db.get_next_version();
         self.db.inc_next_version();
+
+        if let Some(handle) = &self.read_cache {
+            let written_keys: Vec<StorageKey> = state_update
+                .key_preimages
+                .iter()
+                .map(|(_, key)| key.clone().into())
+                .collect();
+            handle.cache.lock().advance(
+                handle.state_at(committed_version),
+                (handle.state_id, committed_version + 1),
+                &written_keys,
+            );
+        }
     }
 
     fn open_proof(
@@ -207,7 +308,11 @@ impl<S: MerkleProofSpec, Q: QueryManager> Storage for ProverStorage<S, Q> {
         let StorageProof { key, value, proof } = state_proof;
         let key_hash = KeyHash::with::<S::Hasher>(key.as_ref());
 
-        proof.verify(state_root, key_hash, value.as_ref().map(|v| v.value()))?;
+        proof.verify(
+            jmt::RootHash(state_root.0),
+            key_hash,
+            value.as_ref().map(|v| v.value()),
+        )?;
         Ok((key, value))
     }
 
@@ -233,9 +338,29 @@ impl<S: MerkleProofSpec, Q: QueryManager> NativeStorage for ProverStorage<S, Q>
         }
     }
 
-    fn get_root_hash(&self, version: Version) -> anyhow::Result<jmt::RootHash> {
+    fn get_root_hash(&self, version: Version) -> anyhow::Result<StateRoot> {
         let temp_merkle: JellyfishMerkleTree<'_, StateDB<Q>, S::Hasher> =
             JellyfishMerkleTree::new(&self.db);
-        temp_merkle.get_root_hash(version)
+        let root = temp_merkle.get_root_hash(version)?;
+        Ok(StateRoot(root.0))
+    }
+
+    fn latest_version(&self) -> Version {
+        self.db.get_next_version() - 1
+    }
+
+    fn get_keys_with_prefix(&self, prefix: &Prefix) -> anyhow::Result<Vec<StorageKey>> {
+        let version = self.db.get_next_version();
+        let mut keys = Vec::new();
+        for key in self
+            .db
+            .get_keys_with_prefix(prefix.as_aligned_vec().as_ref())?
+        {
+            // Deleted keys keep their preimage, so only keep the ones which are still set.
+            if self.db.get_value_option_by_key(version, &key)?.is_some() {
+                keys.push(StorageKey::from(key));
+            }
+        }
+        Ok(keys)
     }
 }
@@ -0,0 +1,206 @@
+//! A bounded cache for values read from the [`StateDB`](sov_db::state_db::StateDB).
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, Mutex};
+
+use jmt::Version;
+use sov_modules_core::{StorageKey, StorageValue};
+
+/// Hit and miss counters of a [`ProverStorage`](crate::ProverStorage) read cache.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReadCacheStats {
+    /// The number of reads served from the cache.
+    pub hits: u64,
+    /// The number of reads that had to go to the backing database.
+    pub misses: u64,
+}
+
+/// Identifies the state whose values a [`ReadCache`] holds: the id of the state that has been
+/// committed, along with the version following it.
+pub(crate) type CachedState = (u64, Version);
+
+/// A least-recently-used cache of the values of a single state.
+///
+/// Every access bumps the entry to a fresh "tick", and the entry with the smallest tick
+/// is evicted once the cache is full.
+pub(crate) struct ReadCache {
+    capacity: usize,
+    state: Option<CachedState>,
+    entries: HashMap<StorageKey, (Option<StorageValue>, u64)>,
+    recency: BTreeMap<u64, StorageKey>,
+    tick: u64,
+    stats: ReadCacheStats,
+}
+
+impl ReadCache {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            state: None,
+            entries: HashMap::with_capacity(capacity),
+            recency: BTreeMap::new(),
+            tick: 0,
+            stats: ReadCacheStats::default(),
+        }
+    }
+
+    /// Returns the value of `key` in `state`, served from the cache or read with `read_from_db`
+    /// and cached. The cached values of any other state are dropped first.
+    pub(crate) fn get_or_read(
+        &mut self,
+        state: CachedState,
+        key: &StorageKey,
+        read_from_db: impl FnOnce() -> Option<StorageValue>,
+    ) -> Option<StorageValue> {
+        if self.state != Some(state) {
+            self.clear();
+            self.state = Some(state);
+        }
+        self.tick += 1;
+        let tick = self.tick;
+
+        if let Some((value, last_used)) = self.entries.get_mut(key) {
+            self.recency.remove(last_used);
+            self.recency.insert(tick, key.clone());
+            *last_used = tick;
+            self.stats.hits += 1;
+            return value.clone();
+        }
+
+        self.stats.misses += 1;
+        let value = read_from_db();
+        if self.capacity == 0 {
+            return value;
+        }
+        if self.entries.len() >= self.capacity {
+            if let Some((_, evicted)) = self.recency.pop_first() {
+                self.entries.remove(&evicted);
+            }
+        }
+        self.recency.insert(tick, key.clone());
+        self.entries.insert(key.clone(), (value.clone(), tick));
+        value
+    }
+
+    /// Moves the cache to the state `to`, which was committed by writing `written_keys` on top
+    /// of `from`. The values which weren't written stay cached. If the cache doesn't hold
+    /// `from`, all the values are dropped.
+    pub(crate) fn advance<'a>(
+        &mut self,
+        from: CachedState,
+        to: CachedState,
+        written_keys: impl IntoIterator<Item = &'a StorageKey>,
+    ) {
+        if self.state == Some(from) {
+            for key in written_keys {
+                if let Some((_, last_used)) = self.entries.remove(key) {
+                    self.recency.remove(&last_used);
+                }
+            }
+        } else {
+            self.clear();
+        }
+        self.state = Some(to);
+    }
+
+    /// Drops all the cached values, keeping the counters.
+    pub(crate) fn clear(&mut self) {
+        self.state = None;
+        self.entries.clear();
+        self.recency.clear();
+    }
+
+    /// Drops all the cached values if they belong to a state committed with `state_id`.
+    pub(crate) fn discard_state(&mut self, state_id: u64) {
+        if self.state.is_some_and(|(id, _)| id == state_id) {
+            self.clear();
+        }
+    }
+
+    pub(crate) fn stats(&self) -> ReadCacheStats {
+        self.stats
+    }
+}
+
+/// A [`ReadCache`] which can be shared by the [`ProverStorage`](crate::ProverStorage) instances
+/// of consecutive blocks, so values which a block doesn't write are still cached for the next
+/// one.
+///
+/// The cache holds the values of a single state. Reading another state, e.g. after a reorg,
+/// drops them, so sharing the cache never changes the values which are read.
+#[derive(Clone)]
+pub struct SharedReadCache(Arc<Mutex<ReadCache>>);
+
+impl SharedReadCache {
+    /// Creates a cache holding up to `capacity` values.
+    pub fn new(capacity: usize) -> Self {
+        Self(Arc::new(Mutex::new(ReadCache::new(capacity))))
+    }
+
+    /// Returns the hit and miss counters of the cache.
+    pub fn stats(&self) -> ReadCacheStats {
+        self.lock().stats()
+    }
+
+    /// Drops the cached values if they belong to the state committed with `state_id`, e.g.
+    /// because that state has been discarded.
+    pub fn discard_state(&self, state_id: u64) {
+        self.lock().discard_state(state_id);
+    }
+
+    pub(crate) fn lock(&self) -> std::sync::MutexGuard<'_, ReadCache> {
+        self.0.lock().unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_least_recently_used_entry_is_evicted() {
+        let mut cache = ReadCache::new(2);
+        let value = |v: &str| Some(StorageValue::from(v));
+        let state = (1, 1);
+
+        cache.get_or_read(state, &"a".into(), || value("a"));
+        cache.get_or_read(state, &"b".into(), || value("b"));
+        // Touch `a`, so `b` is the least recently used entry.
+        cache.get_or_read(state, &"a".into(), || unreachable!());
+        cache.get_or_read(state, &"c".into(), || value("c"));
+
+        assert_eq!(
+            cache.get_or_read(state, &"a".into(), || unreachable!()),
+            value("a")
+        );
+        assert_eq!(
+            cache.get_or_read(state, &"b".into(), || value("new b")),
+            value("new b")
+        );
+        assert_eq!(cache.stats(), ReadCacheStats { hits: 2, misses: 4 });
+    }
+
+    #[test]
+    fn test_only_written_keys_are_dropped_on_advance() {
+        let mut cache = ReadCache::new(4);
+        let value = |v: &str| Some(StorageValue::from(v));
+
+        cache.get_or_read((1, 1), &"a".into(), || value("a"));
+        cache.get_or_read((1, 1), &"b".into(), || value("b"));
+        cache.advance((1, 1), (2, 2), [&StorageKey::from("b")]);
+
+        assert_eq!(
+            cache.get_or_read((2, 2), &"a".into(), || unreachable!()),
+            value("a")
+        );
+        assert_eq!(
+            cache.get_or_read((2, 2), &"b".into(), || value("new b")),
+            value("new b")
+        );
+        // Reading the state of a sibling drops the values of the other branch.
+        assert_eq!(
+            cache.get_or_read((3, 2), &"b".into(), || value("sibling b")),
+            value("sibling b")
+        );
+    }
+}
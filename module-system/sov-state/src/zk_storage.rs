@@ -5,6 +5,7 @@ use jmt::KeyHash;
 use sov_modules_core::{
     OrderedReadsAndWrites, Storage, StorageKey, StorageProof, StorageValue, Witness,
 };
+use sov_rollup_interface::StateRoot;
 #[cfg(all(target_os = "zkvm", feature = "bench"))]
 use sov_zk_cycle_macros::cycle_tracker;
 
@@ -97,7 +98,7 @@ impl<S: MerkleProofSpec> Storage for ZkStorage<S> {
     type Witness = S::Witness;
     type RuntimeConfig = ();
     type Proof = jmt::proof::SparseMerkleProof<S::Hasher>;
-    type Root = jmt::RootHash;
+    type Root = StateRoot;
     type StateUpdate = ();
 
     fn get(
@@ -121,7 +122,7 @@ impl<S: MerkleProofSpec> Storage for ZkStorage<S> {
 
         let new_root = jmt_verify_update::<S>(prev_state_root, state_accesses, witness);
 
-        Ok((jmt::RootHash(new_root), ()))
+        Ok((StateRoot(new_root), ()))
     }
 
     #[cfg_attr(all(target_os = "zkvm", feature = "bench"), cycle_tracker)]
@@ -134,7 +135,11 @@ impl<S: MerkleProofSpec> Storage for ZkStorage<S> {
         let StorageProof { key, value, proof } = state_proof;
         let key_hash = KeyHash::with::<S::Hasher>(key.as_ref());
 
-        proof.verify(state_root, key_hash, value.as_ref().map(|v| v.value()))?;
+        proof.verify(
+            jmt::RootHash(state_root.0),
+            key_hash,
+            value.as_ref().map(|v| v.value()),
+        )?;
         Ok((key, value))
     }
 
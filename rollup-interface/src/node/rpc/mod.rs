@@ -87,6 +87,9 @@ pub enum EventIdentifier {
     /// The monotonically increasing number of the event, ordered by the DA layer For example, if the first tx
     /// contains 7 events, tx 2 contains 11 events, and tx 3 contains 7 txs,
     /// the last event in tx 3 would have number 25. The counter never resets.
+    ///
+    /// Within a slot, events are numbered by batch, then by transaction within the batch, then
+    /// by emission order within the transaction.
     Number(u64),
 }
 
@@ -273,7 +276,8 @@ pub trait LedgerRpcProvider {
 
     /// Get a range of slots. This query is the most efficient way to
     /// fetch large numbers of slots, since it allows for easy batching of
-    /// db queries for adjacent items.
+    /// db queries for adjacent items. The range is inclusive, and the results are
+    /// returned in order, stopping at the latest slot known to the node.
     fn get_slots_range<B: DeserializeOwned, T: DeserializeOwned>(
         &self,
         start: u64,
@@ -283,7 +287,8 @@ pub trait LedgerRpcProvider {
 
     /// Get a range of batches. This query is the most efficient way to
     /// fetch large numbers of batches, since it allows for easy batching of
-    /// db queries for adjacent items.
+    /// db queries for adjacent items. The range is inclusive, and the results are
+    /// returned in order, stopping at the latest batch known to the node.
     fn get_batches_range<B: DeserializeOwned, T: DeserializeOwned>(
         &self,
         start: u64,
@@ -291,6 +296,16 @@ pub trait LedgerRpcProvider {
         query_mode: QueryMode,
     ) -> Result<Vec<Option<BatchResponse<B, T>>>, anyhow::Error>;
 
+    /// Get the batches numbered `start` to `end` which were read from blobs of the given
+    /// rollup-defined category. The range is inclusive, and the results are returned in order.
+    fn get_batches_by_category<B: DeserializeOwned, T: DeserializeOwned>(
+        &self,
+        category: u8,
+        start: u64,
+        end: u64,
+        query_mode: QueryMode,
+    ) -> Result<Vec<Option<BatchResponse<B, T>>>, anyhow::Error>;
+
     /// Get a range of batches. This query is the most efficient way to
     /// fetch large numbers of transactions, since it allows for easy batching of
     /// db queries for adjacent items.
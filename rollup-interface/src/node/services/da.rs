@@ -1,5 +1,10 @@
 //! The da module defines traits used by the full node to interact with the DA layer.
 
+#[cfg(feature = "native")]
+use core::future::Future;
+#[cfg(feature = "native")]
+use core::time::Duration;
+
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
@@ -36,7 +41,7 @@ pub trait DaService: Send + Sync + 'static {
     >;
 
     /// A transaction ID, used to identify the transaction in the DA layer.
-    type TransactionId: PartialEq + Eq + PartialOrd + Ord + core::hash::Hash;
+    type TransactionId: PartialEq + Eq + PartialOrd + Ord + core::hash::Hash + Send + Sync;
 
     /// The error type for fallible methods.
     type Error: core::fmt::Debug + Send + Sync + core::fmt::Display;
@@ -110,6 +115,10 @@ pub trait DaService: Send + Sync + 'static {
     /// Returns nothing if the transaction was successfully sent.
     async fn send_transaction(&self, blob: &[u8]) -> Result<Self::TransactionId, Self::Error>;
 
+    /// Waits until the transaction with the given ID is included in a finalized block,
+    /// and returns the height of that block.
+    async fn wait_for_finalization(&self, tx_id: &Self::TransactionId) -> Result<u64, Self::Error>;
+
     /// Sends am aggregated ZK proofs to the DA layer.
     async fn send_aggregated_zk_proof(
         &self,
@@ -120,6 +129,225 @@ pub trait DaService: Send + Sync + 'static {
     async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error>;
 }
 
+/// How a [`RetryingDaService`] retries failed calls to the DA layer.
+#[cfg(feature = "native")]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryConfig {
+    /// How many times a failed call is retried before its error is returned.
+    pub max_retries: u32,
+    /// The delay before the first retry. It doubles after every retry.
+    pub initial_backoff: Duration,
+    /// The upper bound on the delay between two retries.
+    pub max_backoff: Duration,
+}
+
+#[cfg(feature = "native")]
+impl Default for RetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 5,
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_secs(10),
+        }
+    }
+}
+
+/// The error returned by a [`RetryingDaService`].
+#[cfg(feature = "native")]
+#[derive(Debug)]
+pub enum RetryError<E> {
+    /// The call failed with an error which is not retryable, or the call is never retried.
+    NotRetried(E),
+    /// The call failed with a retryable error on every attempt.
+    RetriesExhausted {
+        /// How many times the call was made, including the first attempt.
+        attempts: u32,
+        /// The error returned by the last attempt.
+        last_error: E,
+    },
+}
+
+#[cfg(feature = "native")]
+impl<E> RetryError<E> {
+    /// Returns the error of the underlying [`DaService`].
+    pub fn into_inner(self) -> E {
+        match self {
+            RetryError::NotRetried(e) => e,
+            RetryError::RetriesExhausted { last_error, .. } => last_error,
+        }
+    }
+}
+
+#[cfg(feature = "native")]
+impl<E: core::fmt::Display> core::fmt::Display for RetryError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            RetryError::NotRetried(e) => write!(f, "{}", e),
+            RetryError::RetriesExhausted {
+                attempts,
+                last_error,
+            } => write!(f, "giving up after {} attempts: {}", attempts, last_error),
+        }
+    }
+}
+
+#[cfg(feature = "native")]
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for RetryError<E> {}
+
+/// A [`DaService`] which retries [`DaService::get_block_at`] and
+/// [`DaService::get_last_finalized_block_header`] of the wrapped service with exponential backoff.
+///
+/// All other calls are forwarded as is, and their errors are returned as [`RetryError::NotRetried`].
+#[cfg(feature = "native")]
+#[derive(Clone)]
+pub struct RetryingDaService<D: DaService> {
+    inner: D,
+    config: RetryConfig,
+    is_retryable: fn(&D::Error) -> bool,
+}
+
+#[cfg(feature = "native")]
+impl<D: DaService> RetryingDaService<D> {
+    /// Wraps `inner`, retrying every error it returns.
+    pub fn new(inner: D, config: RetryConfig) -> Self {
+        Self {
+            inner,
+            config,
+            is_retryable: |_| true,
+        }
+    }
+
+    /// Only retries the errors for which `is_retryable` returns `true`. Any other error, such as
+    /// a height which is not available yet, is returned right away as [`RetryError::NotRetried`].
+    pub fn with_retryable_errors(mut self, is_retryable: fn(&D::Error) -> bool) -> Self {
+        self.is_retryable = is_retryable;
+        self
+    }
+
+    /// Returns the wrapped [`DaService`].
+    pub fn inner(&self) -> &D {
+        &self.inner
+    }
+
+    async fn retry<T, F, Fut>(&self, mut call: F) -> Result<T, RetryError<D::Error>>
+    where
+        F: FnMut() -> Fut + Send,
+        Fut: Future<Output = Result<T, D::Error>> + Send,
+        T: Send,
+    {
+        let mut backoff = self.config.initial_backoff;
+        let mut attempts = 0;
+        loop {
+            attempts += 1;
+            match call().await {
+                Ok(value) => return Ok(value),
+                Err(e) if !(self.is_retryable)(&e) => return Err(RetryError::NotRetried(e)),
+                Err(e) if attempts > self.config.max_retries => {
+                    return Err(RetryError::RetriesExhausted {
+                        attempts,
+                        last_error: e,
+                    })
+                }
+                Err(_) => {
+                    tokio::time::sleep(backoff).await;
+                    backoff = core::cmp::min(backoff * 2, self.config.max_backoff);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "native")]
+#[async_trait::async_trait]
+impl<D: DaService> DaService for RetryingDaService<D> {
+    type Spec = D::Spec;
+    type Verifier = D::Verifier;
+    type FilteredBlock = D::FilteredBlock;
+    type HeaderStream =
+        futures::stream::MapErr<D::HeaderStream, fn(D::Error) -> RetryError<D::Error>>;
+    type TransactionId = D::TransactionId;
+    type Error = RetryError<D::Error>;
+
+    async fn get_block_at(&self, height: u64) -> Result<Self::FilteredBlock, Self::Error> {
+        self.retry(|| self.inner.get_block_at(height)).await
+    }
+
+    async fn get_last_finalized_block_header(
+        &self,
+    ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
+        self.retry(|| self.inner.get_last_finalized_block_header())
+            .await
+    }
+
+    async fn subscribe_finalized_header(&self) -> Result<Self::HeaderStream, Self::Error> {
+        use futures::TryStreamExt;
+        let stream = self
+            .inner
+            .subscribe_finalized_header()
+            .await
+            .map_err(RetryError::NotRetried)?;
+        Ok(stream.map_err(RetryError::NotRetried as fn(_) -> _))
+    }
+
+    async fn get_head_block_header(
+        &self,
+    ) -> Result<<Self::Spec as DaSpec>::BlockHeader, Self::Error> {
+        self.inner
+            .get_head_block_header()
+            .await
+            .map_err(RetryError::NotRetried)
+    }
+
+    fn extract_relevant_blobs(
+        &self,
+        block: &Self::FilteredBlock,
+    ) -> Vec<<Self::Spec as DaSpec>::BlobTransaction> {
+        self.inner.extract_relevant_blobs(block)
+    }
+
+    async fn get_extraction_proof(
+        &self,
+        block: &Self::FilteredBlock,
+        blobs: &[<Self::Spec as DaSpec>::BlobTransaction],
+    ) -> (
+        <Self::Spec as DaSpec>::InclusionMultiProof,
+        <Self::Spec as DaSpec>::CompletenessProof,
+    ) {
+        self.inner.get_extraction_proof(block, blobs).await
+    }
+
+    async fn send_transaction(&self, blob: &[u8]) -> Result<Self::TransactionId, Self::Error> {
+        self.inner
+            .send_transaction(blob)
+            .await
+            .map_err(RetryError::NotRetried)
+    }
+
+    async fn wait_for_finalization(&self, tx_id: &Self::TransactionId) -> Result<u64, Self::Error> {
+        self.inner
+            .wait_for_finalization(tx_id)
+            .await
+            .map_err(RetryError::NotRetried)
+    }
+
+    async fn send_aggregated_zk_proof(
+        &self,
+        aggregated_proof_data: &[u8],
+    ) -> Result<u64, Self::Error> {
+        self.inner
+            .send_aggregated_zk_proof(aggregated_proof_data)
+            .await
+            .map_err(RetryError::NotRetried)
+    }
+
+    async fn get_aggregated_proofs_at(&self, height: u64) -> Result<Vec<Vec<u8>>, Self::Error> {
+        self.inner
+            .get_aggregated_proofs_at(height)
+            .await
+            .map_err(RetryError::NotRetried)
+    }
+}
+
 /// `SlotData` is the subset of a DA layer block which is stored in the rollup's database.
 /// At the very least, the rollup needs access to the hashes and headers of all DA layer blocks,
 /// but rollup may choose to store partial (or full) block data as well.
@@ -143,4 +371,9 @@ pub trait SlotData:
     fn header(&self) -> &Self::BlockHeader;
     /// Get the validity condition set associated with the slot
     fn validity_condition(&self) -> Self::Cond;
+    /// The timestamp of the DA layer block, in seconds since the unix epoch.
+    /// Defaults to the time of the header, see [`crate::da::Time::unix_timestamp`].
+    fn timestamp(&self) -> u64 {
+        self.header().time().unix_timestamp()
+    }
 }
@@ -27,7 +27,8 @@ pub trait DaSpec: 'static + Debug + PartialEq + Eq + Clone {
     type ValidityCondition: ValidityCondition + Send + Sync;
 
     /// A proof that each tx in a set of blob transactions is included in a given block.
-    type InclusionMultiProof: Serialize + DeserializeOwned + Send + Sync;
+    /// The default value is the proof for an empty set of transactions.
+    type InclusionMultiProof: Serialize + DeserializeOwned + Default + Send + Sync;
 
     /// A proof that a claimed set of transactions is complete.
     /// For example, this could be a range proof demonstrating that
@@ -65,6 +66,18 @@ pub trait DaVerifier: Send + Sync {
         inclusion_proof: <Self::Spec as DaSpec>::InclusionMultiProof,
         completeness_proof: <Self::Spec as DaSpec>::CompletenessProof,
     ) -> Result<<Self::Spec as DaSpec>::ValidityCondition, Self::Error>;
+
+    /// Verify that a block contains no transactions relevant to the rollup.
+    ///
+    /// This lets a light client tell a genuinely empty slot apart from a slot whose transactions
+    /// were withheld from it. The guarantee is only as strong as the DA layer's completeness proofs.
+    fn verify_no_relevant_txs(
+        &self,
+        block_header: &<Self::Spec as DaSpec>::BlockHeader,
+        completeness_proof: <Self::Spec as DaSpec>::CompletenessProof,
+    ) -> Result<<Self::Spec as DaSpec>::ValidityCondition, Self::Error> {
+        self.verify_relevant_tx_list(block_header, &[], Default::default(), completeness_proof)
+    }
 }
 
 #[cfg(feature = "std")]
@@ -80,6 +93,13 @@ pub struct CountedBufReader<B: bytes::Buf> {
     /// An accumulator that stores the data read from the blob buffer into a vector.
     /// Allows easy access to the data that has already been read
     accumulator: Vec<u8>,
+
+    /// The number of bytes read from the beginning of the blob buffer.
+    /// This can exceed the length of the accumulator if it's capped.
+    counter: usize,
+
+    /// The maximum length of the accumulator, if any.
+    max_accumulator_len: Option<usize>,
 }
 
 #[cfg(feature = "std")]
@@ -90,11 +110,27 @@ impl<B: bytes::Buf> CountedBufReader<B> {
         CountedBufReader {
             inner,
             accumulator: Vec::with_capacity(buf_size),
+            counter: 0,
+            max_accumulator_len: None,
+        }
+    }
+
+    /// Creates a new buffer reader whose accumulator never grows past `max_accumulator_len` bytes.
+    /// Data read beyond that limit is still counted, but it's not accumulated.
+    pub fn with_max_accumulator_len(inner: B, max_accumulator_len: usize) -> Self {
+        let buf_size = core::cmp::min(inner.remaining(), max_accumulator_len);
+        CountedBufReader {
+            inner,
+            accumulator: Vec::with_capacity(buf_size),
+            counter: 0,
+            max_accumulator_len: Some(max_accumulator_len),
         }
     }
 
     /// Advance the accumulator by `num_bytes` bytes. If `num_bytes` is greater than the length
     /// of remaining unverified data, then all remaining unverified data is added to the accumulator.
+    ///
+    /// Advancing a reader which has been fully read (including an empty one) is a no-op.
     pub fn advance(&mut self, num_bytes: usize) {
         let requested = num_bytes;
         let remaining = self.inner.remaining();
@@ -104,15 +140,25 @@ impl<B: bytes::Buf> CountedBufReader<B> {
         // `Buf::advance` would panic if `num_bytes` was greater than the length of the remaining unverified data,
         // but we just advance to the end of the buffer.
         let num_to_read = core::cmp::min(remaining, requested);
+        self.counter += num_to_read;
+
+        let num_to_accumulate = match self.max_accumulator_len {
+            Some(max_len) => {
+                core::cmp::min(num_to_read, max_len.saturating_sub(self.accumulator.len()))
+            }
+            None => num_to_read,
+        };
         // Extend the inner vector with zeros (copy_to_slice requires the vector to have
         // the correct *length* not just capacity)
         self.accumulator
-            .resize(self.accumulator.len() + num_to_read, 0);
+            .resize(self.accumulator.len() + num_to_accumulate, 0);
 
         // Use copy_to_slice to overwrite the zeros we just added
         let accumulator_len = self.accumulator.len();
         self.inner
-            .copy_to_slice(self.accumulator[accumulator_len - num_to_read..].as_mut());
+            .copy_to_slice(self.accumulator[accumulator_len - num_to_accumulate..].as_mut());
+        // Skip the data which doesn't fit in the accumulator
+        self.inner.advance(num_to_read - num_to_accumulate);
     }
 
     /// Getter: returns a reference to an accumulator of the blob data read by the rollup
@@ -122,7 +168,24 @@ impl<B: bytes::Buf> CountedBufReader<B> {
 
     /// Contains the total length of the data (length already read + length remaining)
     pub fn total_len(&self) -> usize {
-        self.inner.remaining() + self.accumulator.len()
+        self.inner.remaining() + self.counter
+    }
+
+    /// Returns the number of bytes read from the beginning of the buffer.
+    pub fn counter(&self) -> usize {
+        self.counter
+    }
+
+    /// Returns true if all the data has been read.
+    /// An empty buffer is fully read as soon as it's created.
+    pub fn is_fully_read(&self) -> bool {
+        !self.inner.has_remaining()
+    }
+
+    /// Returns true if some of the data read didn't fit in the accumulator, because
+    /// its maximum length has been reached.
+    pub fn accumulator_limit_exceeded(&self) -> bool {
+        self.counter > self.accumulator.len()
     }
 }
 
@@ -145,6 +208,11 @@ pub trait BlobReaderTrait: Serialize + DeserializeOwned + Send + Sync + 'static
     /// Returns the hash of the blob as it appears on the DA layer
     fn hash(&self) -> [u8; 32];
 
+    /// Returns true if [`hash`](BlobReaderTrait::hash) matches the contents of the blob, so that a blob
+    /// claiming somebody else's hash can be detected during execution. The STF ignores blobs failing
+    /// this check.
+    fn verify_hash(&self) -> bool;
+
     /// Returns a slice containing all the data accessible to the rollup at this point in time.
     /// When running in native mode, the rollup can extend this slice by calling `advance`. In zk-mode,
     /// the rollup is limited to only the verified data.
@@ -271,6 +339,11 @@ impl Time {
         self.secs
     }
 
+    /// Returns the number of whole seconds since the epoch, or zero for times before the epoch.
+    pub fn unix_timestamp(&self) -> u64 {
+        u64::try_from(self.secs).unwrap_or_default()
+    }
+
     /// Returns the fractional part of this [`Time`], in nanoseconds.
     ///
     /// This method does not return the length of the time when represented by nanoseconds.
@@ -279,3 +352,29 @@ impl Time {
         self.nanos
     }
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_accumulator_stops_growing_at_max_len() {
+        let data = bytes::Bytes::from((0..10u8).collect::<Vec<_>>());
+        let mut reader = CountedBufReader::with_max_accumulator_len(data, 4);
+
+        reader.advance(3);
+        assert_eq!(reader.accumulator(), &[0, 1, 2]);
+        assert!(!reader.accumulator_limit_exceeded());
+
+        reader.advance(3);
+        assert_eq!(reader.accumulator(), &[0, 1, 2, 3]);
+        assert_eq!(reader.counter(), 6);
+        assert!(reader.accumulator_limit_exceeded());
+
+        reader.advance(100);
+        assert_eq!(reader.accumulator(), &[0, 1, 2, 3]);
+        assert_eq!(reader.counter(), 10);
+        assert_eq!(reader.total_len(), 10);
+        assert!(reader.is_fully_read());
+    }
+}
@@ -11,8 +11,11 @@ use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 pub mod optimistic;
+mod state_root;
 pub mod storage;
 
+pub use state_root::StateRoot;
+
 /// A marker trait for general addresses.
 pub trait BasicAddress:
     Eq
@@ -0,0 +1,131 @@
+//! Defines the [`StateRoot`] type shared by the storage, the state transition function and the
+//! full node.
+
+use core::fmt;
+use core::str::FromStr;
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use hex::FromHex;
+use serde::de::Error as _;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::maybestd::string::String;
+
+/// A 32-byte commitment to the state of the rollup.
+///
+/// The newtype keeps state roots apart from block hashes and other digests with the same
+/// representation. It is displayed and serialized to human-readable formats as a `0x`-prefixed
+/// hex string, and to binary formats as the raw 32 bytes.
+///
+/// A block hash can't be passed where a state root is expected:
+///
+/// ```compile_fail
+/// use sov_rollup_interface::StateRoot;
+///
+/// struct BlockHash([u8; 32]);
+///
+/// fn check_root(_root: StateRoot) {}
+///
+/// check_root(BlockHash([0; 32]));
+/// ```
+#[derive(
+    Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, BorshSerialize, BorshDeserialize,
+)]
+pub struct StateRoot(pub [u8; 32]);
+
+impl StateRoot {
+    /// Returns the raw bytes of the state root.
+    pub const fn into_inner(self) -> [u8; 32] {
+        self.0
+    }
+}
+
+impl fmt::Display for StateRoot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x{}", hex::encode(self.0))
+    }
+}
+
+impl fmt::Debug for StateRoot {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "StateRoot({})", self)
+    }
+}
+
+impl FromStr for StateRoot {
+    type Err = hex::FromHexError;
+
+    /// Parses a hex string, with or without the `0x` prefix.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("0x").unwrap_or(s);
+        <[u8; 32]>::from_hex(s).map(Self)
+    }
+}
+
+impl Serialize for StateRoot {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StateRoot {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            s.parse().map_err(D::Error::custom)
+        } else {
+            <[u8; 32]>::deserialize(deserializer).map(Self)
+        }
+    }
+}
+
+impl From<[u8; 32]> for StateRoot {
+    fn from(root: [u8; 32]) -> Self {
+        Self(root)
+    }
+}
+
+impl From<StateRoot> for [u8; 32] {
+    fn from(root: StateRoot) -> Self {
+        root.0
+    }
+}
+
+impl AsRef<[u8]> for StateRoot {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hex_roundtrip() {
+        let root = StateRoot([0xab; 32]);
+        let hex = root.to_string();
+        assert_eq!(format!("0x{}", "ab".repeat(32)), hex);
+        assert_eq!(root, hex.parse().unwrap());
+        assert_eq!(root, hex.trim_start_matches("0x").parse().unwrap());
+        assert!("0xab".parse::<StateRoot>().is_err());
+    }
+
+    #[test]
+    fn test_serialization_roundtrip() {
+        let root = StateRoot::from([7; 32]);
+
+        let json = serde_json::to_string(&root).unwrap();
+        assert_eq!(format!("\"{}\"", root), json);
+        assert_eq!(root, serde_json::from_str(&json).unwrap());
+
+        let bytes = root.try_to_vec().unwrap();
+        assert_eq!(root.0.to_vec(), bytes);
+        assert_eq!(root, StateRoot::try_from_slice(&bytes).unwrap());
+        assert_eq!([7; 32], <[u8; 32]>::from(root));
+    }
+}
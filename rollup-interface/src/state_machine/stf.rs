@@ -72,6 +72,9 @@ pub struct BatchReceipt<BatchReceiptContents, TxReceiptContents> {
     pub tx_receipts: Vec<TransactionReceipt<TxReceiptContents>>,
     /// Any additional structured data to be saved in the database and served over RPC
     pub inner: BatchReceiptContents,
+    /// The rollup-defined category of the blob this batch was read from, if the rollup
+    /// tags its blobs with one. Batches are indexed by category in the ledger.
+    pub category: Option<u8>,
 }
 
 /// Result of applying a slot to current state
@@ -124,6 +127,19 @@ pub trait StateTransitionFunction<Vm: Zkvm, Da: DaSpec> {
     /// The validity condition that must be verified outside of the Vm
     type Condition: ValidityCondition;
 
+    /// Decodes the genesis parameters from their canonical encoding, which is JSON unless the
+    /// implementation overrides it.
+    ///
+    /// Nodes and provers should load genesis files through this method, so that they all agree on
+    /// the parameters the chain was initialized with.
+    #[cfg(feature = "std")]
+    fn genesis_from_bytes(bytes: &[u8]) -> anyhow::Result<Self::GenesisParams>
+    where
+        Self::GenesisParams: DeserializeOwned,
+    {
+        Ok(serde_json::from_slice(bytes)?)
+    }
+
     /// Perform one-time initialization for the genesis block and
     /// returns the resulting root hash and changeset.
     /// If the init chain fails we panic.
@@ -202,6 +202,7 @@ impl<B: Arbitrary + 'static, R: Arbitrary + 'static> Arbitrary for BatchReceipt<
                         batch_hash,
                         tx_receipts: txs,
                         inner: receipt,
+                        category: None,
                     }
                 })
                 .boxed()
@@ -73,11 +73,56 @@ pub trait Zkvm: Send + Sync {
 
     /// Same as [`verify`](Zkvm::verify), except that instead of returning the output
     /// as a serialized array, it returns a state transition structure.
-    /// TODO: specify a deserializer for the output
+    ///
+    /// The output is decoded with the encoding used by [`ZkvmGuest::commit`] in this zkVM.
+    /// Use [`verify_and_extract_output_with`](Zkvm::verify_and_extract_output_with)
+    /// if the guest committed its output with a different encoding.
     fn verify_and_extract_output<Da: DaSpec, Root: Serialize + DeserializeOwned>(
         serialized_proof: &[u8],
         code_commitment: &Self::CodeCommitment,
     ) -> Result<StateTransition<Da, Root>, Self::Error>;
+
+    /// Same as [`verify_and_extract_output`](Zkvm::verify_and_extract_output), except that the
+    /// output is decoded with the given [`OutputEncoding`].
+    fn verify_and_extract_output_with<E, Da, Root>(
+        serialized_proof: &[u8],
+        code_commitment: &Self::CodeCommitment,
+    ) -> Result<StateTransition<Da, Root>, Self::Error>
+    where
+        Da: DaSpec,
+        E: OutputEncoding<StateTransition<Da, Root>>,
+        Self::Error: From<E::Error>,
+    {
+        let output = Self::verify(serialized_proof, code_commitment)?;
+        Ok(E::decode(output)?)
+    }
+
+    /// Whether proofs can be verified in the current environment. Some implementations can only
+    /// verify proofs in specific environments (e.g. inside the zkVM itself), and may panic when
+    /// [`verify`](Zkvm::verify) is called elsewhere, so callers should check this first.
+    fn supports_verification() -> bool {
+        true
+    }
+}
+
+/// An encoding of the outputs committed by a zkVM program.
+pub trait OutputEncoding<T> {
+    /// The error returned when an output can't be decoded.
+    type Error;
+
+    /// Decodes an output committed by a zkVM program.
+    fn decode(output: &[u8]) -> Result<T, Self::Error>;
+}
+
+/// An [`OutputEncoding`] for outputs committed as borsh.
+pub struct BorshEncoding;
+
+impl<T: BorshDeserialize> OutputEncoding<T> for BorshEncoding {
+    type Error = borsh::maybestd::io::Error;
+
+    fn decode(output: &[u8]) -> Result<T, Self::Error> {
+        T::try_from_slice(output)
+    }
 }
 
 /// A trait which is accessible from within a zkVM program.
@@ -107,6 +152,20 @@ pub trait ValidityCondition:
     /// Combine two conditions into one (typically run inside a recursive proof).
     /// Returns an error if the two conditions cannot be combined
     fn combine<H: Digest>(&self, rhs: Self) -> Result<Self, Self::Error>;
+
+    /// Combine a sequence of conditions into one, folding from left to right with
+    /// [`ValidityCondition::combine`]. Returns `Ok(None)` if the sequence is empty.
+    fn combine_all<H: Digest>(
+        conditions: impl IntoIterator<Item = Self>,
+    ) -> Result<Option<Self>, Self::Error> {
+        let mut conditions = conditions.into_iter();
+        let Some(first) = conditions.next() else {
+            return Ok(None);
+        };
+        conditions
+            .try_fold(first, |combined, next| combined.combine::<H>(next))
+            .map(Some)
+    }
 }
 
 /// The public output of a SNARK proof in Sovereign, this struct makes a claim that
@@ -129,6 +188,80 @@ pub struct StateTransition<Da: DaSpec, Root> {
     pub validity_condition: Da::ValidityCondition,
 }
 
+/// The reason a sequence of [`StateTransition`]s doesn't form a chain, returned by
+/// [`verify_transition_chain`].
+#[derive(Debug)]
+pub enum TransitionChainError<E> {
+    /// The sequence contains no transitions.
+    Empty,
+    /// The initial state root of the transition at `index` differs from the final state root
+    /// of the transition before it.
+    RootMismatch {
+        /// The position of the first transition which doesn't continue the chain.
+        index: usize,
+    },
+    /// The validity condition of the transition at `index` can't be combined with the
+    /// conditions of the transitions before it.
+    IncompatibleConditions {
+        /// The position of the transition whose condition couldn't be combined.
+        index: usize,
+        /// The error returned by [`ValidityCondition::combine`].
+        error: E,
+    },
+}
+
+impl<E: core::fmt::Display> core::fmt::Display for TransitionChainError<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            TransitionChainError::Empty => write!(f, "the transition chain is empty"),
+            TransitionChainError::RootMismatch { index } => write!(
+                f,
+                "the initial state root of transition {} doesn't match the final state root of transition {}",
+                index,
+                index - 1
+            ),
+            TransitionChainError::IncompatibleConditions { index, error } => write!(
+                f,
+                "the validity condition of transition {} can't be combined: {}",
+                index, error
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl<E: core::fmt::Debug + core::fmt::Display> std::error::Error for TransitionChainError<E> {}
+
+/// Checks that `transitions` are contiguous, i.e. every transition starts from the final state
+/// root of the one before it, and returns the combination of all their validity conditions.
+///
+/// This is what an aggregated proof over a range of slots has to check before it can claim
+/// the transition from the first initial state root to the last final state root.
+pub fn verify_transition_chain<Da: DaSpec, Root: PartialEq, H: Digest>(
+    transitions: &[StateTransition<Da, Root>],
+) -> Result<
+    Da::ValidityCondition,
+    TransitionChainError<<Da::ValidityCondition as ValidityCondition>::Error>,
+> {
+    let (first, rest) = transitions
+        .split_first()
+        .ok_or(TransitionChainError::Empty)?;
+
+    let mut combined = first.validity_condition;
+    let mut previous = first;
+    for (offset, transition) in rest.iter().enumerate() {
+        let index = offset + 1;
+        if transition.initial_state_root != previous.final_state_root {
+            return Err(TransitionChainError::RootMismatch { index });
+        }
+        combined = combined
+            .combine::<H>(transition.validity_condition)
+            .map_err(|error| TransitionChainError::IncompatibleConditions { index, error })?;
+        previous = transition;
+    }
+    Ok(combined)
+}
+
 /// This trait expresses that a type can check a validity condition.
 pub trait ValidityConditionChecker<Condition: ValidityCondition>:
     BorshDeserialize + BorshSerialize + Debug
@@ -15,7 +15,6 @@ use sov_modules_api::{Address, AddressBech32, EncodeCall, PrivateKey, PublicKey,
 use sov_rollup_interface::da::{BlockHeaderTrait, DaSpec, DaVerifier, Time};
 use sov_rollup_interface::services::da::{DaService, SlotData};
 
-const DEFAULT_CHAIN_ID: u64 = 0;
 const DEFAULT_GAS_TIP: u64 = 0;
 const DEFAULT_GAS_LIMIT: u64 = 0;
 
@@ -35,12 +34,14 @@ pub fn sender_address_with_pkey() -> (Address, DefaultPrivateKey) {
 
 #[derive(Clone, Default)]
 /// A simple [`DaService`] for a random number generator.
-pub struct RngDaService;
+pub struct RngDaService {
+    chain_id: u64,
+}
 
 impl RngDaService {
-    /// Instantiates a new [`RngDaService`].
-    pub fn new() -> Self {
-        RngDaService
+    /// Instantiates a new [`RngDaService`], whose transactions are signed for `chain_id`.
+    pub fn new(chain_id: u64) -> Self {
+        RngDaService { chain_id }
     }
 }
 
@@ -94,7 +95,7 @@ impl DaService for RngDaService {
                 height,
                 time: Time::now(),
             },
-            validity_cond: MockValidityCond { is_valid: true },
+            validity_cond: MockValidityCond::default(),
             blobs: Default::default(),
         };
 
@@ -130,14 +131,18 @@ impl DaService for RngDaService {
 
         let data = if block.header().height() == 1 {
             // creating the token
-            generate_create_token_payload(0)
+            generate_create_token_payload(0, self.chain_id)
         } else {
             // generating the transfer transactions
-            generate_transfers(num_txns, (block.header.height() - 2) * (num_txns as u64))
+            generate_transfers(
+                num_txns,
+                (block.header.height() - 2) * (num_txns as u64),
+                self.chain_id,
+            )
         };
 
         let address = MockAddress::from(MOCK_SEQUENCER_DA_ADDRESS);
-        let blob = MockBlob::new(data, address, [0u8; 32]);
+        let blob = MockBlob::with_data_hash(data, address);
 
         vec![blob]
     }
@@ -157,6 +162,13 @@ impl DaService for RngDaService {
         unimplemented!()
     }
 
+    async fn wait_for_finalization(
+        &self,
+        _tx_id: &Self::TransactionId,
+    ) -> Result<u64, Self::Error> {
+        anyhow::bail!("The RNG DA service doesn't accept transactions, so none can be finalized")
+    }
+
     async fn send_aggregated_zk_proof(&self, _proof: &[u8]) -> Result<u64, Self::Error> {
         unimplemented!()
     }
@@ -183,11 +195,11 @@ impl DaVerifier for RngDaVerifier {
         _inclusion_proof: <Self::Spec as DaSpec>::InclusionMultiProof,
         _completeness_proof: <Self::Spec as DaSpec>::CompletenessProof,
     ) -> Result<<Self::Spec as DaSpec>::ValidityCondition, Self::Error> {
-        Ok(MockValidityCond { is_valid: true })
+        Ok(MockValidityCond::default())
     }
 }
 
-pub fn generate_transfers(n: usize, start_nonce: u64) -> Vec<u8> {
+pub fn generate_transfers(n: usize, start_nonce: u64, chain_id: u64) -> Vec<u8> {
     let token_name = "sov-test-token";
     let (sa, pk) = sender_address_with_pkey();
     let token_address = sov_bank::get_token_address::<DefaultContext>(token_name, sa.as_ref(), 11);
@@ -210,7 +222,7 @@ pub fn generate_transfers(n: usize, start_nonce: u64) -> Vec<u8> {
         let tx = Transaction::<DefaultContext>::new_signed_tx(
             &pk,
             enc_msg,
-            DEFAULT_CHAIN_ID,
+            chain_id,
             DEFAULT_GAS_TIP,
             DEFAULT_GAS_LIMIT,
             start_nonce + (i as u64),
@@ -221,7 +233,7 @@ pub fn generate_transfers(n: usize, start_nonce: u64) -> Vec<u8> {
     message_vec.try_to_vec().unwrap()
 }
 
-pub fn generate_create_token_payload(start_nonce: u64) -> Vec<u8> {
+pub fn generate_create_token_payload(start_nonce: u64, chain_id: u64) -> Vec<u8> {
     let mut message_vec = vec![];
 
     let (minter_address, pk) = sender_address_with_pkey();
@@ -238,7 +250,7 @@ pub fn generate_create_token_payload(start_nonce: u64) -> Vec<u8> {
     let tx = Transaction::<DefaultContext>::new_signed_tx(
         &pk,
         enc_msg,
-        DEFAULT_CHAIN_ID,
+        chain_id,
         DEFAULT_GAS_TIP,
         DEFAULT_GAS_LIMIT,
         start_nonce,
@@ -1,3 +1,6 @@
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
 use risc0_zkvm_platform::syscall::SyscallName;
 
 pub fn get_syscall_name() -> SyscallName {
@@ -6,14 +9,125 @@ pub fn get_syscall_name() -> SyscallName {
     SyscallName::from_bytes_with_nul(bytes.as_ptr())
 }
 
+/// Serializes a labeled cycle count as the label, a null separator and the count as 8
+/// little-endian bytes, which is the payload format of the `cycle_metrics` syscall.
+pub fn encode_labeled_cycles(label: &str, cycles: u64) -> Vec<u8> {
+    let mut serialized = Vec::with_capacity(label.len() + 1 + std::mem::size_of::<u64>());
+    serialized.extend(label.as_bytes());
+    serialized.push(0);
+    serialized.extend(cycles.to_le_bytes());
+    serialized
+}
+
+/// Parses a cycle count payload sent by the guest. The count may be preceded by a
+/// null-terminated label, as produced by [`encode_labeled_cycles`].
+///
+/// Returns `None` if the payload is malformed.
+pub fn decode_cycle_payload(input: &[u8]) -> Option<(Option<&str>, u64)> {
+    let (label, count_bytes) = match input.len().checked_sub(std::mem::size_of::<u64>())? {
+        0 => (None, input),
+        split => {
+            let (label_with_null, count_bytes) = input.split_at(split);
+            let (label, null) = label_with_null.split_at(split - 1);
+            if null != [0] {
+                return None;
+            }
+            (Some(std::str::from_utf8(label).ok()?), count_bytes)
+        }
+    };
+    let count = u64::from_le_bytes(count_bytes.try_into().ok()?);
+    Some((label, count))
+}
+
+/// The cycle statistics of one labeled region, accumulated by a [`CycleAggregator`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RegionCycles {
+    /// How many samples were recorded.
+    pub count: u64,
+    /// The sum of the cycles of all samples.
+    pub total: u64,
+    /// The largest sample.
+    pub max: u64,
+}
+
+/// Accumulates the labeled cycle counts reported by the guest during a run.
+#[derive(Debug, Default)]
+pub struct CycleAggregator {
+    regions: BTreeMap<String, RegionCycles>,
+    verbose: bool,
+}
+
+impl CycleAggregator {
+    /// Creates an empty aggregator which doesn't print the individual samples.
+    pub const fn new() -> Self {
+        Self {
+            regions: BTreeMap::new(),
+            verbose: false,
+        }
+    }
+
+    /// Also print every sample as it is recorded.
+    pub fn set_verbose(&mut self, verbose: bool) {
+        self.verbose = verbose;
+    }
+
+    /// Adds a sample of `cycles` to the region named `label`.
+    pub fn record(&mut self, label: &str, cycles: u64) {
+        if self.verbose {
+            println!("== syscall ==> {}: {}", label, cycles);
+        }
+        let region = self.regions.entry(label.to_string()).or_default();
+        region.count += 1;
+        region.total = region.total.saturating_add(cycles);
+        region.max = region.max.max(cycles);
+    }
+
+    /// Returns the statistics of the region named `label`, if any sample was recorded for it.
+    pub fn region(&self, label: &str) -> Option<&RegionCycles> {
+        self.regions.get(label)
+    }
+
+    /// Formats a table of all the regions, by decreasing total cycles.
+    pub fn summary(&self) -> String {
+        let mut regions: Vec<_> = self.regions.iter().collect();
+        regions.sort_by(|(_, a), (_, b)| b.total.cmp(&a.total));
+
+        let width = regions
+            .iter()
+            .map(|(label, _)| label.len())
+            .chain(["Region".len()])
+            .max()
+            .unwrap_or_default();
+        let mut table = format!(
+            "{:<width$} | {:>12} | {:>8} | {:>12}\n",
+            "Region", "Total", "Count", "Max"
+        );
+        for (label, region) in regions {
+            table.push_str(&format!(
+                "{:<width$} | {:>12} | {:>8} | {:>12}\n",
+                label, region.total, region.count, region.max
+            ));
+        }
+        table
+    }
+}
+
+/// The aggregator of the labeled samples reported by the guest. The samples of [`CycleScope`]s
+/// are sent through the `cycle_metrics` syscall, so it is fed by the host's callback for that
+/// syscall, as well as by [`cycle_count_callback`].
+pub static CYCLE_AGGREGATOR: Mutex<CycleAggregator> = Mutex::new(CycleAggregator::new());
+
+/// Records the labeled cycle counts sent by the guest in [`CYCLE_AGGREGATOR`]. Unlabeled
+/// counts, sent by [`print_cycle_count`], are absolute, so they are only printed if the
+/// aggregator is verbose.
 #[cfg(feature = "native")]
 pub fn cycle_count_callback(input: risc0_zkvm::Bytes) -> risc0_zkvm::Result<risc0_zkvm::Bytes> {
-    if input.len() == std::mem::size_of::<usize>() {
-        let mut array = [0u8; std::mem::size_of::<usize>()];
-        array.copy_from_slice(&input);
-        println!("== syscall ==> {}", usize::from_le_bytes(array));
-    } else {
-        println!("NONE");
+    let mut aggregator = CYCLE_AGGREGATOR.lock().unwrap_or_else(|e| e.into_inner());
+    match decode_cycle_payload(&input) {
+        Some((Some(label), count)) => aggregator.record(label, count),
+        Some((None, count)) if aggregator.verbose => println!("== syscall ==> {}", count),
+        Some((None, _)) => {}
+        None => println!("NONE"),
     }
     Ok(risc0_zkvm::Bytes::new())
 }
@@ -29,3 +143,99 @@ pub fn print_cycle_count() {
     let serialized = (risc0_zkvm::guest::env::get_cycle_count() as u64).to_le_bytes();
     risc0_zkvm::guest::env::send_recv_slice::<u8, u8>(metrics_syscall_name, &serialized);
 }
+
+/// A guard which measures the cycles spent while it is alive. When dropped, it reports the
+/// cycle delta under its label through the `cycle_metrics` syscall, so the host can attribute
+/// the cycles to a named region of the guest program.
+///
+/// See also the [`cycle_span`] macro, which wraps a block in a scope.
+pub struct CycleScope {
+    label: &'static str,
+    start: u64,
+}
+
+impl CycleScope {
+    /// Starts measuring the cycles of the region named `label`.
+    pub fn new(label: &'static str) -> Self {
+        Self {
+            label,
+            start: risc0_zkvm::guest::env::get_cycle_count() as u64,
+        }
+    }
+}
+
+impl Drop for CycleScope {
+    fn drop(&mut self) {
+        let end = risc0_zkvm::guest::env::get_cycle_count() as u64;
+        let serialized = encode_labeled_cycles(self.label, end - self.start);
+        risc0_zkvm::guest::env::send_recv_slice::<u8, u8>(get_syscall_name(), &serialized);
+    }
+}
+
+/// Evaluates a block within a [`CycleScope`] named `label`, and returns the value of the block.
+///
+/// ```rust,ignore
+/// let root = cycle_span!("compute_root", { tree.root() });
+/// ```
+#[macro_export]
+macro_rules! cycle_span {
+    ($label:expr, $body:block) => {{
+        let _scope = $crate::CycleScope::new($label);
+        $body
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_labeled_payload() {
+        let payload = encode_labeled_cycles("apply_blob", 1234);
+        assert_eq!(
+            Some((Some("apply_blob"), 1234)),
+            decode_cycle_payload(&payload)
+        );
+
+        let unlabeled = 42u64.to_le_bytes();
+        assert_eq!(Some((None, 42)), decode_cycle_payload(&unlabeled));
+
+        // The label must be followed by a null separator.
+        let mut missing_separator = b"apply_blob".to_vec();
+        missing_separator.extend(7u64.to_le_bytes());
+        assert_eq!(None, decode_cycle_payload(&missing_separator));
+        assert_eq!(None, decode_cycle_payload(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_aggregate_labeled_samples() {
+        let mut aggregator = CycleAggregator::new();
+        for (label, cycles) in [("apply_blob", 100), ("verify", 30), ("apply_blob", 250)] {
+            aggregator.record(label, cycles);
+        }
+
+        assert_eq!(
+            Some(&RegionCycles {
+                count: 2,
+                total: 350,
+                max: 250
+            }),
+            aggregator.region("apply_blob")
+        );
+        assert_eq!(
+            Some(&RegionCycles {
+                count: 1,
+                total: 30,
+                max: 30
+            }),
+            aggregator.region("verify")
+        );
+        assert_eq!(None, aggregator.region("begin_slot"));
+
+        let summary = aggregator.summary();
+        let rows: Vec<_> = summary.lines().collect();
+        assert_eq!(3, rows.len());
+        assert!(rows[1].starts_with("apply_blob"));
+        assert!(rows[2].starts_with("verify"));
+    }
+}